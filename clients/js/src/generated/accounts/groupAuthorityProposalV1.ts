@@ -0,0 +1,204 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+import { Key, KeyArgs, getKeySerializer } from '../types';
+
+export type GroupAuthorityProposalV1 =
+  Account<GroupAuthorityProposalV1AccountData>;
+
+export type GroupAuthorityProposalV1AccountData = {
+  key: Key;
+  group: PublicKey;
+  updateAuthority: PublicKey;
+  newUpdateAuthority: PublicKey;
+  payer: PublicKey;
+};
+
+export type GroupAuthorityProposalV1AccountDataArgs = {
+  key: KeyArgs;
+  group: PublicKey;
+  updateAuthority: PublicKey;
+  newUpdateAuthority: PublicKey;
+  payer: PublicKey;
+};
+
+export function getGroupAuthorityProposalV1AccountDataSerializer(): Serializer<
+  GroupAuthorityProposalV1AccountDataArgs,
+  GroupAuthorityProposalV1AccountData
+> {
+  return struct<GroupAuthorityProposalV1AccountData>(
+    [
+      ['key', getKeySerializer()],
+      ['group', publicKeySerializer()],
+      ['updateAuthority', publicKeySerializer()],
+      ['newUpdateAuthority', publicKeySerializer()],
+      ['payer', publicKeySerializer()],
+    ],
+    { description: 'GroupAuthorityProposalV1AccountData' }
+  ) as Serializer<
+    GroupAuthorityProposalV1AccountDataArgs,
+    GroupAuthorityProposalV1AccountData
+  >;
+}
+
+export function deserializeGroupAuthorityProposalV1(
+  rawAccount: RpcAccount
+): GroupAuthorityProposalV1 {
+  return deserializeAccount(
+    rawAccount,
+    getGroupAuthorityProposalV1AccountDataSerializer()
+  );
+}
+
+export async function fetchGroupAuthorityProposalV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<GroupAuthorityProposalV1> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'GroupAuthorityProposalV1');
+  return deserializeGroupAuthorityProposalV1(maybeAccount);
+}
+
+export async function safeFetchGroupAuthorityProposalV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<GroupAuthorityProposalV1 | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists
+    ? deserializeGroupAuthorityProposalV1(maybeAccount)
+    : null;
+}
+
+export async function fetchAllGroupAuthorityProposalV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<GroupAuthorityProposalV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'GroupAuthorityProposalV1');
+    return deserializeGroupAuthorityProposalV1(maybeAccount);
+  });
+}
+
+export async function safeFetchAllGroupAuthorityProposalV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<GroupAuthorityProposalV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) =>
+      deserializeGroupAuthorityProposalV1(maybeAccount as RpcAccount)
+    );
+}
+
+export function getGroupAuthorityProposalV1GpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'mplCore',
+    'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      key: KeyArgs;
+      group: PublicKey;
+      updateAuthority: PublicKey;
+      newUpdateAuthority: PublicKey;
+      payer: PublicKey;
+    }>({
+      key: [0, getKeySerializer()],
+      group: [1, publicKeySerializer()],
+      updateAuthority: [33, publicKeySerializer()],
+      newUpdateAuthority: [65, publicKeySerializer()],
+      payer: [97, publicKeySerializer()],
+    })
+    .deserializeUsing<GroupAuthorityProposalV1>((account) =>
+      deserializeGroupAuthorityProposalV1(account)
+    );
+}
+
+export function getGroupAuthorityProposalV1Size(): number {
+  return 129;
+}
+
+export function findGroupAuthorityProposalV1Pda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** The address of the group account */
+    group: PublicKey;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'mplCore',
+    'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('mpl-core-group-authority-proposal'),
+    publicKeySerializer().serialize(seeds.group),
+  ]);
+}
+
+export async function fetchGroupAuthorityProposalV1FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findGroupAuthorityProposalV1Pda>[1],
+  options?: RpcGetAccountOptions
+): Promise<GroupAuthorityProposalV1> {
+  return fetchGroupAuthorityProposalV1(
+    context,
+    findGroupAuthorityProposalV1Pda(context, seeds),
+    options
+  );
+}
+
+export async function safeFetchGroupAuthorityProposalV1FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findGroupAuthorityProposalV1Pda>[1],
+  options?: RpcGetAccountOptions
+): Promise<GroupAuthorityProposalV1 | null> {
+  return safeFetchGroupAuthorityProposalV1(
+    context,
+    findGroupAuthorityProposalV1Pda(context, seeds),
+    options
+  );
+}
@@ -0,0 +1,196 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+import { Key, KeyArgs, getKeySerializer } from '../types';
+
+export type GroupDelegateV1 = Account<GroupDelegateV1AccountData>;
+
+export type GroupDelegateV1AccountData = {
+  key: Key;
+  group: PublicKey;
+  delegate: PublicKey;
+  updateAuthority: PublicKey;
+};
+
+export type GroupDelegateV1AccountDataArgs = {
+  key: KeyArgs;
+  group: PublicKey;
+  delegate: PublicKey;
+  updateAuthority: PublicKey;
+};
+
+export function getGroupDelegateV1AccountDataSerializer(): Serializer<
+  GroupDelegateV1AccountDataArgs,
+  GroupDelegateV1AccountData
+> {
+  return struct<GroupDelegateV1AccountData>(
+    [
+      ['key', getKeySerializer()],
+      ['group', publicKeySerializer()],
+      ['delegate', publicKeySerializer()],
+      ['updateAuthority', publicKeySerializer()],
+    ],
+    { description: 'GroupDelegateV1AccountData' }
+  ) as Serializer<GroupDelegateV1AccountDataArgs, GroupDelegateV1AccountData>;
+}
+
+export function deserializeGroupDelegateV1(
+  rawAccount: RpcAccount
+): GroupDelegateV1 {
+  return deserializeAccount(
+    rawAccount,
+    getGroupDelegateV1AccountDataSerializer()
+  );
+}
+
+export async function fetchGroupDelegateV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<GroupDelegateV1> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'GroupDelegateV1');
+  return deserializeGroupDelegateV1(maybeAccount);
+}
+
+export async function safeFetchGroupDelegateV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<GroupDelegateV1 | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists ? deserializeGroupDelegateV1(maybeAccount) : null;
+}
+
+export async function fetchAllGroupDelegateV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<GroupDelegateV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'GroupDelegateV1');
+    return deserializeGroupDelegateV1(maybeAccount);
+  });
+}
+
+export async function safeFetchAllGroupDelegateV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<GroupDelegateV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) =>
+      deserializeGroupDelegateV1(maybeAccount as RpcAccount)
+    );
+}
+
+export function getGroupDelegateV1GpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'mplCore',
+    'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      key: KeyArgs;
+      group: PublicKey;
+      delegate: PublicKey;
+      updateAuthority: PublicKey;
+    }>({
+      key: [0, getKeySerializer()],
+      group: [1, publicKeySerializer()],
+      delegate: [33, publicKeySerializer()],
+      updateAuthority: [65, publicKeySerializer()],
+    })
+    .deserializeUsing<GroupDelegateV1>((account) =>
+      deserializeGroupDelegateV1(account)
+    );
+}
+
+export function getGroupDelegateV1Size(): number {
+  return 97;
+}
+
+export function findGroupDelegateV1Pda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** The address of the group account */
+    group: PublicKey;
+    /** The address of the delegate */
+    delegate: PublicKey;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'mplCore',
+    'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('mpl-core-group-delegate'),
+    publicKeySerializer().serialize(seeds.group),
+    publicKeySerializer().serialize(seeds.delegate),
+  ]);
+}
+
+export async function fetchGroupDelegateV1FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findGroupDelegateV1Pda>[1],
+  options?: RpcGetAccountOptions
+): Promise<GroupDelegateV1> {
+  return fetchGroupDelegateV1(
+    context,
+    findGroupDelegateV1Pda(context, seeds),
+    options
+  );
+}
+
+export async function safeFetchGroupDelegateV1FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findGroupDelegateV1Pda>[1],
+  options?: RpcGetAccountOptions
+): Promise<GroupDelegateV1 | null> {
+  return safeFetchGroupDelegateV1(
+    context,
+    findGroupDelegateV1Pda(context, seeds),
+    options
+  );
+}
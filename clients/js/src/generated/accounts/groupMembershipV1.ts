@@ -0,0 +1,192 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+import { Key, KeyArgs, getKeySerializer } from '../types';
+
+export type GroupMembershipV1 = Account<GroupMembershipV1AccountData>;
+
+export type GroupMembershipV1AccountData = {
+  key: Key;
+  group: PublicKey;
+  member: PublicKey;
+};
+
+export type GroupMembershipV1AccountDataArgs = {
+  key: KeyArgs;
+  group: PublicKey;
+  member: PublicKey;
+};
+
+export function getGroupMembershipV1AccountDataSerializer(): Serializer<
+  GroupMembershipV1AccountDataArgs,
+  GroupMembershipV1AccountData
+> {
+  return struct<GroupMembershipV1AccountData>(
+    [
+      ['key', getKeySerializer()],
+      ['group', publicKeySerializer()],
+      ['member', publicKeySerializer()],
+    ],
+    { description: 'GroupMembershipV1AccountData' }
+  ) as Serializer<
+    GroupMembershipV1AccountDataArgs,
+    GroupMembershipV1AccountData
+  >;
+}
+
+export function deserializeGroupMembershipV1(
+  rawAccount: RpcAccount
+): GroupMembershipV1 {
+  return deserializeAccount(
+    rawAccount,
+    getGroupMembershipV1AccountDataSerializer()
+  );
+}
+
+export async function fetchGroupMembershipV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<GroupMembershipV1> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'GroupMembershipV1');
+  return deserializeGroupMembershipV1(maybeAccount);
+}
+
+export async function safeFetchGroupMembershipV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<GroupMembershipV1 | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists
+    ? deserializeGroupMembershipV1(maybeAccount)
+    : null;
+}
+
+export async function fetchAllGroupMembershipV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<GroupMembershipV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'GroupMembershipV1');
+    return deserializeGroupMembershipV1(maybeAccount);
+  });
+}
+
+export async function safeFetchAllGroupMembershipV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<GroupMembershipV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) =>
+      deserializeGroupMembershipV1(maybeAccount as RpcAccount)
+    );
+}
+
+export function getGroupMembershipV1GpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'mplCore',
+    'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{ key: KeyArgs; group: PublicKey; member: PublicKey }>({
+      key: [0, getKeySerializer()],
+      group: [1, publicKeySerializer()],
+      member: [33, publicKeySerializer()],
+    })
+    .deserializeUsing<GroupMembershipV1>((account) =>
+      deserializeGroupMembershipV1(account)
+    );
+}
+
+export function getGroupMembershipV1Size(): number {
+  return 65;
+}
+
+export function findGroupMembershipV1Pda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** The address of the group account */
+    group: PublicKey;
+    /** The address of the member asset account */
+    member: PublicKey;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'mplCore',
+    'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('mpl-core-group-membership'),
+    publicKeySerializer().serialize(seeds.group),
+    publicKeySerializer().serialize(seeds.member),
+  ]);
+}
+
+export async function fetchGroupMembershipV1FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findGroupMembershipV1Pda>[1],
+  options?: RpcGetAccountOptions
+): Promise<GroupMembershipV1> {
+  return fetchGroupMembershipV1(
+    context,
+    findGroupMembershipV1Pda(context, seeds),
+    options
+  );
+}
+
+export async function safeFetchGroupMembershipV1FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findGroupMembershipV1Pda>[1],
+  options?: RpcGetAccountOptions
+): Promise<GroupMembershipV1 | null> {
+  return safeFetchGroupMembershipV1(
+    context,
+    findGroupMembershipV1Pda(context, seeds),
+    options
+  );
+}
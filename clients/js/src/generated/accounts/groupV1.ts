@@ -23,6 +23,7 @@ import {
   array,
   publicKey as publicKeySerializer,
   string,
+  u32,
 } from '@metaplex-foundation/umi/serializers';
 import {
   GroupV1AccountData,
@@ -107,6 +108,7 @@ export function getGroupV1GpaBuilder(
       groups: Array<PublicKey>;
       parentGroups: Array<PublicKey>;
       assets: Array<PublicKey>;
+      membershipCount: number;
     }>({
       key: [0, getKeySerializer()],
       updateAuthority: [1, publicKeySerializer()],
@@ -116,6 +118,7 @@ export function getGroupV1GpaBuilder(
       groups: [null, array(publicKeySerializer())],
       parentGroups: [null, array(publicKeySerializer())],
       assets: [null, array(publicKeySerializer())],
+      membershipCount: [null, u32()],
     })
     .deserializeUsing<GroupV1>((account) => deserializeGroupV1(account))
     .whereField('key', Key.GroupV1);
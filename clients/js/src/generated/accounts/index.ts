@@ -9,6 +9,9 @@
 export * from './assetSigner';
 export * from './assetV1';
 export * from './collectionV1';
+export * from './groupAuthorityProposalV1';
+export * from './groupDelegateV1';
+export * from './groupMembershipV1';
 export * from './groupV1';
 export * from './hashedAssetV1';
 export * from './pluginHeaderV1';
@@ -824,6 +824,177 @@ nameToErrorMap.set(
   InconsistentGroupRelationshipError
 );
 
+/** InvalidBatchAccounts: Invalid accounts provided for batch operation */
+export class InvalidBatchAccountsError extends ProgramError {
+  override readonly name: string = 'InvalidBatchAccounts';
+
+  readonly code: number = 0x39; // 57
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid accounts provided for batch operation', program, cause);
+  }
+}
+codeToErrorMap.set(0x39, InvalidBatchAccountsError);
+nameToErrorMap.set('InvalidBatchAccounts', InvalidBatchAccountsError);
+
+/** InvalidMultisig: Invalid multisig signers or threshold */
+export class InvalidMultisigError extends ProgramError {
+  override readonly name: string = 'InvalidMultisig';
+
+  readonly code: number = 0x3a; // 58
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid multisig signers or threshold', program, cause);
+  }
+}
+codeToErrorMap.set(0x3a, InvalidMultisigError);
+nameToErrorMap.set('InvalidMultisig', InvalidMultisigError);
+
+/** MaxSupplyReached: The maximum supply of editions has been printed */
+export class MaxSupplyReachedError extends ProgramError {
+  override readonly name: string = 'MaxSupplyReached';
+
+  readonly code: number = 0x3b; // 59
+
+  constructor(program: Program, cause?: Error) {
+    super('The maximum supply of editions has been printed', program, cause);
+  }
+}
+codeToErrorMap.set(0x3b, MaxSupplyReachedError);
+nameToErrorMap.set('MaxSupplyReached', MaxSupplyReachedError);
+
+/** ProgramNotAllowed: The program is not allowed by the execute guard */
+export class ProgramNotAllowedError extends ProgramError {
+  override readonly name: string = 'ProgramNotAllowed';
+
+  readonly code: number = 0x3c; // 60
+
+  constructor(program: Program, cause?: Error) {
+    super('The program is not allowed by the execute guard', program, cause);
+  }
+}
+codeToErrorMap.set(0x3c, ProgramNotAllowedError);
+nameToErrorMap.set('ProgramNotAllowed', ProgramNotAllowedError);
+
+/** SpendLimitExceeded: The asset signer spent more than the execute guard allows */
+export class SpendLimitExceededError extends ProgramError {
+  override readonly name: string = 'SpendLimitExceeded';
+
+  readonly code: number = 0x3d; // 61
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'The asset signer spent more than the execute guard allows',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x3d, SpendLimitExceededError);
+nameToErrorMap.set('SpendLimitExceeded', SpendLimitExceededError);
+
+/** InvalidGroupMembership: Invalid group membership account */
+export class InvalidGroupMembershipError extends ProgramError {
+  override readonly name: string = 'InvalidGroupMembership';
+
+  readonly code: number = 0x3e; // 62
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid group membership account', program, cause);
+  }
+}
+codeToErrorMap.set(0x3e, InvalidGroupMembershipError);
+nameToErrorMap.set('InvalidGroupMembership', InvalidGroupMembershipError);
+
+/** InvalidGroupDelegate: Invalid group delegate account */
+export class InvalidGroupDelegateError extends ProgramError {
+  override readonly name: string = 'InvalidGroupDelegate';
+
+  readonly code: number = 0x3f; // 63
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid group delegate account', program, cause);
+  }
+}
+codeToErrorMap.set(0x3f, InvalidGroupDelegateError);
+nameToErrorMap.set('InvalidGroupDelegate', InvalidGroupDelegateError);
+
+/** InvalidGroupAuthorityProposal: Invalid group authority proposal account */
+export class InvalidGroupAuthorityProposalError extends ProgramError {
+  override readonly name: string = 'InvalidGroupAuthorityProposal';
+
+  readonly code: number = 0x40; // 64
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid group authority proposal account', program, cause);
+  }
+}
+codeToErrorMap.set(0x40, InvalidGroupAuthorityProposalError);
+nameToErrorMap.set(
+  'InvalidGroupAuthorityProposal',
+  InvalidGroupAuthorityProposalError
+);
+
+/** GroupCycleDetected: Adding the group would create a cycle in the group hierarchy */
+export class GroupCycleDetectedError extends ProgramError {
+  override readonly name: string = 'GroupCycleDetected';
+
+  readonly code: number = 0x41; // 65
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Adding the group would create a cycle in the group hierarchy',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x41, GroupCycleDetectedError);
+nameToErrorMap.set('GroupCycleDetected', GroupCycleDetectedError);
+
+/** InvalidGroupAncestry: Group ancestry proof is incomplete or invalid */
+export class InvalidGroupAncestryError extends ProgramError {
+  override readonly name: string = 'InvalidGroupAncestry';
+
+  readonly code: number = 0x42; // 66
+
+  constructor(program: Program, cause?: Error) {
+    super('Group ancestry proof is incomplete or invalid', program, cause);
+  }
+}
+codeToErrorMap.set(0x42, InvalidGroupAncestryError);
+nameToErrorMap.set('InvalidGroupAncestry', InvalidGroupAncestryError);
+
+/** MissingGroupAccount: Missing group account required by the Groups plugin */
+export class MissingGroupAccountError extends ProgramError {
+  override readonly name: string = 'MissingGroupAccount';
+
+  readonly code: number = 0x43; // 67
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Missing group account required by the Groups plugin',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x43, MissingGroupAccountError);
+nameToErrorMap.set('MissingGroupAccount', MissingGroupAccountError);
+
+/** GroupFrozen: Group is frozen */
+export class GroupFrozenError extends ProgramError {
+  override readonly name: string = 'GroupFrozen';
+
+  readonly code: number = 0x44; // 68
+
+  constructor(program: Program, cause?: Error) {
+    super('Group is frozen', program, cause);
+  }
+}
+codeToErrorMap.set(0x44, GroupFrozenError);
+nameToErrorMap.set('GroupFrozen', GroupFrozenError);
+
 /**
  * Attempts to resolve a custom program error from the provided error code.
  * @category Errors
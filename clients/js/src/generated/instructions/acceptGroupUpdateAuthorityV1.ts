@@ -0,0 +1,159 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findGroupAuthorityProposalV1Pda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type AcceptGroupUpdateAuthorityV1InstructionAccounts = {
+  /** The address of the group */
+  group: PublicKey | Pda;
+  /** The update authority proposal account of the group */
+  groupAuthorityProposal?: PublicKey | Pda;
+  /** The account paying for the storage fees */
+  payer?: Signer;
+  /** The proposed update authority of the group */
+  newUpdateAuthority: Signer;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+  /** The account that paid for the proposal, receiving its reclaimed lamports */
+  proposalPayer: PublicKey | Pda;
+};
+
+// Data.
+export type AcceptGroupUpdateAuthorityV1InstructionData = {
+  discriminator: number;
+};
+
+export type AcceptGroupUpdateAuthorityV1InstructionDataArgs = {};
+
+export function getAcceptGroupUpdateAuthorityV1InstructionDataSerializer(): Serializer<
+  AcceptGroupUpdateAuthorityV1InstructionDataArgs,
+  AcceptGroupUpdateAuthorityV1InstructionData
+> {
+  return mapSerializer<
+    AcceptGroupUpdateAuthorityV1InstructionDataArgs,
+    any,
+    AcceptGroupUpdateAuthorityV1InstructionData
+  >(
+    struct<AcceptGroupUpdateAuthorityV1InstructionData>(
+      [['discriminator', u8()]],
+      { description: 'AcceptGroupUpdateAuthorityV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 55 })
+  ) as Serializer<
+    AcceptGroupUpdateAuthorityV1InstructionDataArgs,
+    AcceptGroupUpdateAuthorityV1InstructionData
+  >;
+}
+
+// Instruction.
+export function acceptGroupUpdateAuthorityV1(
+  context: Pick<Context, 'eddsa' | 'payer' | 'programs'>,
+  input: AcceptGroupUpdateAuthorityV1InstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplCore',
+    'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    group: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.group ?? null,
+    },
+    groupAuthorityProposal: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.groupAuthorityProposal ?? null,
+    },
+    payer: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    newUpdateAuthority: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.newUpdateAuthority ?? null,
+    },
+    systemProgram: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+    proposalPayer: {
+      index: 5,
+      isWritable: true as boolean,
+      value: input.proposalPayer ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.groupAuthorityProposal.value) {
+    resolvedAccounts.groupAuthorityProposal.value =
+      findGroupAuthorityProposalV1Pda(context, {
+        group: expectPublicKey(resolvedAccounts.group.value),
+      });
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data =
+    getAcceptGroupUpdateAuthorityV1InstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
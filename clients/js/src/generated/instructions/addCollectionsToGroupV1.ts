@@ -32,7 +32,7 @@ export type AddCollectionsToGroupV1InstructionAccounts = {
   group: PublicKey | Pda;
   /** The account paying for storage fees */
   payer?: Signer;
-  /** The group update authority and collection update authority or delegate */
+  /** The group update authority or delegate and collection update authority or delegate */
   authority?: Signer;
   /** The system program */
   systemProgram?: PublicKey | Pda;
@@ -0,0 +1,156 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+import {
+  GroupPluginInitInfo,
+  GroupPluginInitInfoArgs,
+  getGroupPluginInitInfoSerializer,
+} from '../types';
+
+// Accounts.
+export type AddGroupPluginV1InstructionAccounts = {
+  /** The address of the group */
+  group: PublicKey | Pda;
+  /** The account paying for the storage fees */
+  payer?: Signer;
+  /** The update authority of the group */
+  authority?: Signer;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type AddGroupPluginV1InstructionData = {
+  discriminator: number;
+  initInfo: GroupPluginInitInfo;
+};
+
+export type AddGroupPluginV1InstructionDataArgs = {
+  initInfo: GroupPluginInitInfoArgs;
+};
+
+export function getAddGroupPluginV1InstructionDataSerializer(): Serializer<
+  AddGroupPluginV1InstructionDataArgs,
+  AddGroupPluginV1InstructionData
+> {
+  return mapSerializer<
+    AddGroupPluginV1InstructionDataArgs,
+    any,
+    AddGroupPluginV1InstructionData
+  >(
+    struct<AddGroupPluginV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['initInfo', getGroupPluginInitInfoSerializer()],
+      ],
+      { description: 'AddGroupPluginV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 49 })
+  ) as Serializer<
+    AddGroupPluginV1InstructionDataArgs,
+    AddGroupPluginV1InstructionData
+  >;
+}
+
+// Args.
+export type AddGroupPluginV1InstructionArgs =
+  AddGroupPluginV1InstructionDataArgs;
+
+// Instruction.
+export function addGroupPluginV1(
+  context: Pick<Context, 'payer' | 'programs'>,
+  input: AddGroupPluginV1InstructionAccounts & AddGroupPluginV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplCore',
+    'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    group: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.group ?? null,
+    },
+    payer: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    authority: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    systemProgram: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: AddGroupPluginV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getAddGroupPluginV1InstructionDataSerializer().serialize(
+    resolvedArgs as AddGroupPluginV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
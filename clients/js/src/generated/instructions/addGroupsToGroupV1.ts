@@ -8,16 +8,20 @@
 
 import {
   Context,
+  Option,
+  OptionOrNullable,
   Pda,
   PublicKey,
   Signer,
   TransactionBuilder,
+  none,
   transactionBuilder,
 } from '@metaplex-foundation/umi';
 import {
   Serializer,
   array,
   mapSerializer,
+  option,
   publicKey as publicKeySerializer,
   struct,
   u8,
@@ -44,10 +48,14 @@ export type AddGroupsToGroupV1InstructionAccounts = {
 export type AddGroupsToGroupV1InstructionData = {
   discriminator: number;
   groups: Array<PublicKey>;
+  ancestors: Option<Array<PublicKey>>;
+  descendants: Option<Array<PublicKey>>;
 };
 
 export type AddGroupsToGroupV1InstructionDataArgs = {
   groups: Array<PublicKey>;
+  ancestors?: OptionOrNullable<Array<PublicKey>>;
+  descendants?: OptionOrNullable<Array<PublicKey>>;
 };
 
 export function getAddGroupsToGroupV1InstructionDataSerializer(): Serializer<
@@ -63,10 +71,17 @@ export function getAddGroupsToGroupV1InstructionDataSerializer(): Serializer<
       [
         ['discriminator', u8()],
         ['groups', array(publicKeySerializer())],
+        ['ancestors', option(array(publicKeySerializer()))],
+        ['descendants', option(array(publicKeySerializer()))],
       ],
       { description: 'AddGroupsToGroupV1InstructionData' }
     ),
-    (value) => ({ ...value, discriminator: 37 })
+    (value) => ({
+      ...value,
+      discriminator: 37,
+      ancestors: value.ancestors ?? none(),
+      descendants: value.descendants ?? none(),
+    })
   ) as Serializer<
     AddGroupsToGroupV1InstructionDataArgs,
     AddGroupsToGroupV1InstructionData
@@ -0,0 +1,157 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findGroupDelegateV1Pda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type ApproveGroupDelegateV1InstructionAccounts = {
+  /** The address of the group */
+  group: PublicKey | Pda;
+  /** The delegate account of the group */
+  groupDelegate?: PublicKey | Pda;
+  /** The account paying for the storage fees */
+  payer?: Signer;
+  /** The update authority of the group */
+  authority?: Signer;
+  /** The delegate to approve */
+  delegate: PublicKey | Pda;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type ApproveGroupDelegateV1InstructionData = { discriminator: number };
+
+export type ApproveGroupDelegateV1InstructionDataArgs = {};
+
+export function getApproveGroupDelegateV1InstructionDataSerializer(): Serializer<
+  ApproveGroupDelegateV1InstructionDataArgs,
+  ApproveGroupDelegateV1InstructionData
+> {
+  return mapSerializer<
+    ApproveGroupDelegateV1InstructionDataArgs,
+    any,
+    ApproveGroupDelegateV1InstructionData
+  >(
+    struct<ApproveGroupDelegateV1InstructionData>([['discriminator', u8()]], {
+      description: 'ApproveGroupDelegateV1InstructionData',
+    }),
+    (value) => ({ ...value, discriminator: 52 })
+  ) as Serializer<
+    ApproveGroupDelegateV1InstructionDataArgs,
+    ApproveGroupDelegateV1InstructionData
+  >;
+}
+
+// Instruction.
+export function approveGroupDelegateV1(
+  context: Pick<Context, 'eddsa' | 'payer' | 'programs'>,
+  input: ApproveGroupDelegateV1InstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplCore',
+    'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    group: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.group ?? null,
+    },
+    groupDelegate: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.groupDelegate ?? null,
+    },
+    payer: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    authority: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    delegate: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.delegate ?? null,
+    },
+    systemProgram: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.groupDelegate.value) {
+    resolvedAccounts.groupDelegate.value = findGroupDelegateV1Pda(context, {
+      group: expectPublicKey(resolvedAccounts.group.value),
+      delegate: expectPublicKey(resolvedAccounts.delegate.value),
+    });
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getApproveGroupDelegateV1InstructionDataSerializer().serialize(
+    {}
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,131 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type BurnBatchV1InstructionAccounts = {
+  /** The collection to which the assets belong */
+  collection?: PublicKey | Pda;
+  /** The account paying for the storage fees */
+  payer?: Signer;
+  /** The owner or delegate of the assets */
+  authority?: Signer;
+};
+
+// Data.
+export type BurnBatchV1InstructionData = {
+  discriminator: number;
+  numAssets: number;
+};
+
+export type BurnBatchV1InstructionDataArgs = { numAssets: number };
+
+export function getBurnBatchV1InstructionDataSerializer(): Serializer<
+  BurnBatchV1InstructionDataArgs,
+  BurnBatchV1InstructionData
+> {
+  return mapSerializer<
+    BurnBatchV1InstructionDataArgs,
+    any,
+    BurnBatchV1InstructionData
+  >(
+    struct<BurnBatchV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['numAssets', u8()],
+      ],
+      { description: 'BurnBatchV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 43 })
+  ) as Serializer<BurnBatchV1InstructionDataArgs, BurnBatchV1InstructionData>;
+}
+
+// Args.
+export type BurnBatchV1InstructionArgs = BurnBatchV1InstructionDataArgs;
+
+// Instruction.
+export function burnBatchV1(
+  context: Pick<Context, 'payer' | 'programs'>,
+  input: BurnBatchV1InstructionAccounts & BurnBatchV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplCore',
+    'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    collection: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.collection ?? null,
+    },
+    payer: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    authority: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: BurnBatchV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getBurnBatchV1InstructionDataSerializer().serialize(
+    resolvedArgs as BurnBatchV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
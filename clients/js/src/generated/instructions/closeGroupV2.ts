@@ -0,0 +1,146 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  bool,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type CloseGroupV2InstructionAccounts = {
+  /** The address of the group to close */
+  group: PublicKey | Pda;
+  /** The account receiving reclaimed lamports */
+  payer?: Signer;
+  /** The update authority of the group */
+  authority?: Signer;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type CloseGroupV2InstructionData = {
+  discriminator: number;
+  detachOnly: boolean;
+};
+
+export type CloseGroupV2InstructionDataArgs = { detachOnly: boolean };
+
+export function getCloseGroupV2InstructionDataSerializer(): Serializer<
+  CloseGroupV2InstructionDataArgs,
+  CloseGroupV2InstructionData
+> {
+  return mapSerializer<
+    CloseGroupV2InstructionDataArgs,
+    any,
+    CloseGroupV2InstructionData
+  >(
+    struct<CloseGroupV2InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['detachOnly', bool()],
+      ],
+      { description: 'CloseGroupV2InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 56 })
+  ) as Serializer<CloseGroupV2InstructionDataArgs, CloseGroupV2InstructionData>;
+}
+
+// Args.
+export type CloseGroupV2InstructionArgs = CloseGroupV2InstructionDataArgs;
+
+// Instruction.
+export function closeGroupV2(
+  context: Pick<Context, 'payer' | 'programs'>,
+  input: CloseGroupV2InstructionAccounts & CloseGroupV2InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplCore',
+    'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    group: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.group ?? null,
+    },
+    payer: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    authority: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    systemProgram: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: CloseGroupV2InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getCloseGroupV2InstructionDataSerializer().serialize(
+    resolvedArgs as CloseGroupV2InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,156 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+import {
+  AssetInitInfo,
+  AssetInitInfoArgs,
+  getAssetInitInfoSerializer,
+} from '../types';
+
+// Accounts.
+export type CreateBatchV1InstructionAccounts = {
+  /** The collection to which the assets belong */
+  collection: PublicKey | Pda;
+  /** The authority signing for creation */
+  authority?: Signer;
+  /** The account paying for the storage fees */
+  payer?: Signer;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type CreateBatchV1InstructionData = {
+  discriminator: number;
+  assets: Array<AssetInitInfo>;
+};
+
+export type CreateBatchV1InstructionDataArgs = {
+  assets: Array<AssetInitInfoArgs>;
+};
+
+export function getCreateBatchV1InstructionDataSerializer(): Serializer<
+  CreateBatchV1InstructionDataArgs,
+  CreateBatchV1InstructionData
+> {
+  return mapSerializer<
+    CreateBatchV1InstructionDataArgs,
+    any,
+    CreateBatchV1InstructionData
+  >(
+    struct<CreateBatchV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['assets', array(getAssetInitInfoSerializer())],
+      ],
+      { description: 'CreateBatchV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 44 })
+  ) as Serializer<
+    CreateBatchV1InstructionDataArgs,
+    CreateBatchV1InstructionData
+  >;
+}
+
+// Args.
+export type CreateBatchV1InstructionArgs = CreateBatchV1InstructionDataArgs;
+
+// Instruction.
+export function createBatchV1(
+  context: Pick<Context, 'payer' | 'programs'>,
+  input: CreateBatchV1InstructionAccounts & CreateBatchV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplCore',
+    'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    collection: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.collection ?? null,
+    },
+    authority: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    payer: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    systemProgram: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: CreateBatchV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getCreateBatchV1InstructionDataSerializer().serialize(
+    resolvedArgs as CreateBatchV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,177 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findAssetSignerPda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+import {
+  ExecuteBatchInstruction,
+  ExecuteBatchInstructionArgs,
+  getExecuteBatchInstructionSerializer,
+} from '../types';
+
+// Accounts.
+export type ExecuteBatchV1InstructionAccounts = {
+  /** The address of the asset */
+  asset: PublicKey | Pda;
+  /** The collection to which the asset belongs */
+  collection?: PublicKey | Pda;
+  /** The signing PDA for the asset */
+  assetSigner?: PublicKey | Pda;
+  /** The account paying for the storage fees */
+  payer?: PublicKey | Pda | Signer;
+  /** The owner or delegate of the asset */
+  authority?: Signer;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type ExecuteBatchV1InstructionData = {
+  discriminator: number;
+  instructions: Array<ExecuteBatchInstruction>;
+};
+
+export type ExecuteBatchV1InstructionDataArgs = {
+  instructions: Array<ExecuteBatchInstructionArgs>;
+};
+
+export function getExecuteBatchV1InstructionDataSerializer(): Serializer<
+  ExecuteBatchV1InstructionDataArgs,
+  ExecuteBatchV1InstructionData
+> {
+  return mapSerializer<
+    ExecuteBatchV1InstructionDataArgs,
+    any,
+    ExecuteBatchV1InstructionData
+  >(
+    struct<ExecuteBatchV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['instructions', array(getExecuteBatchInstructionSerializer())],
+      ],
+      { description: 'ExecuteBatchV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 47 })
+  ) as Serializer<
+    ExecuteBatchV1InstructionDataArgs,
+    ExecuteBatchV1InstructionData
+  >;
+}
+
+// Args.
+export type ExecuteBatchV1InstructionArgs = ExecuteBatchV1InstructionDataArgs;
+
+// Instruction.
+export function executeBatchV1(
+  context: Pick<Context, 'eddsa' | 'payer' | 'programs'>,
+  input: ExecuteBatchV1InstructionAccounts & ExecuteBatchV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplCore',
+    'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    asset: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.asset ?? null,
+    },
+    collection: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.collection ?? null,
+    },
+    assetSigner: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.assetSigner ?? null,
+    },
+    payer: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    authority: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    systemProgram: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: ExecuteBatchV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.assetSigner.value) {
+    resolvedAccounts.assetSigner.value = findAssetSignerPda(context, {
+      asset: expectPublicKey(resolvedAccounts.asset.value),
+    });
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getExecuteBatchV1InstructionDataSerializer().serialize(
+    resolvedArgs as ExecuteBatchV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
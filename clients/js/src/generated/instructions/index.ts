@@ -6,42 +6,58 @@
  * @see https://github.com/metaplex-foundation/kinobi
  */
 
+export * from './acceptGroupUpdateAuthorityV1';
 export * from './addAssetsToGroupV1';
 export * from './addCollectionExternalPluginAdapterV1';
 export * from './addCollectionPluginV1';
 export * from './addCollectionsToGroupV1';
 export * from './addExternalPluginAdapterV1';
+export * from './addGroupPluginV1';
 export * from './addGroupsToGroupV1';
 export * from './addPluginV1';
 export * from './approveCollectionPluginAuthorityV1';
+export * from './approveGroupDelegateV1';
 export * from './approvePluginAuthorityV1';
+export * from './burnBatchV1';
 export * from './burnCollectionV1';
 export * from './burnV1';
 export * from './closeGroupV1';
+export * from './closeGroupV2';
 export * from './collect';
 export * from './compressV1';
+export * from './createBatchV1';
 export * from './createCollectionV1';
 export * from './createCollectionV2';
 export * from './createGroupV1';
 export * from './createV1';
 export * from './createV2';
 export * from './decompressV1';
+export * from './executeBatchV1';
+export * from './executeCollectionV1';
 export * from './executeV1';
+export * from './moveToCollectionV1';
+export * from './printEditionV1';
+export * from './proposeGroupUpdateAuthorityV1';
 export * from './removeAssetsFromGroupV1';
 export * from './removeCollectionExternalPluginAdapterV1';
 export * from './removeCollectionPluginV1';
 export * from './removeCollectionsFromGroupV1';
 export * from './removeExternalPluginAdapterV1';
+export * from './removeGroupPluginV1';
 export * from './removeGroupsFromGroupV1';
 export * from './removePluginV1';
 export * from './revokeCollectionPluginAuthorityV1';
+export * from './revokeGroupDelegateV1';
 export * from './revokePluginAuthorityV1';
+export * from './transferBatchV1';
 export * from './transferV1';
+export * from './transferV2';
 export * from './updateCollectionExternalPluginAdapterV1';
 export * from './updateCollectionInfoV1';
 export * from './updateCollectionPluginV1';
 export * from './updateCollectionV1';
 export * from './updateExternalPluginAdapterV1';
+export * from './updateGroupPluginV1';
 export * from './updateGroupV1';
 export * from './updatePluginV1';
 export * from './updateV1';
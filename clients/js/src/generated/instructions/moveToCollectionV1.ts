@@ -0,0 +1,161 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type MoveToCollectionV1InstructionAccounts = {
+  /** The address of the asset */
+  asset: PublicKey | Pda;
+  /** The collection the asset is moved out of */
+  collection: PublicKey | Pda;
+  /** The collection the asset is moved into */
+  newCollection: PublicKey | Pda;
+  /** The account paying for the storage fees */
+  payer?: Signer;
+  /** The update authority or update delegate of the collection */
+  authority?: Signer;
+  /** The update authority or update delegate of the new collection. Defaults to the authority */
+  newCollectionAuthority?: Signer;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+  /** The SPL Noop Program */
+  logWrapper?: PublicKey | Pda;
+};
+
+// Data.
+export type MoveToCollectionV1InstructionData = { discriminator: number };
+
+export type MoveToCollectionV1InstructionDataArgs = {};
+
+export function getMoveToCollectionV1InstructionDataSerializer(): Serializer<
+  MoveToCollectionV1InstructionDataArgs,
+  MoveToCollectionV1InstructionData
+> {
+  return mapSerializer<
+    MoveToCollectionV1InstructionDataArgs,
+    any,
+    MoveToCollectionV1InstructionData
+  >(
+    struct<MoveToCollectionV1InstructionData>([['discriminator', u8()]], {
+      description: 'MoveToCollectionV1InstructionData',
+    }),
+    (value) => ({ ...value, discriminator: 57 })
+  ) as Serializer<
+    MoveToCollectionV1InstructionDataArgs,
+    MoveToCollectionV1InstructionData
+  >;
+}
+
+// Instruction.
+export function moveToCollectionV1(
+  context: Pick<Context, 'payer' | 'programs'>,
+  input: MoveToCollectionV1InstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplCore',
+    'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    asset: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.asset ?? null,
+    },
+    collection: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.collection ?? null,
+    },
+    newCollection: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.newCollection ?? null,
+    },
+    payer: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    authority: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    newCollectionAuthority: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.newCollectionAuthority ?? null,
+    },
+    systemProgram: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+    logWrapper: {
+      index: 7,
+      isWritable: false as boolean,
+      value: input.logWrapper ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getMoveToCollectionV1InstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,194 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Option,
+  OptionOrNullable,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  none,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  option,
+  string,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+import {
+  PluginAuthorityPair,
+  PluginAuthorityPairArgs,
+  getPluginAuthorityPairSerializer,
+} from '../types';
+
+// Accounts.
+export type PrintEditionV1InstructionAccounts = {
+  /** The address of the new edition */
+  asset: Signer;
+  /** The master edition collection */
+  collection: PublicKey | Pda;
+  /** The authority signing for creation */
+  authority?: Signer;
+  /** The account paying for the storage fees */
+  payer?: Signer;
+  /** The owner of the new edition. Defaults to the payer if not present. */
+  owner?: PublicKey | Pda;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+  /** The SPL Noop Program */
+  logWrapper?: PublicKey | Pda;
+};
+
+// Data.
+export type PrintEditionV1InstructionData = {
+  discriminator: number;
+  name: Option<string>;
+  uri: Option<string>;
+  plugins: Option<Array<PluginAuthorityPair>>;
+};
+
+export type PrintEditionV1InstructionDataArgs = {
+  name?: OptionOrNullable<string>;
+  uri?: OptionOrNullable<string>;
+  plugins?: OptionOrNullable<Array<PluginAuthorityPairArgs>>;
+};
+
+export function getPrintEditionV1InstructionDataSerializer(): Serializer<
+  PrintEditionV1InstructionDataArgs,
+  PrintEditionV1InstructionData
+> {
+  return mapSerializer<
+    PrintEditionV1InstructionDataArgs,
+    any,
+    PrintEditionV1InstructionData
+  >(
+    struct<PrintEditionV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['name', option(string())],
+        ['uri', option(string())],
+        ['plugins', option(array(getPluginAuthorityPairSerializer()))],
+      ],
+      { description: 'PrintEditionV1InstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: 46,
+      name: value.name ?? none(),
+      uri: value.uri ?? none(),
+      plugins: value.plugins ?? none(),
+    })
+  ) as Serializer<
+    PrintEditionV1InstructionDataArgs,
+    PrintEditionV1InstructionData
+  >;
+}
+
+// Args.
+export type PrintEditionV1InstructionArgs = PrintEditionV1InstructionDataArgs;
+
+// Instruction.
+export function printEditionV1(
+  context: Pick<Context, 'payer' | 'programs'>,
+  input: PrintEditionV1InstructionAccounts & PrintEditionV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplCore',
+    'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    asset: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.asset ?? null,
+    },
+    collection: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.collection ?? null,
+    },
+    authority: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    payer: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    owner: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.owner ?? null,
+    },
+    systemProgram: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+    logWrapper: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.logWrapper ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: PrintEditionV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getPrintEditionV1InstructionDataSerializer().serialize(
+    resolvedArgs as PrintEditionV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,157 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+import {
+  GroupPluginKey,
+  GroupPluginKeyArgs,
+  getGroupPluginKeySerializer,
+} from '../types';
+
+// Accounts.
+export type RemoveGroupPluginV1InstructionAccounts = {
+  /** The address of the group */
+  group: PublicKey | Pda;
+  /** The account receiving reclaimed lamports */
+  payer?: Signer;
+  /** The update authority of the group */
+  authority?: Signer;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type RemoveGroupPluginV1InstructionData = {
+  discriminator: number;
+  key: GroupPluginKey;
+};
+
+export type RemoveGroupPluginV1InstructionDataArgs = {
+  key: GroupPluginKeyArgs;
+};
+
+export function getRemoveGroupPluginV1InstructionDataSerializer(): Serializer<
+  RemoveGroupPluginV1InstructionDataArgs,
+  RemoveGroupPluginV1InstructionData
+> {
+  return mapSerializer<
+    RemoveGroupPluginV1InstructionDataArgs,
+    any,
+    RemoveGroupPluginV1InstructionData
+  >(
+    struct<RemoveGroupPluginV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['key', getGroupPluginKeySerializer()],
+      ],
+      { description: 'RemoveGroupPluginV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 51 })
+  ) as Serializer<
+    RemoveGroupPluginV1InstructionDataArgs,
+    RemoveGroupPluginV1InstructionData
+  >;
+}
+
+// Args.
+export type RemoveGroupPluginV1InstructionArgs =
+  RemoveGroupPluginV1InstructionDataArgs;
+
+// Instruction.
+export function removeGroupPluginV1(
+  context: Pick<Context, 'payer' | 'programs'>,
+  input: RemoveGroupPluginV1InstructionAccounts &
+    RemoveGroupPluginV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplCore',
+    'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    group: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.group ?? null,
+    },
+    payer: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    authority: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    systemProgram: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: RemoveGroupPluginV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getRemoveGroupPluginV1InstructionDataSerializer().serialize(
+    resolvedArgs as RemoveGroupPluginV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,128 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type RevokeGroupDelegateV1InstructionAccounts = {
+  /** The address of the group */
+  group: PublicKey | Pda;
+  /** The delegate account of the group */
+  groupDelegate: PublicKey | Pda;
+  /** The account receiving reclaimed lamports */
+  payer?: Signer;
+  /** The update authority of the group or the delegate */
+  authority?: Signer;
+};
+
+// Data.
+export type RevokeGroupDelegateV1InstructionData = { discriminator: number };
+
+export type RevokeGroupDelegateV1InstructionDataArgs = {};
+
+export function getRevokeGroupDelegateV1InstructionDataSerializer(): Serializer<
+  RevokeGroupDelegateV1InstructionDataArgs,
+  RevokeGroupDelegateV1InstructionData
+> {
+  return mapSerializer<
+    RevokeGroupDelegateV1InstructionDataArgs,
+    any,
+    RevokeGroupDelegateV1InstructionData
+  >(
+    struct<RevokeGroupDelegateV1InstructionData>([['discriminator', u8()]], {
+      description: 'RevokeGroupDelegateV1InstructionData',
+    }),
+    (value) => ({ ...value, discriminator: 53 })
+  ) as Serializer<
+    RevokeGroupDelegateV1InstructionDataArgs,
+    RevokeGroupDelegateV1InstructionData
+  >;
+}
+
+// Instruction.
+export function revokeGroupDelegateV1(
+  context: Pick<Context, 'payer' | 'programs'>,
+  input: RevokeGroupDelegateV1InstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplCore',
+    'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    group: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.group ?? null,
+    },
+    groupDelegate: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.groupDelegate ?? null,
+    },
+    payer: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    authority: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getRevokeGroupDelegateV1InstructionDataSerializer().serialize(
+    {}
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,141 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type TransferBatchV1InstructionAccounts = {
+  /** The account paying for the storage fees */
+  payer?: Signer;
+  /** The owner or delegate of the assets */
+  authority?: Signer;
+  /** The new owner to which to transfer the assets */
+  newOwner: PublicKey | Pda;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type TransferBatchV1InstructionData = {
+  discriminator: number;
+  numAssets: number;
+};
+
+export type TransferBatchV1InstructionDataArgs = { numAssets: number };
+
+export function getTransferBatchV1InstructionDataSerializer(): Serializer<
+  TransferBatchV1InstructionDataArgs,
+  TransferBatchV1InstructionData
+> {
+  return mapSerializer<
+    TransferBatchV1InstructionDataArgs,
+    any,
+    TransferBatchV1InstructionData
+  >(
+    struct<TransferBatchV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['numAssets', u8()],
+      ],
+      { description: 'TransferBatchV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 42 })
+  ) as Serializer<
+    TransferBatchV1InstructionDataArgs,
+    TransferBatchV1InstructionData
+  >;
+}
+
+// Args.
+export type TransferBatchV1InstructionArgs = TransferBatchV1InstructionDataArgs;
+
+// Instruction.
+export function transferBatchV1(
+  context: Pick<Context, 'payer' | 'programs'>,
+  input: TransferBatchV1InstructionAccounts & TransferBatchV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplCore',
+    'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    payer: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    authority: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    newOwner: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.newOwner ?? null,
+    },
+    systemProgram: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: TransferBatchV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getTransferBatchV1InstructionDataSerializer().serialize(
+    resolvedArgs as TransferBatchV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
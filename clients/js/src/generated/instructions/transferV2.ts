@@ -0,0 +1,167 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type TransferV2InstructionAccounts = {
+  /** The address of the asset */
+  asset: PublicKey | Pda;
+  /** The collection to which the asset belongs */
+  collection?: PublicKey | Pda;
+  /** The account paying for the storage fees */
+  payer?: Signer;
+  /** The owner or delegate of the asset */
+  authority?: Signer;
+  /** The new owner to which to transfer the asset */
+  newOwner: PublicKey | Pda;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+  /** The SPL Noop Program */
+  logWrapper?: PublicKey | Pda;
+};
+
+// Data.
+export type TransferV2InstructionData = {
+  discriminator: number;
+  salePrice: bigint;
+};
+
+export type TransferV2InstructionDataArgs = { salePrice: number | bigint };
+
+export function getTransferV2InstructionDataSerializer(): Serializer<
+  TransferV2InstructionDataArgs,
+  TransferV2InstructionData
+> {
+  return mapSerializer<
+    TransferV2InstructionDataArgs,
+    any,
+    TransferV2InstructionData
+  >(
+    struct<TransferV2InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['salePrice', u64()],
+      ],
+      { description: 'TransferV2InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 45 })
+  ) as Serializer<TransferV2InstructionDataArgs, TransferV2InstructionData>;
+}
+
+// Args.
+export type TransferV2InstructionArgs = TransferV2InstructionDataArgs;
+
+// Instruction.
+export function transferV2(
+  context: Pick<Context, 'payer' | 'programs'>,
+  input: TransferV2InstructionAccounts & TransferV2InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplCore',
+    'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    asset: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.asset ?? null,
+    },
+    collection: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.collection ?? null,
+    },
+    payer: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    authority: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    newOwner: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.newOwner ?? null,
+    },
+    systemProgram: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+    logWrapper: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.logWrapper ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: TransferV2InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getTransferV2InstructionDataSerializer().serialize(
+    resolvedArgs as TransferV2InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
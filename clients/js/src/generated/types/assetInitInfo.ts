@@ -0,0 +1,50 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  PluginAuthorityPair,
+  PluginAuthorityPairArgs,
+  getPluginAuthorityPairSerializer,
+} from '.';
+
+export type AssetInitInfo = {
+  owner: PublicKey;
+  name: string;
+  uri: string;
+  plugins: Array<PluginAuthorityPair>;
+};
+
+export type AssetInitInfoArgs = {
+  owner: PublicKey;
+  name: string;
+  uri: string;
+  plugins: Array<PluginAuthorityPairArgs>;
+};
+
+export function getAssetInitInfoSerializer(): Serializer<
+  AssetInitInfoArgs,
+  AssetInitInfo
+> {
+  return struct<AssetInitInfo>(
+    [
+      ['owner', publicKeySerializer()],
+      ['name', string()],
+      ['uri', string()],
+      ['plugins', array(getPluginAuthorityPairSerializer())],
+    ],
+    { description: 'AssetInitInfo' }
+  ) as Serializer<AssetInitInfoArgs, AssetInitInfo>;
+}
@@ -22,9 +22,20 @@ import {
 export type BaseRuleSet =
   | { __kind: 'None' }
   | { __kind: 'ProgramAllowList'; fields: [Array<PublicKey>] }
-  | { __kind: 'ProgramDenyList'; fields: [Array<PublicKey>] };
+  | { __kind: 'ProgramDenyList'; fields: [Array<PublicKey>] }
+  | { __kind: 'EnforcePayment' }
+  | { __kind: 'WalletAllowList'; fields: [Array<PublicKey>] }
+  | { __kind: 'WalletDenyList'; fields: [Array<PublicKey>] }
+  | { __kind: 'Composite'; fields: [Array<BaseRuleSet>] };
 
-export type BaseRuleSetArgs = BaseRuleSet;
+export type BaseRuleSetArgs =
+  | { __kind: 'None' }
+  | { __kind: 'ProgramAllowList'; fields: [Array<PublicKey>] }
+  | { __kind: 'ProgramDenyList'; fields: [Array<PublicKey>] }
+  | { __kind: 'EnforcePayment' }
+  | { __kind: 'WalletAllowList'; fields: [Array<PublicKey>] }
+  | { __kind: 'WalletDenyList'; fields: [Array<PublicKey>] }
+  | { __kind: 'Composite'; fields: [Array<BaseRuleSetArgs>] };
 
 export function getBaseRuleSetSerializer(): Serializer<
   BaseRuleSetArgs,
@@ -45,6 +56,25 @@ export function getBaseRuleSetSerializer(): Serializer<
           ['fields', tuple([array(publicKeySerializer())])],
         ]),
       ],
+      ['EnforcePayment', unit()],
+      [
+        'WalletAllowList',
+        struct<GetDataEnumKindContent<BaseRuleSet, 'WalletAllowList'>>([
+          ['fields', tuple([array(publicKeySerializer())])],
+        ]),
+      ],
+      [
+        'WalletDenyList',
+        struct<GetDataEnumKindContent<BaseRuleSet, 'WalletDenyList'>>([
+          ['fields', tuple([array(publicKeySerializer())])],
+        ]),
+      ],
+      [
+        'Composite',
+        struct<GetDataEnumKindContent<BaseRuleSet, 'Composite'>>([
+          ['fields', tuple([array(getBaseRuleSetSerializer())])],
+        ]),
+      ],
     ],
     { description: 'BaseRuleSet' }
   ) as Serializer<BaseRuleSetArgs, BaseRuleSet>;
@@ -62,6 +92,21 @@ export function baseRuleSet(
   kind: 'ProgramDenyList',
   data: GetDataEnumKindContent<BaseRuleSetArgs, 'ProgramDenyList'>['fields']
 ): GetDataEnumKind<BaseRuleSetArgs, 'ProgramDenyList'>;
+export function baseRuleSet(
+  kind: 'EnforcePayment'
+): GetDataEnumKind<BaseRuleSetArgs, 'EnforcePayment'>;
+export function baseRuleSet(
+  kind: 'WalletAllowList',
+  data: GetDataEnumKindContent<BaseRuleSetArgs, 'WalletAllowList'>['fields']
+): GetDataEnumKind<BaseRuleSetArgs, 'WalletAllowList'>;
+export function baseRuleSet(
+  kind: 'WalletDenyList',
+  data: GetDataEnumKindContent<BaseRuleSetArgs, 'WalletDenyList'>['fields']
+): GetDataEnumKind<BaseRuleSetArgs, 'WalletDenyList'>;
+export function baseRuleSet(
+  kind: 'Composite',
+  data: GetDataEnumKindContent<BaseRuleSetArgs, 'Composite'>['fields']
+): GetDataEnumKind<BaseRuleSetArgs, 'Composite'>;
 export function baseRuleSet<K extends BaseRuleSetArgs['__kind']>(
   kind: K,
   data?: any
@@ -11,17 +11,20 @@ import {
   GetDataEnumKind,
   GetDataEnumKindContent,
   Serializer,
+  array,
   dataEnum,
   publicKey as publicKeySerializer,
   struct,
   tuple,
+  u8,
   unit,
 } from '@metaplex-foundation/umi/serializers';
 
 export type BaseUpdateAuthority =
   | { __kind: 'None' }
   | { __kind: 'Address'; fields: [PublicKey] }
-  | { __kind: 'Collection'; fields: [PublicKey] };
+  | { __kind: 'Collection'; fields: [PublicKey] }
+  | { __kind: 'Multisig'; signers: Array<PublicKey>; threshold: number };
 
 export type BaseUpdateAuthorityArgs = BaseUpdateAuthority;
 
@@ -44,6 +47,13 @@ export function getBaseUpdateAuthoritySerializer(): Serializer<
           ['fields', tuple([publicKeySerializer()])],
         ]),
       ],
+      [
+        'Multisig',
+        struct<GetDataEnumKindContent<BaseUpdateAuthority, 'Multisig'>>([
+          ['signers', array(publicKeySerializer())],
+          ['threshold', u8()],
+        ]),
+      ],
     ],
     { description: 'BaseUpdateAuthority' }
   ) as Serializer<BaseUpdateAuthorityArgs, BaseUpdateAuthority>;
@@ -61,6 +71,10 @@ export function baseUpdateAuthority(
   kind: 'Collection',
   data: GetDataEnumKindContent<BaseUpdateAuthorityArgs, 'Collection'>['fields']
 ): GetDataEnumKind<BaseUpdateAuthorityArgs, 'Collection'>;
+export function baseUpdateAuthority(
+  kind: 'Multisig',
+  data: GetDataEnumKindContent<BaseUpdateAuthorityArgs, 'Multisig'>
+): GetDataEnumKind<BaseUpdateAuthorityArgs, 'Multisig'>;
 export function baseUpdateAuthority<
   K extends BaseUpdateAuthorityArgs['__kind'],
 >(kind: K, data?: any): Extract<BaseUpdateAuthorityArgs, { __kind: K }> {
@@ -0,0 +1,39 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Option, OptionOrNullable } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  i64,
+  option,
+  struct,
+  u32,
+} from '@metaplex-foundation/umi/serializers';
+
+export type BurnDelegateV2 = {
+  expiresAt: Option<bigint>;
+  maxUses: Option<number>;
+};
+
+export type BurnDelegateV2Args = {
+  expiresAt: OptionOrNullable<number | bigint>;
+  maxUses: OptionOrNullable<number>;
+};
+
+export function getBurnDelegateV2Serializer(): Serializer<
+  BurnDelegateV2Args,
+  BurnDelegateV2
+> {
+  return struct<BurnDelegateV2>(
+    [
+      ['expiresAt', option(i64())],
+      ['maxUses', option(u32())],
+    ],
+    { description: 'BurnDelegateV2' }
+  ) as Serializer<BurnDelegateV2Args, BurnDelegateV2>;
+}
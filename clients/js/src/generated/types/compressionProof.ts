@@ -18,9 +18,12 @@ import {
 import {
   BaseUpdateAuthority,
   BaseUpdateAuthorityArgs,
+  HashableExternalPluginAdapterSchema,
+  HashableExternalPluginAdapterSchemaArgs,
   HashablePluginSchema,
   HashablePluginSchemaArgs,
   getBaseUpdateAuthoritySerializer,
+  getHashableExternalPluginAdapterSchemaSerializer,
   getHashablePluginSchemaSerializer,
 } from '.';
 
@@ -31,6 +34,7 @@ export type CompressionProof = {
   uri: string;
   seq: bigint;
   plugins: Array<HashablePluginSchema>;
+  externalPluginAdapters: Array<HashableExternalPluginAdapterSchema>;
 };
 
 export type CompressionProofArgs = {
@@ -40,6 +44,7 @@ export type CompressionProofArgs = {
   uri: string;
   seq: number | bigint;
   plugins: Array<HashablePluginSchemaArgs>;
+  externalPluginAdapters: Array<HashableExternalPluginAdapterSchemaArgs>;
 };
 
 export function getCompressionProofSerializer(): Serializer<
@@ -54,6 +59,10 @@ export function getCompressionProofSerializer(): Serializer<
       ['uri', string()],
       ['seq', u64()],
       ['plugins', array(getHashablePluginSchemaSerializer())],
+      [
+        'externalPluginAdapters',
+        array(getHashableExternalPluginAdapterSchemaSerializer()),
+      ],
     ],
     { description: 'CompressionProof' }
   ) as Serializer<CompressionProofArgs, CompressionProof>;
@@ -0,0 +1,39 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Serializer,
+  bytes,
+  struct,
+  u32,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+
+export type ExecuteBatchInstruction = {
+  programIndex: number;
+  accountsStart: number;
+  accountsLen: number;
+  data: Uint8Array;
+};
+
+export type ExecuteBatchInstructionArgs = ExecuteBatchInstruction;
+
+export function getExecuteBatchInstructionSerializer(): Serializer<
+  ExecuteBatchInstructionArgs,
+  ExecuteBatchInstruction
+> {
+  return struct<ExecuteBatchInstruction>(
+    [
+      ['programIndex', u8()],
+      ['accountsStart', u8()],
+      ['accountsLen', u8()],
+      ['data', bytes({ size: u32() })],
+    ],
+    { description: 'ExecuteBatchInstruction' }
+  ) as Serializer<ExecuteBatchInstructionArgs, ExecuteBatchInstruction>;
+}
@@ -0,0 +1,34 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Serializer,
+  array,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ExecuteGuardProgram,
+  ExecuteGuardProgramArgs,
+  getExecuteGuardProgramSerializer,
+} from '.';
+
+export type ExecuteGuard = { allowedPrograms: Array<ExecuteGuardProgram> };
+
+export type ExecuteGuardArgs = {
+  allowedPrograms: Array<ExecuteGuardProgramArgs>;
+};
+
+export function getExecuteGuardSerializer(): Serializer<
+  ExecuteGuardArgs,
+  ExecuteGuard
+> {
+  return struct<ExecuteGuard>(
+    [['allowedPrograms', array(getExecuteGuardProgramSerializer())]],
+    { description: 'ExecuteGuard' }
+  ) as Serializer<ExecuteGuardArgs, ExecuteGuard>;
+}
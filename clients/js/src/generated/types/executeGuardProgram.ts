@@ -0,0 +1,39 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Option, OptionOrNullable, PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  option,
+  publicKey as publicKeySerializer,
+  struct,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+
+export type ExecuteGuardProgram = {
+  programId: PublicKey;
+  instructionSpendLimit: Option<bigint>;
+};
+
+export type ExecuteGuardProgramArgs = {
+  programId: PublicKey;
+  instructionSpendLimit: OptionOrNullable<number | bigint>;
+};
+
+export function getExecuteGuardProgramSerializer(): Serializer<
+  ExecuteGuardProgramArgs,
+  ExecuteGuardProgram
+> {
+  return struct<ExecuteGuardProgram>(
+    [
+      ['programId', publicKeySerializer()],
+      ['instructionSpendLimit', option(u64())],
+    ],
+    { description: 'ExecuteGuardProgram' }
+  ) as Serializer<ExecuteGuardProgramArgs, ExecuteGuardProgram>;
+}
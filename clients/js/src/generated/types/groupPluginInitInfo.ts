@@ -0,0 +1,103 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Option, OptionOrNullable } from '@metaplex-foundation/umi';
+import {
+  GetDataEnumKind,
+  GetDataEnumKindContent,
+  Serializer,
+  dataEnum,
+  option,
+  struct,
+  tuple,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  BaseExternalPluginAdapterInitInfo,
+  BaseExternalPluginAdapterInitInfoArgs,
+  BasePluginAuthority,
+  BasePluginAuthorityArgs,
+  Plugin,
+  PluginArgs,
+  getBaseExternalPluginAdapterInitInfoSerializer,
+  getBasePluginAuthoritySerializer,
+  getPluginSerializer,
+} from '.';
+
+export type GroupPluginInitInfo =
+  | {
+      __kind: 'Plugin';
+      plugin: Plugin;
+      initAuthority: Option<BasePluginAuthority>;
+    }
+  | {
+      __kind: 'ExternalPluginAdapter';
+      fields: [BaseExternalPluginAdapterInitInfo];
+    };
+
+export type GroupPluginInitInfoArgs =
+  | {
+      __kind: 'Plugin';
+      plugin: PluginArgs;
+      initAuthority: OptionOrNullable<BasePluginAuthorityArgs>;
+    }
+  | {
+      __kind: 'ExternalPluginAdapter';
+      fields: [BaseExternalPluginAdapterInitInfoArgs];
+    };
+
+export function getGroupPluginInitInfoSerializer(): Serializer<
+  GroupPluginInitInfoArgs,
+  GroupPluginInitInfo
+> {
+  return dataEnum<GroupPluginInitInfo>(
+    [
+      [
+        'Plugin',
+        struct<GetDataEnumKindContent<GroupPluginInitInfo, 'Plugin'>>([
+          ['plugin', getPluginSerializer()],
+          ['initAuthority', option(getBasePluginAuthoritySerializer())],
+        ]),
+      ],
+      [
+        'ExternalPluginAdapter',
+        struct<
+          GetDataEnumKindContent<GroupPluginInitInfo, 'ExternalPluginAdapter'>
+        >([
+          ['fields', tuple([getBaseExternalPluginAdapterInitInfoSerializer()])],
+        ]),
+      ],
+    ],
+    { description: 'GroupPluginInitInfo' }
+  ) as Serializer<GroupPluginInitInfoArgs, GroupPluginInitInfo>;
+}
+
+// Data Enum Helpers.
+export function groupPluginInitInfo(
+  kind: 'Plugin',
+  data: GetDataEnumKindContent<GroupPluginInitInfoArgs, 'Plugin'>
+): GetDataEnumKind<GroupPluginInitInfoArgs, 'Plugin'>;
+export function groupPluginInitInfo(
+  kind: 'ExternalPluginAdapter',
+  data: GetDataEnumKindContent<
+    GroupPluginInitInfoArgs,
+    'ExternalPluginAdapter'
+  >['fields']
+): GetDataEnumKind<GroupPluginInitInfoArgs, 'ExternalPluginAdapter'>;
+export function groupPluginInitInfo<
+  K extends GroupPluginInitInfoArgs['__kind'],
+>(kind: K, data?: any): Extract<GroupPluginInitInfoArgs, { __kind: K }> {
+  return Array.isArray(data)
+    ? { __kind: kind, fields: data }
+    : { __kind: kind, ...(data ?? {}) };
+}
+export function isGroupPluginInitInfo<K extends GroupPluginInitInfo['__kind']>(
+  kind: K,
+  value: GroupPluginInitInfo
+): value is GroupPluginInitInfo & { __kind: K } {
+  return value.__kind === kind;
+}
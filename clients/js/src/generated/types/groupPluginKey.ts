@@ -0,0 +1,85 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  GetDataEnumKind,
+  GetDataEnumKindContent,
+  Serializer,
+  dataEnum,
+  struct,
+  tuple,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  BaseExternalPluginAdapterKey,
+  BaseExternalPluginAdapterKeyArgs,
+  PluginType,
+  PluginTypeArgs,
+  getBaseExternalPluginAdapterKeySerializer,
+  getPluginTypeSerializer,
+} from '.';
+
+export type GroupPluginKey =
+  | { __kind: 'Plugin'; fields: [PluginType] }
+  | { __kind: 'ExternalPluginAdapter'; fields: [BaseExternalPluginAdapterKey] };
+
+export type GroupPluginKeyArgs =
+  | { __kind: 'Plugin'; fields: [PluginTypeArgs] }
+  | {
+      __kind: 'ExternalPluginAdapter';
+      fields: [BaseExternalPluginAdapterKeyArgs];
+    };
+
+export function getGroupPluginKeySerializer(): Serializer<
+  GroupPluginKeyArgs,
+  GroupPluginKey
+> {
+  return dataEnum<GroupPluginKey>(
+    [
+      [
+        'Plugin',
+        struct<GetDataEnumKindContent<GroupPluginKey, 'Plugin'>>([
+          ['fields', tuple([getPluginTypeSerializer()])],
+        ]),
+      ],
+      [
+        'ExternalPluginAdapter',
+        struct<GetDataEnumKindContent<GroupPluginKey, 'ExternalPluginAdapter'>>(
+          [['fields', tuple([getBaseExternalPluginAdapterKeySerializer()])]]
+        ),
+      ],
+    ],
+    { description: 'GroupPluginKey' }
+  ) as Serializer<GroupPluginKeyArgs, GroupPluginKey>;
+}
+
+// Data Enum Helpers.
+export function groupPluginKey(
+  kind: 'Plugin',
+  data: GetDataEnumKindContent<GroupPluginKeyArgs, 'Plugin'>['fields']
+): GetDataEnumKind<GroupPluginKeyArgs, 'Plugin'>;
+export function groupPluginKey(
+  kind: 'ExternalPluginAdapter',
+  data: GetDataEnumKindContent<
+    GroupPluginKeyArgs,
+    'ExternalPluginAdapter'
+  >['fields']
+): GetDataEnumKind<GroupPluginKeyArgs, 'ExternalPluginAdapter'>;
+export function groupPluginKey<K extends GroupPluginKeyArgs['__kind']>(
+  kind: K,
+  data?: any
+): Extract<GroupPluginKeyArgs, { __kind: K }> {
+  return Array.isArray(data)
+    ? { __kind: kind, fields: data }
+    : { __kind: kind, ...(data ?? {}) };
+}
+export function isGroupPluginKey<K extends GroupPluginKey['__kind']>(
+  kind: K,
+  value: GroupPluginKey
+): value is GroupPluginKey & { __kind: K } {
+  return value.__kind === kind;
+}
@@ -0,0 +1,97 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  GetDataEnumKind,
+  GetDataEnumKindContent,
+  Serializer,
+  dataEnum,
+  struct,
+  tuple,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  BaseExternalPluginAdapterKey,
+  BaseExternalPluginAdapterKeyArgs,
+  BaseExternalPluginAdapterUpdateInfo,
+  BaseExternalPluginAdapterUpdateInfoArgs,
+  Plugin,
+  PluginArgs,
+  getBaseExternalPluginAdapterKeySerializer,
+  getBaseExternalPluginAdapterUpdateInfoSerializer,
+  getPluginSerializer,
+} from '.';
+
+export type GroupPluginUpdateInfo =
+  | { __kind: 'Plugin'; fields: [Plugin] }
+  | {
+      __kind: 'ExternalPluginAdapter';
+      key: BaseExternalPluginAdapterKey;
+      updateInfo: BaseExternalPluginAdapterUpdateInfo;
+    };
+
+export type GroupPluginUpdateInfoArgs =
+  | { __kind: 'Plugin'; fields: [PluginArgs] }
+  | {
+      __kind: 'ExternalPluginAdapter';
+      key: BaseExternalPluginAdapterKeyArgs;
+      updateInfo: BaseExternalPluginAdapterUpdateInfoArgs;
+    };
+
+export function getGroupPluginUpdateInfoSerializer(): Serializer<
+  GroupPluginUpdateInfoArgs,
+  GroupPluginUpdateInfo
+> {
+  return dataEnum<GroupPluginUpdateInfo>(
+    [
+      [
+        'Plugin',
+        struct<GetDataEnumKindContent<GroupPluginUpdateInfo, 'Plugin'>>([
+          ['fields', tuple([getPluginSerializer()])],
+        ]),
+      ],
+      [
+        'ExternalPluginAdapter',
+        struct<
+          GetDataEnumKindContent<GroupPluginUpdateInfo, 'ExternalPluginAdapter'>
+        >([
+          ['key', getBaseExternalPluginAdapterKeySerializer()],
+          ['updateInfo', getBaseExternalPluginAdapterUpdateInfoSerializer()],
+        ]),
+      ],
+    ],
+    { description: 'GroupPluginUpdateInfo' }
+  ) as Serializer<GroupPluginUpdateInfoArgs, GroupPluginUpdateInfo>;
+}
+
+// Data Enum Helpers.
+export function groupPluginUpdateInfo(
+  kind: 'Plugin',
+  data: GetDataEnumKindContent<GroupPluginUpdateInfoArgs, 'Plugin'>['fields']
+): GetDataEnumKind<GroupPluginUpdateInfoArgs, 'Plugin'>;
+export function groupPluginUpdateInfo(
+  kind: 'ExternalPluginAdapter',
+  data: GetDataEnumKindContent<
+    GroupPluginUpdateInfoArgs,
+    'ExternalPluginAdapter'
+  >
+): GetDataEnumKind<GroupPluginUpdateInfoArgs, 'ExternalPluginAdapter'>;
+export function groupPluginUpdateInfo<
+  K extends GroupPluginUpdateInfoArgs['__kind'],
+>(kind: K, data?: any): Extract<GroupPluginUpdateInfoArgs, { __kind: K }> {
+  return Array.isArray(data)
+    ? { __kind: kind, fields: data }
+    : { __kind: kind, ...(data ?? {}) };
+}
+export function isGroupPluginUpdateInfo<
+  K extends GroupPluginUpdateInfo['__kind'],
+>(
+  kind: K,
+  value: GroupPluginUpdateInfo
+): value is GroupPluginUpdateInfo & { __kind: K } {
+  return value.__kind === kind;
+}
@@ -14,6 +14,7 @@ import {
   publicKey as publicKeySerializer,
   string,
   struct,
+  u32,
 } from '@metaplex-foundation/umi/serializers';
 import { Key, getKeySerializer } from '.';
 
@@ -26,6 +27,7 @@ export type GroupV1AccountData = {
   groups: Array<PublicKey>;
   parentGroups: Array<PublicKey>;
   assets: Array<PublicKey>;
+  membershipCount: number;
 };
 
 export type GroupV1AccountDataArgs = {
@@ -36,6 +38,7 @@ export type GroupV1AccountDataArgs = {
   groups: Array<PublicKey>;
   parentGroups: Array<PublicKey>;
   assets: Array<PublicKey>;
+  membershipCount: number;
 };
 
 export function getGroupV1AccountDataSerializer(): Serializer<
@@ -53,6 +56,7 @@ export function getGroupV1AccountDataSerializer(): Serializer<
         ['groups', array(publicKeySerializer())],
         ['parentGroups', array(publicKeySerializer())],
         ['assets', array(publicKeySerializer())],
+        ['membershipCount', u32()],
       ],
       { description: 'GroupV1AccountData' }
     ),
@@ -0,0 +1,79 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  GetDataEnumKind,
+  GetDataEnumKindContent,
+  Serializer,
+  dataEnum,
+  struct,
+  tuple,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  HashableExternalPluginAdapterSchemaV1,
+  HashableExternalPluginAdapterSchemaV1Args,
+  getHashableExternalPluginAdapterSchemaV1Serializer,
+} from '.';
+
+export type HashableExternalPluginAdapterSchema =
+  | { __kind: 'V1'; fields: [HashableExternalPluginAdapterSchemaV1] };
+
+export type HashableExternalPluginAdapterSchemaArgs =
+  | { __kind: 'V1'; fields: [HashableExternalPluginAdapterSchemaV1Args] };
+
+export function getHashableExternalPluginAdapterSchemaSerializer(): Serializer<
+  HashableExternalPluginAdapterSchemaArgs,
+  HashableExternalPluginAdapterSchema
+> {
+  return dataEnum<HashableExternalPluginAdapterSchema>(
+    [
+      [
+        'V1',
+        struct<
+          GetDataEnumKindContent<HashableExternalPluginAdapterSchema, 'V1'>
+        >([
+          [
+            'fields',
+            tuple([getHashableExternalPluginAdapterSchemaV1Serializer()]),
+          ],
+        ]),
+      ],
+    ],
+    { description: 'HashableExternalPluginAdapterSchema' }
+  ) as Serializer<
+    HashableExternalPluginAdapterSchemaArgs,
+    HashableExternalPluginAdapterSchema
+  >;
+}
+
+// Data Enum Helpers.
+export function hashableExternalPluginAdapterSchema(
+  kind: 'V1',
+  data: GetDataEnumKindContent<
+    HashableExternalPluginAdapterSchemaArgs,
+    'V1'
+  >['fields']
+): GetDataEnumKind<HashableExternalPluginAdapterSchemaArgs, 'V1'>;
+export function hashableExternalPluginAdapterSchema<
+  K extends HashableExternalPluginAdapterSchemaArgs['__kind'],
+>(
+  kind: K,
+  data?: any
+): Extract<HashableExternalPluginAdapterSchemaArgs, { __kind: K }> {
+  return Array.isArray(data)
+    ? { __kind: kind, fields: data }
+    : { __kind: kind, ...(data ?? {}) };
+}
+export function isHashableExternalPluginAdapterSchema<
+  K extends HashableExternalPluginAdapterSchema['__kind'],
+>(
+  kind: K,
+  value: HashableExternalPluginAdapterSchema
+): value is HashableExternalPluginAdapterSchema & { __kind: K } {
+  return value.__kind === kind;
+}
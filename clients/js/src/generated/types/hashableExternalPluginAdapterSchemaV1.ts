@@ -0,0 +1,80 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Option, OptionOrNullable } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  bytes,
+  option,
+  struct,
+  tuple,
+  u32,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  BasePluginAuthority,
+  BasePluginAuthorityArgs,
+  ExternalCheckResult,
+  ExternalCheckResultArgs,
+  ExternalPluginAdapter,
+  ExternalPluginAdapterArgs,
+  HookableLifecycleEvent,
+  HookableLifecycleEventArgs,
+  getBasePluginAuthoritySerializer,
+  getExternalCheckResultSerializer,
+  getExternalPluginAdapterSerializer,
+  getHookableLifecycleEventSerializer,
+} from '.';
+
+export type HashableExternalPluginAdapterSchemaV1 = {
+  index: bigint;
+  authority: BasePluginAuthority;
+  lifecycleChecks: Option<Array<[HookableLifecycleEvent, ExternalCheckResult]>>;
+  adapter: ExternalPluginAdapter;
+  data: Option<Uint8Array>;
+};
+
+export type HashableExternalPluginAdapterSchemaV1Args = {
+  index: number | bigint;
+  authority: BasePluginAuthorityArgs;
+  lifecycleChecks: OptionOrNullable<
+    Array<[HookableLifecycleEventArgs, ExternalCheckResultArgs]>
+  >;
+  adapter: ExternalPluginAdapterArgs;
+  data: OptionOrNullable<Uint8Array>;
+};
+
+export function getHashableExternalPluginAdapterSchemaV1Serializer(): Serializer<
+  HashableExternalPluginAdapterSchemaV1Args,
+  HashableExternalPluginAdapterSchemaV1
+> {
+  return struct<HashableExternalPluginAdapterSchemaV1>(
+    [
+      ['index', u64()],
+      ['authority', getBasePluginAuthoritySerializer()],
+      [
+        'lifecycleChecks',
+        option(
+          array(
+            tuple([
+              getHookableLifecycleEventSerializer(),
+              getExternalCheckResultSerializer(),
+            ])
+          )
+        ),
+      ],
+      ['adapter', getExternalPluginAdapterSerializer()],
+      ['data', option(bytes({ size: u32() }))],
+    ],
+    { description: 'HashableExternalPluginAdapterSchemaV1' }
+  ) as Serializer<
+    HashableExternalPluginAdapterSchemaV1Args,
+    HashableExternalPluginAdapterSchemaV1
+  >;
+}
@@ -7,6 +7,7 @@
  */
 
 export * from './addBlocker';
+export * from './assetInitInfo';
 export * from './attribute';
 export * from './attributes';
 export * from './autograph';
@@ -46,10 +47,14 @@ export * from './baseUpdateAuthority';
 export * from './baseValidationResultsOffset';
 export * from './bubblegumV2';
 export * from './burnDelegate';
+export * from './burnDelegateV2';
 export * from './compressionProof';
 export * from './creator';
 export * from './dataState';
 export * from './edition';
+export * from './executeBatchInstruction';
+export * from './executeGuard';
+export * from './executeGuardProgram';
 export * from './externalCheckResult';
 export * from './externalPluginAdapter';
 export * from './externalPluginAdapterSchema';
@@ -58,12 +63,20 @@ export * from './externalRegistryRecord';
 export * from './externalValidationResult';
 export * from './freezeDelegate';
 export * from './freezeExecute';
+export * from './groupPluginInitInfo';
+export * from './groupPluginKey';
+export * from './groupPluginUpdateInfo';
 export * from './groups';
+export * from './hashableExternalPluginAdapterSchema';
+export * from './hashableExternalPluginAdapterSchemaV1';
 export * from './hashablePluginSchema';
 export * from './hashedAssetSchema';
 export * from './hookableLifecycleEvent';
 export * from './immutableMetadata';
 export * from './key';
+export * from './listing';
+export * from './listingRecipient';
+export * from './multisig';
 export * from './oracleValidation';
 export * from './permanentBurnDelegate';
 export * from './permanentFreezeDelegate';
@@ -75,7 +88,11 @@ export * from './pluginType';
 export * from './registryRecord';
 export * from './relationshipEntry';
 export * from './relationshipKind';
+export * from './rental';
+export * from './soulbound';
+export * from './timeLock';
 export * from './transferDelegate';
+export * from './transferDelegateV2';
 export * from './updateDelegate';
 export * from './updateType';
 export * from './validationResult';
@@ -16,6 +16,9 @@ export enum Key {
   PluginRegistryV1,
   CollectionV1,
   GroupV1,
+  GroupMembershipV1,
+  GroupDelegateV1,
+  GroupAuthorityProposalV1,
 }
 
 export type KeyArgs = Key;
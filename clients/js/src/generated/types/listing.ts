@@ -0,0 +1,45 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Option, OptionOrNullable, PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  option,
+  publicKey as publicKeySerializer,
+  struct,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ListingRecipient,
+  ListingRecipientArgs,
+  getListingRecipientSerializer,
+} from '.';
+
+export type Listing = {
+  price: bigint;
+  mint: Option<PublicKey>;
+  recipients: Array<ListingRecipient>;
+};
+
+export type ListingArgs = {
+  price: number | bigint;
+  mint: OptionOrNullable<PublicKey>;
+  recipients: Array<ListingRecipientArgs>;
+};
+
+export function getListingSerializer(): Serializer<ListingArgs, Listing> {
+  return struct<Listing>(
+    [
+      ['price', u64()],
+      ['mint', option(publicKeySerializer())],
+      ['recipients', array(getListingRecipientSerializer())],
+    ],
+    { description: 'Listing' }
+  ) as Serializer<ListingArgs, Listing>;
+}
@@ -0,0 +1,32 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  publicKey as publicKeySerializer,
+  struct,
+  u16,
+} from '@metaplex-foundation/umi/serializers';
+
+export type ListingRecipient = { address: PublicKey; basisPoints: number };
+
+export type ListingRecipientArgs = ListingRecipient;
+
+export function getListingRecipientSerializer(): Serializer<
+  ListingRecipientArgs,
+  ListingRecipient
+> {
+  return struct<ListingRecipient>(
+    [
+      ['address', publicKeySerializer()],
+      ['basisPoints', u16()],
+    ],
+    { description: 'ListingRecipient' }
+  ) as Serializer<ListingRecipientArgs, ListingRecipient>;
+}
@@ -0,0 +1,30 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  publicKey as publicKeySerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+
+export type Multisig = { signers: Array<PublicKey>; threshold: number };
+
+export type MultisigArgs = Multisig;
+
+export function getMultisigSerializer(): Serializer<MultisigArgs, Multisig> {
+  return struct<Multisig>(
+    [
+      ['signers', array(publicKeySerializer())],
+      ['threshold', u8()],
+    ],
+    { description: 'Multisig' }
+  ) as Serializer<MultisigArgs, Multisig>;
+}
@@ -29,8 +29,12 @@ import {
   BubblegumV2Args,
   BurnDelegate,
   BurnDelegateArgs,
+  BurnDelegateV2,
+  BurnDelegateV2Args,
   Edition,
   EditionArgs,
+  ExecuteGuard,
+  ExecuteGuardArgs,
   FreezeDelegate,
   FreezeDelegateArgs,
   FreezeExecute,
@@ -39,6 +43,10 @@ import {
   GroupsArgs,
   ImmutableMetadata,
   ImmutableMetadataArgs,
+  Listing,
+  ListingArgs,
+  Multisig,
+  MultisigArgs,
   PermanentBurnDelegate,
   PermanentBurnDelegateArgs,
   PermanentFreezeDelegate,
@@ -47,8 +55,16 @@ import {
   PermanentFreezeExecuteArgs,
   PermanentTransferDelegate,
   PermanentTransferDelegateArgs,
+  Rental,
+  RentalArgs,
+  Soulbound,
+  SoulboundArgs,
+  TimeLock,
+  TimeLockArgs,
   TransferDelegate,
   TransferDelegateArgs,
+  TransferDelegateV2,
+  TransferDelegateV2Args,
   UpdateDelegate,
   UpdateDelegateArgs,
   VerifiedCreators,
@@ -60,16 +76,24 @@ import {
   getBaseRoyaltiesSerializer,
   getBubblegumV2Serializer,
   getBurnDelegateSerializer,
+  getBurnDelegateV2Serializer,
   getEditionSerializer,
+  getExecuteGuardSerializer,
   getFreezeDelegateSerializer,
   getFreezeExecuteSerializer,
   getGroupsSerializer,
   getImmutableMetadataSerializer,
+  getListingSerializer,
+  getMultisigSerializer,
   getPermanentBurnDelegateSerializer,
   getPermanentFreezeDelegateSerializer,
   getPermanentFreezeExecuteSerializer,
   getPermanentTransferDelegateSerializer,
+  getRentalSerializer,
+  getSoulboundSerializer,
+  getTimeLockSerializer,
   getTransferDelegateSerializer,
+  getTransferDelegateV2Serializer,
   getUpdateDelegateSerializer,
   getVerifiedCreatorsSerializer,
 } from '.';
@@ -93,7 +117,15 @@ export type Plugin =
   | { __kind: 'BubblegumV2'; fields: [BubblegumV2] }
   | { __kind: 'FreezeExecute'; fields: [FreezeExecute] }
   | { __kind: 'PermanentFreezeExecute'; fields: [PermanentFreezeExecute] }
-  | { __kind: 'Groups'; fields: [Groups] };
+  | { __kind: 'Groups'; fields: [Groups] }
+  | { __kind: 'Soulbound'; fields: [Soulbound] }
+  | { __kind: 'TimeLock'; fields: [TimeLock] }
+  | { __kind: 'Rental'; fields: [Rental] }
+  | { __kind: 'Listing'; fields: [Listing] }
+  | { __kind: 'Multisig'; fields: [Multisig] }
+  | { __kind: 'ExecuteGuard'; fields: [ExecuteGuard] }
+  | { __kind: 'TransferDelegateV2'; fields: [TransferDelegateV2] }
+  | { __kind: 'BurnDelegateV2'; fields: [BurnDelegateV2] };
 
 export type PluginArgs =
   | { __kind: 'Royalties'; fields: [BaseRoyaltiesArgs] }
@@ -117,7 +149,15 @@ export type PluginArgs =
   | { __kind: 'BubblegumV2'; fields: [BubblegumV2Args] }
   | { __kind: 'FreezeExecute'; fields: [FreezeExecuteArgs] }
   | { __kind: 'PermanentFreezeExecute'; fields: [PermanentFreezeExecuteArgs] }
-  | { __kind: 'Groups'; fields: [GroupsArgs] };
+  | { __kind: 'Groups'; fields: [GroupsArgs] }
+  | { __kind: 'Soulbound'; fields: [SoulboundArgs] }
+  | { __kind: 'TimeLock'; fields: [TimeLockArgs] }
+  | { __kind: 'Rental'; fields: [RentalArgs] }
+  | { __kind: 'Listing'; fields: [ListingArgs] }
+  | { __kind: 'Multisig'; fields: [MultisigArgs] }
+  | { __kind: 'ExecuteGuard'; fields: [ExecuteGuardArgs] }
+  | { __kind: 'TransferDelegateV2'; fields: [TransferDelegateV2Args] }
+  | { __kind: 'BurnDelegateV2'; fields: [BurnDelegateV2Args] };
 
 export function getPluginSerializer(): Serializer<PluginArgs, Plugin> {
   return dataEnum<Plugin>(
@@ -236,6 +276,54 @@ export function getPluginSerializer(): Serializer<PluginArgs, Plugin> {
           ['fields', tuple([getGroupsSerializer()])],
         ]),
       ],
+      [
+        'Soulbound',
+        struct<GetDataEnumKindContent<Plugin, 'Soulbound'>>([
+          ['fields', tuple([getSoulboundSerializer()])],
+        ]),
+      ],
+      [
+        'TimeLock',
+        struct<GetDataEnumKindContent<Plugin, 'TimeLock'>>([
+          ['fields', tuple([getTimeLockSerializer()])],
+        ]),
+      ],
+      [
+        'Rental',
+        struct<GetDataEnumKindContent<Plugin, 'Rental'>>([
+          ['fields', tuple([getRentalSerializer()])],
+        ]),
+      ],
+      [
+        'Listing',
+        struct<GetDataEnumKindContent<Plugin, 'Listing'>>([
+          ['fields', tuple([getListingSerializer()])],
+        ]),
+      ],
+      [
+        'Multisig',
+        struct<GetDataEnumKindContent<Plugin, 'Multisig'>>([
+          ['fields', tuple([getMultisigSerializer()])],
+        ]),
+      ],
+      [
+        'ExecuteGuard',
+        struct<GetDataEnumKindContent<Plugin, 'ExecuteGuard'>>([
+          ['fields', tuple([getExecuteGuardSerializer()])],
+        ]),
+      ],
+      [
+        'TransferDelegateV2',
+        struct<GetDataEnumKindContent<Plugin, 'TransferDelegateV2'>>([
+          ['fields', tuple([getTransferDelegateV2Serializer()])],
+        ]),
+      ],
+      [
+        'BurnDelegateV2',
+        struct<GetDataEnumKindContent<Plugin, 'BurnDelegateV2'>>([
+          ['fields', tuple([getBurnDelegateV2Serializer()])],
+        ]),
+      ],
     ],
     { description: 'Plugin' }
   ) as Serializer<PluginArgs, Plugin>;
@@ -321,6 +409,38 @@ export function plugin(
   kind: 'Groups',
   data: GetDataEnumKindContent<PluginArgs, 'Groups'>['fields']
 ): GetDataEnumKind<PluginArgs, 'Groups'>;
+export function plugin(
+  kind: 'Soulbound',
+  data: GetDataEnumKindContent<PluginArgs, 'Soulbound'>['fields']
+): GetDataEnumKind<PluginArgs, 'Soulbound'>;
+export function plugin(
+  kind: 'TimeLock',
+  data: GetDataEnumKindContent<PluginArgs, 'TimeLock'>['fields']
+): GetDataEnumKind<PluginArgs, 'TimeLock'>;
+export function plugin(
+  kind: 'Rental',
+  data: GetDataEnumKindContent<PluginArgs, 'Rental'>['fields']
+): GetDataEnumKind<PluginArgs, 'Rental'>;
+export function plugin(
+  kind: 'Listing',
+  data: GetDataEnumKindContent<PluginArgs, 'Listing'>['fields']
+): GetDataEnumKind<PluginArgs, 'Listing'>;
+export function plugin(
+  kind: 'Multisig',
+  data: GetDataEnumKindContent<PluginArgs, 'Multisig'>['fields']
+): GetDataEnumKind<PluginArgs, 'Multisig'>;
+export function plugin(
+  kind: 'ExecuteGuard',
+  data: GetDataEnumKindContent<PluginArgs, 'ExecuteGuard'>['fields']
+): GetDataEnumKind<PluginArgs, 'ExecuteGuard'>;
+export function plugin(
+  kind: 'TransferDelegateV2',
+  data: GetDataEnumKindContent<PluginArgs, 'TransferDelegateV2'>['fields']
+): GetDataEnumKind<PluginArgs, 'TransferDelegateV2'>;
+export function plugin(
+  kind: 'BurnDelegateV2',
+  data: GetDataEnumKindContent<PluginArgs, 'BurnDelegateV2'>['fields']
+): GetDataEnumKind<PluginArgs, 'BurnDelegateV2'>;
 export function plugin<K extends PluginArgs['__kind']>(
   kind: K,
   data?: any
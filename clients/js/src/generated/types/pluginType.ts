@@ -28,6 +28,14 @@ export enum PluginType {
   FreezeExecute,
   PermanentFreezeExecute,
   Groups,
+  Soulbound,
+  TimeLock,
+  Rental,
+  Listing,
+  Multisig,
+  ExecuteGuard,
+  TransferDelegateV2,
+  BurnDelegateV2,
 }
 
 export type PluginTypeArgs = PluginType;
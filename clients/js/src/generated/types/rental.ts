@@ -0,0 +1,38 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  publicKey as publicKeySerializer,
+  struct,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+
+export type Rental = {
+  lender: PublicKey;
+  renter: PublicKey;
+  expirySlot: bigint;
+};
+
+export type RentalArgs = {
+  lender: PublicKey;
+  renter: PublicKey;
+  expirySlot: number | bigint;
+};
+
+export function getRentalSerializer(): Serializer<RentalArgs, Rental> {
+  return struct<Rental>(
+    [
+      ['lender', publicKeySerializer()],
+      ['renter', publicKeySerializer()],
+      ['expirySlot', u64()],
+    ],
+    { description: 'Rental' }
+  ) as Serializer<RentalArgs, Rental>;
+}
@@ -0,0 +1,19 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Serializer, i64, struct } from '@metaplex-foundation/umi/serializers';
+
+export type TimeLock = { unlockAt: bigint };
+
+export type TimeLockArgs = { unlockAt: number | bigint };
+
+export function getTimeLockSerializer(): Serializer<TimeLockArgs, TimeLock> {
+  return struct<TimeLock>([['unlockAt', i64()]], {
+    description: 'TimeLock',
+  }) as Serializer<TimeLockArgs, TimeLock>;
+}
@@ -0,0 +1,39 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Option, OptionOrNullable } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  i64,
+  option,
+  struct,
+  u32,
+} from '@metaplex-foundation/umi/serializers';
+
+export type TransferDelegateV2 = {
+  expiresAt: Option<bigint>;
+  maxUses: Option<number>;
+};
+
+export type TransferDelegateV2Args = {
+  expiresAt: OptionOrNullable<number | bigint>;
+  maxUses: OptionOrNullable<number>;
+};
+
+export function getTransferDelegateV2Serializer(): Serializer<
+  TransferDelegateV2Args,
+  TransferDelegateV2
+> {
+  return struct<TransferDelegateV2>(
+    [
+      ['expiresAt', option(i64())],
+      ['maxUses', option(u32())],
+    ],
+    { description: 'TransferDelegateV2' }
+  ) as Serializer<TransferDelegateV2Args, TransferDelegateV2>;
+}
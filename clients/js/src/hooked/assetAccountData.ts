@@ -16,6 +16,7 @@ import {
   AssetPluginsList,
   registryRecordsToPluginsList,
   UpdateAuthority,
+  updateAuthorityFromBase,
 } from '../plugins';
 import {
   PluginRegistryV1AccountData,
@@ -95,21 +96,13 @@ export const getAssetV1AccountDataSerializer = (): Serializer<
           buffer
         );
     }
-    const updateAuth = {
-      type: asset.updateAuthority.__kind,
-      address:
-        asset.updateAuthority.__kind === 'None'
-          ? undefined
-          : asset.updateAuthority.fields[0],
-    };
-
     return [
       {
         pluginHeader,
         ...pluginsList,
         ...externalPluginAdaptersList,
         ...asset,
-        updateAuthority: updateAuth,
+        updateAuthority: updateAuthorityFromBase(asset.updateAuthority),
       },
       finalOffset,
     ];
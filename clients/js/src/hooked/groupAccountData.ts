@@ -1,9 +1,16 @@
-import { Serializer } from '@metaplex-foundation/umi/serializers';
+import {
+  Serializer,
+  array,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
 
 import {
   Key,
   PluginHeaderV1,
   PluginHeaderV1AccountData,
+  getKeySerializer,
   getPluginHeaderV1AccountDataSerializer,
 } from '../generated';
 
@@ -34,6 +41,24 @@ export type GroupV1AccountDataArgs = GenGroupV1AccountDataArgs & {
   pluginHeader?: Omit<PluginHeaderV1, 'publicKey' | 'header'>;
 };
 
+/**
+ * Groups stored before `membershipCount` was added end after `assets`, and have no plugins.
+ */
+const getLegacyGroupV1AccountDataSerializer = () =>
+  struct<Omit<GenGroupV1AccountData, 'membershipCount'>>(
+    [
+      ['key', getKeySerializer()],
+      ['updateAuthority', publicKeySerializer()],
+      ['name', string()],
+      ['uri', string()],
+      ['collections', array(publicKeySerializer())],
+      ['groups', array(publicKeySerializer())],
+      ['parentGroups', array(publicKeySerializer())],
+      ['assets', array(publicKeySerializer())],
+    ],
+    { description: 'LegacyGroupV1AccountData' }
+  );
+
 export const getGroupV1AccountDataSerializer = (): Serializer<
   GroupV1AccountDataArgs,
   GroupV1AccountData
@@ -54,9 +79,13 @@ export const getGroupV1AccountDataSerializer = (): Serializer<
       throw new Error('Invalid plugin header serializer configuration.');
     }
 
-    // Deserialize base group data
-    const [group, groupOffset] =
-      genGetGroupV1AccountDataSerializer().deserialize(buffer, offset);
+    // Deserialize base group data, reading legacy groups with a count of zero.
+    const [legacyGroup, legacyGroupOffset] =
+      getLegacyGroupV1AccountDataSerializer().deserialize(buffer, offset);
+    const [group, groupOffset]: [GenGroupV1AccountData, number] =
+      legacyGroupOffset === buffer.length
+        ? [{ ...legacyGroup, membershipCount: 0 }, legacyGroupOffset]
+        : genGetGroupV1AccountDataSerializer().deserialize(buffer, offset);
 
     if (group.key !== Key.GroupV1) {
       throw new Error(`Expected a Group account, got key: ${group.key}`);
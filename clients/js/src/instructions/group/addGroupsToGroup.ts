@@ -1,5 +1,10 @@
-import { Context } from '@metaplex-foundation/umi';
-import { addGroupsToGroupV1 } from '../../generated';
+import { Context, PublicKey } from '@metaplex-foundation/umi';
+import {
+  addGroupsToGroupV1,
+  GroupV1,
+  safeFetchAllGroupV1,
+  safeFetchGroupV1,
+} from '../../generated';
 
 export type AddGroupsToGroupArgs = Parameters<typeof addGroupsToGroupV1>[1];
 
@@ -7,3 +12,83 @@ export const addGroupsToGroup = (
   context: Pick<Context, 'payer' | 'programs'>,
   args: AddGroupsToGroupArgs
 ) => addGroupsToGroupV1(context, args);
+
+export type AddGroupsToGroupWithProofArgs = Omit<
+  AddGroupsToGroupArgs,
+  'parentGroup' | 'ancestors' | 'descendants'
+> & {
+  parentGroup: PublicKey;
+};
+
+/**
+ * Every group reachable from the start groups through next, level by level.
+ * Groups that cannot be fetched are still listed so the program can reject the proof.
+ * @param {Pick<Context, 'rpc'>} context Umi context
+ * @param {PublicKey[]} start The groups of the first level
+ * @param {(group: GroupV1) => PublicKey[]} next The groups of the next level
+ * @returns {Promise<PublicKey[]>} The groups of the proof
+ */
+const fetchGroupProof = async (
+  context: Pick<Context, 'rpc'>,
+  start: PublicKey[],
+  next: (group: GroupV1) => PublicKey[]
+): Promise<PublicKey[]> => {
+  const proof: PublicKey[] = [];
+  let level = start;
+
+  while (level.length > 0) {
+    const unvisited = level.filter(
+      (group, index) => !proof.includes(group) && level.indexOf(group) === index
+    );
+    proof.push(...unvisited);
+    // eslint-disable-next-line no-await-in-loop
+    const groups = await safeFetchAllGroupV1(context, unvisited);
+    level = groups.flatMap(next);
+  }
+
+  return proof;
+};
+
+/**
+ * Add groups to a parent group along with the ancestry proof of the parent group and
+ * the descendant proofs of the new child groups, fetching the groups they walk.
+ * The child groups and the proofs are passed as remaining accounts.
+ * @param {Pick<Context, 'payer' | 'programs' | 'rpc'>} context Umi context
+ * @param {AddGroupsToGroupWithProofArgs} args The instruction arguments
+ * @returns {Promise<TransactionBuilder>} The transaction builder
+ */
+export const addGroupsToGroupWithProof = async (
+  context: Pick<Context, 'payer' | 'programs' | 'rpc'>,
+  args: AddGroupsToGroupWithProofArgs
+) => {
+  const parentGroup = await safeFetchGroupV1(context, args.parentGroup);
+  const ancestors = await fetchGroupProof(
+    context,
+    parentGroup?.parentGroups ?? [],
+    (group) => group.parentGroups
+  );
+
+  const childGroups = await safeFetchAllGroupV1(context, args.groups);
+  const descendants = await fetchGroupProof(
+    context,
+    childGroups.flatMap((group) => group.groups),
+    (group) => group.groups
+  );
+
+  return addGroupsToGroupV1(context, {
+    ...args,
+    ancestors,
+    descendants,
+  }).addRemainingAccounts([
+    ...args.groups.map((group) => ({
+      pubkey: group,
+      isSigner: false,
+      isWritable: true,
+    })),
+    ...[...ancestors, ...descendants].map((group) => ({
+      pubkey: group,
+      isSigner: false,
+      isWritable: false,
+    })),
+  ]);
+};
@@ -8,6 +8,7 @@ export * from './freeze';
 export * from './group';
 export * from './legacyDelegate';
 export * from './legacyRevoke';
+export * from './listing';
 export * from './removePlugin';
 export * from './revokePluginAuthority';
 export * from './transfer';
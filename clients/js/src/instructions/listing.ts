@@ -0,0 +1,147 @@
+import {
+  Context,
+  publicKey,
+  PublicKey,
+  Signer,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  publicKey as publicKeySerializer,
+  u32,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { AssetV1, Listing } from '../generated';
+
+const SYSTEM_PROGRAM_ID = publicKey('11111111111111111111111111111111');
+const SPL_TOKEN_PROGRAM_ID = publicKey(
+  'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'
+);
+const SPL_ASSOCIATED_TOKEN_PROGRAM_ID = publicKey(
+  'ATokenGPvbdGVxr1b2hdsZrYYbPCZR8Ji4Pa5VWDTEwN'
+);
+
+export type ListingPaymentsArgs = {
+  asset: Pick<AssetV1, 'publicKey' | 'owner'>;
+  listing: Pick<Listing, 'price' | 'mint' | 'recipients'>;
+  /** The buyer paying the price, defaults to the payer. */
+  buyer?: Signer;
+  /** The token program of the mint, defaults to the SPL Token program. */
+  tokenProgram?: PublicKey;
+  /** The decimals of the mint, required for listings priced in tokens. */
+  decimals?: number;
+};
+
+/**
+ * Returns the amount owed to each recipient when the asset is bought from its owner.
+ * The owner receives what the recipients are not paid.
+ */
+export const listingRequiredPayments = (
+  owner: PublicKey,
+  listing: Pick<Listing, 'price' | 'recipients'>
+): [PublicKey, bigint][] => {
+  const payments = new Map<PublicKey, bigint>();
+  let paidToRecipients = 0n;
+  listing.recipients.forEach((recipient) => {
+    const amount = (listing.price * BigInt(recipient.basisPoints)) / 10_000n;
+    paidToRecipients += amount;
+    payments.set(
+      recipient.address,
+      (payments.get(recipient.address) ?? 0n) + amount
+    );
+  });
+  payments.set(
+    owner,
+    (payments.get(owner) ?? 0n) + listing.price - paidToRecipients
+  );
+
+  return [...payments.entries()].filter(([, amount]) => amount > 0n);
+};
+
+/**
+ * Builds the payments for a listed asset, which must immediately precede the transfer
+ * of the asset to the buyer. Each payment is a system program transfer of lamports, or an
+ * SPL token `TransferChecked` between associated token accounts, with the asset appended as
+ * the last account to tie the payment to the asset.
+ */
+export const listingPayments = (
+  context: Pick<Context, 'payer' | 'eddsa'>,
+  {
+    asset,
+    listing,
+    buyer = context.payer,
+    tokenProgram = SPL_TOKEN_PROGRAM_ID,
+    decimals,
+  }: ListingPaymentsArgs
+) => {
+  const assetMeta = {
+    pubkey: asset.publicKey,
+    isSigner: false,
+    isWritable: false,
+  };
+  const associatedTokenAccount = (wallet: PublicKey, mint: PublicKey) =>
+    context.eddsa.findPda(SPL_ASSOCIATED_TOKEN_PROGRAM_ID, [
+      publicKeySerializer().serialize(wallet),
+      publicKeySerializer().serialize(tokenProgram),
+      publicKeySerializer().serialize(mint),
+    ])[0];
+
+  let builder = transactionBuilder();
+  listingRequiredPayments(asset.owner, listing).forEach(
+    ([recipient, amount]) => {
+      if (listing.mint.__option === 'None') {
+        builder = builder.add({
+          instruction: {
+            programId: SYSTEM_PROGRAM_ID,
+            keys: [
+              { pubkey: buyer.publicKey, isSigner: true, isWritable: true },
+              { pubkey: recipient, isSigner: false, isWritable: true },
+              assetMeta,
+            ],
+            data: new Uint8Array([
+              ...u32().serialize(2),
+              ...u64().serialize(amount),
+            ]),
+          },
+          signers: [buyer],
+          bytesCreatedOnChain: 0,
+        });
+        return;
+      }
+
+      if (decimals === undefined) {
+        throw new Error('The decimals of the mint are required');
+      }
+      const mint = listing.mint.value;
+      builder = builder.add({
+        instruction: {
+          programId: tokenProgram,
+          keys: [
+            {
+              pubkey: associatedTokenAccount(buyer.publicKey, mint),
+              isSigner: false,
+              isWritable: true,
+            },
+            { pubkey: mint, isSigner: false, isWritable: false },
+            {
+              pubkey: associatedTokenAccount(recipient, mint),
+              isSigner: false,
+              isWritable: true,
+            },
+            { pubkey: buyer.publicKey, isSigner: true, isWritable: false },
+            assetMeta,
+          ],
+          data: new Uint8Array([
+            ...u8().serialize(12),
+            ...u64().serialize(amount),
+            ...u8().serialize(decimals),
+          ]),
+        },
+        signers: [buyer],
+        bytesCreatedOnChain: 0,
+      });
+    }
+  );
+
+  return builder;
+};
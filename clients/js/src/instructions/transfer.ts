@@ -7,8 +7,14 @@ export type TransferArgs = Omit<
   Parameters<typeof transferV1>[1],
   'asset' | 'collection'
 > & {
-  asset: Pick<AssetV1, 'publicKey' | 'owner' | 'oracles' | 'lifecycleHooks'>;
-  collection?: Pick<CollectionV1, 'publicKey' | 'oracles' | 'lifecycleHooks'>;
+  asset: Pick<
+    AssetV1,
+    'publicKey' | 'owner' | 'oracles' | 'lifecycleHooks' | 'groups'
+  >;
+  collection?: Pick<
+    CollectionV1,
+    'publicKey' | 'oracles' | 'lifecycleHooks' | 'groups'
+  >;
 };
 
 export const transfer = (
@@ -32,9 +38,19 @@ export const transfer = (
     }
   );
 
+  // Groups of the asset and its collection are passed so their freezes are enforced.
+  const groupAccounts = [
+    ...(asset.groups?.groups ?? []),
+    ...(collection?.groups?.groups ?? []),
+  ].map((group) => ({
+    pubkey: group,
+    isSigner: false,
+    isWritable: false,
+  }));
+
   return transferV1(context, {
     ...args,
     asset: asset.publicKey,
     collection: collection?.publicKey,
-  }).addRemainingAccounts(extraAccounts);
+  }).addRemainingAccounts([...extraAccounts, ...groupAccounts]);
 };
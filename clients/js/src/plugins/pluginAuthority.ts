@@ -4,6 +4,8 @@ import { BasePluginAuthority } from '../generated';
 export type PluginAuthority = {
   type: PluginAuthorityType;
   address?: PublicKey;
+  signers?: PublicKey[];
+  threshold?: number;
 };
 
 export type PluginAuthorityType = BasePluginAuthority['__kind'];
@@ -15,6 +17,13 @@ export function pluginAuthorityToBase(u: PluginAuthority): BasePluginAuthority {
       address: u.address as PublicKey,
     };
   }
+  if (u.type === 'Multisig') {
+    return {
+      __kind: 'Multisig',
+      signers: u.signers as PublicKey[],
+      threshold: u.threshold as number,
+    };
+  }
   return {
     __kind: u.type,
   };
@@ -23,6 +32,13 @@ export function pluginAuthorityToBase(u: PluginAuthority): BasePluginAuthority {
 export function pluginAuthorityFromBase(
   authority: BasePluginAuthority
 ): PluginAuthority {
+  if (authority.__kind === 'Multisig') {
+    return {
+      type: authority.__kind,
+      signers: authority.signers,
+      threshold: authority.threshold,
+    };
+  }
   return {
     type: authority.__kind,
     address: (authority as any).address,
@@ -36,5 +52,13 @@ export function comparePluginAuthorities(
     return false;
   }
 
+  if (a.type === 'Multisig') {
+    return (
+      a.threshold === b.threshold &&
+      a.signers?.length === b.signers?.length &&
+      (a.signers ?? []).every((signer, i) => signer === b.signers?.[i])
+    );
+  }
+
   return a.address === b.address;
 }
@@ -14,6 +14,21 @@ export type UnwrappedRuleSet =
   | {
       type: 'ProgramDenyList';
       addresses: PublicKey[];
+    }
+  | {
+      type: 'EnforcePayment';
+    }
+  | {
+      type: 'WalletAllowList';
+      addresses: PublicKey[];
+    }
+  | {
+      type: 'WalletDenyList';
+      addresses: PublicKey[];
+    }
+  | {
+      type: 'Composite';
+      ruleSets: RuleSet[];
     };
 
 export type RuleSet = UnwrappedRuleSet | BaseRuleSet;
@@ -35,13 +50,21 @@ export function ruleSetToBase(r: RuleSet): BaseRuleSet {
 
   if (
     ruleSet.type === 'ProgramAllowList' ||
-    ruleSet.type === 'ProgramDenyList'
+    ruleSet.type === 'ProgramDenyList' ||
+    ruleSet.type === 'WalletAllowList' ||
+    ruleSet.type === 'WalletDenyList'
   ) {
     return {
       __kind: ruleSet.type,
       fields: [ruleSet.addresses],
     };
   }
+  if (ruleSet.type === 'Composite') {
+    return {
+      __kind: ruleSet.type,
+      fields: [ruleSet.ruleSets.map(ruleSetToBase)],
+    };
+  }
   return { __kind: ruleSet.type };
 }
 
@@ -52,28 +75,35 @@ export function royaltiesToBase(r: Royalties): BaseRoyalties {
   };
 }
 
-export function royaltiesFromBase(r: BaseRoyalties): Royalties {
-  let ruleSet: RuleSet;
-  if (r.ruleSet.__kind === 'ProgramAllowList') {
-    ruleSet = {
-      ...r.ruleSet,
-      type: 'ProgramAllowList',
-      addresses: r.ruleSet.fields[0],
-    };
-  } else if (r.ruleSet.__kind === 'ProgramDenyList') {
-    ruleSet = {
-      ...r.ruleSet,
-      type: 'ProgramDenyList',
-      addresses: r.ruleSet.fields[0],
+export function ruleSetFromBase(r: BaseRuleSet): RuleSet {
+  if (
+    r.__kind === 'ProgramAllowList' ||
+    r.__kind === 'ProgramDenyList' ||
+    r.__kind === 'WalletAllowList' ||
+    r.__kind === 'WalletDenyList'
+  ) {
+    return {
+      ...r,
+      type: r.__kind,
+      addresses: r.fields[0],
     };
-  } else {
-    ruleSet = {
-      ...r.ruleSet,
-      type: r.ruleSet.__kind,
+  }
+  if (r.__kind === 'Composite') {
+    return {
+      ...r,
+      type: r.__kind,
+      ruleSets: r.fields[0].map(ruleSetFromBase),
     };
   }
   return {
     ...r,
-    ruleSet,
+    type: r.__kind,
+  };
+}
+
+export function royaltiesFromBase(r: BaseRoyalties): Royalties {
+  return {
+    ...r,
+    ruleSet: ruleSetFromBase(r.ruleSet),
   };
 }
@@ -9,8 +9,12 @@ import {
   BaseRoyaltiesArgs,
   BubblegumV2,
   BurnDelegate,
+  BurnDelegateV2,
+  BurnDelegateV2Args,
   Edition,
   EditionArgs,
+  ExecuteGuard,
+  ExecuteGuardArgs,
   FreezeDelegate,
   FreezeDelegateArgs,
   FreezeExecute,
@@ -18,6 +22,10 @@ import {
   Groups,
   GroupsArgs,
   ImmutableMetadata,
+  Listing,
+  ListingArgs,
+  Multisig,
+  MultisigArgs,
   PermanentBurnDelegate,
   PermanentFreezeDelegate,
   PermanentFreezeDelegateArgs,
@@ -25,8 +33,15 @@ import {
   PermanentFreezeExecuteArgs,
   PermanentTransferDelegate,
   basePluginAuthority as pluginAuthority,
+  Rental,
+  RentalArgs,
   baseRuleSet as ruleSet,
+  Soulbound,
+  TimeLock,
+  TimeLockArgs,
   TransferDelegate,
+  TransferDelegateV2,
+  TransferDelegateV2Args,
   baseUpdateAuthority as updateAuthority,
   UpdateDelegate,
   UpdateDelegateArgs,
@@ -110,6 +125,37 @@ export type CreatePluginArgs =
   | {
       type: 'PermanentFreezeExecute';
       data: PermanentFreezeExecuteArgs;
+    }
+  | {
+      type: 'Soulbound';
+    }
+  | {
+      type: 'TimeLock';
+      data: TimeLockArgs;
+    }
+  | {
+      type: 'Rental';
+      data: RentalArgs;
+    }
+  | {
+      type: 'Listing';
+      data: ListingArgs;
+    }
+  | {
+      type: 'Multisig';
+      data: MultisigArgs;
+    }
+  | {
+      type: 'TransferDelegateV2';
+      data: TransferDelegateV2Args;
+    }
+  | {
+      type: 'BurnDelegateV2';
+      data: BurnDelegateV2Args;
+    }
+  | {
+      type: 'ExecuteGuard';
+      data: ExecuteGuardArgs;
     };
 
 export type AuthorityArgsV2 = {
@@ -151,7 +197,19 @@ export type OwnerManagedPluginArgsV2 =
     } & AutographArgs)
   | ({
       type: 'FreezeExecute';
-    } & FreezeExecuteArgs);
+    } & FreezeExecuteArgs)
+  | ({
+      type: 'Rental';
+    } & RentalArgs)
+  | ({
+      type: 'Listing';
+    } & ListingArgs)
+  | ({
+      type: 'TransferDelegateV2';
+    } & TransferDelegateV2Args)
+  | ({
+      type: 'BurnDelegateV2';
+    } & BurnDelegateV2Args);
 
 export type AuthorityManagedPluginArgsV2 =
   | ({
@@ -177,7 +235,19 @@ export type AuthorityManagedPluginArgsV2 =
     }
   | ({
       type: 'VerifiedCreators';
-    } & VerifiedCreatorsArgs);
+    } & VerifiedCreatorsArgs)
+  | {
+      type: 'Soulbound';
+    }
+  | ({
+      type: 'TimeLock';
+    } & TimeLockArgs)
+  | ({
+      type: 'Multisig';
+    } & MultisigArgs)
+  | ({
+      type: 'ExecuteGuard';
+    } & ExecuteGuardArgs);
 
 export type AssetAddablePluginArgsV2 =
   | OwnerManagedPluginArgsV2
@@ -219,6 +289,14 @@ export type BubblegumV2Plugin = BasePlugin & BubblegumV2;
 export type FreezeExecutePlugin = BasePlugin & FreezeExecute;
 export type GroupsPlugin = BasePlugin & Groups;
 export type PermanentFreezeExecutePlugin = BasePlugin & PermanentFreezeExecute;
+export type SoulboundPlugin = BasePlugin & Soulbound;
+export type TimeLockPlugin = BasePlugin & TimeLock;
+export type RentalPlugin = BasePlugin & Rental;
+export type ListingPlugin = BasePlugin & Listing;
+export type MultisigPlugin = BasePlugin & Multisig;
+export type TransferDelegateV2Plugin = BasePlugin & TransferDelegateV2;
+export type BurnDelegateV2Plugin = BasePlugin & BurnDelegateV2;
+export type ExecuteGuardPlugin = BasePlugin & ExecuteGuard;
 
 export type CommonPluginsList = {
   attributes?: AttributesPlugin;
@@ -234,6 +312,10 @@ export type CommonPluginsList = {
   verifiedCreators?: VerifiedCreatorsPlugin;
   freezeExecute?: FreezeExecutePlugin;
   permanentFreezeExecute?: PermanentFreezeExecutePlugin;
+  soulbound?: SoulboundPlugin;
+  timeLock?: TimeLockPlugin;
+  multisig?: MultisigPlugin;
+  executeGuard?: ExecuteGuardPlugin;
 };
 
 export type AssetPluginsList = {
@@ -241,6 +323,10 @@ export type AssetPluginsList = {
   burnDelegate?: BurnDelegatePlugin;
   transferDelegate?: TransferDelegatePlugin;
   edition?: EditionPlugin;
+  rental?: RentalPlugin;
+  listing?: ListingPlugin;
+  transferDelegateV2?: TransferDelegateV2Plugin;
+  burnDelegateV2?: BurnDelegateV2Plugin;
 } & CommonPluginsList;
 
 export type CollectionPluginsList = {
@@ -6,6 +6,8 @@ export type UpdateAuthorityType = BaseUpdateAuthority['__kind'];
 export type UpdateAuthority = {
   type: UpdateAuthorityType;
   address?: PublicKey;
+  signers?: PublicKey[];
+  threshold?: number;
 };
 
 export function updateAuthorityToBase(u: UpdateAuthority): BaseUpdateAuthority {
@@ -14,8 +16,36 @@ export function updateAuthorityToBase(u: UpdateAuthority): BaseUpdateAuthority {
       __kind: 'None',
     };
   }
+  if (u.type === 'Multisig') {
+    return {
+      __kind: 'Multisig',
+      signers: u.signers as PublicKey[],
+      threshold: u.threshold as number,
+    };
+  }
   return {
     __kind: u.type,
     fields: [u.address as PublicKey],
   };
 }
+
+export function updateAuthorityFromBase(
+  authority: BaseUpdateAuthority
+): UpdateAuthority {
+  if (authority.__kind === 'None') {
+    return {
+      type: 'None',
+    };
+  }
+  if (authority.__kind === 'Multisig') {
+    return {
+      type: 'Multisig',
+      signers: authority.signers,
+      threshold: authority.threshold,
+    };
+  }
+  return {
+    type: authority.__kind,
+    address: authority.fields[0],
+  };
+}
@@ -1,4 +1,4 @@
-import { generateSigner, publicKey } from '@metaplex-foundation/umi';
+import { generateSigner, publicKey, some } from '@metaplex-foundation/umi';
 import test from 'ava';
 import { getAssetV1AccountDataSerializer } from '../src/hooked';
 import {
@@ -15,7 +15,7 @@ import {
 
 import { createAsset, createUmi } from './_setupRaw';
 
-test.skip('it can compress an asset without any plugins as the owner', async (t) => {
+test('it can compress an asset without any plugins as the owner', async (t) => {
   // Given a Umi instance and a new signer.
   const umi = await createUmi();
   const asset = await createAsset(umi);
@@ -34,7 +34,10 @@ test.skip('it can compress an asset without any plugins as the owner', async (t)
 
   // And the hash matches the expected value.
   const hashedAssetSchema: HashedAssetSchema = {
-    assetHash: hash(getAssetV1AccountDataSerializer().serialize(asset)),
+    // The sequence number is initialized when the asset is compressed.
+    assetHash: hash(
+      getAssetV1AccountDataSerializer().serialize({ ...asset, seq: some(1) })
+    ),
     pluginHashes: [],
   };
 
@@ -44,7 +47,7 @@ test.skip('it can compress an asset without any plugins as the owner', async (t)
   t.deepEqual(afterAsset.hash, hashedAsset);
 });
 
-test.skip('it cannot compress an asset if not the owner', async (t) => {
+test('it cannot compress an asset if not the owner', async (t) => {
   // Given a Umi instance and a new signer.
   const umi = await createUmi();
   const assetAddress = generateSigner(umi);
@@ -74,7 +77,7 @@ test.skip('it cannot compress an asset if not the owner', async (t) => {
     authority: attacker,
   }).sendAndConfirm(umi);
 
-  await t.throwsAsync(result, { name: 'InvalidAuthority' });
+  await t.throwsAsync(result, { name: 'NoApprovals' });
 
   const afterAsset = await fetchAssetV1(umi, assetAddress.publicKey);
   // console.log("Account State:", afterAsset);
@@ -87,40 +90,6 @@ test.skip('it cannot compress an asset if not the owner', async (t) => {
   });
 });
 
-test('it cannot compress an asset because it is not available', async (t) => {
-  // Given a Umi instance and a new signer.
-  const umi = await createUmi();
-  const assetAddress = generateSigner(umi);
-
-  // When we create a new account.
-  await createV1(umi, {
-    dataState: DataState.AccountState,
-    asset: assetAddress,
-    name: 'Test Bread',
-    uri: 'https://example.com/bread',
-  }).sendAndConfirm(umi);
-
-  // Then an account was created with the correct data.
-  const beforeAsset = await fetchAssetV1(umi, assetAddress.publicKey);
-  // console.log("Account State:", beforeAsset);
-  t.like(beforeAsset, <AssetV1>{
-    publicKey: assetAddress.publicKey,
-    updateAuthority: { type: 'Address', address: umi.identity.publicKey },
-    owner: umi.identity.publicKey,
-    name: 'Test Bread',
-    uri: 'https://example.com/bread',
-  });
-
-  // And when we compress the asset.
-  const result = compressV1(umi, {
-    asset: assetAddress.publicKey,
-    authority: umi.identity,
-    logWrapper: publicKey('noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV'),
-  }).sendAndConfirm(umi);
-
-  await t.throwsAsync(result, { name: 'NotAvailable' });
-});
-
 test('it cannot use an invalid system program', async (t) => {
   // Given a Umi instance and a new signer.
   const umi = await createUmi();
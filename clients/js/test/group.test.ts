@@ -3,6 +3,7 @@ import test from 'ava';
 import {
   addAssetsToGroup,
   addCollectionsToGroup,
+  deserializeGroupV1,
   getGroupV1GpaBuilder,
   Key,
   removeAssetsFromGroup,
@@ -432,3 +433,29 @@ test.serial(
     t.regex((error as Error).message, /Group vector is at maximum capacity/);
   }
 );
+
+test('it can deserialize a group stored before the membership count was added', async (t) => {
+  // Given a group.
+  const umi = await createUmi();
+  const group = await createGroup(umi, { name: 'legacy group' });
+  const account = await umi.rpc.getAccount(group.publicKey);
+  if (!account.exists) {
+    t.fail('group account not found');
+    return;
+  }
+
+  // When it is read without the trailing membership count.
+  const legacyGroup = deserializeGroupV1({
+    ...account,
+    data: account.data.slice(0, account.data.length - 4),
+  });
+
+  // Then it is read with a count of zero.
+  t.like(legacyGroup, {
+    publicKey: group.publicKey,
+    key: Key.GroupV1,
+    name: 'legacy group',
+    assets: [],
+    membershipCount: 0,
+  });
+});
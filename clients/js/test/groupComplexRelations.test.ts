@@ -1,6 +1,10 @@
 import { generateSigner } from '@metaplex-foundation/umi';
 import test from 'ava';
-import { addGroupsToGroup, removeGroupsFromGroup } from '../src';
+import {
+  addGroupsToGroup,
+  addGroupsToGroupWithProof,
+  removeGroupsFromGroup,
+} from '../src';
 import { assertGroup, createGroup, createUmi } from './_setupRaw';
 
 // -----------------------------------------------------------------------------
@@ -86,6 +90,47 @@ test('it rejects adding a parent group as its own child group', async (t) => {
   );
 });
 
+test('it builds the ancestry proof when adding groups to a group', async (t) => {
+  const umi = await createUmi();
+  const top = await createGroup(umi, { name: 'top' });
+  const middle = await createGroup(umi, { name: 'middle' });
+  const bottom = await createGroup(umi, { name: 'bottom' });
+
+  await (
+    await addGroupsToGroupWithProof(umi, {
+      parentGroup: top.publicKey,
+      groups: [middle.publicKey],
+      authority: umi.identity,
+    })
+  ).sendAndConfirm(umi);
+
+  await (
+    await addGroupsToGroupWithProof(umi, {
+      parentGroup: middle.publicKey,
+      groups: [bottom.publicKey],
+      authority: umi.identity,
+    })
+  ).sendAndConfirm(umi);
+
+  await assertGroup(t, umi, {
+    group: bottom.publicKey,
+    updateAuthority: umi.identity.publicKey,
+    parentGroups: [middle.publicKey],
+  });
+
+  // Nesting the top group below the bottom group would create a cycle.
+  await t.throwsAsync(
+    (
+      await addGroupsToGroupWithProof(umi, {
+        parentGroup: bottom.publicKey,
+        groups: [top.publicKey],
+        authority: umi.identity,
+      })
+    ).sendAndConfirm(umi),
+    { name: 'GroupCycleDetected' }
+  );
+});
+
 test('it rejects addGroupsToGroup when signer is not parent group authority', async (t) => {
   const umi = await createUmi();
   const attacker = generateSigner(umi);
@@ -1,4 +1,4 @@
-import { generateSigner } from '@metaplex-foundation/umi';
+import { generateSigner, none, some } from '@metaplex-foundation/umi';
 import test from 'ava';
 import { addPlugin, revokePluginAuthority, burn } from '../../../src';
 import {
@@ -250,3 +250,43 @@ test('a burnDelegate can burn using delegated update authority from collection',
 
   await assertBurned(t, umi, asset.publicKey);
 });
+
+test('a burnDelegateV2 with no uses left cannot burn an asset', async (t) => {
+  const umi = await createUmi();
+  const asset = await createAsset(umi, {});
+  const burnDelegate = generateSigner(umi);
+
+  await addPlugin(umi, {
+    asset: asset.publicKey,
+    plugin: {
+      type: 'BurnDelegateV2',
+      expiresAt: null,
+      maxUses: 0,
+      authority: {
+        type: 'Address',
+        address: burnDelegate.publicKey,
+      },
+    },
+  }).sendAndConfirm(umi);
+
+  await assertAsset(t, umi, {
+    asset: asset.publicKey,
+    owner: umi.identity.publicKey,
+    updateAuthority: { type: 'Address', address: umi.identity.publicKey },
+    burnDelegateV2: {
+      authority: {
+        type: 'Address',
+        address: burnDelegate.publicKey,
+      },
+      expiresAt: none(),
+      maxUses: some(0),
+    },
+  });
+
+  const result = burn(umi, {
+    asset,
+    authority: burnDelegate,
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(result, { name: 'NoApprovals' });
+});
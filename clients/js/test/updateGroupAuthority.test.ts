@@ -1,6 +1,10 @@
-import { generateSigner } from '@metaplex-foundation/umi';
+import { generateSigner, sol } from '@metaplex-foundation/umi';
 import test from 'ava';
-import { updateGroup } from '../src';
+import {
+  acceptGroupUpdateAuthorityV1,
+  proposeGroupUpdateAuthorityV1,
+  updateGroup,
+} from '../src';
 import {
   assertGroup,
   createGroup,
@@ -64,6 +68,48 @@ test("it can transfer a group's update authority", async (t) => {
   t.is(error?.name, 'InvalidAuthority');
 });
 
+test("it can hand over a group's update authority once accepted", async (t) => {
+  const umi = await createUmi();
+  const group = await createGroup(umi);
+
+  const proposalPayer = generateSigner(umi);
+  await umi.rpc.airdrop(proposalPayer.publicKey, sol(1));
+  const newAuthority = generateSigner(umi);
+
+  await proposeGroupUpdateAuthorityV1(umi, {
+    group: group.publicKey,
+    payer: proposalPayer,
+    authority: umi.identity,
+    newUpdateAuthority: newAuthority.publicKey,
+  }).sendAndConfirm(umi);
+
+  // The group is unchanged until the proposal is accepted.
+  await assertGroup(t, umi, {
+    ...DEFAULT_GROUP,
+    group: group.publicKey,
+    updateAuthority: umi.identity.publicKey,
+  });
+
+  const balanceBefore = await umi.rpc.getBalance(proposalPayer.publicKey);
+
+  await acceptGroupUpdateAuthorityV1(umi, {
+    group: group.publicKey,
+    payer: umi.identity,
+    newUpdateAuthority: newAuthority,
+    proposalPayer: proposalPayer.publicKey,
+  }).sendAndConfirm(umi);
+
+  await assertGroup(t, umi, {
+    ...DEFAULT_GROUP,
+    group: group.publicKey,
+    updateAuthority: newAuthority.publicKey,
+  });
+
+  // The rent of the proposal is refunded to the account that paid for it.
+  const balanceAfter = await umi.rpc.getBalance(proposalPayer.publicKey);
+  t.true(balanceAfter.basisPoints > balanceBefore.basisPoints);
+});
+
 test('it can updateGroup with both name and URI simultaneously', async (t) => {
   const umi = await createUmi();
   const group = await createGroup(umi, {
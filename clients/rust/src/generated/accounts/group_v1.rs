@@ -6,6 +6,7 @@
 //!
 
 use crate::generated::types::Key;
+use crate::hooked::MembershipCount;
 #[cfg(feature = "anchor")]
 use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
 #[cfg(not(feature = "anchor"))]
@@ -45,6 +46,7 @@ pub struct GroupV1 {
         serde(with = "serde_with::As::<Vec<serde_with::DisplayFromStr>>")
     )]
     pub assets: Vec<Pubkey>,
+    pub membership_count: MembershipCount,
 }
 
 impl GroupV1 {
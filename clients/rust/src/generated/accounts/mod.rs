@@ -8,6 +8,9 @@
 pub(crate) mod r#asset_signer;
 pub(crate) mod r#base_asset_v1;
 pub(crate) mod r#base_collection_v1;
+pub(crate) mod r#group_authority_proposal_v1;
+pub(crate) mod r#group_delegate_v1;
+pub(crate) mod r#group_membership_v1;
 pub(crate) mod r#group_v1;
 pub(crate) mod r#hashed_asset_v1;
 pub(crate) mod r#plugin_header_v1;
@@ -16,6 +19,9 @@ pub(crate) mod r#plugin_registry_v1;
 pub use self::r#asset_signer::*;
 pub use self::r#base_asset_v1::*;
 pub use self::r#base_collection_v1::*;
+pub use self::r#group_authority_proposal_v1::*;
+pub use self::r#group_delegate_v1::*;
+pub use self::r#group_membership_v1::*;
 pub use self::r#group_v1::*;
 pub use self::r#hashed_asset_v1::*;
 pub use self::r#plugin_header_v1::*;
@@ -182,6 +182,42 @@ pub enum MplCoreError {
     /// 56 (0x38) - Bidirectional group relationship is inconsistent
     #[error("Bidirectional group relationship is inconsistent")]
     InconsistentGroupRelationship,
+    /// 57 (0x39) - Invalid accounts provided for batch operation
+    #[error("Invalid accounts provided for batch operation")]
+    InvalidBatchAccounts,
+    /// 58 (0x3A) - Invalid multisig signers or threshold
+    #[error("Invalid multisig signers or threshold")]
+    InvalidMultisig,
+    /// 59 (0x3B) - The maximum supply of editions has been printed
+    #[error("The maximum supply of editions has been printed")]
+    MaxSupplyReached,
+    /// 60 (0x3C) - The program is not allowed by the execute guard
+    #[error("The program is not allowed by the execute guard")]
+    ProgramNotAllowed,
+    /// 61 (0x3D) - The asset signer spent more than the execute guard allows
+    #[error("The asset signer spent more than the execute guard allows")]
+    SpendLimitExceeded,
+    /// 62 (0x3E) - Invalid group membership account
+    #[error("Invalid group membership account")]
+    InvalidGroupMembership,
+    /// 63 (0x3F) - Invalid group delegate account
+    #[error("Invalid group delegate account")]
+    InvalidGroupDelegate,
+    /// 64 (0x40) - Invalid group authority proposal account
+    #[error("Invalid group authority proposal account")]
+    InvalidGroupAuthorityProposal,
+    /// 65 (0x41) - Adding the group would create a cycle in the group hierarchy
+    #[error("Adding the group would create a cycle in the group hierarchy")]
+    GroupCycleDetected,
+    /// 66 (0x42) - Group ancestry proof is incomplete or invalid
+    #[error("Group ancestry proof is incomplete or invalid")]
+    InvalidGroupAncestry,
+    /// 67 (0x43) - Missing group account required by the Groups plugin
+    #[error("Missing group account required by the Groups plugin")]
+    MissingGroupAccount,
+    /// 68 (0x44) - Group is frozen
+    #[error("Group is frozen")]
+    GroupFrozen,
 }
 
 impl From<MplCoreError> for ProgramError {
@@ -251,6 +287,18 @@ impl TryFrom<u32> for MplCoreError {
             54 => Ok(MplCoreError::GroupVectorFull),
             55 => Ok(MplCoreError::GroupNestingDepthExceeded),
             56 => Ok(MplCoreError::InconsistentGroupRelationship),
+            57 => Ok(MplCoreError::InvalidBatchAccounts),
+            58 => Ok(MplCoreError::InvalidMultisig),
+            59 => Ok(MplCoreError::MaxSupplyReached),
+            60 => Ok(MplCoreError::ProgramNotAllowed),
+            61 => Ok(MplCoreError::SpendLimitExceeded),
+            62 => Ok(MplCoreError::InvalidGroupMembership),
+            63 => Ok(MplCoreError::InvalidGroupDelegate),
+            64 => Ok(MplCoreError::InvalidGroupAuthorityProposal),
+            65 => Ok(MplCoreError::GroupCycleDetected),
+            66 => Ok(MplCoreError::InvalidGroupAncestry),
+            67 => Ok(MplCoreError::MissingGroupAccount),
+            68 => Ok(MplCoreError::GroupFrozen),
             _ => Err(ProgramError::InvalidArgument),
         }
     }
@@ -346,6 +394,26 @@ impl ToStr for MplCoreError {
             MplCoreError::InconsistentGroupRelationship => {
                 "Bidirectional group relationship is inconsistent"
             }
+            MplCoreError::InvalidBatchAccounts => "Invalid accounts provided for batch operation",
+            MplCoreError::InvalidMultisig => "Invalid multisig signers or threshold",
+            MplCoreError::MaxSupplyReached => "The maximum supply of editions has been printed",
+            MplCoreError::ProgramNotAllowed => "The program is not allowed by the execute guard",
+            MplCoreError::SpendLimitExceeded => {
+                "The asset signer spent more than the execute guard allows"
+            }
+            MplCoreError::InvalidGroupMembership => "Invalid group membership account",
+            MplCoreError::InvalidGroupDelegate => "Invalid group delegate account",
+            MplCoreError::InvalidGroupAuthorityProposal => {
+                "Invalid group authority proposal account"
+            }
+            MplCoreError::GroupCycleDetected => {
+                "Adding the group would create a cycle in the group hierarchy"
+            }
+            MplCoreError::InvalidGroupAncestry => "Group ancestry proof is incomplete or invalid",
+            MplCoreError::MissingGroupAccount => {
+                "Missing group account required by the Groups plugin"
+            }
+            MplCoreError::GroupFrozen => "Group is frozen",
         }
     }
 }
@@ -0,0 +1,497 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Accounts.
+pub struct AcceptGroupUpdateAuthorityV1 {
+    /// The address of the group
+    pub group: solana_program::pubkey::Pubkey,
+    /// The update authority proposal account of the group
+    pub group_authority_proposal: solana_program::pubkey::Pubkey,
+    /// The account paying for the storage fees
+    pub payer: solana_program::pubkey::Pubkey,
+    /// The proposed update authority of the group
+    pub new_update_authority: solana_program::pubkey::Pubkey,
+    /// The system program
+    pub system_program: solana_program::pubkey::Pubkey,
+    /// The account that paid for the proposal, receiving its reclaimed lamports
+    pub proposal_payer: solana_program::pubkey::Pubkey,
+}
+
+impl AcceptGroupUpdateAuthorityV1 {
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(&[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.group, false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.group_authority_proposal,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.payer, true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.new_update_authority,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.proposal_payer,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let data = borsh::to_vec(&(AcceptGroupUpdateAuthorityV1InstructionData::new())).unwrap();
+
+        solana_program::instruction::Instruction {
+            program_id: crate::MPL_CORE_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+pub struct AcceptGroupUpdateAuthorityV1InstructionData {
+    discriminator: u8,
+}
+
+impl AcceptGroupUpdateAuthorityV1InstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 55 }
+    }
+}
+
+/// Instruction builder for `AcceptGroupUpdateAuthorityV1`.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` group
+///   1. `[writable]` group_authority_proposal
+///   2. `[writable, signer]` payer
+///   3. `[signer]` new_update_authority
+///   4. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   5. `[writable]` proposal_payer
+#[derive(Default)]
+pub struct AcceptGroupUpdateAuthorityV1Builder {
+    group: Option<solana_program::pubkey::Pubkey>,
+    group_authority_proposal: Option<solana_program::pubkey::Pubkey>,
+    payer: Option<solana_program::pubkey::Pubkey>,
+    new_update_authority: Option<solana_program::pubkey::Pubkey>,
+    system_program: Option<solana_program::pubkey::Pubkey>,
+    proposal_payer: Option<solana_program::pubkey::Pubkey>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl AcceptGroupUpdateAuthorityV1Builder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// The address of the group
+    #[inline(always)]
+    pub fn group(&mut self, group: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.group = Some(group);
+        self
+    }
+    /// The update authority proposal account of the group
+    #[inline(always)]
+    pub fn group_authority_proposal(
+        &mut self,
+        group_authority_proposal: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.group_authority_proposal = Some(group_authority_proposal);
+        self
+    }
+    /// The account paying for the storage fees
+    #[inline(always)]
+    pub fn payer(&mut self, payer: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.payer = Some(payer);
+        self
+    }
+    /// The proposed update authority of the group
+    #[inline(always)]
+    pub fn new_update_authority(
+        &mut self,
+        new_update_authority: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.new_update_authority = Some(new_update_authority);
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    /// The system program
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    /// The account that paid for the proposal, receiving its reclaimed lamports
+    #[inline(always)]
+    pub fn proposal_payer(&mut self, proposal_payer: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.proposal_payer = Some(proposal_payer);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = AcceptGroupUpdateAuthorityV1 {
+            group: self.group.expect("group is not set"),
+            group_authority_proposal: self
+                .group_authority_proposal
+                .expect("group_authority_proposal is not set"),
+            payer: self.payer.expect("payer is not set"),
+            new_update_authority: self
+                .new_update_authority
+                .expect("new_update_authority is not set"),
+            system_program: self
+                .system_program
+                .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            proposal_payer: self.proposal_payer.expect("proposal_payer is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
+    }
+}
+
+/// `accept_group_update_authority_v1` CPI accounts.
+pub struct AcceptGroupUpdateAuthorityV1CpiAccounts<'a, 'b> {
+    /// The address of the group
+    pub group: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The update authority proposal account of the group
+    pub group_authority_proposal: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The account paying for the storage fees
+    pub payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The proposed update authority of the group
+    pub new_update_authority: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The system program
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The account that paid for the proposal, receiving its reclaimed lamports
+    pub proposal_payer: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `accept_group_update_authority_v1` CPI instruction.
+pub struct AcceptGroupUpdateAuthorityV1Cpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The address of the group
+    pub group: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The update authority proposal account of the group
+    pub group_authority_proposal: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The account paying for the storage fees
+    pub payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The proposed update authority of the group
+    pub new_update_authority: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The system program
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The account that paid for the proposal, receiving its reclaimed lamports
+    pub proposal_payer: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+impl<'a, 'b> AcceptGroupUpdateAuthorityV1Cpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: AcceptGroupUpdateAuthorityV1CpiAccounts<'a, 'b>,
+    ) -> Self {
+        Self {
+            __program: program,
+            group: accounts.group,
+            group_authority_proposal: accounts.group_authority_proposal,
+            payer: accounts.payer,
+            new_update_authority: accounts.new_update_authority,
+            system_program: accounts.system_program,
+            proposal_payer: accounts.proposal_payer,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.group.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.group_authority_proposal.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.payer.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.new_update_authority.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.proposal_payer.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_writable: remaining_account.1,
+                is_signer: remaining_account.2,
+            })
+        });
+        let data = borsh::to_vec(&(AcceptGroupUpdateAuthorityV1InstructionData::new())).unwrap();
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::MPL_CORE_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(6 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.group.clone());
+        account_infos.push(self.group_authority_proposal.clone());
+        account_infos.push(self.payer.clone());
+        account_infos.push(self.new_update_authority.clone());
+        account_infos.push(self.system_program.clone());
+        account_infos.push(self.proposal_payer.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `AcceptGroupUpdateAuthorityV1` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` group
+///   1. `[writable]` group_authority_proposal
+///   2. `[writable, signer]` payer
+///   3. `[signer]` new_update_authority
+///   4. `[]` system_program
+///   5. `[writable]` proposal_payer
+pub struct AcceptGroupUpdateAuthorityV1CpiBuilder<'a, 'b> {
+    instruction: Box<AcceptGroupUpdateAuthorityV1CpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> AcceptGroupUpdateAuthorityV1CpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(AcceptGroupUpdateAuthorityV1CpiBuilderInstruction {
+            __program: program,
+            group: None,
+            group_authority_proposal: None,
+            payer: None,
+            new_update_authority: None,
+            system_program: None,
+            proposal_payer: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// The address of the group
+    #[inline(always)]
+    pub fn group(&mut self, group: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.group = Some(group);
+        self
+    }
+    /// The update authority proposal account of the group
+    #[inline(always)]
+    pub fn group_authority_proposal(
+        &mut self,
+        group_authority_proposal: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.group_authority_proposal = Some(group_authority_proposal);
+        self
+    }
+    /// The account paying for the storage fees
+    #[inline(always)]
+    pub fn payer(&mut self, payer: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.payer = Some(payer);
+        self
+    }
+    /// The proposed update authority of the group
+    #[inline(always)]
+    pub fn new_update_authority(
+        &mut self,
+        new_update_authority: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.new_update_authority = Some(new_update_authority);
+        self
+    }
+    /// The system program
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
+    /// The account that paid for the proposal, receiving its reclaimed lamports
+    #[inline(always)]
+    pub fn proposal_payer(
+        &mut self,
+        proposal_payer: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.proposal_payer = Some(proposal_payer);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let instruction = AcceptGroupUpdateAuthorityV1Cpi {
+            __program: self.instruction.__program,
+
+            group: self.instruction.group.expect("group is not set"),
+
+            group_authority_proposal: self
+                .instruction
+                .group_authority_proposal
+                .expect("group_authority_proposal is not set"),
+
+            payer: self.instruction.payer.expect("payer is not set"),
+
+            new_update_authority: self
+                .instruction
+                .new_update_authority
+                .expect("new_update_authority is not set"),
+
+            system_program: self
+                .instruction
+                .system_program
+                .expect("system_program is not set"),
+
+            proposal_payer: self
+                .instruction
+                .proposal_payer
+                .expect("proposal_payer is not set"),
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+struct AcceptGroupUpdateAuthorityV1CpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    group: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    group_authority_proposal: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    new_update_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    proposal_payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
@@ -16,7 +16,7 @@ pub struct AddCollectionsToGroupV1 {
     pub group: solana_program::pubkey::Pubkey,
     /// The account paying for storage fees
     pub payer: solana_program::pubkey::Pubkey,
-    /// The group update authority and collection update authority or delegate
+    /// The group update authority or delegate and collection update authority or delegate
     pub authority: Option<solana_program::pubkey::Pubkey>,
     /// The system program
     pub system_program: solana_program::pubkey::Pubkey,
@@ -109,7 +109,7 @@ impl AddCollectionsToGroupV1Builder {
         self
     }
     /// `[optional account]`
-    /// The group update authority and collection update authority or delegate
+    /// The group update authority or delegate and collection update authority or delegate
     #[inline(always)]
     pub fn authority(&mut self, authority: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
         self.authority = authority;
@@ -161,7 +161,7 @@ pub struct AddCollectionsToGroupV1CpiAccounts<'a, 'b> {
     pub group: &'b solana_program::account_info::AccountInfo<'a>,
     /// The account paying for storage fees
     pub payer: &'b solana_program::account_info::AccountInfo<'a>,
-    /// The group update authority and collection update authority or delegate
+    /// The group update authority or delegate and collection update authority or delegate
     pub authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The system program
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
@@ -175,7 +175,7 @@ pub struct AddCollectionsToGroupV1Cpi<'a, 'b> {
     pub group: &'b solana_program::account_info::AccountInfo<'a>,
     /// The account paying for storage fees
     pub payer: &'b solana_program::account_info::AccountInfo<'a>,
-    /// The group update authority and collection update authority or delegate
+    /// The group update authority or delegate and collection update authority or delegate
     pub authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The system program
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
@@ -322,7 +322,7 @@ impl<'a, 'b> AddCollectionsToGroupV1CpiBuilder<'a, 'b> {
         self
     }
     /// `[optional account]`
-    /// The group update authority and collection update authority or delegate
+    /// The group update authority or delegate and collection update authority or delegate
     #[inline(always)]
     pub fn authority(
         &mut self,
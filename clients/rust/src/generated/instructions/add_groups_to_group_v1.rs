@@ -89,6 +89,8 @@ impl AddGroupsToGroupV1InstructionData {
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct AddGroupsToGroupV1InstructionArgs {
     pub groups: Vec<Pubkey>,
+    pub ancestors: Option<Vec<Pubkey>>,
+    pub descendants: Option<Vec<Pubkey>>,
 }
 
 /// Instruction builder for `AddGroupsToGroupV1`.
@@ -106,6 +108,8 @@ pub struct AddGroupsToGroupV1Builder {
     authority: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
     groups: Option<Vec<Pubkey>>,
+    ancestors: Option<Vec<Pubkey>>,
+    descendants: Option<Vec<Pubkey>>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -144,6 +148,18 @@ impl AddGroupsToGroupV1Builder {
         self.groups = Some(groups);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn ancestors(&mut self, ancestors: Vec<Pubkey>) -> &mut Self {
+        self.ancestors = Some(ancestors);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn descendants(&mut self, descendants: Vec<Pubkey>) -> &mut Self {
+        self.descendants = Some(descendants);
+        self
+    }
     /// Add an aditional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -174,6 +190,8 @@ impl AddGroupsToGroupV1Builder {
         };
         let args = AddGroupsToGroupV1InstructionArgs {
             groups: self.groups.clone().expect("groups is not set"),
+            ancestors: self.ancestors.clone(),
+            descendants: self.descendants.clone(),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -337,6 +355,8 @@ impl<'a, 'b> AddGroupsToGroupV1CpiBuilder<'a, 'b> {
             authority: None,
             system_program: None,
             groups: None,
+            ancestors: None,
+            descendants: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -380,6 +400,18 @@ impl<'a, 'b> AddGroupsToGroupV1CpiBuilder<'a, 'b> {
         self.instruction.groups = Some(groups);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn ancestors(&mut self, ancestors: Vec<Pubkey>) -> &mut Self {
+        self.instruction.ancestors = Some(ancestors);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn descendants(&mut self, descendants: Vec<Pubkey>) -> &mut Self {
+        self.instruction.descendants = Some(descendants);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -423,6 +455,8 @@ impl<'a, 'b> AddGroupsToGroupV1CpiBuilder<'a, 'b> {
     ) -> solana_program::entrypoint::ProgramResult {
         let args = AddGroupsToGroupV1InstructionArgs {
             groups: self.instruction.groups.clone().expect("groups is not set"),
+            ancestors: self.instruction.ancestors.clone(),
+            descendants: self.instruction.descendants.clone(),
         };
         let instruction = AddGroupsToGroupV1Cpi {
             __program: self.instruction.__program,
@@ -456,6 +490,8 @@ struct AddGroupsToGroupV1CpiBuilderInstruction<'a, 'b> {
     authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     groups: Option<Vec<Pubkey>>,
+    ancestors: Option<Vec<Pubkey>>,
+    descendants: Option<Vec<Pubkey>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
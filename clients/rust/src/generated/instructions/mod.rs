@@ -5,42 +5,58 @@
 //! [https://github.com/metaplex-foundation/kinobi]
 //!
 
+pub(crate) mod r#accept_group_update_authority_v1;
 pub(crate) mod r#add_assets_to_group_v1;
 pub(crate) mod r#add_collection_external_plugin_adapter_v1;
 pub(crate) mod r#add_collection_plugin_v1;
 pub(crate) mod r#add_collections_to_group_v1;
 pub(crate) mod r#add_external_plugin_adapter_v1;
+pub(crate) mod r#add_group_plugin_v1;
 pub(crate) mod r#add_groups_to_group_v1;
 pub(crate) mod r#add_plugin_v1;
 pub(crate) mod r#approve_collection_plugin_authority_v1;
+pub(crate) mod r#approve_group_delegate_v1;
 pub(crate) mod r#approve_plugin_authority_v1;
+pub(crate) mod r#burn_batch_v1;
 pub(crate) mod r#burn_collection_v1;
 pub(crate) mod r#burn_v1;
 pub(crate) mod r#close_group_v1;
+pub(crate) mod r#close_group_v2;
 pub(crate) mod r#collect;
 pub(crate) mod r#compress_v1;
+pub(crate) mod r#create_batch_v1;
 pub(crate) mod r#create_collection_v1;
 pub(crate) mod r#create_collection_v2;
 pub(crate) mod r#create_group_v1;
 pub(crate) mod r#create_v1;
 pub(crate) mod r#create_v2;
 pub(crate) mod r#decompress_v1;
+pub(crate) mod r#execute_batch_v1;
+pub(crate) mod r#execute_collection_v1;
 pub(crate) mod r#execute_v1;
+pub(crate) mod r#move_to_collection_v1;
+pub(crate) mod r#print_edition_v1;
+pub(crate) mod r#propose_group_update_authority_v1;
 pub(crate) mod r#remove_assets_from_group_v1;
 pub(crate) mod r#remove_collection_external_plugin_adapter_v1;
 pub(crate) mod r#remove_collection_plugin_v1;
 pub(crate) mod r#remove_collections_from_group_v1;
 pub(crate) mod r#remove_external_plugin_adapter_v1;
+pub(crate) mod r#remove_group_plugin_v1;
 pub(crate) mod r#remove_groups_from_group_v1;
 pub(crate) mod r#remove_plugin_v1;
 pub(crate) mod r#revoke_collection_plugin_authority_v1;
+pub(crate) mod r#revoke_group_delegate_v1;
 pub(crate) mod r#revoke_plugin_authority_v1;
+pub(crate) mod r#transfer_batch_v1;
 pub(crate) mod r#transfer_v1;
+pub(crate) mod r#transfer_v2;
 pub(crate) mod r#update_collection_external_plugin_adapter_v1;
 pub(crate) mod r#update_collection_info_v1;
 pub(crate) mod r#update_collection_plugin_v1;
 pub(crate) mod r#update_collection_v1;
 pub(crate) mod r#update_external_plugin_adapter_v1;
+pub(crate) mod r#update_group_plugin_v1;
 pub(crate) mod r#update_group_v1;
 pub(crate) mod r#update_plugin_v1;
 pub(crate) mod r#update_v1;
@@ -48,42 +64,58 @@ pub(crate) mod r#update_v2;
 pub(crate) mod r#write_collection_external_plugin_adapter_data_v1;
 pub(crate) mod r#write_external_plugin_adapter_data_v1;
 
+pub use self::r#accept_group_update_authority_v1::*;
 pub use self::r#add_assets_to_group_v1::*;
 pub use self::r#add_collection_external_plugin_adapter_v1::*;
 pub use self::r#add_collection_plugin_v1::*;
 pub use self::r#add_collections_to_group_v1::*;
 pub use self::r#add_external_plugin_adapter_v1::*;
+pub use self::r#add_group_plugin_v1::*;
 pub use self::r#add_groups_to_group_v1::*;
 pub use self::r#add_plugin_v1::*;
 pub use self::r#approve_collection_plugin_authority_v1::*;
+pub use self::r#approve_group_delegate_v1::*;
 pub use self::r#approve_plugin_authority_v1::*;
+pub use self::r#burn_batch_v1::*;
 pub use self::r#burn_collection_v1::*;
 pub use self::r#burn_v1::*;
 pub use self::r#close_group_v1::*;
+pub use self::r#close_group_v2::*;
 pub use self::r#collect::*;
 pub use self::r#compress_v1::*;
+pub use self::r#create_batch_v1::*;
 pub use self::r#create_collection_v1::*;
 pub use self::r#create_collection_v2::*;
 pub use self::r#create_group_v1::*;
 pub use self::r#create_v1::*;
 pub use self::r#create_v2::*;
 pub use self::r#decompress_v1::*;
+pub use self::r#execute_batch_v1::*;
+pub use self::r#execute_collection_v1::*;
 pub use self::r#execute_v1::*;
+pub use self::r#move_to_collection_v1::*;
+pub use self::r#print_edition_v1::*;
+pub use self::r#propose_group_update_authority_v1::*;
 pub use self::r#remove_assets_from_group_v1::*;
 pub use self::r#remove_collection_external_plugin_adapter_v1::*;
 pub use self::r#remove_collection_plugin_v1::*;
 pub use self::r#remove_collections_from_group_v1::*;
 pub use self::r#remove_external_plugin_adapter_v1::*;
+pub use self::r#remove_group_plugin_v1::*;
 pub use self::r#remove_groups_from_group_v1::*;
 pub use self::r#remove_plugin_v1::*;
 pub use self::r#revoke_collection_plugin_authority_v1::*;
+pub use self::r#revoke_group_delegate_v1::*;
 pub use self::r#revoke_plugin_authority_v1::*;
+pub use self::r#transfer_batch_v1::*;
 pub use self::r#transfer_v1::*;
+pub use self::r#transfer_v2::*;
 pub use self::r#update_collection_external_plugin_adapter_v1::*;
 pub use self::r#update_collection_info_v1::*;
 pub use self::r#update_collection_plugin_v1::*;
 pub use self::r#update_collection_v1::*;
 pub use self::r#update_external_plugin_adapter_v1::*;
+pub use self::r#update_group_plugin_v1::*;
 pub use self::r#update_group_v1::*;
 pub use self::r#update_plugin_v1::*;
 pub use self::r#update_v1::*;
@@ -17,7 +17,7 @@ pub struct RemoveCollectionsFromGroupV1 {
     pub group: solana_program::pubkey::Pubkey,
     /// The account paying for storage fees
     pub payer: solana_program::pubkey::Pubkey,
-    /// The group update authority and collection update authority or delegate
+    /// The group update authority or delegate and collection update authority or delegate
     pub authority: Option<solana_program::pubkey::Pubkey>,
     /// The system program
     pub system_program: solana_program::pubkey::Pubkey,
@@ -126,7 +126,7 @@ impl RemoveCollectionsFromGroupV1Builder {
         self
     }
     /// `[optional account]`
-    /// The group update authority and collection update authority or delegate
+    /// The group update authority or delegate and collection update authority or delegate
     #[inline(always)]
     pub fn authority(&mut self, authority: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
         self.authority = authority;
@@ -186,7 +186,7 @@ pub struct RemoveCollectionsFromGroupV1CpiAccounts<'a, 'b> {
     pub group: &'b solana_program::account_info::AccountInfo<'a>,
     /// The account paying for storage fees
     pub payer: &'b solana_program::account_info::AccountInfo<'a>,
-    /// The group update authority and collection update authority or delegate
+    /// The group update authority or delegate and collection update authority or delegate
     pub authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The system program
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
@@ -200,7 +200,7 @@ pub struct RemoveCollectionsFromGroupV1Cpi<'a, 'b> {
     pub group: &'b solana_program::account_info::AccountInfo<'a>,
     /// The account paying for storage fees
     pub payer: &'b solana_program::account_info::AccountInfo<'a>,
-    /// The group update authority and collection update authority or delegate
+    /// The group update authority or delegate and collection update authority or delegate
     pub authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The system program
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
@@ -355,7 +355,7 @@ impl<'a, 'b> RemoveCollectionsFromGroupV1CpiBuilder<'a, 'b> {
         self
     }
     /// `[optional account]`
-    /// The group update authority and collection update authority or delegate
+    /// The group update authority or delegate and collection update authority or delegate
     #[inline(always)]
     pub fn authority(
         &mut self,
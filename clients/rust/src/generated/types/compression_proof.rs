@@ -5,6 +5,7 @@
 //! [https://github.com/metaplex-foundation/kinobi]
 //!
 
+use crate::generated::types::HashableExternalPluginAdapterSchema;
 use crate::generated::types::HashablePluginSchema;
 use crate::generated::types::UpdateAuthority;
 #[cfg(feature = "anchor")]
@@ -28,4 +29,5 @@ pub struct CompressionProof {
     pub uri: String,
     pub seq: u64,
     pub plugins: Vec<HashablePluginSchema>,
+    pub external_plugin_adapters: Vec<HashableExternalPluginAdapterSchema>,
 }
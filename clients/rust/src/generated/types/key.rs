@@ -23,4 +23,7 @@ pub enum Key {
     PluginRegistryV1,
     CollectionV1,
     GroupV1,
+    GroupMembershipV1,
+    GroupDelegateV1,
+    GroupAuthorityProposalV1,
 }
@@ -12,12 +12,14 @@ pub(crate) mod r#agent_identity_update_info;
 pub(crate) mod r#app_data;
 pub(crate) mod r#app_data_init_info;
 pub(crate) mod r#app_data_update_info;
+pub(crate) mod r#asset_init_info;
 pub(crate) mod r#attribute;
 pub(crate) mod r#attributes;
 pub(crate) mod r#autograph;
 pub(crate) mod r#autograph_signature;
 pub(crate) mod r#bubblegum_v2;
 pub(crate) mod r#burn_delegate;
+pub(crate) mod r#burn_delegate_v2;
 pub(crate) mod r#compression_proof;
 pub(crate) mod r#creator;
 pub(crate) mod r#data_section;
@@ -25,6 +27,9 @@ pub(crate) mod r#data_section_init_info;
 pub(crate) mod r#data_section_update_info;
 pub(crate) mod r#data_state;
 pub(crate) mod r#edition;
+pub(crate) mod r#execute_batch_instruction;
+pub(crate) mod r#execute_guard;
+pub(crate) mod r#execute_guard_program;
 pub(crate) mod r#external_check_result;
 pub(crate) mod r#external_plugin_adapter;
 pub(crate) mod r#external_plugin_adapter_init_info;
@@ -37,7 +42,12 @@ pub(crate) mod r#external_validation_result;
 pub(crate) mod r#extra_account;
 pub(crate) mod r#freeze_delegate;
 pub(crate) mod r#freeze_execute;
+pub(crate) mod r#group_plugin_init_info;
+pub(crate) mod r#group_plugin_key;
+pub(crate) mod r#group_plugin_update_info;
 pub(crate) mod r#groups;
+pub(crate) mod r#hashable_external_plugin_adapter_schema;
+pub(crate) mod r#hashable_external_plugin_adapter_schema_v1;
 pub(crate) mod r#hashable_plugin_schema;
 pub(crate) mod r#hashed_asset_schema;
 pub(crate) mod r#hookable_lifecycle_event;
@@ -53,7 +63,10 @@ pub(crate) mod r#linked_data_key;
 pub(crate) mod r#linked_lifecycle_hook;
 pub(crate) mod r#linked_lifecycle_hook_init_info;
 pub(crate) mod r#linked_lifecycle_hook_update_info;
+pub(crate) mod r#listing;
+pub(crate) mod r#listing_recipient;
 pub(crate) mod r#master_edition;
+pub(crate) mod r#multisig;
 pub(crate) mod r#oracle;
 pub(crate) mod r#oracle_init_info;
 pub(crate) mod r#oracle_update_info;
@@ -69,10 +82,14 @@ pub(crate) mod r#plugin_type;
 pub(crate) mod r#registry_record;
 pub(crate) mod r#relationship_entry;
 pub(crate) mod r#relationship_kind;
+pub(crate) mod r#rental;
 pub(crate) mod r#royalties;
 pub(crate) mod r#rule_set;
 pub(crate) mod r#seed;
+pub(crate) mod r#soulbound;
+pub(crate) mod r#time_lock;
 pub(crate) mod r#transfer_delegate;
+pub(crate) mod r#transfer_delegate_v2;
 pub(crate) mod r#update_authority;
 pub(crate) mod r#update_delegate;
 pub(crate) mod r#update_type;
@@ -88,12 +105,14 @@ pub use self::r#agent_identity_update_info::*;
 pub use self::r#app_data::*;
 pub use self::r#app_data_init_info::*;
 pub use self::r#app_data_update_info::*;
+pub use self::r#asset_init_info::*;
 pub use self::r#attribute::*;
 pub use self::r#attributes::*;
 pub use self::r#autograph::*;
 pub use self::r#autograph_signature::*;
 pub use self::r#bubblegum_v2::*;
 pub use self::r#burn_delegate::*;
+pub use self::r#burn_delegate_v2::*;
 pub use self::r#compression_proof::*;
 pub use self::r#creator::*;
 pub use self::r#data_section::*;
@@ -101,6 +120,9 @@ pub use self::r#data_section_init_info::*;
 pub use self::r#data_section_update_info::*;
 pub use self::r#data_state::*;
 pub use self::r#edition::*;
+pub use self::r#execute_batch_instruction::*;
+pub use self::r#execute_guard::*;
+pub use self::r#execute_guard_program::*;
 pub use self::r#external_check_result::*;
 pub use self::r#external_plugin_adapter::*;
 pub use self::r#external_plugin_adapter_init_info::*;
@@ -113,7 +135,12 @@ pub use self::r#external_validation_result::*;
 pub use self::r#extra_account::*;
 pub use self::r#freeze_delegate::*;
 pub use self::r#freeze_execute::*;
+pub use self::r#group_plugin_init_info::*;
+pub use self::r#group_plugin_key::*;
+pub use self::r#group_plugin_update_info::*;
 pub use self::r#groups::*;
+pub use self::r#hashable_external_plugin_adapter_schema::*;
+pub use self::r#hashable_external_plugin_adapter_schema_v1::*;
 pub use self::r#hashable_plugin_schema::*;
 pub use self::r#hashed_asset_schema::*;
 pub use self::r#hookable_lifecycle_event::*;
@@ -129,7 +156,10 @@ pub use self::r#linked_data_key::*;
 pub use self::r#linked_lifecycle_hook::*;
 pub use self::r#linked_lifecycle_hook_init_info::*;
 pub use self::r#linked_lifecycle_hook_update_info::*;
+pub use self::r#listing::*;
+pub use self::r#listing_recipient::*;
 pub use self::r#master_edition::*;
+pub use self::r#multisig::*;
 pub use self::r#oracle::*;
 pub use self::r#oracle_init_info::*;
 pub use self::r#oracle_update_info::*;
@@ -145,10 +175,14 @@ pub use self::r#plugin_type::*;
 pub use self::r#registry_record::*;
 pub use self::r#relationship_entry::*;
 pub use self::r#relationship_kind::*;
+pub use self::r#rental::*;
 pub use self::r#royalties::*;
 pub use self::r#rule_set::*;
 pub use self::r#seed::*;
+pub use self::r#soulbound::*;
+pub use self::r#time_lock::*;
 pub use self::r#transfer_delegate::*;
+pub use self::r#transfer_delegate_v2::*;
 pub use self::r#update_authority::*;
 pub use self::r#update_delegate::*;
 pub use self::r#update_type::*;
@@ -10,18 +10,26 @@ use crate::generated::types::Attributes;
 use crate::generated::types::Autograph;
 use crate::generated::types::BubblegumV2;
 use crate::generated::types::BurnDelegate;
+use crate::generated::types::BurnDelegateV2;
 use crate::generated::types::Edition;
+use crate::generated::types::ExecuteGuard;
 use crate::generated::types::FreezeDelegate;
 use crate::generated::types::FreezeExecute;
 use crate::generated::types::Groups;
 use crate::generated::types::ImmutableMetadata;
+use crate::generated::types::Listing;
 use crate::generated::types::MasterEdition;
+use crate::generated::types::Multisig;
 use crate::generated::types::PermanentBurnDelegate;
 use crate::generated::types::PermanentFreezeDelegate;
 use crate::generated::types::PermanentFreezeExecute;
 use crate::generated::types::PermanentTransferDelegate;
+use crate::generated::types::Rental;
 use crate::generated::types::Royalties;
+use crate::generated::types::Soulbound;
+use crate::generated::types::TimeLock;
 use crate::generated::types::TransferDelegate;
+use crate::generated::types::TransferDelegateV2;
 use crate::generated::types::UpdateDelegate;
 use crate::generated::types::VerifiedCreators;
 #[cfg(feature = "anchor")]
@@ -53,4 +61,12 @@ pub enum Plugin {
     FreezeExecute(FreezeExecute),
     PermanentFreezeExecute(PermanentFreezeExecute),
     Groups(Groups),
+    Soulbound(Soulbound),
+    TimeLock(TimeLock),
+    Rental(Rental),
+    Listing(Listing),
+    Multisig(Multisig),
+    ExecuteGuard(ExecuteGuard),
+    TransferDelegateV2(TransferDelegateV2),
+    BurnDelegateV2(BurnDelegateV2),
 }
@@ -26,4 +26,12 @@ pub enum PluginAuthority {
         )]
         address: Pubkey,
     },
+    Multisig {
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<Vec<serde_with::DisplayFromStr>>")
+        )]
+        signers: Vec<Pubkey>,
+        threshold: u8,
+    },
 }
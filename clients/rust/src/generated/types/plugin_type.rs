@@ -35,4 +35,12 @@ pub enum PluginType {
     FreezeExecute,
     PermanentFreezeExecute,
     Groups,
+    Soulbound,
+    TimeLock,
+    Rental,
+    Listing,
+    Multisig,
+    ExecuteGuard,
+    TransferDelegateV2,
+    BurnDelegateV2,
 }
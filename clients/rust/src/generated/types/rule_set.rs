@@ -27,4 +27,16 @@ pub enum RuleSet {
         serde(with = "serde_with::As::<Vec<serde_with::DisplayFromStr>>")
     )]
     ProgramDenyList(Vec<Pubkey>),
+    EnforcePayment,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<Vec<serde_with::DisplayFromStr>>")
+    )]
+    WalletAllowList(Vec<Pubkey>),
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<Vec<serde_with::DisplayFromStr>>")
+    )]
+    WalletDenyList(Vec<Pubkey>),
+    Composite(Vec<RuleSet>),
 }
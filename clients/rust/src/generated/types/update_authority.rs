@@ -27,4 +27,12 @@ pub enum UpdateAuthority {
         serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
     )]
     Collection(Pubkey),
+    Multisig {
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<Vec<serde_with::DisplayFromStr>>")
+        )]
+        signers: Vec<Pubkey>,
+        threshold: u8,
+    },
 }
@@ -9,11 +9,13 @@ use crate::{
     accounts::{BaseAssetV1, BaseCollectionV1, PluginHeaderV1},
     types::{
         AddBlocker, AgentIdentity, AppData, Attributes, Autograph, BubblegumV2, BurnDelegate,
-        DataSection, Edition, ExternalCheckResult, ExternalPluginAdapter, ExternalPluginAdapterKey,
-        FreezeDelegate, FreezeExecute, Groups, ImmutableMetadata, Key, LifecycleHook,
-        LinkedAppData, LinkedLifecycleHook, MasterEdition, Oracle, PermanentBurnDelegate,
-        PermanentFreezeDelegate, PermanentFreezeExecute, PermanentTransferDelegate,
-        PluginAuthority, Royalties, TransferDelegate, UpdateDelegate, VerifiedCreators,
+        BurnDelegateV2, DataSection, Edition, ExecuteGuard, ExternalCheckResult,
+        ExternalPluginAdapter, ExternalPluginAdapterKey, FreezeDelegate, FreezeExecute, Groups,
+        ImmutableMetadata, Key, LifecycleHook, LinkedAppData, LinkedLifecycleHook, Listing,
+        MasterEdition, Multisig, Oracle, PermanentBurnDelegate, PermanentFreezeDelegate,
+        PermanentFreezeExecute, PermanentTransferDelegate, PluginAuthority, Rental, Royalties,
+        Soulbound, TimeLock, TransferDelegate, TransferDelegateV2, UpdateDelegate,
+        VerifiedCreators,
     },
 };
 
@@ -23,6 +25,7 @@ pub enum AuthorityType {
     Owner,
     UpdateAuthority,
     Address,
+    Multisig,
 }
 
 impl From<PluginAuthority> for AuthorityType {
@@ -32,10 +35,13 @@ impl From<PluginAuthority> for AuthorityType {
             PluginAuthority::Owner => AuthorityType::Owner,
             PluginAuthority::UpdateAuthority => AuthorityType::UpdateAuthority,
             PluginAuthority::Address { address: _ } => AuthorityType::Address,
+            PluginAuthority::Multisig { .. } => AuthorityType::Multisig,
         }
     }
 }
 
+/// The authority of a plugin. A multisig authority only records its type here, its signers and
+/// threshold are read from the `PluginAuthority` of the registry record.
 #[derive(Debug, Eq, PartialEq, Copy, Clone)]
 pub struct BaseAuthority {
     pub authority_type: AuthorityType,
@@ -61,6 +67,10 @@ impl From<PluginAuthority> for BaseAuthority {
                 authority_type: AuthorityType::Address,
                 address: Some(address),
             },
+            PluginAuthority::Multisig { .. } => BaseAuthority {
+                authority_type: AuthorityType::Multisig,
+                address: None,
+            },
         }
     }
 }
@@ -185,6 +195,54 @@ pub struct GroupsPlugin {
     pub groups: Groups,
 }
 
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct SoulboundPlugin {
+    pub base: BasePlugin,
+    pub soulbound: Soulbound,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct TimeLockPlugin {
+    pub base: BasePlugin,
+    pub time_lock: TimeLock,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct RentalPlugin {
+    pub base: BasePlugin,
+    pub rental: Rental,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct ListingPlugin {
+    pub base: BasePlugin,
+    pub listing: Listing,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct MultisigPlugin {
+    pub base: BasePlugin,
+    pub multisig: Multisig,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct ExecuteGuardPlugin {
+    pub base: BasePlugin,
+    pub execute_guard: ExecuteGuard,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct TransferDelegateV2Plugin {
+    pub base: BasePlugin,
+    pub transfer_delegate_v2: TransferDelegateV2,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct BurnDelegateV2Plugin {
+    pub base: BasePlugin,
+    pub burn_delegate_v2: BurnDelegateV2,
+}
+
 #[derive(Debug, Default)]
 pub struct PluginsList {
     pub royalties: Option<RoyaltiesPlugin>,
@@ -206,6 +264,14 @@ pub struct PluginsList {
     pub freeze_execute: Option<FreezeExecutePlugin>,
     pub permanent_freeze_execute: Option<PermanentFreezeExecutePlugin>,
     pub groups: Option<GroupsPlugin>,
+    pub soulbound: Option<SoulboundPlugin>,
+    pub time_lock: Option<TimeLockPlugin>,
+    pub rental: Option<RentalPlugin>,
+    pub listing: Option<ListingPlugin>,
+    pub multisig: Option<MultisigPlugin>,
+    pub execute_guard: Option<ExecuteGuardPlugin>,
+    pub transfer_delegate_v2: Option<TransferDelegateV2Plugin>,
+    pub burn_delegate_v2: Option<BurnDelegateV2Plugin>,
 }
 
 #[derive(Debug, Default)]
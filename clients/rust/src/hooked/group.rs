@@ -0,0 +1,272 @@
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::{
+    AnchorDeserialize as CrateDeserialize, AnchorSerialize as CrateSerialize,
+};
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize as CrateDeserialize, BorshSerialize as CrateSerialize};
+use solana_program::{instruction::AccountMeta, pubkey::Pubkey};
+use std::{
+    collections::HashSet,
+    io::{Read, Result, Write},
+    ops::Deref,
+};
+
+use crate::{
+    accounts::{GroupDelegateV1, GroupMembershipV1, GroupV1},
+    instructions::AddGroupsToGroupV1Builder,
+    types::{Groups, Key},
+};
+
+/// The number of members of a group stored in `GroupMembershipV1` accounts.
+///
+/// Groups stored before the count was added end after `assets`, and are read with a count of
+/// zero.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct MembershipCount(pub u32);
+
+impl Deref for MembershipCount {
+    type Target = u32;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<u32> for MembershipCount {
+    fn from(count: u32) -> Self {
+        Self(count)
+    }
+}
+
+impl PartialEq<u32> for MembershipCount {
+    fn eq(&self, other: &u32) -> bool {
+        self.0 == *other
+    }
+}
+
+impl CrateSerialize for MembershipCount {
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        self.0.serialize(writer)
+    }
+}
+
+impl CrateDeserialize for MembershipCount {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        let mut bytes = [0u8; 4];
+        if reader.read(&mut bytes[..1])? == 0 {
+            return Ok(Self(0));
+        }
+
+        reader.read_exact(&mut bytes[1..])?;
+        Ok(Self(u32::from_le_bytes(bytes)))
+    }
+}
+
+impl GroupMembershipV1 {
+    /// The offset of the group address in the account data.
+    pub const GROUP_OFFSET: usize = 1;
+    /// The offset of the member asset address in the account data.
+    pub const MEMBER_OFFSET: usize = 33;
+
+    /// The `(offset, bytes)` memcmp filters selecting the membership accounts of a group, for
+    /// use with `getProgramAccounts`.
+    pub fn group_filters(group: &Pubkey) -> Vec<(usize, Vec<u8>)> {
+        vec![
+            (0, vec![Key::GroupMembershipV1 as u8]),
+            (Self::GROUP_OFFSET, group.to_bytes().to_vec()),
+        ]
+    }
+
+    /// The `(offset, bytes)` memcmp filters selecting the membership accounts of an asset, for
+    /// use with `getProgramAccounts`.
+    pub fn member_filters(member: &Pubkey) -> Vec<(usize, Vec<u8>)> {
+        vec![
+            (0, vec![Key::GroupMembershipV1 as u8]),
+            (Self::MEMBER_OFFSET, member.to_bytes().to_vec()),
+        ]
+    }
+}
+
+impl GroupDelegateV1 {
+    /// The offset of the group address in the account data.
+    pub const GROUP_OFFSET: usize = 1;
+    /// The offset of the delegate address in the account data.
+    pub const DELEGATE_OFFSET: usize = 33;
+
+    /// The `(offset, bytes)` memcmp filters selecting the delegate accounts of a group, for use
+    /// with `getProgramAccounts`.
+    pub fn group_filters(group: &Pubkey) -> Vec<(usize, Vec<u8>)> {
+        vec![
+            (0, vec![Key::GroupDelegateV1 as u8]),
+            (Self::GROUP_OFFSET, group.to_bytes().to_vec()),
+        ]
+    }
+}
+
+impl Groups {
+    /// The group accounts a transfer of a member, or of an asset in a member collection, passes
+    /// in its remaining accounts so frozen groups can be enforced.
+    pub fn transfer_accounts(&self) -> Vec<AccountMeta> {
+        self.groups
+            .iter()
+            .map(|group| AccountMeta::new_readonly(*group, false))
+            .collect()
+    }
+}
+
+impl GroupV1 {
+    /// The assets of the group, from its `assets` vector followed by the membership accounts of
+    /// the group.  Memberships of other groups are ignored.
+    pub fn all_assets(&self, group: &Pubkey, memberships: &[GroupMembershipV1]) -> Vec<Pubkey> {
+        self.assets
+            .iter()
+            .copied()
+            .chain(
+                memberships
+                    .iter()
+                    .filter(|membership| {
+                        membership.key == Key::GroupMembershipV1 && membership.group == *group
+                    })
+                    .map(|membership| membership.member),
+            )
+            .collect()
+    }
+
+    /// The remaining accounts of `CloseGroupV2` detaching every member of the group, split into
+    /// batches of at most `batch_size` members.  Assets stored in membership accounts are paired
+    /// with their membership account in the same batch.  All batches but the last are meant to be
+    /// sent with `detach_only` set.
+    pub fn close_batches(
+        &self,
+        group: &Pubkey,
+        memberships: &[GroupMembershipV1],
+        batch_size: usize,
+    ) -> Vec<Vec<AccountMeta>> {
+        let mut members: Vec<Vec<AccountMeta>> = self
+            .collections
+            .iter()
+            .chain(self.groups.iter())
+            .chain(self.parent_groups.iter())
+            .chain(self.assets.iter())
+            .map(|member| vec![AccountMeta::new(*member, false)])
+            .collect();
+
+        members.extend(
+            memberships
+                .iter()
+                .filter(|membership| {
+                    membership.key == Key::GroupMembershipV1 && membership.group == *group
+                })
+                .map(|membership| {
+                    let (membership_address, _) =
+                        GroupMembershipV1::find_pda(group, &membership.member);
+                    vec![
+                        AccountMeta::new(membership.member, false),
+                        AccountMeta::new(membership_address, false),
+                    ]
+                }),
+        );
+
+        members
+            .chunks(batch_size.max(1))
+            .map(|batch| batch.concat())
+            .collect()
+    }
+
+    /// The ancestry proof of `AddGroupsToGroupV1` when this group is the parent group: every
+    /// group above it, level by level, fetched through `fetch`.  Groups that cannot be fetched
+    /// are still listed so the program can reject the proof instead of the client guessing.
+    pub fn ancestry_proof<F>(&self, fetch: F) -> Vec<Pubkey>
+    where
+        F: FnMut(&Pubkey) -> Option<GroupV1>,
+    {
+        group_proof(&self.parent_groups, fetch, |group| group.parent_groups)
+    }
+
+    /// The descendant proof of `AddGroupsToGroupV1` when this group is a new child group: every
+    /// group below it, level by level, fetched through `fetch`.  The proofs of several new child
+    /// groups can be concatenated, and are only needed for children with child groups of their
+    /// own.
+    pub fn descendant_proof<F>(&self, fetch: F) -> Vec<Pubkey>
+    where
+        F: FnMut(&Pubkey) -> Option<GroupV1>,
+    {
+        group_proof(&self.groups, fetch, |group| group.groups)
+    }
+}
+
+impl AddGroupsToGroupV1Builder {
+    /// Add `groups` below `parent_group` with an ancestry proof of the parent group and the
+    /// descendant proofs of the new child groups, fetching the groups they walk through `fetch`.
+    /// Sets the `groups`, `ancestors` and `descendants` arguments along with their remaining
+    /// accounts, so no other remaining accounts are needed.
+    pub fn groups_with_proof<F>(
+        &mut self,
+        parent_group: Pubkey,
+        groups: Vec<Pubkey>,
+        mut fetch: F,
+    ) -> &mut Self
+    where
+        F: FnMut(&Pubkey) -> Option<GroupV1>,
+    {
+        let ancestors = fetch(&parent_group)
+            .map(|parent_group| parent_group.ancestry_proof(&mut fetch))
+            .unwrap_or_default();
+
+        let mut descendants = Vec::new();
+        for group in &groups {
+            if let Some(group) = fetch(group) {
+                for descendant in group.descendant_proof(&mut fetch) {
+                    if !descendants.contains(&descendant) {
+                        descendants.push(descendant);
+                    }
+                }
+            }
+        }
+
+        let remaining_accounts: Vec<AccountMeta> = groups
+            .iter()
+            .map(|group| AccountMeta::new(*group, false))
+            .chain(
+                ancestors
+                    .iter()
+                    .chain(descendants.iter())
+                    .map(|group| AccountMeta::new_readonly(*group, false)),
+            )
+            .collect();
+
+        self.parent_group(parent_group)
+            .groups(groups)
+            .ancestors(ancestors)
+            .descendants(descendants)
+            .add_remaining_accounts(&remaining_accounts)
+    }
+}
+
+/// Every group reachable from `start` through `next`, level by level.
+fn group_proof<F>(start: &[Pubkey], mut fetch: F, next: fn(GroupV1) -> Vec<Pubkey>) -> Vec<Pubkey>
+where
+    F: FnMut(&Pubkey) -> Option<GroupV1>,
+{
+    let mut proof = Vec::new();
+    let mut visited = HashSet::new();
+    let mut level = start.to_vec();
+
+    while !level.is_empty() {
+        let mut next_level = Vec::new();
+        for key in level {
+            if !visited.insert(key) {
+                continue;
+            }
+            proof.push(key);
+            if let Some(group) = fetch(&key) {
+                next_level.extend(next(group));
+            }
+        }
+        level = next_level;
+    }
+
+    proof
+}
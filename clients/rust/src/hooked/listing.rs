@@ -0,0 +1,157 @@
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    pubkey,
+    pubkey::Pubkey,
+};
+
+use crate::types::Listing;
+
+/// The System program ID.
+const SYSTEM_PROGRAM_ID: Pubkey = pubkey!("11111111111111111111111111111111");
+
+/// The SPL Associated Token Account program ID.
+const SPL_ASSOCIATED_TOKEN_ACCOUNT_ID: Pubkey =
+    pubkey!("ATokenGPvbdGVxr1b2hdsZrYYbPCZR8Ji4Pa5VWDTEwN");
+
+/// The system program `Transfer` instruction discriminator.
+const SYSTEM_TRANSFER_DISCRIMINATOR: u32 = 2;
+
+/// The SPL token `TransferChecked` instruction discriminator.
+const TOKEN_TRANSFER_CHECKED_DISCRIMINATOR: u8 = 12;
+
+impl Listing {
+    /// Returns the amount owed to each recipient when the asset is bought from the owner,
+    /// keyed by recipient address. The owner receives what the recipients are not paid.
+    pub fn required_payments(&self, owner: &Pubkey) -> Vec<(Pubkey, u64)> {
+        let mut payments: Vec<(Pubkey, u64)> = Vec::with_capacity(self.recipients.len() + 1);
+        let mut paid_to_recipients = 0u64;
+        for recipient in &self.recipients {
+            let amount = (self.price as u128 * recipient.basis_points as u128 / 10_000) as u64;
+            paid_to_recipients += amount;
+            add_payment(&mut payments, recipient.address, amount);
+        }
+        add_payment(
+            &mut payments,
+            *owner,
+            self.price.saturating_sub(paid_to_recipients),
+        );
+
+        payments.retain(|(_, amount)| *amount > 0);
+        payments
+    }
+
+    /// Returns the instructions paying the lamport price of the asset, which must immediately
+    /// precede the transfer of the asset to the buyer.
+    pub fn lamport_payment_instructions(
+        &self,
+        asset: &Pubkey,
+        owner: &Pubkey,
+        buyer: &Pubkey,
+    ) -> Vec<Instruction> {
+        self.required_payments(owner)
+            .into_iter()
+            .map(|(recipient, lamports)| lamport_payment(buyer, &recipient, lamports, asset))
+            .collect()
+    }
+
+    /// Returns the instructions paying the token price of the asset from and to the associated
+    /// token accounts of the buyer and the recipients, which must immediately precede the
+    /// transfer of the asset to the buyer. Returns `None` if the listing is priced in lamports.
+    pub fn token_payment_instructions(
+        &self,
+        asset: &Pubkey,
+        owner: &Pubkey,
+        buyer: &Pubkey,
+        token_program: &Pubkey,
+        decimals: u8,
+    ) -> Option<Vec<Instruction>> {
+        let mint = self.mint?;
+        let source = associated_token_address(buyer, token_program, &mint);
+        Some(
+            self.required_payments(owner)
+                .into_iter()
+                .map(|(recipient, amount)| {
+                    token_payment(
+                        token_program,
+                        &source,
+                        &mint,
+                        &associated_token_address(&recipient, token_program, &mint),
+                        buyer,
+                        amount,
+                        decimals,
+                        asset,
+                    )
+                })
+                .collect(),
+        )
+    }
+}
+
+/// A system program `Transfer` of lamports for a listed asset. The asset is appended as the
+/// last account, which the system program ignores, to tie the payment to the asset.
+pub fn lamport_payment(from: &Pubkey, to: &Pubkey, lamports: u64, asset: &Pubkey) -> Instruction {
+    let mut data = Vec::with_capacity(12);
+    data.extend_from_slice(&SYSTEM_TRANSFER_DISCRIMINATOR.to_le_bytes());
+    data.extend_from_slice(&lamports.to_le_bytes());
+
+    Instruction {
+        program_id: SYSTEM_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*from, true),
+            AccountMeta::new(*to, false),
+            AccountMeta::new_readonly(*asset, false),
+        ],
+        data,
+    }
+}
+
+/// An SPL token `TransferChecked` for a listed asset. The destination must be the associated
+/// token account of the recipient. The asset is appended as the last account, which the token
+/// program ignores, to tie the payment to the asset.
+#[allow(clippy::too_many_arguments)]
+pub fn token_payment(
+    token_program: &Pubkey,
+    source: &Pubkey,
+    mint: &Pubkey,
+    destination: &Pubkey,
+    authority: &Pubkey,
+    amount: u64,
+    decimals: u8,
+    asset: &Pubkey,
+) -> Instruction {
+    let mut data = Vec::with_capacity(10);
+    data.push(TOKEN_TRANSFER_CHECKED_DISCRIMINATOR);
+    data.extend_from_slice(&amount.to_le_bytes());
+    data.push(decimals);
+
+    Instruction {
+        program_id: *token_program,
+        accounts: vec![
+            AccountMeta::new(*source, false),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new(*destination, false),
+            AccountMeta::new_readonly(*authority, true),
+            AccountMeta::new_readonly(*asset, false),
+        ],
+        data,
+    }
+}
+
+/// Returns the associated token account of the wallet for the mint.
+pub fn associated_token_address(wallet: &Pubkey, token_program: &Pubkey, mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[wallet.as_ref(), token_program.as_ref(), mint.as_ref()],
+        &SPL_ASSOCIATED_TOKEN_ACCOUNT_ID,
+    )
+    .0
+}
+
+fn add_payment(payments: &mut Vec<(Pubkey, u64)>, recipient: Pubkey, amount: u64) {
+    match payments
+        .iter_mut()
+        .find(|(address, _)| *address == recipient)
+    {
+        Some((_, total)) => *total += amount,
+        None => payments.push((recipient, amount)),
+    }
+}
@@ -8,6 +8,12 @@ pub mod asset;
 
 pub mod collection;
 
+pub mod group;
+pub use group::*;
+
+pub mod listing;
+pub use listing::*;
+
 #[cfg(feature = "anchor")]
 use anchor_lang::prelude::{
     AnchorDeserialize as CrateDeserialize, AnchorSerialize as CrateSerialize,
@@ -51,6 +57,14 @@ impl From<&Plugin> for PluginType {
             Plugin::FreezeExecute(_) => PluginType::FreezeExecute,
             Plugin::PermanentFreezeExecute(_) => PluginType::PermanentFreezeExecute,
             Plugin::Groups(_) => PluginType::Groups,
+            Plugin::Soulbound(_) => PluginType::Soulbound,
+            Plugin::TimeLock(_) => PluginType::TimeLock,
+            Plugin::Rental(_) => PluginType::Rental,
+            Plugin::Listing(_) => PluginType::Listing,
+            Plugin::Multisig(_) => PluginType::Multisig,
+            Plugin::ExecuteGuard(_) => PluginType::ExecuteGuard,
+            Plugin::TransferDelegateV2(_) => PluginType::TransferDelegateV2,
+            Plugin::BurnDelegateV2(_) => PluginType::BurnDelegateV2,
         }
     }
 }
@@ -134,9 +148,10 @@ impl DataBlob for BaseAssetV1 {
     fn len(&self) -> usize {
         let mut size = BaseAssetV1::BASE_LEN + self.name.len() + self.uri.len();
 
-        if let UpdateAuthority::Address(_) | UpdateAuthority::Collection(_) = self.update_authority
-        {
-            size += 32;
+        match &self.update_authority {
+            UpdateAuthority::Address(_) | UpdateAuthority::Collection(_) => size += 32,
+            UpdateAuthority::Multisig { signers, .. } => size += 4 + signers.len() * 32 + 1,
+            UpdateAuthority::None => (),
         }
 
         if self.seq.is_some() {
@@ -13,13 +13,15 @@ use crate::{
         Plugin, PluginAuthority, PluginType, RegistryRecord,
     },
     AddBlockerPlugin, AppDataWithData, AttributesPlugin, AutographPlugin, BaseAuthority,
-    BasePlugin, BubblegumV2Plugin, BurnDelegatePlugin, DataBlob, DataSectionWithData,
-    EditionPlugin, ExternalPluginAdaptersList, ExternalRegistryRecordSafe, FreezeDelegatePlugin,
-    FreezeExecutePlugin, GroupsPlugin, ImmutableMetadataPlugin, LifecycleHookWithData,
-    MasterEditionPlugin, PermanentBurnDelegatePlugin, PermanentFreezeDelegatePlugin,
+    BasePlugin, BubblegumV2Plugin, BurnDelegatePlugin, BurnDelegateV2Plugin, DataBlob,
+    DataSectionWithData, EditionPlugin, ExecuteGuardPlugin, ExternalPluginAdaptersList,
+    ExternalRegistryRecordSafe, FreezeDelegatePlugin, FreezeExecutePlugin, GroupsPlugin,
+    ImmutableMetadataPlugin, LifecycleHookWithData, ListingPlugin, MasterEditionPlugin,
+    MultisigPlugin, PermanentBurnDelegatePlugin, PermanentFreezeDelegatePlugin,
     PermanentFreezeExecutePlugin, PermanentTransferDelegatePlugin, PluginRegistryV1Safe,
-    PluginsList, RegistryRecordSafe, RoyaltiesPlugin, SolanaAccount, TransferDelegatePlugin,
-    UpdateDelegatePlugin, VerifiedCreatorsPlugin,
+    PluginsList, RegistryRecordSafe, RentalPlugin, RoyaltiesPlugin, SolanaAccount, SoulboundPlugin,
+    TimeLockPlugin, TransferDelegatePlugin, TransferDelegateV2Plugin, UpdateDelegatePlugin,
+    VerifiedCreatorsPlugin,
 };
 
 /// Fetch the plugin from the registry.
@@ -362,6 +364,35 @@ pub(crate) fn registry_records_to_plugin_list(
                             permanent_freeze_execute,
                         })
                     }
+                    Plugin::Soulbound(soulbound) => {
+                        acc.soulbound = Some(SoulboundPlugin { base, soulbound })
+                    }
+                    Plugin::TimeLock(time_lock) => {
+                        acc.time_lock = Some(TimeLockPlugin { base, time_lock })
+                    }
+                    Plugin::Rental(rental) => acc.rental = Some(RentalPlugin { base, rental }),
+                    Plugin::Listing(listing) => acc.listing = Some(ListingPlugin { base, listing }),
+                    Plugin::Multisig(multisig) => {
+                        acc.multisig = Some(MultisigPlugin { base, multisig })
+                    }
+                    Plugin::ExecuteGuard(execute_guard) => {
+                        acc.execute_guard = Some(ExecuteGuardPlugin {
+                            base,
+                            execute_guard,
+                        })
+                    }
+                    Plugin::TransferDelegateV2(transfer_delegate_v2) => {
+                        acc.transfer_delegate_v2 = Some(TransferDelegateV2Plugin {
+                            base,
+                            transfer_delegate_v2,
+                        })
+                    }
+                    Plugin::BurnDelegateV2(burn_delegate_v2) => {
+                        acc.burn_delegate_v2 = Some(BurnDelegateV2Plugin {
+                            base,
+                            burn_delegate_v2,
+                        })
+                    }
                 }
             }
             Ok(acc)
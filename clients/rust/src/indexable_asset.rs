@@ -445,6 +445,7 @@ impl IndexableAsset {
             + (group.parent_groups.len() * 32)
             + 4 // assets length
             + (group.assets.len() * 32)
+            + 4 // membership_count
     }
 
     // Add a processed plugin to the correct `IndexableAsset` struct member.
@@ -559,7 +560,9 @@ impl IndexableAsset {
             }
             Key::GroupV1 => {
                 let group = GroupV1::from_bytes(account)?;
-                let base_size = Self::group_len(&group);
+                // Groups stored before `membership_count` was added end after `assets`, and
+                // have no plugins.
+                let base_size = Self::group_len(&group).min(account.len());
                 let indexable_asset = Self::from_group(group);
                 (indexable_asset, base_size)
             }
@@ -0,0 +1,382 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    errors::MplCoreError,
+    instructions::{BurnBatchV1Builder, BurnV1Builder},
+    types::{
+        BurnDelegateV2, Key, Plugin, PluginAuthority, PluginAuthorityPair, Soulbound, TimeLock,
+    },
+};
+pub use setup::*;
+
+use solana_program::{clock::Clock, instruction::AccountMeta};
+use solana_program_test::tokio;
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+#[tokio::test]
+async fn burn_batch_assets_in_collection() {
+    let mut context = program_test().start_with_context().await;
+
+    let collection = Keypair::new();
+    create_collection(
+        &mut context,
+        CreateCollectionHelperArgs {
+            collection: &collection,
+            update_authority: None,
+            payer: None,
+            name: None,
+            uri: None,
+            plugins: vec![],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let assets = [Keypair::new(), Keypair::new(), Keypair::new()];
+    for asset in assets.iter() {
+        create_asset(
+            &mut context,
+            CreateAssetHelperArgs {
+                owner: None,
+                payer: None,
+                asset,
+                data_state: None,
+                name: None,
+                uri: None,
+                authority: None,
+                update_authority: None,
+                collection: Some(collection.pubkey()),
+                plugins: vec![],
+                external_plugin_adapters: vec![],
+            },
+        )
+        .await
+        .unwrap();
+    }
+
+    let burn_ix = BurnBatchV1Builder::new()
+        .collection(Some(collection.pubkey()))
+        .payer(context.payer.pubkey())
+        .num_assets(assets.len() as u8)
+        .add_remaining_accounts(
+            &assets
+                .iter()
+                .map(|asset| AccountMeta::new(asset.pubkey(), false))
+                .collect::<Vec<_>>(),
+        )
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[burn_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Each burned asset is left as a one byte tombstone.
+    for asset in assets.iter() {
+        let account = context
+            .banks_client
+            .get_account(asset.pubkey())
+            .await
+            .expect("get_account")
+            .expect("asset account not found");
+        assert_eq!(account.data, vec![Key::Uninitialized as u8]);
+    }
+
+    let update_authority = context.payer.pubkey();
+    assert_collection(
+        &mut context,
+        AssertCollectionHelperArgs {
+            collection: collection.pubkey(),
+            update_authority,
+            name: None,
+            uri: None,
+            num_minted: 3,
+            current_size: 0,
+            plugins: vec![],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn burn_asset_as_use_limited_burn_delegate() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let delegate = Keypair::new();
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::BurnDelegateV2(BurnDelegateV2 {
+                    expires_at: None,
+                    max_uses: Some(1),
+                }),
+                authority: Some(PluginAuthority::Address {
+                    address: delegate.pubkey(),
+                }),
+            }],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let burn_ix = BurnV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .authority(Some(delegate.pubkey()))
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[burn_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &delegate],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let account = context
+        .banks_client
+        .get_account(asset.pubkey())
+        .await
+        .expect("get_account")
+        .expect("asset account not found");
+    assert_eq!(account.data, vec![Key::Uninitialized as u8]);
+}
+
+#[tokio::test]
+async fn cannot_burn_asset_as_exhausted_burn_delegate() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let delegate = Keypair::new();
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::BurnDelegateV2(BurnDelegateV2 {
+                    expires_at: None,
+                    max_uses: Some(0),
+                }),
+                authority: Some(PluginAuthority::Address {
+                    address: delegate.pubkey(),
+                }),
+            }],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let burn_ix = BurnV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .authority(Some(delegate.pubkey()))
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[burn_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &delegate],
+        context.last_blockhash,
+    );
+
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_custom_instruction_error!(0, error, MplCoreError::NoApprovals);
+}
+
+#[tokio::test]
+async fn cannot_burn_asset_as_expired_burn_delegate() {
+    let mut context = program_test().start_with_context().await;
+
+    let mut clock: Clock = context.banks_client.get_sysvar().await.unwrap();
+    let expires_at = clock.unix_timestamp + 1_000;
+
+    let asset = Keypair::new();
+    let delegate = Keypair::new();
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::BurnDelegateV2(BurnDelegateV2 {
+                    expires_at: Some(expires_at),
+                    max_uses: None,
+                }),
+                authority: Some(PluginAuthority::Address {
+                    address: delegate.pubkey(),
+                }),
+            }],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    // Move the clock to the expiry time.
+    clock.unix_timestamp = expires_at;
+    context.set_sysvar(&clock);
+    context.get_new_latest_blockhash().await.unwrap();
+
+    let burn_ix = BurnV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .authority(Some(delegate.pubkey()))
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[burn_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &delegate],
+        context.last_blockhash,
+    );
+
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_custom_instruction_error!(0, error, MplCoreError::NoApprovals);
+}
+
+#[tokio::test]
+async fn burn_soulbound_asset() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::Soulbound(Soulbound {}),
+                authority: None,
+            }],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let burn_ix = BurnV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[burn_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let account = context
+        .banks_client
+        .get_account(asset.pubkey())
+        .await
+        .expect("get_account")
+        .expect("asset account not found");
+    assert_eq!(account.data, vec![Key::Uninitialized as u8]);
+}
+
+#[tokio::test]
+async fn cannot_burn_time_locked_asset_before_unlock() {
+    let mut context = program_test().start_with_context().await;
+
+    let clock: Clock = context.banks_client.get_sysvar().await.unwrap();
+
+    let asset = Keypair::new();
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::TimeLock(TimeLock {
+                    unlock_at: clock.unix_timestamp + 1_000,
+                }),
+                authority: None,
+            }],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let burn_ix = BurnV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[burn_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_custom_instruction_error!(0, error, MplCoreError::InvalidAuthority);
+}
@@ -0,0 +1,519 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    accounts::{GroupMembershipV1, GroupV1},
+    errors::MplCoreError,
+    instructions::{
+        AddAssetsToGroupV1Builder, AddCollectionsToGroupV1Builder, BurnV1Builder,
+        CloseGroupV1Builder, CloseGroupV2Builder, CollectBuilder, CreateGroupV1Builder,
+        RemoveAssetsFromGroupV1Builder,
+    },
+    types::Key,
+    Asset, Collection,
+};
+pub use setup::*;
+
+use solana_program::instruction::{AccountMeta, Instruction};
+use solana_program_test::{tokio, BanksClientError, ProgramTestContext};
+use solana_sdk::{
+    native_token::LAMPORTS_PER_SOL, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    transaction::Transaction,
+};
+use solana_system_interface::program as system_program;
+
+async fn send(context: &mut ProgramTestContext, ix: Instruction) -> Result<(), BanksClientError> {
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await
+}
+
+/// Create a group holding a collection in its inline vector and an asset in a membership
+/// account, returning the membership account address.
+async fn create_group_with_members(
+    context: &mut ProgramTestContext,
+    group: &Keypair,
+    collection: &Keypair,
+    asset: &Keypair,
+) -> Pubkey {
+    let ix = CreateGroupV1Builder::new()
+        .group(group.pubkey())
+        .payer(context.payer.pubkey())
+        .name("Test Group".to_owned())
+        .uri("https://example.com/group".to_owned())
+        .relationships(vec![])
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, group],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    create_collection(
+        context,
+        CreateCollectionHelperArgs {
+            collection,
+            update_authority: None,
+            payer: None,
+            name: None,
+            uri: None,
+            plugins: vec![],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    create_asset(
+        context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let ix = AddCollectionsToGroupV1Builder::new()
+        .group(group.pubkey())
+        .payer(context.payer.pubkey())
+        .add_remaining_account(AccountMeta::new(collection.pubkey(), false))
+        .instruction();
+    send(context, ix).await.unwrap();
+
+    let (membership, _) = GroupMembershipV1::find_pda(&group.pubkey(), &asset.pubkey());
+    let ix = AddAssetsToGroupV1Builder::new()
+        .group(group.pubkey())
+        .payer(context.payer.pubkey())
+        .add_remaining_accounts(&[
+            AccountMeta::new(asset.pubkey(), false),
+            AccountMeta::new(membership, false),
+        ])
+        .instruction();
+    send(context, ix).await.unwrap();
+
+    membership
+}
+
+async fn account_data(context: &mut ProgramTestContext, address: Pubkey) -> Vec<u8> {
+    context
+        .banks_client
+        .get_account(address)
+        .await
+        .expect("get_account")
+        .expect("account not found")
+        .data
+}
+
+/// Collect the lamports of a closed account, which hands it over to the system program.
+async fn collect(context: &mut ProgramTestContext, address: Pubkey) {
+    let ix = CollectBuilder::new()
+        .add_remaining_account(AccountMeta::new(address, false))
+        .instruction();
+    for recipient in [ix.accounts[0].pubkey, ix.accounts[1].pubkey] {
+        airdrop(context, &recipient, LAMPORTS_PER_SOL)
+            .await
+            .unwrap();
+    }
+    send(context, ix).await.unwrap();
+
+    let account = context
+        .banks_client
+        .get_account(address)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(account.owner, system_program::ID);
+    assert_eq!(account.data, vec![Key::Uninitialized as u8]);
+}
+
+#[tokio::test]
+async fn close_group_detaching_members_over_several_transactions() {
+    let mut context = program_test().start_with_context().await;
+
+    let group = Keypair::new();
+    let collection = Keypair::new();
+    let asset = Keypair::new();
+    let membership = create_group_with_members(&mut context, &group, &collection, &asset).await;
+
+    let group_data =
+        GroupV1::from_bytes(&account_data(&mut context, group.pubkey()).await).unwrap();
+    let membership_data =
+        GroupMembershipV1::from_bytes(&account_data(&mut context, membership).await).unwrap();
+    let batches = group_data.close_batches(&group.pubkey(), &[membership_data], 1);
+    assert_eq!(
+        batches,
+        vec![
+            vec![AccountMeta::new(collection.pubkey(), false)],
+            vec![
+                AccountMeta::new(asset.pubkey(), false),
+                AccountMeta::new(membership, false),
+            ],
+        ]
+    );
+
+    // Detach the collection and keep the group open.
+    let ix = CloseGroupV2Builder::new()
+        .group(group.pubkey())
+        .payer(context.payer.pubkey())
+        .detach_only(true)
+        .add_remaining_accounts(&batches[0])
+        .instruction();
+    send(&mut context, ix).await.unwrap();
+
+    let group_data =
+        GroupV1::from_bytes(&account_data(&mut context, group.pubkey()).await).unwrap();
+    assert!(group_data.collections.is_empty());
+
+    // The emptied Groups plugin is removed from the collection.
+    let collection_data =
+        Collection::from_bytes(&account_data(&mut context, collection.pubkey()).await).unwrap();
+    assert!(collection_data.plugin_list.groups.is_none());
+
+    // Detach the asset and close the group.
+    let ix = CloseGroupV2Builder::new()
+        .group(group.pubkey())
+        .payer(context.payer.pubkey())
+        .detach_only(false)
+        .add_remaining_accounts(&batches[1])
+        .instruction();
+    send(&mut context, ix).await.unwrap();
+
+    assert_eq!(
+        account_data(&mut context, group.pubkey()).await,
+        vec![Key::Uninitialized as u8]
+    );
+    assert_eq!(
+        account_data(&mut context, membership).await,
+        vec![Key::Uninitialized as u8]
+    );
+
+    let asset_data = Asset::from_bytes(&account_data(&mut context, asset.pubkey()).await).unwrap();
+    assert!(asset_data.plugin_list.groups.is_none());
+}
+
+#[tokio::test]
+async fn cannot_close_group_with_remaining_members() {
+    let mut context = program_test().start_with_context().await;
+
+    let group = Keypair::new();
+    let collection = Keypair::new();
+    let asset = Keypair::new();
+    let membership = create_group_with_members(&mut context, &group, &collection, &asset).await;
+
+    // Only the asset is detached, so the collection is still in the group.
+    let ix = CloseGroupV2Builder::new()
+        .group(group.pubkey())
+        .payer(context.payer.pubkey())
+        .detach_only(false)
+        .add_remaining_accounts(&[
+            AccountMeta::new(asset.pubkey(), false),
+            AccountMeta::new(membership, false),
+        ])
+        .instruction();
+
+    let error = send(&mut context, ix).await.unwrap_err();
+
+    assert_custom_instruction_error!(0, error, MplCoreError::GroupMustBeEmpty);
+}
+
+#[tokio::test]
+async fn cannot_close_group_v2_with_remaining_membership_accounts() {
+    let mut context = program_test().start_with_context().await;
+
+    let group = Keypair::new();
+    let collection = Keypair::new();
+    let asset = Keypair::new();
+    let membership = create_group_with_members(&mut context, &group, &collection, &asset).await;
+
+    // Only the collection is detached, so the asset's membership account is still counted.
+    let ix = CloseGroupV2Builder::new()
+        .group(group.pubkey())
+        .payer(context.payer.pubkey())
+        .detach_only(false)
+        .add_remaining_account(AccountMeta::new(collection.pubkey(), false))
+        .instruction();
+
+    let error = send(&mut context, ix).await.unwrap_err();
+
+    assert_custom_instruction_error!(0, error, MplCoreError::GroupMustBeEmpty);
+
+    // Detaching the asset closes its membership account and uncounts it.
+    let ix = CloseGroupV2Builder::new()
+        .group(group.pubkey())
+        .payer(context.payer.pubkey())
+        .detach_only(true)
+        .add_remaining_accounts(&[
+            AccountMeta::new(asset.pubkey(), false),
+            AccountMeta::new(membership, false),
+        ])
+        .instruction();
+    send(&mut context, ix).await.unwrap();
+
+    let group_data =
+        GroupV1::from_bytes(&account_data(&mut context, group.pubkey()).await).unwrap();
+    assert_eq!(group_data.membership_count, 0);
+}
+
+#[tokio::test]
+async fn cannot_close_group_v1_with_membership_accounts() {
+    let mut context = program_test().start_with_context().await;
+
+    let group = Keypair::new();
+    let collection = Keypair::new();
+    let asset = Keypair::new();
+    let membership = create_group_with_members(&mut context, &group, &collection, &asset).await;
+
+    // Detach the collection so only the asset in its membership account remains.
+    let ix = CloseGroupV2Builder::new()
+        .group(group.pubkey())
+        .payer(context.payer.pubkey())
+        .detach_only(true)
+        .add_remaining_account(AccountMeta::new(collection.pubkey(), false))
+        .instruction();
+    send(&mut context, ix).await.unwrap();
+
+    let group_data =
+        GroupV1::from_bytes(&account_data(&mut context, group.pubkey()).await).unwrap();
+    assert!(group_data.assets.is_empty());
+    assert_eq!(group_data.membership_count, 1);
+
+    let ix = CloseGroupV1Builder::new()
+        .group(group.pubkey())
+        .payer(context.payer.pubkey())
+        .instruction();
+    let error = send(&mut context, ix).await.unwrap_err();
+
+    assert_custom_instruction_error!(0, error, MplCoreError::GroupMustBeEmpty);
+
+    // Once the asset is removed the group can be closed.
+    let ix = RemoveAssetsFromGroupV1Builder::new()
+        .group(group.pubkey())
+        .payer(context.payer.pubkey())
+        .assets(vec![asset.pubkey()])
+        .add_remaining_accounts(&[
+            AccountMeta::new(asset.pubkey(), false),
+            AccountMeta::new(membership, false),
+        ])
+        .instruction();
+    send(&mut context, ix).await.unwrap();
+
+    let ix = CloseGroupV1Builder::new()
+        .group(group.pubkey())
+        .payer(context.payer.pubkey())
+        .instruction();
+    send(&mut context, ix).await.unwrap();
+
+    assert_eq!(
+        account_data(&mut context, group.pubkey()).await,
+        vec![Key::Uninitialized as u8]
+    );
+}
+
+#[tokio::test]
+async fn close_group_detaching_burned_assets() {
+    let mut context = program_test().start_with_context().await;
+
+    let group = Keypair::new();
+    let collection = Keypair::new();
+    let asset = Keypair::new();
+    let membership = create_group_with_members(&mut context, &group, &collection, &asset).await;
+
+    // A second asset is stored in the group's assets vector.
+    let inline_asset = Keypair::new();
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &inline_asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let ix = AddAssetsToGroupV1Builder::new()
+        .group(group.pubkey())
+        .payer(context.payer.pubkey())
+        .add_remaining_account(AccountMeta::new(inline_asset.pubkey(), false))
+        .instruction();
+    send(&mut context, ix).await.unwrap();
+
+    // The owner burns both assets while they are in the group.
+    for burned in [&asset, &inline_asset] {
+        let ix = BurnV1Builder::new()
+            .asset(burned.pubkey())
+            .payer(context.payer.pubkey())
+            .instruction();
+        send(&mut context, ix).await.unwrap();
+
+        assert_eq!(
+            account_data(&mut context, burned.pubkey()).await,
+            vec![Key::Uninitialized as u8]
+        );
+    }
+
+    // The burned assets are detached next to the collection and the group is closed.
+    let ix = CloseGroupV2Builder::new()
+        .group(group.pubkey())
+        .payer(context.payer.pubkey())
+        .detach_only(false)
+        .add_remaining_accounts(&[
+            AccountMeta::new(collection.pubkey(), false),
+            AccountMeta::new(asset.pubkey(), false),
+            AccountMeta::new(membership, false),
+            AccountMeta::new(inline_asset.pubkey(), false),
+        ])
+        .instruction();
+    send(&mut context, ix).await.unwrap();
+
+    assert_eq!(
+        account_data(&mut context, group.pubkey()).await,
+        vec![Key::Uninitialized as u8]
+    );
+    assert_eq!(
+        account_data(&mut context, membership).await,
+        vec![Key::Uninitialized as u8]
+    );
+}
+
+#[tokio::test]
+async fn close_group_detaching_collected_burned_asset() {
+    let mut context = program_test().start_with_context().await;
+
+    let group = Keypair::new();
+    let collection = Keypair::new();
+    let asset = Keypair::new();
+    let membership = create_group_with_members(&mut context, &group, &collection, &asset).await;
+
+    let ix = BurnV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .instruction();
+    send(&mut context, ix).await.unwrap();
+
+    collect(&mut context, asset.pubkey()).await;
+
+    let ix = CloseGroupV2Builder::new()
+        .group(group.pubkey())
+        .payer(context.payer.pubkey())
+        .detach_only(false)
+        .add_remaining_accounts(&[
+            AccountMeta::new(collection.pubkey(), false),
+            AccountMeta::new(asset.pubkey(), false),
+            AccountMeta::new(membership, false),
+        ])
+        .instruction();
+    send(&mut context, ix).await.unwrap();
+
+    assert_eq!(
+        account_data(&mut context, group.pubkey()).await,
+        vec![Key::Uninitialized as u8]
+    );
+    assert_eq!(
+        account_data(&mut context, membership).await,
+        vec![Key::Uninitialized as u8]
+    );
+}
+
+#[tokio::test]
+async fn remove_collected_burned_asset_from_group() {
+    let mut context = program_test().start_with_context().await;
+
+    let group = Keypair::new();
+    let collection = Keypair::new();
+    let asset = Keypair::new();
+    let membership = create_group_with_members(&mut context, &group, &collection, &asset).await;
+
+    let ix = BurnV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .instruction();
+    send(&mut context, ix).await.unwrap();
+    collect(&mut context, asset.pubkey()).await;
+
+    let ix = RemoveAssetsFromGroupV1Builder::new()
+        .group(group.pubkey())
+        .payer(context.payer.pubkey())
+        .assets(vec![asset.pubkey()])
+        .add_remaining_accounts(&[
+            AccountMeta::new(asset.pubkey(), false),
+            AccountMeta::new(membership, false),
+        ])
+        .instruction();
+    send(&mut context, ix).await.unwrap();
+
+    let group_data =
+        GroupV1::from_bytes(&account_data(&mut context, group.pubkey()).await).unwrap();
+    assert_eq!(group_data.membership_count, 0);
+    assert_eq!(
+        account_data(&mut context, membership).await,
+        vec![Key::Uninitialized as u8]
+    );
+}
+
+#[tokio::test]
+async fn add_asset_back_to_group_after_membership_is_collected() {
+    let mut context = program_test().start_with_context().await;
+
+    let group = Keypair::new();
+    let collection = Keypair::new();
+    let asset = Keypair::new();
+    let membership = create_group_with_members(&mut context, &group, &collection, &asset).await;
+
+    let ix = RemoveAssetsFromGroupV1Builder::new()
+        .group(group.pubkey())
+        .payer(context.payer.pubkey())
+        .assets(vec![asset.pubkey()])
+        .add_remaining_accounts(&[
+            AccountMeta::new(asset.pubkey(), false),
+            AccountMeta::new(membership, false),
+        ])
+        .instruction();
+    send(&mut context, ix).await.unwrap();
+    collect(&mut context, membership).await;
+
+    // The collected membership account is reopened for the asset.
+    let ix = AddAssetsToGroupV1Builder::new()
+        .group(group.pubkey())
+        .payer(context.payer.pubkey())
+        .add_remaining_accounts(&[
+            AccountMeta::new(asset.pubkey(), false),
+            AccountMeta::new(membership, false),
+        ])
+        .instruction();
+    send(&mut context, ix).await.unwrap();
+
+    let membership_data =
+        GroupMembershipV1::from_bytes(&account_data(&mut context, membership).await).unwrap();
+    assert_eq!(membership_data.group, group.pubkey());
+    assert_eq!(membership_data.member, asset.pubkey());
+
+    let group_data =
+        GroupV1::from_bytes(&account_data(&mut context, group.pubkey()).await).unwrap();
+    assert_eq!(group_data.membership_count, 1);
+}
@@ -0,0 +1,353 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    errors::MplCoreError,
+    instructions::{
+        CompressV1Builder, DecompressV1Builder, WriteExternalPluginAdapterDataV1Builder,
+    },
+    types::{
+        AppData, AppDataInitInfo, CompressionProof, Creator, ExternalPluginAdapter,
+        ExternalPluginAdapterInitInfo, ExternalPluginAdapterKey, ExternalPluginAdapterSchema,
+        FreezeDelegate, HashableExternalPluginAdapterSchema, HashableExternalPluginAdapterSchemaV1,
+        HashablePluginSchema, Key, Plugin, PluginAuthority, PluginAuthorityPair, Royalties,
+        RuleSet, TransferDelegateV2, UpdateAuthority,
+    },
+    Asset,
+};
+pub use setup::*;
+
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer, transaction::Transaction};
+
+const ASSET_NAME: &str = "Test Asset";
+const ASSET_URI: &str = "https://example.com/asset";
+
+/// Create a collection with royalties and an asset in it with owner managed plugins,
+/// returning the plugins as they are hashed when the asset is compressed.
+async fn create_asset_in_collection_with_plugins(
+    context: &mut ProgramTestContext,
+    asset: &Keypair,
+    collection: &Keypair,
+) -> Vec<HashablePluginSchema> {
+    create_collection(
+        context,
+        CreateCollectionHelperArgs {
+            collection,
+            update_authority: None,
+            payer: None,
+            name: None,
+            uri: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::Royalties(Royalties {
+                    basis_points: 500,
+                    creators: vec![Creator {
+                        address: context.payer.pubkey(),
+                        percentage: 100,
+                    }],
+                    rule_set: RuleSet::None,
+                }),
+                authority: None,
+            }],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let transfer_delegate = TransferDelegateV2 {
+        expires_at: None,
+        max_uses: Some(2),
+    };
+    create_asset(
+        context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: Some(collection.pubkey()),
+            plugins: vec![
+                PluginAuthorityPair {
+                    plugin: Plugin::FreezeDelegate(FreezeDelegate { frozen: false }),
+                    authority: Some(PluginAuthority::Owner),
+                },
+                PluginAuthorityPair {
+                    plugin: Plugin::TransferDelegateV2(transfer_delegate.clone()),
+                    authority: Some(PluginAuthority::Owner),
+                },
+            ],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    vec![
+        HashablePluginSchema {
+            index: 0,
+            authority: PluginAuthority::Owner,
+            plugin: Plugin::FreezeDelegate(FreezeDelegate { frozen: false }),
+        },
+        HashablePluginSchema {
+            index: 1,
+            authority: PluginAuthority::Owner,
+            plugin: Plugin::TransferDelegateV2(transfer_delegate),
+        },
+    ]
+}
+
+async fn compress_asset(
+    context: &mut ProgramTestContext,
+    asset: Pubkey,
+    collection: Option<Pubkey>,
+) {
+    let compress_ix = CompressV1Builder::new()
+        .asset(asset)
+        .collection(collection)
+        .payer(context.payer.pubkey())
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[compress_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let asset_account = context
+        .banks_client
+        .get_account(asset)
+        .await
+        .expect("get_account")
+        .expect("asset account not found");
+    assert_eq!(asset_account.data[0], Key::HashedAssetV1 as u8);
+}
+
+#[tokio::test]
+async fn compress_and_decompress_asset_in_collection() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let collection = Keypair::new();
+    let plugins = create_asset_in_collection_with_plugins(&mut context, &asset, &collection).await;
+
+    compress_asset(&mut context, asset.pubkey(), Some(collection.pubkey())).await;
+
+    let decompress_ix = DecompressV1Builder::new()
+        .asset(asset.pubkey())
+        .collection(Some(collection.pubkey()))
+        .payer(context.payer.pubkey())
+        .compression_proof(CompressionProof {
+            owner: context.payer.pubkey(),
+            update_authority: UpdateAuthority::Collection(collection.pubkey()),
+            name: ASSET_NAME.to_owned(),
+            uri: ASSET_URI.to_owned(),
+            seq: 1,
+            plugins,
+            external_plugin_adapters: vec![],
+        })
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[decompress_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let owner = context.payer.pubkey();
+    assert_asset(
+        &mut context,
+        AssertAssetHelperArgs {
+            asset: asset.pubkey(),
+            owner,
+            update_authority: Some(UpdateAuthority::Collection(collection.pubkey())),
+            name: None,
+            uri: None,
+            plugins: vec![
+                PluginAuthorityPair {
+                    plugin: Plugin::FreezeDelegate(FreezeDelegate { frozen: false }),
+                    authority: Some(PluginAuthority::Owner),
+                },
+                PluginAuthorityPair {
+                    plugin: Plugin::TransferDelegateV2(TransferDelegateV2 {
+                        expires_at: None,
+                        max_uses: Some(2),
+                    }),
+                    authority: Some(PluginAuthority::Owner),
+                },
+            ],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn cannot_decompress_asset_with_incorrect_proof() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let collection = Keypair::new();
+    let mut plugins =
+        create_asset_in_collection_with_plugins(&mut context, &asset, &collection).await;
+
+    compress_asset(&mut context, asset.pubkey(), Some(collection.pubkey())).await;
+
+    // Try to decompress the asset with the freeze delegate it was compressed with removed.
+    plugins.remove(0);
+    let decompress_ix = DecompressV1Builder::new()
+        .asset(asset.pubkey())
+        .collection(Some(collection.pubkey()))
+        .payer(context.payer.pubkey())
+        .compression_proof(CompressionProof {
+            owner: context.payer.pubkey(),
+            update_authority: UpdateAuthority::Collection(collection.pubkey()),
+            name: ASSET_NAME.to_owned(),
+            uri: ASSET_URI.to_owned(),
+            seq: 1,
+            plugins,
+            external_plugin_adapters: vec![],
+        })
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[decompress_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_custom_instruction_error!(0, error, MplCoreError::IncorrectAssetHash);
+}
+
+#[tokio::test]
+async fn compress_and_decompress_asset_with_app_data() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![],
+            external_plugin_adapters: vec![ExternalPluginAdapterInitInfo::AppData(
+                AppDataInitInfo {
+                    init_plugin_authority: Some(PluginAuthority::UpdateAuthority),
+                    data_authority: PluginAuthority::UpdateAuthority,
+                    schema: Some(ExternalPluginAdapterSchema::Binary),
+                },
+            )],
+        },
+    )
+    .await
+    .unwrap();
+
+    let data = vec![1, 2, 3, 4];
+    let write_ix = WriteExternalPluginAdapterDataV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .key(ExternalPluginAdapterKey::AppData(
+            PluginAuthority::UpdateAuthority,
+        ))
+        .data(data.clone())
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[write_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    compress_asset(&mut context, asset.pubkey(), None).await;
+
+    let app_data = AppData {
+        data_authority: PluginAuthority::UpdateAuthority,
+        schema: ExternalPluginAdapterSchema::Binary,
+    };
+    let decompress_ix = DecompressV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .compression_proof(CompressionProof {
+            owner: context.payer.pubkey(),
+            update_authority: UpdateAuthority::Address(context.payer.pubkey()),
+            name: ASSET_NAME.to_owned(),
+            uri: ASSET_URI.to_owned(),
+            seq: 1,
+            plugins: vec![],
+            external_plugin_adapters: vec![HashableExternalPluginAdapterSchema::V1(
+                HashableExternalPluginAdapterSchemaV1 {
+                    index: 0,
+                    authority: PluginAuthority::UpdateAuthority,
+                    lifecycle_checks: None,
+                    adapter: ExternalPluginAdapter::AppData(app_data.clone()),
+                    data: Some(data.clone()),
+                },
+            )],
+        })
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[decompress_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let owner = context.payer.pubkey();
+    assert_asset(
+        &mut context,
+        AssertAssetHelperArgs {
+            asset: asset.pubkey(),
+            owner,
+            update_authority: Some(UpdateAuthority::Address(owner)),
+            name: None,
+            uri: None,
+            plugins: vec![],
+            external_plugin_adapters: vec![ExternalPluginAdapter::AppData(app_data)],
+        },
+    )
+    .await;
+
+    // The app data is restored.
+    let asset_account = context
+        .banks_client
+        .get_account(asset.pubkey())
+        .await
+        .expect("get_account")
+        .expect("asset account not found");
+    let asset_data = Asset::from_bytes(&asset_account.data).unwrap();
+    let app_data_with_data = &asset_data.external_plugin_adapter_list.app_data[0];
+    assert_eq!(
+        asset_account.data[app_data_with_data.data_offset
+            ..app_data_with_data.data_offset + app_data_with_data.data_len],
+        data
+    );
+}
@@ -1,12 +1,21 @@
 #![cfg(feature = "test-sbf")]
 pub mod setup;
-use mpl_core::types::{
-    FreezeDelegate, Plugin, PluginAuthority, PluginAuthorityPair, UpdateAuthority,
+use mpl_core::{
+    errors::MplCoreError,
+    instructions::CreateBatchV1Builder,
+    types::{
+        AssetInitInfo, FreezeDelegate, Plugin, PluginAuthority, PluginAuthorityPair, Rental,
+        UpdateAuthority,
+    },
+    Asset,
 };
 pub use setup::*;
 
+use solana_program::{clock::Clock, instruction::AccountMeta};
 use solana_program_test::tokio;
-use solana_sdk::{native_token::LAMPORTS_PER_SOL, signature::Keypair, signer::Signer};
+use solana_sdk::{
+    native_token::LAMPORTS_PER_SOL, signature::Keypair, signer::Signer, transaction::Transaction,
+};
 
 #[tokio::test]
 async fn create_asset_in_account_state() {
@@ -232,3 +241,209 @@ async fn create_asset_with_plugins_with_different_update_authority() {
     )
     .await;
 }
+
+#[tokio::test]
+async fn create_batch_assets_in_collection() {
+    let mut context = program_test().start_with_context().await;
+
+    let collection = Keypair::new();
+    create_collection(
+        &mut context,
+        CreateCollectionHelperArgs {
+            collection: &collection,
+            update_authority: None,
+            payer: None,
+            name: None,
+            uri: None,
+            plugins: vec![],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let first_asset = Keypair::new();
+    let second_asset = Keypair::new();
+    let first_owner = Keypair::new();
+    let second_owner = Keypair::new();
+    let create_ix = CreateBatchV1Builder::new()
+        .collection(collection.pubkey())
+        .payer(context.payer.pubkey())
+        .assets(vec![
+            AssetInitInfo {
+                owner: first_owner.pubkey(),
+                name: "Test Asset".to_owned(),
+                uri: "https://example.com/asset".to_owned(),
+                plugins: vec![],
+            },
+            AssetInitInfo {
+                owner: second_owner.pubkey(),
+                name: "Test Asset".to_owned(),
+                uri: "https://example.com/asset".to_owned(),
+                plugins: vec![PluginAuthorityPair {
+                    authority: None,
+                    plugin: Plugin::FreezeDelegate(FreezeDelegate { frozen: false }),
+                }],
+            },
+        ])
+        .add_remaining_accounts(&[
+            AccountMeta::new(first_asset.pubkey(), true),
+            AccountMeta::new(second_asset.pubkey(), true),
+        ])
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[create_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &first_asset, &second_asset],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    assert_asset(
+        &mut context,
+        AssertAssetHelperArgs {
+            asset: first_asset.pubkey(),
+            owner: first_owner.pubkey(),
+            update_authority: Some(UpdateAuthority::Collection(collection.pubkey())),
+            name: None,
+            uri: None,
+            plugins: vec![],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await;
+
+    assert_asset(
+        &mut context,
+        AssertAssetHelperArgs {
+            asset: second_asset.pubkey(),
+            owner: second_owner.pubkey(),
+            update_authority: Some(UpdateAuthority::Collection(collection.pubkey())),
+            name: None,
+            uri: None,
+            plugins: vec![PluginAuthorityPair {
+                authority: Some(PluginAuthority::Owner),
+                plugin: Plugin::FreezeDelegate(FreezeDelegate { frozen: false }),
+            }],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await;
+
+    let update_authority = context.payer.pubkey();
+    assert_collection(
+        &mut context,
+        AssertCollectionHelperArgs {
+            collection: collection.pubkey(),
+            update_authority,
+            name: None,
+            uri: None,
+            num_minted: 2,
+            current_size: 2,
+            plugins: vec![],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn create_asset_with_rental() {
+    let mut context = program_test().start_with_context().await;
+
+    let clock: Clock = context.banks_client.get_sysvar().await.unwrap();
+    let rental = Rental {
+        lender: context.payer.pubkey(),
+        renter: Keypair::new().pubkey(),
+        expiry_slot: clock.slot + 100,
+    };
+
+    let asset = Keypair::new();
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::Rental(rental.clone()),
+                authority: None,
+            }],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let account = context
+        .banks_client
+        .get_account(asset.pubkey())
+        .await
+        .expect("get_account")
+        .expect("asset account not found");
+    let asset_data = Asset::from_bytes(&account.data).unwrap();
+    assert_eq!(asset_data.plugin_list.rental.unwrap().rental, rental);
+}
+
+#[tokio::test]
+async fn cannot_create_asset_with_invalid_rental() {
+    let mut context = program_test().start_with_context().await;
+
+    let clock: Clock = context.banks_client.get_sysvar().await.unwrap();
+    let owner = context.payer.pubkey();
+    let renter = Keypair::new().pubkey();
+
+    // The lender must own the asset, lend it to someone else and expire in the future.
+    let rentals = [
+        Rental {
+            lender: Keypair::new().pubkey(),
+            renter,
+            expiry_slot: clock.slot + 100,
+        },
+        Rental {
+            lender: owner,
+            renter: owner,
+            expiry_slot: clock.slot + 100,
+        },
+        Rental {
+            lender: owner,
+            renter,
+            expiry_slot: clock.slot,
+        },
+    ];
+
+    for rental in rentals {
+        let asset = Keypair::new();
+        let error = create_asset(
+            &mut context,
+            CreateAssetHelperArgs {
+                owner: None,
+                payer: None,
+                asset: &asset,
+                data_state: None,
+                name: None,
+                uri: None,
+                authority: None,
+                update_authority: None,
+                collection: None,
+                plugins: vec![PluginAuthorityPair {
+                    plugin: Plugin::Rental(rental),
+                    authority: None,
+                }],
+                external_plugin_adapters: vec![],
+            },
+        )
+        .await
+        .unwrap_err();
+
+        assert_custom_instruction_error!(0, error, MplCoreError::InvalidAuthority);
+    }
+}
@@ -0,0 +1,685 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    accounts::AssetSigner,
+    errors::MplCoreError,
+    instructions::{
+        ExecuteBatchV1Builder, ExecuteCollectionV1Builder, ExecuteV1Builder, TransferV1Builder,
+        UpdatePluginV1Builder,
+    },
+    types::{
+        ExecuteBatchInstruction, ExecuteGuard, ExecuteGuardProgram, PermanentFreezeExecute, Plugin,
+        PluginAuthorityPair, Soulbound,
+    },
+    Asset,
+};
+pub use setup::*;
+
+use solana_program::instruction::AccountMeta;
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer, transaction::Transaction};
+use solana_system_interface::{instruction as system_instruction, program as system_program};
+
+#[tokio::test]
+async fn execute_batch_of_transfers_from_asset_signer() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let (asset_signer, _) = AssetSigner::find_pda(&asset.pubkey());
+    airdrop(&mut context, &asset_signer, 1_000_000_000)
+        .await
+        .unwrap();
+
+    let first_recipient = Keypair::new().pubkey();
+    let second_recipient = Keypair::new().pubkey();
+    let amount = 100_000_000;
+    let transfer_data = system_instruction::transfer(&asset_signer, &first_recipient, amount).data;
+
+    let ix = ExecuteBatchV1Builder::new()
+        .asset(asset.pubkey())
+        .asset_signer(asset_signer)
+        .payer(context.payer.pubkey(), true)
+        .instructions(vec![
+            ExecuteBatchInstruction {
+                program_index: 0,
+                accounts_start: 1,
+                accounts_len: 2,
+                data: transfer_data.clone(),
+            },
+            ExecuteBatchInstruction {
+                program_index: 0,
+                accounts_start: 3,
+                accounts_len: 2,
+                data: transfer_data,
+            },
+        ])
+        .add_remaining_accounts(&[
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new(asset_signer, false),
+            AccountMeta::new(first_recipient, false),
+            AccountMeta::new(asset_signer, false),
+            AccountMeta::new(second_recipient, false),
+        ])
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    for recipient in [first_recipient, second_recipient] {
+        let balance = context.banks_client.get_balance(recipient).await.unwrap();
+        assert_eq!(balance, amount);
+    }
+
+    let asset_signer_balance = context
+        .banks_client
+        .get_balance(asset_signer)
+        .await
+        .unwrap();
+    assert_eq!(asset_signer_balance, 1_000_000_000 - 2 * amount);
+}
+
+#[tokio::test]
+async fn cannot_execute_batch_with_accounts_out_of_range() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let (asset_signer, _) = AssetSigner::find_pda(&asset.pubkey());
+    let recipient = Keypair::new().pubkey();
+
+    let ix = ExecuteBatchV1Builder::new()
+        .asset(asset.pubkey())
+        .asset_signer(asset_signer)
+        .payer(context.payer.pubkey(), true)
+        .instructions(vec![ExecuteBatchInstruction {
+            program_index: 0,
+            accounts_start: 1,
+            accounts_len: 3,
+            data: system_instruction::transfer(&asset_signer, &recipient, 1).data,
+        }])
+        .add_remaining_accounts(&[
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new(asset_signer, false),
+            AccountMeta::new(recipient, false),
+        ])
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_custom_instruction_error!(0, error, MplCoreError::InvalidBatchAccounts);
+}
+
+#[tokio::test]
+async fn execute_batch_invoking_mpl_core() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    // An asset owned by the asset signer.
+    let (asset_signer, _) = AssetSigner::find_pda(&asset.pubkey());
+    let owned_asset = Keypair::new();
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: Some(asset_signer),
+            payer: None,
+            asset: &owned_asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    // The asset signer signs the inner instruction through the execute.
+    let new_owner = Keypair::new().pubkey();
+    let transfer_ix = TransferV1Builder::new()
+        .asset(owned_asset.pubkey())
+        .payer(asset_signer)
+        .new_owner(new_owner)
+        .instruction();
+
+    let ix = ExecuteBatchV1Builder::new()
+        .asset(asset.pubkey())
+        .asset_signer(asset_signer)
+        .payer(context.payer.pubkey(), true)
+        .instructions(vec![ExecuteBatchInstruction {
+            program_index: 0,
+            accounts_start: 1,
+            accounts_len: transfer_ix.accounts.len() as u8,
+            data: transfer_ix.data,
+        }])
+        .add_remaining_accounts(
+            &[AccountMeta::new_readonly(mpl_core::ID, false)]
+                .into_iter()
+                .chain(transfer_ix.accounts.into_iter().map(|account| AccountMeta {
+                    is_signer: false,
+                    ..account
+                }))
+                .collect::<Vec<_>>(),
+        )
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let owned_asset_account = context
+        .banks_client
+        .get_account(owned_asset.pubkey())
+        .await
+        .expect("get_account")
+        .expect("asset account not found");
+    let owned_asset_data = Asset::from_bytes(&owned_asset_account.data).unwrap();
+    assert_eq!(owned_asset_data.base.owner, new_owner);
+}
+
+/// Create an asset with an execute guard and fund its asset signer.
+async fn create_guarded_asset(
+    context: &mut ProgramTestContext,
+    asset: &Keypair,
+    allowed_programs: Vec<ExecuteGuardProgram>,
+) -> Pubkey {
+    create_asset(
+        context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::ExecuteGuard(ExecuteGuard { allowed_programs }),
+                authority: None,
+            }],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let (asset_signer, _) = AssetSigner::find_pda(&asset.pubkey());
+    airdrop(context, &asset_signer, 1_000_000_000)
+        .await
+        .unwrap();
+
+    asset_signer
+}
+
+#[tokio::test]
+async fn cannot_execute_program_not_allowed_by_execute_guard() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let asset_signer = create_guarded_asset(
+        &mut context,
+        &asset,
+        vec![ExecuteGuardProgram {
+            program_id: Pubkey::new_unique(),
+            instruction_spend_limit: None,
+        }],
+    )
+    .await;
+
+    let recipient = Keypair::new().pubkey();
+    let ix = ExecuteV1Builder::new()
+        .asset(asset.pubkey())
+        .asset_signer(asset_signer)
+        .payer(context.payer.pubkey(), true)
+        .program_id(system_program::ID)
+        .instruction_data(system_instruction::transfer(&asset_signer, &recipient, 1).data)
+        .add_remaining_accounts(&[
+            AccountMeta::new(asset_signer, false),
+            AccountMeta::new(recipient, false),
+        ])
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_custom_instruction_error!(0, error, MplCoreError::ProgramNotAllowed);
+}
+
+#[tokio::test]
+async fn cannot_execute_batch_over_execute_guard_spend_limit() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let amount = 100_000_000;
+    let asset_signer = create_guarded_asset(
+        &mut context,
+        &asset,
+        vec![ExecuteGuardProgram {
+            program_id: system_program::ID,
+            instruction_spend_limit: Some(amount + amount / 2),
+        }],
+    )
+    .await;
+
+    // Each transfer is within the limit, but together they exceed it.
+    let recipient = Keypair::new().pubkey();
+    let transfer_data = system_instruction::transfer(&asset_signer, &recipient, amount).data;
+    let ix = ExecuteBatchV1Builder::new()
+        .asset(asset.pubkey())
+        .asset_signer(asset_signer)
+        .payer(context.payer.pubkey(), true)
+        .instructions(vec![
+            ExecuteBatchInstruction {
+                program_index: 0,
+                accounts_start: 1,
+                accounts_len: 2,
+                data: transfer_data.clone(),
+            },
+            ExecuteBatchInstruction {
+                program_index: 0,
+                accounts_start: 1,
+                accounts_len: 2,
+                data: transfer_data,
+            },
+        ])
+        .add_remaining_accounts(&[
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new(asset_signer, false),
+            AccountMeta::new(recipient, false),
+        ])
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_custom_instruction_error!(0, error, MplCoreError::SpendLimitExceeded);
+}
+
+#[tokio::test]
+async fn execute_batch_reentering_asset_keeps_execute_guard_from_start() {
+    let mut context = program_test().start_with_context().await;
+
+    // The asset signer is the update authority of its own asset, so it can update the guard.
+    let asset = Keypair::new();
+    let (asset_signer, _) = AssetSigner::find_pda(&asset.pubkey());
+    let amount = 100_000_000;
+    let guarded_programs = |spend_limit| {
+        vec![
+            ExecuteGuardProgram {
+                program_id: mpl_core::ID,
+                instruction_spend_limit: None,
+            },
+            ExecuteGuardProgram {
+                program_id: system_program::ID,
+                instruction_spend_limit: Some(spend_limit),
+            },
+        ]
+    };
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: Some(asset_signer),
+            collection: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::ExecuteGuard(ExecuteGuard {
+                    allowed_programs: guarded_programs(amount),
+                }),
+                authority: None,
+            }],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+    airdrop(&mut context, &asset_signer, 1_000_000_000)
+        .await
+        .unwrap();
+
+    // Raise the spend limit of the asset's own guard, then spend above the original limit.
+    let update_plugin_ix = UpdatePluginV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(asset_signer)
+        .plugin(Plugin::ExecuteGuard(ExecuteGuard {
+            allowed_programs: guarded_programs(3 * amount),
+        }))
+        .instruction();
+    let update_plugin_accounts_len = update_plugin_ix.accounts.len() as u8;
+    let recipient = Keypair::new().pubkey();
+
+    let ix = ExecuteBatchV1Builder::new()
+        .asset(asset.pubkey())
+        .asset_signer(asset_signer)
+        .payer(context.payer.pubkey(), true)
+        .instructions(vec![
+            ExecuteBatchInstruction {
+                program_index: 0,
+                accounts_start: 2,
+                accounts_len: update_plugin_accounts_len,
+                data: update_plugin_ix.data,
+            },
+            ExecuteBatchInstruction {
+                program_index: 1,
+                accounts_start: 2 + update_plugin_accounts_len,
+                accounts_len: 2,
+                data: system_instruction::transfer(&asset_signer, &recipient, 2 * amount).data,
+            },
+        ])
+        .add_remaining_accounts(
+            &[
+                AccountMeta::new_readonly(mpl_core::ID, false),
+                AccountMeta::new_readonly(system_program::ID, false),
+            ]
+            .into_iter()
+            .chain(
+                update_plugin_ix
+                    .accounts
+                    .into_iter()
+                    .map(|account| AccountMeta {
+                        is_signer: false,
+                        ..account
+                    }),
+            )
+            .chain([
+                AccountMeta::new(asset_signer, false),
+                AccountMeta::new(recipient, false),
+            ])
+            .collect::<Vec<_>>(),
+        )
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    // The batch is still checked against the guard loaded before the update.
+    assert_custom_instruction_error!(0, error, MplCoreError::SpendLimitExceeded);
+}
+
+/// Create a collection with the given plugins and fund its signer PDA.
+async fn create_funded_collection(
+    context: &mut ProgramTestContext,
+    collection: &Keypair,
+    plugins: Vec<PluginAuthorityPair>,
+) -> Pubkey {
+    create_collection(
+        context,
+        CreateCollectionHelperArgs {
+            collection,
+            update_authority: None,
+            payer: None,
+            name: None,
+            uri: None,
+            plugins,
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    // The collection signer uses the same seeds as the asset signer.
+    let (collection_signer, _) = AssetSigner::find_pda(&collection.pubkey());
+    airdrop(context, &collection_signer, 1_000_000_000)
+        .await
+        .unwrap();
+
+    collection_signer
+}
+
+#[tokio::test]
+async fn execute_transfer_from_collection_signer() {
+    let mut context = program_test().start_with_context().await;
+
+    let collection = Keypair::new();
+    let collection_signer = create_funded_collection(&mut context, &collection, vec![]).await;
+
+    let recipient = Keypair::new().pubkey();
+    let amount = 100_000_000;
+    let ix = ExecuteCollectionV1Builder::new()
+        .collection(collection.pubkey())
+        .collection_signer(collection_signer)
+        .payer(context.payer.pubkey(), true)
+        .program_id(system_program::ID)
+        .instruction_data(system_instruction::transfer(&collection_signer, &recipient, amount).data)
+        .add_remaining_accounts(&[
+            AccountMeta::new(collection_signer, false),
+            AccountMeta::new(recipient, false),
+        ])
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let balance = context.banks_client.get_balance(recipient).await.unwrap();
+    assert_eq!(balance, amount);
+}
+
+#[tokio::test]
+async fn cannot_execute_from_collection_signer_with_permanent_freeze_execute() {
+    let mut context = program_test().start_with_context().await;
+
+    let collection = Keypair::new();
+    let collection_signer = create_funded_collection(
+        &mut context,
+        &collection,
+        vec![PluginAuthorityPair {
+            plugin: Plugin::PermanentFreezeExecute(PermanentFreezeExecute { frozen: true }),
+            authority: None,
+        }],
+    )
+    .await;
+
+    let recipient = Keypair::new().pubkey();
+    let ix = ExecuteCollectionV1Builder::new()
+        .collection(collection.pubkey())
+        .collection_signer(collection_signer)
+        .payer(context.payer.pubkey(), true)
+        .program_id(system_program::ID)
+        .instruction_data(system_instruction::transfer(&collection_signer, &recipient, 1).data)
+        .add_remaining_accounts(&[
+            AccountMeta::new(collection_signer, false),
+            AccountMeta::new(recipient, false),
+        ])
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_custom_instruction_error!(0, error, MplCoreError::InvalidAuthority);
+}
+
+#[tokio::test]
+async fn execute_from_soulbound_asset() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::Soulbound(Soulbound {}),
+                authority: None,
+            }],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let (asset_signer, _) = AssetSigner::find_pda(&asset.pubkey());
+    airdrop(&mut context, &asset_signer, 1_000_000_000)
+        .await
+        .unwrap();
+
+    let recipient = Keypair::new().pubkey();
+    let ix = ExecuteV1Builder::new()
+        .asset(asset.pubkey())
+        .asset_signer(asset_signer)
+        .payer(context.payer.pubkey(), true)
+        .program_id(system_program::ID)
+        .instruction_data(system_instruction::transfer(&asset_signer, &recipient, 1_000_000).data)
+        .add_remaining_accounts(&[
+            AccountMeta::new(asset_signer, false),
+            AccountMeta::new(recipient, false),
+        ])
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let recipient_account = context
+        .banks_client
+        .get_account(recipient)
+        .await
+        .expect("get_account")
+        .expect("recipient account not found");
+    assert_eq!(recipient_account.lamports, 1_000_000);
+}
@@ -0,0 +1,226 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use std::collections::HashMap;
+
+use mpl_core::{
+    accounts::GroupV1,
+    errors::MplCoreError,
+    instructions::{AddGroupsToGroupV1Builder, CreateGroupV1Builder},
+};
+pub use setup::*;
+
+use solana_program::instruction::{AccountMeta, Instruction};
+use solana_program_test::{tokio, BanksClientError, ProgramTestContext};
+use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer, transaction::Transaction};
+
+async fn send(context: &mut ProgramTestContext, ix: Instruction) -> Result<(), BanksClientError> {
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await
+}
+
+async fn create_group(context: &mut ProgramTestContext) -> Pubkey {
+    let group = Keypair::new();
+    let ix = CreateGroupV1Builder::new()
+        .group(group.pubkey())
+        .payer(context.payer.pubkey())
+        .name("Test Group".to_owned())
+        .uri("https://example.com/group".to_owned())
+        .relationships(vec![])
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &group],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    group.pubkey()
+}
+
+async fn fetch_group(context: &mut ProgramTestContext, group: Pubkey) -> GroupV1 {
+    let account = context
+        .banks_client
+        .get_account(group)
+        .await
+        .expect("get_account")
+        .expect("account not found");
+
+    GroupV1::from_bytes(&account.data).unwrap()
+}
+
+/// Fetch every group reachable from `start` up front so the proof builders can walk them.
+async fn fetch_reachable(
+    context: &mut ProgramTestContext,
+    start: &[Pubkey],
+) -> HashMap<Pubkey, GroupV1> {
+    let mut fetched = HashMap::new();
+    let mut pending = start.to_vec();
+    while let Some(group) = pending.pop() {
+        if fetched.contains_key(&group) {
+            continue;
+        }
+        let data = fetch_group(context, group).await;
+        pending.extend(data.parent_groups.iter().copied());
+        pending.extend(data.groups.iter().copied());
+        fetched.insert(group, data);
+    }
+
+    fetched
+}
+
+/// Build `AddGroupsToGroupV1` with the ancestry proof of the parent group and the descendant
+/// proof of the child group.
+async fn add_group_with_proof(
+    context: &mut ProgramTestContext,
+    parent: Pubkey,
+    child: Pubkey,
+) -> Instruction {
+    let fetched = fetch_reachable(context, &[parent, child]).await;
+
+    AddGroupsToGroupV1Builder::new()
+        .payer(context.payer.pubkey())
+        .groups_with_proof(parent, vec![child], |group| fetched.get(group).cloned())
+        .instruction()
+}
+
+/// Create a chain of `len` groups, each nested below the previous one.
+async fn create_chain(context: &mut ProgramTestContext, len: usize) -> Vec<Pubkey> {
+    let mut chain: Vec<Pubkey> = Vec::with_capacity(len);
+    for _ in 0..len {
+        let group = create_group(context).await;
+        if let Some(parent) = chain.last() {
+            let ix = add_group_with_proof(context, *parent, group).await;
+            send(context, ix).await.unwrap();
+        }
+        chain.push(group);
+    }
+
+    chain
+}
+
+#[tokio::test]
+async fn cannot_add_ancestor_as_child_group() {
+    let mut context = program_test().start_with_context().await;
+
+    let root = create_group(&mut context).await;
+    let middle = create_group(&mut context).await;
+    let leaf = create_group(&mut context).await;
+
+    let ix = add_group_with_proof(&mut context, root, middle).await;
+    send(&mut context, ix).await.unwrap();
+    let ix = add_group_with_proof(&mut context, middle, leaf).await;
+    send(&mut context, ix).await.unwrap();
+
+    let leaf_group = fetch_group(&mut context, leaf).await;
+    assert_eq!(leaf_group.parent_groups, vec![middle]);
+
+    // Adding the root below the leaf would close the loop root -> middle -> leaf -> root.
+    let ix = add_group_with_proof(&mut context, leaf, root).await;
+    let error = send(&mut context, ix).await.unwrap_err();
+
+    assert_custom_instruction_error!(0, error, MplCoreError::GroupCycleDetected);
+}
+
+#[tokio::test]
+async fn cannot_add_group_with_incomplete_ancestry_proof() {
+    let mut context = program_test().start_with_context().await;
+
+    let root = create_group(&mut context).await;
+    let middle = create_group(&mut context).await;
+    let leaf = create_group(&mut context).await;
+    let child = create_group(&mut context).await;
+
+    let ix = add_group_with_proof(&mut context, root, middle).await;
+    send(&mut context, ix).await.unwrap();
+    let ix = add_group_with_proof(&mut context, middle, leaf).await;
+    send(&mut context, ix).await.unwrap();
+
+    // The proof stops at the middle group and omits the root.
+    let ix = AddGroupsToGroupV1Builder::new()
+        .parent_group(leaf)
+        .payer(context.payer.pubkey())
+        .groups(vec![child])
+        .ancestors(vec![middle])
+        .add_remaining_accounts(&[
+            AccountMeta::new(child, false),
+            AccountMeta::new_readonly(middle, false),
+        ])
+        .instruction();
+    let error = send(&mut context, ix).await.unwrap_err();
+
+    assert_custom_instruction_error!(0, error, MplCoreError::InvalidGroupAncestry);
+}
+
+#[tokio::test]
+async fn add_group_without_ancestry_proof() {
+    let mut context = program_test().start_with_context().await;
+
+    let parent = create_group(&mut context).await;
+    let child = create_group(&mut context).await;
+
+    let mut ix = AddGroupsToGroupV1Builder::new()
+        .parent_group(parent)
+        .payer(context.payer.pubkey())
+        .groups(vec![child])
+        .add_remaining_account(AccountMeta::new(child, false))
+        .instruction();
+
+    // Instruction data built before the ancestry and descendant proofs existed ends after the
+    // groups.
+    assert_eq!(ix.data.pop(), Some(0));
+    assert_eq!(ix.data.pop(), Some(0));
+    send(&mut context, ix).await.unwrap();
+
+    let parent_group = fetch_group(&mut context, parent).await;
+    assert_eq!(parent_group.groups, vec![child]);
+}
+
+#[tokio::test]
+async fn cannot_add_group_whose_descendants_exceed_nesting_depth() {
+    let mut context = program_test().start_with_context().await;
+
+    // The leaf of the upper chain is four levels deep and the root of the lower chain has
+    // four levels of groups below it.
+    let upper = create_chain(&mut context, 5).await;
+    let lower = create_chain(&mut context, 5).await;
+
+    let ix = add_group_with_proof(&mut context, upper[4], lower[0]).await;
+    let error = send(&mut context, ix).await.unwrap_err();
+
+    assert_custom_instruction_error!(0, error, MplCoreError::GroupNestingDepthExceeded);
+
+    // One level less fits within the maximum nesting depth.
+    let ix = add_group_with_proof(&mut context, upper[3], lower[0]).await;
+    send(&mut context, ix).await.unwrap();
+
+    let lower_root = fetch_group(&mut context, lower[0]).await;
+    assert_eq!(lower_root.parent_groups, vec![upper[3]]);
+}
+
+#[tokio::test]
+async fn cannot_add_group_with_incomplete_descendant_proof() {
+    let mut context = program_test().start_with_context().await;
+
+    let parent = create_group(&mut context).await;
+    let lower = create_chain(&mut context, 2).await;
+
+    // The ancestry proof is given but the group below the child is left out.
+    let ix = AddGroupsToGroupV1Builder::new()
+        .parent_group(parent)
+        .payer(context.payer.pubkey())
+        .groups(vec![lower[0]])
+        .ancestors(vec![])
+        .add_remaining_account(AccountMeta::new(lower[0], false))
+        .instruction();
+    let error = send(&mut context, ix).await.unwrap_err();
+
+    assert_custom_instruction_error!(0, error, MplCoreError::InvalidGroupAncestry);
+}
@@ -0,0 +1,237 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    accounts::{GroupAuthorityProposalV1, GroupDelegateV1, GroupV1},
+    errors::MplCoreError,
+    instructions::{
+        AcceptGroupUpdateAuthorityV1Builder, AddAssetsToGroupV1Builder,
+        ApproveGroupDelegateV1Builder, CreateGroupV1Builder, ProposeGroupUpdateAuthorityV1Builder,
+        RevokeGroupDelegateV1Builder,
+    },
+    types::Key,
+};
+pub use setup::*;
+
+use solana_program::instruction::{AccountMeta, Instruction};
+use solana_program_test::{tokio, BanksClientError, ProgramTestContext};
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+async fn create_group_with_asset(
+    context: &mut ProgramTestContext,
+    group: &Keypair,
+    asset: &Keypair,
+    asset_update_authority: &Keypair,
+) {
+    let ix = CreateGroupV1Builder::new()
+        .group(group.pubkey())
+        .payer(context.payer.pubkey())
+        .name("Test Group".to_owned())
+        .uri("https://example.com/group".to_owned())
+        .relationships(vec![])
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, group],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    create_asset(
+        context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: Some(asset_update_authority.pubkey()),
+            collection: None,
+            plugins: vec![],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+}
+
+async fn process(
+    context: &mut ProgramTestContext,
+    ix: Instruction,
+    signers: &[&Keypair],
+) -> Result<(), BanksClientError> {
+    let mut all_signers = vec![&context.payer];
+    all_signers.extend_from_slice(signers);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &all_signers,
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await
+}
+
+#[tokio::test]
+async fn delegate_can_add_asset_to_group() {
+    let mut context = program_test().start_with_context().await;
+
+    let group = Keypair::new();
+    let asset = Keypair::new();
+    let delegate = Keypair::new();
+    create_group_with_asset(&mut context, &group, &asset, &delegate).await;
+
+    let (group_delegate, _) = GroupDelegateV1::find_pda(&group.pubkey(), &delegate.pubkey());
+
+    let ix = ApproveGroupDelegateV1Builder::new()
+        .group(group.pubkey())
+        .group_delegate(group_delegate)
+        .payer(context.payer.pubkey())
+        .delegate(delegate.pubkey())
+        .instruction();
+    process(&mut context, ix, &[]).await.unwrap();
+
+    let delegate_account = context
+        .banks_client
+        .get_account(group_delegate)
+        .await
+        .expect("get_account")
+        .expect("group delegate account not found");
+    assert_eq!(
+        GroupDelegateV1::from_bytes(&delegate_account.data).unwrap(),
+        GroupDelegateV1 {
+            key: Key::GroupDelegateV1,
+            group: group.pubkey(),
+            delegate: delegate.pubkey(),
+            update_authority: context.payer.pubkey(),
+        }
+    );
+
+    // The delegate record leads the remaining accounts.
+    let ix = AddAssetsToGroupV1Builder::new()
+        .group(group.pubkey())
+        .payer(context.payer.pubkey())
+        .authority(Some(delegate.pubkey()))
+        .add_remaining_accounts(&[
+            AccountMeta::new_readonly(group_delegate, false),
+            AccountMeta::new(asset.pubkey(), false),
+        ])
+        .instruction();
+    process(&mut context, ix, &[&delegate]).await.unwrap();
+
+    let group_account = context
+        .banks_client
+        .get_account(group.pubkey())
+        .await
+        .expect("get_account")
+        .expect("group account not found");
+    let group_data = GroupV1::from_bytes(&group_account.data).unwrap();
+    assert_eq!(group_data.assets, vec![asset.pubkey()]);
+}
+
+#[tokio::test]
+async fn revoked_delegate_cannot_add_asset_to_group() {
+    let mut context = program_test().start_with_context().await;
+
+    let group = Keypair::new();
+    let asset = Keypair::new();
+    let delegate = Keypair::new();
+    create_group_with_asset(&mut context, &group, &asset, &delegate).await;
+
+    let (group_delegate, _) = GroupDelegateV1::find_pda(&group.pubkey(), &delegate.pubkey());
+
+    let ix = ApproveGroupDelegateV1Builder::new()
+        .group(group.pubkey())
+        .group_delegate(group_delegate)
+        .payer(context.payer.pubkey())
+        .delegate(delegate.pubkey())
+        .instruction();
+    process(&mut context, ix, &[]).await.unwrap();
+
+    // The delegate may give up its own delegation.
+    let ix = RevokeGroupDelegateV1Builder::new()
+        .group(group.pubkey())
+        .group_delegate(group_delegate)
+        .payer(context.payer.pubkey())
+        .authority(Some(delegate.pubkey()))
+        .instruction();
+    process(&mut context, ix, &[&delegate]).await.unwrap();
+
+    let delegate_account = context
+        .banks_client
+        .get_account(group_delegate)
+        .await
+        .expect("get_account")
+        .expect("group delegate account not found");
+    assert_eq!(delegate_account.data, vec![Key::Uninitialized as u8]);
+
+    let ix = AddAssetsToGroupV1Builder::new()
+        .group(group.pubkey())
+        .payer(context.payer.pubkey())
+        .authority(Some(delegate.pubkey()))
+        .add_remaining_accounts(&[AccountMeta::new(asset.pubkey(), false)])
+        .instruction();
+    let error = process(&mut context, ix, &[&delegate]).await.unwrap_err();
+
+    assert_custom_instruction_error!(0, error, MplCoreError::InvalidAuthority);
+}
+
+#[tokio::test]
+async fn delegate_lapses_when_group_update_authority_is_handed_over() {
+    let mut context = program_test().start_with_context().await;
+
+    let group = Keypair::new();
+    let asset = Keypair::new();
+    let delegate = Keypair::new();
+    create_group_with_asset(&mut context, &group, &asset, &delegate).await;
+
+    let (group_delegate, _) = GroupDelegateV1::find_pda(&group.pubkey(), &delegate.pubkey());
+
+    let ix = ApproveGroupDelegateV1Builder::new()
+        .group(group.pubkey())
+        .group_delegate(group_delegate)
+        .payer(context.payer.pubkey())
+        .delegate(delegate.pubkey())
+        .instruction();
+    process(&mut context, ix, &[]).await.unwrap();
+
+    // Hand the group over to a new update authority.
+    let new_update_authority = Keypair::new();
+    let (proposal, _) = GroupAuthorityProposalV1::find_pda(&group.pubkey());
+    let ix = ProposeGroupUpdateAuthorityV1Builder::new()
+        .group(group.pubkey())
+        .group_authority_proposal(proposal)
+        .payer(context.payer.pubkey())
+        .new_update_authority(new_update_authority.pubkey())
+        .instruction();
+    process(&mut context, ix, &[]).await.unwrap();
+
+    let ix = AcceptGroupUpdateAuthorityV1Builder::new()
+        .group(group.pubkey())
+        .group_authority_proposal(proposal)
+        .payer(context.payer.pubkey())
+        .new_update_authority(new_update_authority.pubkey())
+        .proposal_payer(context.payer.pubkey())
+        .instruction();
+    process(&mut context, ix, &[&new_update_authority])
+        .await
+        .unwrap();
+
+    let ix = AddAssetsToGroupV1Builder::new()
+        .group(group.pubkey())
+        .payer(context.payer.pubkey())
+        .authority(Some(delegate.pubkey()))
+        .add_remaining_accounts(&[
+            AccountMeta::new_readonly(group_delegate, false),
+            AccountMeta::new(asset.pubkey(), false),
+        ])
+        .instruction();
+    let error = process(&mut context, ix, &[&delegate]).await.unwrap_err();
+
+    assert_custom_instruction_error!(0, error, MplCoreError::InvalidAuthority);
+}
@@ -0,0 +1,452 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    accounts::GroupV1,
+    errors::MplCoreError,
+    instructions::{
+        AddAssetsToGroupV1Builder, AddCollectionsToGroupV1Builder, AddGroupPluginV1Builder,
+        BurnV1Builder, CreateGroupV1Builder, RemoveAssetsFromGroupV1Builder,
+        RemoveGroupPluginV1Builder, TransferBatchV1Builder, TransferV1Builder,
+        UpdateGroupPluginV1Builder,
+    },
+    types::{
+        GroupPluginInitInfo, GroupPluginKey, GroupPluginUpdateInfo, Key, PermanentFreezeDelegate,
+        PermanentTransferDelegate, Plugin, PluginAuthorityPair, PluginType,
+    },
+    Asset, Collection,
+};
+pub use setup::*;
+
+use solana_program::instruction::{AccountMeta, Instruction};
+use solana_program_test::{tokio, BanksClientError, ProgramTestContext};
+use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer, transaction::Transaction};
+
+async fn process(
+    context: &mut ProgramTestContext,
+    ix: Instruction,
+) -> Result<(), BanksClientError> {
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await
+}
+
+/// Create a group with no relationships.
+async fn create_group(context: &mut ProgramTestContext, group: &Keypair) {
+    let ix = CreateGroupV1Builder::new()
+        .group(group.pubkey())
+        .payer(context.payer.pubkey())
+        .name("Test Group".to_owned())
+        .uri("https://example.com/group".to_owned())
+        .relationships(vec![])
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, group],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
+
+/// Create a frozen group holding a collection with one asset, owned by the payer unless
+/// `owner` is given.
+async fn create_frozen_group(
+    context: &mut ProgramTestContext,
+    group: &Keypair,
+    collection: &Keypair,
+    asset: &Keypair,
+    owner: Option<Pubkey>,
+    collection_plugins: Vec<PluginAuthorityPair>,
+) {
+    create_group(context, group).await;
+
+    create_collection(
+        context,
+        CreateCollectionHelperArgs {
+            collection,
+            update_authority: None,
+            payer: None,
+            name: None,
+            uri: None,
+            plugins: collection_plugins,
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    create_asset(
+        context,
+        CreateAssetHelperArgs {
+            owner,
+            payer: None,
+            asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: Some(collection.pubkey()),
+            plugins: vec![],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let ix = AddCollectionsToGroupV1Builder::new()
+        .group(group.pubkey())
+        .payer(context.payer.pubkey())
+        .add_remaining_account(AccountMeta::new(collection.pubkey(), false))
+        .instruction();
+    process(context, ix).await.unwrap();
+
+    let ix = AddGroupPluginV1Builder::new()
+        .group(group.pubkey())
+        .payer(context.payer.pubkey())
+        .init_info(GroupPluginInitInfo::Plugin {
+            plugin: Plugin::PermanentFreezeDelegate(PermanentFreezeDelegate { frozen: true }),
+            init_authority: None,
+        })
+        .instruction();
+    process(context, ix).await.unwrap();
+}
+
+/// Build a transfer of the asset passing the groups listed by its collection.
+async fn transfer_ix(
+    context: &mut ProgramTestContext,
+    collection: &Keypair,
+    asset: &Keypair,
+) -> Instruction {
+    let account = context
+        .banks_client
+        .get_account(collection.pubkey())
+        .await
+        .expect("get_account")
+        .expect("collection account not found");
+    let collection_data = Collection::from_bytes(&account.data).unwrap();
+    let groups = collection_data.plugin_list.groups.unwrap().groups;
+
+    TransferV1Builder::new()
+        .asset(asset.pubkey())
+        .collection(Some(collection.pubkey()))
+        .payer(context.payer.pubkey())
+        .new_owner(Keypair::new().pubkey())
+        .add_remaining_accounts(&groups.transfer_accounts())
+        .instruction()
+}
+
+#[tokio::test]
+async fn cannot_transfer_asset_in_frozen_group() {
+    let mut context = program_test().start_with_context().await;
+
+    let group = Keypair::new();
+    let collection = Keypair::new();
+    let asset = Keypair::new();
+    create_frozen_group(&mut context, &group, &collection, &asset, None, vec![]).await;
+
+    let ix = transfer_ix(&mut context, &collection, &asset).await;
+    let error = process(&mut context, ix).await.unwrap_err();
+    assert_custom_instruction_error!(0, error, MplCoreError::GroupFrozen);
+
+    // A frozen group keeps its freeze until it is thawed.
+    let ix = RemoveGroupPluginV1Builder::new()
+        .group(group.pubkey())
+        .payer(context.payer.pubkey())
+        .key(GroupPluginKey::Plugin(PluginType::PermanentFreezeDelegate))
+        .instruction();
+    let error = process(&mut context, ix).await.unwrap_err();
+    assert_custom_instruction_error!(0, error, MplCoreError::GroupFrozen);
+
+    let ix = UpdateGroupPluginV1Builder::new()
+        .group(group.pubkey())
+        .payer(context.payer.pubkey())
+        .update_info(GroupPluginUpdateInfo::Plugin(
+            Plugin::PermanentFreezeDelegate(PermanentFreezeDelegate { frozen: false }),
+        ))
+        .instruction();
+    process(&mut context, ix).await.unwrap();
+
+    let ix = transfer_ix(&mut context, &collection, &asset).await;
+    process(&mut context, ix).await.unwrap();
+}
+
+#[tokio::test]
+async fn permanent_transfer_delegate_cannot_transfer_asset_in_frozen_group() {
+    let mut context = program_test().start_with_context().await;
+
+    let group = Keypair::new();
+    let collection = Keypair::new();
+    let asset = Keypair::new();
+    create_frozen_group(
+        &mut context,
+        &group,
+        &collection,
+        &asset,
+        Some(Keypair::new().pubkey()),
+        vec![PluginAuthorityPair {
+            plugin: Plugin::PermanentTransferDelegate(PermanentTransferDelegate {}),
+            authority: None,
+        }],
+    )
+    .await;
+
+    // The payer is the permanent transfer delegate of the collection, but the group freeze
+    // still rejects the transfer.
+    let ix = transfer_ix(&mut context, &collection, &asset).await;
+    let error = process(&mut context, ix).await.unwrap_err();
+    assert_custom_instruction_error!(0, error, MplCoreError::GroupFrozen);
+}
+
+#[tokio::test]
+async fn cannot_transfer_group_member_in_frozen_collection_group() {
+    let mut context = program_test().start_with_context().await;
+
+    let group = Keypair::new();
+    let collection = Keypair::new();
+    let asset = Keypair::new();
+    create_frozen_group(&mut context, &group, &collection, &asset, None, vec![]).await;
+
+    // The asset is also a direct member of a group that is not frozen.
+    let asset_group = Keypair::new();
+    create_group(&mut context, &asset_group).await;
+    let ix = AddAssetsToGroupV1Builder::new()
+        .group(asset_group.pubkey())
+        .payer(context.payer.pubkey())
+        .add_remaining_accounts(&[
+            AccountMeta::new(asset.pubkey(), false),
+            AccountMeta::new_readonly(collection.pubkey(), false),
+        ])
+        .instruction();
+    process(&mut context, ix).await.unwrap();
+
+    let account = context
+        .banks_client
+        .get_account(asset.pubkey())
+        .await
+        .expect("get_account")
+        .expect("asset account not found");
+    let asset_data = Asset::from_bytes(&account.data).unwrap();
+    let asset_groups = asset_data.plugin_list.groups.unwrap().groups;
+
+    // The groups of the collection are checked next to the groups of the asset.
+    let mut ix = transfer_ix(&mut context, &collection, &asset).await;
+    ix.accounts.extend(asset_groups.transfer_accounts());
+    let error = process(&mut context, ix).await.unwrap_err();
+    assert_custom_instruction_error!(0, error, MplCoreError::GroupFrozen);
+}
+
+#[tokio::test]
+async fn owner_can_burn_asset_in_frozen_group() {
+    let mut context = program_test().start_with_context().await;
+
+    let group = Keypair::new();
+    let collection = Keypair::new();
+    let asset = Keypair::new();
+    create_frozen_group(&mut context, &group, &collection, &asset, None, vec![]).await;
+
+    let ix = BurnV1Builder::new()
+        .asset(asset.pubkey())
+        .collection(Some(collection.pubkey()))
+        .payer(context.payer.pubkey())
+        .instruction();
+    process(&mut context, ix).await.unwrap();
+
+    let account = context
+        .banks_client
+        .get_account(asset.pubkey())
+        .await
+        .expect("get_account")
+        .expect("asset account not found");
+    assert_eq!(account.data, vec![Key::Uninitialized as u8]);
+}
+
+#[tokio::test]
+async fn cannot_transfer_group_member_without_group_accounts() {
+    let mut context = program_test().start_with_context().await;
+
+    let group = Keypair::new();
+    create_group(&mut context, &group).await;
+
+    let asset = Keypair::new();
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let ix = AddAssetsToGroupV1Builder::new()
+        .group(group.pubkey())
+        .payer(context.payer.pubkey())
+        .add_remaining_account(AccountMeta::new(asset.pubkey(), false))
+        .instruction();
+    process(&mut context, ix).await.unwrap();
+
+    // Every group of the asset must be passed to the transfer.
+    let new_owner = Keypair::new().pubkey();
+    let ix = TransferV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .new_owner(new_owner)
+        .instruction();
+    let error = process(&mut context, ix).await.unwrap_err();
+    assert_custom_instruction_error!(0, error, MplCoreError::MissingGroupAccount);
+
+    let ix = TransferV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .new_owner(new_owner)
+        .add_remaining_account(AccountMeta::new_readonly(group.pubkey(), false))
+        .instruction();
+    process(&mut context, ix).await.unwrap();
+
+    let account = context
+        .banks_client
+        .get_account(asset.pubkey())
+        .await
+        .expect("get_account")
+        .expect("asset account not found");
+    let asset_data = Asset::from_bytes(&account.data).unwrap();
+    assert_eq!(asset_data.base.owner, new_owner);
+}
+
+#[tokio::test]
+async fn can_batch_transfer_group_member_with_group_accounts() {
+    let mut context = program_test().start_with_context().await;
+
+    let group = Keypair::new();
+    create_group(&mut context, &group).await;
+
+    let asset = Keypair::new();
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let ix = AddAssetsToGroupV1Builder::new()
+        .group(group.pubkey())
+        .payer(context.payer.pubkey())
+        .add_remaining_account(AccountMeta::new(asset.pubkey(), false))
+        .instruction();
+    process(&mut context, ix).await.unwrap();
+
+    let new_owner = Keypair::new().pubkey();
+    let asset_pair = [
+        AccountMeta::new(asset.pubkey(), false),
+        AccountMeta::new_readonly(mpl_core::ID, false),
+    ];
+    let ix = TransferBatchV1Builder::new()
+        .payer(context.payer.pubkey())
+        .new_owner(new_owner)
+        .num_assets(1)
+        .add_remaining_accounts(&asset_pair)
+        .instruction();
+    let error = process(&mut context, ix).await.unwrap_err();
+    assert_custom_instruction_error!(0, error, MplCoreError::MissingGroupAccount);
+
+    // The groups follow the (asset, collection) pairs.
+    let ix = TransferBatchV1Builder::new()
+        .payer(context.payer.pubkey())
+        .new_owner(new_owner)
+        .num_assets(1)
+        .add_remaining_accounts(&asset_pair)
+        .add_remaining_account(AccountMeta::new_readonly(group.pubkey(), false))
+        .instruction();
+    process(&mut context, ix).await.unwrap();
+
+    let account = context
+        .banks_client
+        .get_account(asset.pubkey())
+        .await
+        .expect("get_account")
+        .expect("asset account not found");
+    let asset_data = Asset::from_bytes(&account.data).unwrap();
+    assert_eq!(asset_data.base.owner, new_owner);
+}
+
+#[tokio::test]
+async fn owner_can_burn_group_member_in_frozen_group() {
+    let mut context = program_test().start_with_context().await;
+
+    let group = Keypair::new();
+    let collection = Keypair::new();
+    let asset = Keypair::new();
+    create_frozen_group(&mut context, &group, &collection, &asset, None, vec![]).await;
+
+    let ix = AddAssetsToGroupV1Builder::new()
+        .group(group.pubkey())
+        .payer(context.payer.pubkey())
+        .add_remaining_accounts(&[
+            AccountMeta::new(asset.pubkey(), false),
+            AccountMeta::new_readonly(collection.pubkey(), false),
+        ])
+        .instruction();
+    process(&mut context, ix).await.unwrap();
+
+    // The owner burns the asset even though it is a direct member of the frozen group.
+    let ix = BurnV1Builder::new()
+        .asset(asset.pubkey())
+        .collection(Some(collection.pubkey()))
+        .payer(context.payer.pubkey())
+        .instruction();
+    process(&mut context, ix).await.unwrap();
+
+    let account = context
+        .banks_client
+        .get_account(asset.pubkey())
+        .await
+        .expect("get_account")
+        .expect("asset account not found");
+    assert_eq!(account.data, vec![Key::Uninitialized as u8]);
+
+    // The group authority then detaches the burned asset.
+    let ix = RemoveAssetsFromGroupV1Builder::new()
+        .group(group.pubkey())
+        .payer(context.payer.pubkey())
+        .assets(vec![asset.pubkey()])
+        .add_remaining_account(AccountMeta::new(asset.pubkey(), false))
+        .instruction();
+    process(&mut context, ix).await.unwrap();
+
+    let account = context
+        .banks_client
+        .get_account(group.pubkey())
+        .await
+        .expect("get_account")
+        .expect("group account not found");
+    let group_data = GroupV1::from_bytes(&account.data).unwrap();
+    assert!(group_data.assets.is_empty());
+}
@@ -0,0 +1,314 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    accounts::{GroupMembershipV1, GroupV1},
+    errors::MplCoreError,
+    instructions::{
+        AddAssetsToGroupV1Builder, CreateGroupV1Builder, RemoveAssetsFromGroupV1Builder,
+    },
+    types::Key,
+    Asset,
+};
+pub use setup::*;
+
+use solana_program::instruction::AccountMeta;
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer, transaction::Transaction};
+
+async fn create_group_with_asset(
+    context: &mut ProgramTestContext,
+    group: &Keypair,
+    asset: &Keypair,
+) {
+    let ix = CreateGroupV1Builder::new()
+        .group(group.pubkey())
+        .payer(context.payer.pubkey())
+        .name("Test Group".to_owned())
+        .uri("https://example.com/group".to_owned())
+        .relationships(vec![])
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, group],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    create_asset(
+        context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+}
+
+async fn group_of(context: &mut ProgramTestContext, group: Pubkey) -> GroupV1 {
+    let account = context
+        .banks_client
+        .get_account(group)
+        .await
+        .expect("get_account")
+        .expect("group account not found");
+    GroupV1::from_bytes(&account.data).unwrap()
+}
+
+#[tokio::test]
+async fn add_and_remove_asset_with_membership_account() {
+    let mut context = program_test().start_with_context().await;
+
+    let group = Keypair::new();
+    let asset = Keypair::new();
+    create_group_with_asset(&mut context, &group, &asset).await;
+
+    let (membership, _) = GroupMembershipV1::find_pda(&group.pubkey(), &asset.pubkey());
+
+    let ix = AddAssetsToGroupV1Builder::new()
+        .group(group.pubkey())
+        .payer(context.payer.pubkey())
+        .add_remaining_accounts(&[
+            AccountMeta::new(asset.pubkey(), false),
+            AccountMeta::new(membership, false),
+        ])
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // The membership is stored in its own account instead of the group.
+    let membership_account = context
+        .banks_client
+        .get_account(membership)
+        .await
+        .expect("get_account")
+        .expect("membership account not found");
+    let membership_data = GroupMembershipV1::from_bytes(&membership_account.data).unwrap();
+    assert_eq!(
+        membership_data,
+        GroupMembershipV1 {
+            key: Key::GroupMembershipV1,
+            group: group.pubkey(),
+            member: asset.pubkey(),
+        }
+    );
+
+    let group_data = group_of(&mut context, group.pubkey()).await;
+    assert!(group_data.assets.is_empty());
+    assert_eq!(group_data.membership_count, 1);
+    assert_eq!(
+        group_data.all_assets(&group.pubkey(), &[membership_data]),
+        vec![asset.pubkey()]
+    );
+
+    let asset_account = context
+        .banks_client
+        .get_account(asset.pubkey())
+        .await
+        .expect("get_account")
+        .expect("asset account not found");
+    let asset_data = Asset::from_bytes(&asset_account.data).unwrap();
+    assert_eq!(
+        asset_data.plugin_list.groups.unwrap().groups.groups,
+        vec![group.pubkey()]
+    );
+
+    let ix = RemoveAssetsFromGroupV1Builder::new()
+        .group(group.pubkey())
+        .payer(context.payer.pubkey())
+        .assets(vec![asset.pubkey()])
+        .add_remaining_accounts(&[
+            AccountMeta::new(asset.pubkey(), false),
+            AccountMeta::new(membership, false),
+        ])
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let membership_account = context
+        .banks_client
+        .get_account(membership)
+        .await
+        .expect("get_account")
+        .expect("membership account not found");
+    assert_eq!(membership_account.data, vec![Key::Uninitialized as u8]);
+
+    let group_data = group_of(&mut context, group.pubkey()).await;
+    assert_eq!(group_data.membership_count, 0);
+}
+
+#[tokio::test]
+async fn cannot_add_asset_with_incorrect_membership_account() {
+    let mut context = program_test().start_with_context().await;
+
+    let group = Keypair::new();
+    let asset = Keypair::new();
+    create_group_with_asset(&mut context, &group, &asset).await;
+
+    // The membership account of a different asset.
+    let (membership, _) = GroupMembershipV1::find_pda(&group.pubkey(), &Pubkey::new_unique());
+
+    let ix = AddAssetsToGroupV1Builder::new()
+        .group(group.pubkey())
+        .payer(context.payer.pubkey())
+        .add_remaining_accounts(&[
+            AccountMeta::new(asset.pubkey(), false),
+            AccountMeta::new(membership, false),
+        ])
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_custom_instruction_error!(0, error, MplCoreError::InvalidGroupMembership);
+}
+
+#[tokio::test]
+async fn cannot_remove_asset_twice_with_one_membership_account() {
+    let mut context = program_test().start_with_context().await;
+
+    let group = Keypair::new();
+    let asset = Keypair::new();
+    create_group_with_asset(&mut context, &group, &asset).await;
+
+    let (membership, _) = GroupMembershipV1::find_pda(&group.pubkey(), &asset.pubkey());
+
+    let ix = AddAssetsToGroupV1Builder::new()
+        .group(group.pubkey())
+        .payer(context.payer.pubkey())
+        .add_remaining_accounts(&[
+            AccountMeta::new(asset.pubkey(), false),
+            AccountMeta::new(membership, false),
+        ])
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // The membership account is closed for the first asset, so the repeated asset is rejected
+    // instead of uncounting the membership twice.
+    let ix = RemoveAssetsFromGroupV1Builder::new()
+        .group(group.pubkey())
+        .payer(context.payer.pubkey())
+        .assets(vec![asset.pubkey(), asset.pubkey()])
+        .add_remaining_accounts(&[
+            AccountMeta::new(asset.pubkey(), false),
+            AccountMeta::new(asset.pubkey(), false),
+            AccountMeta::new(membership, false),
+        ])
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_custom_instruction_error!(0, error, MplCoreError::IncorrectAccount);
+
+    let group_data = group_of(&mut context, group.pubkey()).await;
+    assert_eq!(group_data.membership_count, 1);
+}
+
+#[tokio::test]
+async fn fetch_group_stored_before_membership_count() {
+    let mut context = program_test().start_with_context().await;
+
+    let group = Keypair::new();
+    let asset = Keypair::new();
+    create_group_with_asset(&mut context, &group, &asset).await;
+
+    // Groups stored before the count was added end after the assets.
+    let mut account = context
+        .banks_client
+        .get_account(group.pubkey())
+        .await
+        .expect("get_account")
+        .expect("group account not found");
+    account.data.truncate(account.data.len() - 4);
+    context.set_account(&group.pubkey(), &account.into());
+
+    let group_data = group_of(&mut context, group.pubkey()).await;
+    assert_eq!(group_data.name, "Test Group");
+    assert_eq!(group_data.membership_count, 0);
+
+    let (membership, _) = GroupMembershipV1::find_pda(&group.pubkey(), &asset.pubkey());
+    let ix = AddAssetsToGroupV1Builder::new()
+        .group(group.pubkey())
+        .payer(context.payer.pubkey())
+        .add_remaining_accounts(&[
+            AccountMeta::new(asset.pubkey(), false),
+            AccountMeta::new(membership, false),
+        ])
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // The count is stored once the group is saved again.
+    let account = context
+        .banks_client
+        .get_account(group.pubkey())
+        .await
+        .expect("get_account")
+        .expect("group account not found");
+    assert_eq!(
+        GroupV1::from_bytes(&account.data).unwrap().membership_count,
+        1
+    );
+}
@@ -0,0 +1,166 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    errors::MplCoreError,
+    instructions::{
+        AddGroupPluginV1Builder, CreateGroupV1Builder, RemoveGroupPluginV1Builder,
+        UpdateGroupPluginV1Builder, UpdateGroupV1Builder,
+    },
+    types::{
+        AppDataInitInfo, Attribute, Attributes, ExternalPluginAdapterInitInfo,
+        ExternalPluginAdapterSchema, ExternalPluginAdapterType, FreezeDelegate,
+        GroupPluginInitInfo, GroupPluginKey, GroupPluginUpdateInfo, Key, Plugin, PluginAuthority,
+        PluginType,
+    },
+    IndexableAsset,
+};
+pub use setup::*;
+
+use solana_program::instruction::Instruction;
+use solana_program_test::{tokio, BanksClientError, ProgramTestContext};
+use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer, transaction::Transaction};
+
+async fn create_group(context: &mut ProgramTestContext, group: &Keypair) {
+    let ix = CreateGroupV1Builder::new()
+        .group(group.pubkey())
+        .payer(context.payer.pubkey())
+        .name("Test Group".to_owned())
+        .uri("https://example.com/group".to_owned())
+        .relationships(vec![])
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, group],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
+
+async fn process(
+    context: &mut ProgramTestContext,
+    ix: Instruction,
+) -> Result<(), BanksClientError> {
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await
+}
+
+async fn indexable_group(context: &mut ProgramTestContext, group: Pubkey) -> IndexableAsset {
+    let account = context
+        .banks_client
+        .get_account(group)
+        .await
+        .expect("get_account")
+        .expect("group account not found");
+    IndexableAsset::fetch(Key::GroupV1, &account.data).unwrap()
+}
+
+fn attributes(value: &str) -> Plugin {
+    Plugin::Attributes(Attributes {
+        attribute_list: vec![Attribute {
+            key: "brand".to_owned(),
+            value: value.to_owned(),
+        }],
+    })
+}
+
+#[tokio::test]
+async fn add_update_and_remove_group_plugins() {
+    let mut context = program_test().start_with_context().await;
+
+    let group = Keypair::new();
+    create_group(&mut context, &group).await;
+
+    let ix = AddGroupPluginV1Builder::new()
+        .group(group.pubkey())
+        .payer(context.payer.pubkey())
+        .init_info(GroupPluginInitInfo::Plugin {
+            plugin: attributes("first"),
+            init_authority: None,
+        })
+        .instruction();
+    process(&mut context, ix).await.unwrap();
+
+    let ix = AddGroupPluginV1Builder::new()
+        .group(group.pubkey())
+        .payer(context.payer.pubkey())
+        .init_info(GroupPluginInitInfo::ExternalPluginAdapter(
+            ExternalPluginAdapterInitInfo::AppData(AppDataInitInfo {
+                init_plugin_authority: None,
+                data_authority: PluginAuthority::UpdateAuthority,
+                schema: Some(ExternalPluginAdapterSchema::Json),
+            }),
+        ))
+        .instruction();
+    process(&mut context, ix).await.unwrap();
+
+    // Growing the group itself must move its plugins along with it.
+    let ix = UpdateGroupV1Builder::new()
+        .group(group.pubkey())
+        .payer(context.payer.pubkey())
+        .new_name("A group with a much longer name than before".to_owned())
+        .instruction();
+    process(&mut context, ix).await.unwrap();
+
+    let ix = UpdateGroupPluginV1Builder::new()
+        .group(group.pubkey())
+        .payer(context.payer.pubkey())
+        .update_info(GroupPluginUpdateInfo::Plugin(attributes("second")))
+        .instruction();
+    process(&mut context, ix).await.unwrap();
+
+    let indexable = indexable_group(&mut context, group.pubkey()).await;
+    assert_eq!(
+        indexable.name,
+        "A group with a much longer name than before"
+    );
+    assert_eq!(
+        indexable.plugins.get(&PluginType::Attributes).unwrap().data,
+        attributes("second")
+    );
+    assert_eq!(indexable.external_plugins.len(), 1);
+    assert_eq!(
+        indexable.external_plugins[0].r#type,
+        ExternalPluginAdapterType::AppData
+    );
+
+    let ix = RemoveGroupPluginV1Builder::new()
+        .group(group.pubkey())
+        .payer(context.payer.pubkey())
+        .key(GroupPluginKey::Plugin(PluginType::Attributes))
+        .instruction();
+    process(&mut context, ix).await.unwrap();
+
+    let indexable = indexable_group(&mut context, group.pubkey()).await;
+    assert!(indexable.plugins.is_empty());
+    assert_eq!(indexable.external_plugins.len(), 1);
+}
+
+#[tokio::test]
+async fn cannot_add_unsupported_plugin_to_group() {
+    let mut context = program_test().start_with_context().await;
+
+    let group = Keypair::new();
+    create_group(&mut context, &group).await;
+
+    let ix = AddGroupPluginV1Builder::new()
+        .group(group.pubkey())
+        .payer(context.payer.pubkey())
+        .init_info(GroupPluginInitInfo::Plugin {
+            plugin: Plugin::FreezeDelegate(FreezeDelegate { frozen: true }),
+            init_authority: None,
+        })
+        .instruction();
+
+    let error = process(&mut context, ix).await.unwrap_err();
+
+    assert_custom_instruction_error!(0, error, MplCoreError::InvalidPlugin);
+}
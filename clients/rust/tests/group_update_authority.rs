@@ -0,0 +1,264 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    accounts::{GroupAuthorityProposalV1, GroupV1},
+    errors::MplCoreError,
+    instructions::{
+        AcceptGroupUpdateAuthorityV1Builder, CreateGroupV1Builder,
+        ProposeGroupUpdateAuthorityV1Builder, UpdateGroupV1Builder,
+    },
+    types::Key,
+};
+pub use setup::*;
+
+use solana_program::instruction::Instruction;
+use solana_program_test::{tokio, BanksClientError, ProgramTestContext};
+use solana_sdk::{
+    native_token::LAMPORTS_PER_SOL, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    transaction::Transaction,
+};
+
+async fn create_group(context: &mut ProgramTestContext, group: &Keypair) {
+    let ix = CreateGroupV1Builder::new()
+        .group(group.pubkey())
+        .payer(context.payer.pubkey())
+        .name("Test Group".to_owned())
+        .uri("https://example.com/group".to_owned())
+        .relationships(vec![])
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, group],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
+
+async fn process(
+    context: &mut ProgramTestContext,
+    ix: Instruction,
+    signers: &[&Keypair],
+) -> Result<(), BanksClientError> {
+    let mut all_signers = vec![&context.payer];
+    all_signers.extend_from_slice(signers);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &all_signers,
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await
+}
+
+async fn propose(context: &mut ProgramTestContext, group: Pubkey, new_update_authority: Pubkey) {
+    let (proposal, _) = GroupAuthorityProposalV1::find_pda(&group);
+
+    let ix = ProposeGroupUpdateAuthorityV1Builder::new()
+        .group(group)
+        .group_authority_proposal(proposal)
+        .payer(context.payer.pubkey())
+        .new_update_authority(new_update_authority)
+        .instruction();
+    process(context, ix, &[]).await.unwrap();
+}
+
+fn accept(
+    group: Pubkey,
+    payer: Pubkey,
+    new_update_authority: Pubkey,
+    proposal_payer: Pubkey,
+) -> Instruction {
+    let (proposal, _) = GroupAuthorityProposalV1::find_pda(&group);
+
+    AcceptGroupUpdateAuthorityV1Builder::new()
+        .group(group)
+        .group_authority_proposal(proposal)
+        .payer(payer)
+        .new_update_authority(new_update_authority)
+        .proposal_payer(proposal_payer)
+        .instruction()
+}
+
+#[tokio::test]
+async fn propose_and_accept_group_update_authority() {
+    let mut context = program_test().start_with_context().await;
+
+    let group = Keypair::new();
+    create_group(&mut context, &group).await;
+
+    let new_update_authority = Keypair::new();
+    propose(&mut context, group.pubkey(), new_update_authority.pubkey()).await;
+
+    let (proposal, _) = GroupAuthorityProposalV1::find_pda(&group.pubkey());
+    let proposal_account = context
+        .banks_client
+        .get_account(proposal)
+        .await
+        .expect("get_account")
+        .expect("proposal account not found");
+    assert_eq!(
+        GroupAuthorityProposalV1::from_bytes(&proposal_account.data).unwrap(),
+        GroupAuthorityProposalV1 {
+            key: Key::GroupAuthorityProposalV1,
+            group: group.pubkey(),
+            update_authority: context.payer.pubkey(),
+            new_update_authority: new_update_authority.pubkey(),
+            payer: context.payer.pubkey(),
+        }
+    );
+
+    let ix = accept(
+        group.pubkey(),
+        context.payer.pubkey(),
+        new_update_authority.pubkey(),
+        context.payer.pubkey(),
+    );
+    process(&mut context, ix, &[&new_update_authority])
+        .await
+        .unwrap();
+
+    let group_account = context
+        .banks_client
+        .get_account(group.pubkey())
+        .await
+        .expect("get_account")
+        .expect("group account not found");
+    let group_data = GroupV1::from_bytes(&group_account.data).unwrap();
+    assert_eq!(group_data.update_authority, new_update_authority.pubkey());
+
+    let proposal_account = context
+        .banks_client
+        .get_account(proposal)
+        .await
+        .expect("get_account")
+        .expect("proposal account not found");
+    assert_eq!(proposal_account.data, vec![Key::Uninitialized as u8]);
+}
+
+#[tokio::test]
+async fn cannot_accept_replaced_group_update_authority_proposal() {
+    let mut context = program_test().start_with_context().await;
+
+    let group = Keypair::new();
+    create_group(&mut context, &group).await;
+
+    let new_update_authority = Keypair::new();
+    propose(&mut context, group.pubkey(), new_update_authority.pubkey()).await;
+
+    // A new proposal replaces the pending one.
+    let other_update_authority = Keypair::new();
+    propose(
+        &mut context,
+        group.pubkey(),
+        other_update_authority.pubkey(),
+    )
+    .await;
+
+    let ix = accept(
+        group.pubkey(),
+        context.payer.pubkey(),
+        new_update_authority.pubkey(),
+        context.payer.pubkey(),
+    );
+    let error = process(&mut context, ix, &[&new_update_authority])
+        .await
+        .unwrap_err();
+
+    assert_custom_instruction_error!(0, error, MplCoreError::InvalidAuthority);
+}
+
+#[tokio::test]
+async fn refunds_group_update_authority_proposal_to_its_payer() {
+    let mut context = program_test().start_with_context().await;
+
+    let group = Keypair::new();
+    create_group(&mut context, &group).await;
+
+    // The proposal is paid for by an account other than the update authority.
+    let proposal_payer = Keypair::new();
+    airdrop(&mut context, &proposal_payer.pubkey(), LAMPORTS_PER_SOL)
+        .await
+        .unwrap();
+
+    let new_update_authority = Keypair::new();
+    let (proposal, _) = GroupAuthorityProposalV1::find_pda(&group.pubkey());
+    let ix = ProposeGroupUpdateAuthorityV1Builder::new()
+        .group(group.pubkey())
+        .group_authority_proposal(proposal)
+        .payer(proposal_payer.pubkey())
+        .authority(Some(context.payer.pubkey()))
+        .new_update_authority(new_update_authority.pubkey())
+        .instruction();
+    process(&mut context, ix, &[&proposal_payer]).await.unwrap();
+
+    // The proposal payer must be the account that paid for the proposal.
+    let ix = accept(
+        group.pubkey(),
+        context.payer.pubkey(),
+        new_update_authority.pubkey(),
+        context.payer.pubkey(),
+    );
+    let error = process(&mut context, ix, &[&new_update_authority])
+        .await
+        .unwrap_err();
+    assert_custom_instruction_error!(0, error, MplCoreError::InvalidGroupAuthorityProposal);
+
+    let proposal_lamports = context.banks_client.get_balance(proposal).await.unwrap();
+    let balance_before = context
+        .banks_client
+        .get_balance(proposal_payer.pubkey())
+        .await
+        .unwrap();
+
+    // The accept is paid for by the update authority, but the rent goes to the proposal payer.
+    let ix = accept(
+        group.pubkey(),
+        context.payer.pubkey(),
+        new_update_authority.pubkey(),
+        proposal_payer.pubkey(),
+    );
+    process(&mut context, ix, &[&new_update_authority])
+        .await
+        .unwrap();
+
+    let balance_after = context
+        .banks_client
+        .get_balance(proposal_payer.pubkey())
+        .await
+        .unwrap();
+    let reclaimed = proposal_lamports - context.banks_client.get_balance(proposal).await.unwrap();
+    assert!(reclaimed > 0);
+    assert_eq!(balance_after, balance_before + reclaimed);
+}
+
+#[tokio::test]
+async fn can_change_group_update_authority_with_update_group() {
+    let mut context = program_test().start_with_context().await;
+
+    let group = Keypair::new();
+    create_group(&mut context, &group).await;
+
+    let new_update_authority = Keypair::new();
+    let ix = UpdateGroupV1Builder::new()
+        .group(group.pubkey())
+        .payer(context.payer.pubkey())
+        .new_update_authority(Some(new_update_authority.pubkey()))
+        .new_name("Renamed Group".to_owned())
+        .instruction();
+    process(&mut context, ix, &[]).await.unwrap();
+
+    let account = context
+        .banks_client
+        .get_account(group.pubkey())
+        .await
+        .expect("get_account")
+        .expect("group account not found");
+    let group_data = GroupV1::from_bytes(&account.data).unwrap();
+    assert_eq!(group_data.name, "Renamed Group");
+    assert_eq!(group_data.update_authority, new_update_authority.pubkey());
+}
@@ -0,0 +1,257 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    errors::MplCoreError,
+    instructions::MoveToCollectionV1Builder,
+    types::{
+        AddBlocker, Attribute, Attributes, BubblegumV2, PermanentFreezeDelegate, Plugin,
+        PluginAuthorityPair, TimeLock, UpdateAuthority,
+    },
+    Asset, Collection,
+};
+pub use setup::*;
+
+use solana_program::pubkey::Pubkey;
+use solana_program_test::{tokio, BanksClientError, ProgramTestContext};
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+async fn create_collection_with(
+    context: &mut ProgramTestContext,
+    collection: &Keypair,
+    update_authority: Option<Pubkey>,
+    plugins: Vec<PluginAuthorityPair>,
+) {
+    create_collection(
+        context,
+        CreateCollectionHelperArgs {
+            collection,
+            update_authority,
+            payer: None,
+            name: None,
+            uri: None,
+            plugins,
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+}
+
+async fn create_asset_in(
+    context: &mut ProgramTestContext,
+    asset: &Keypair,
+    collection: &Keypair,
+    plugins: Vec<PluginAuthorityPair>,
+) {
+    create_asset(
+        context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: Some(collection.pubkey()),
+            plugins,
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+}
+
+async fn fetch_collection(context: &mut ProgramTestContext, collection: Pubkey) -> Box<Collection> {
+    let account = context
+        .banks_client
+        .get_account(collection)
+        .await
+        .expect("get_account")
+        .expect("collection account not found");
+    Collection::from_bytes(&account.data).unwrap()
+}
+
+#[tokio::test]
+async fn move_asset_to_collection_with_another_authority() {
+    let mut context = program_test().start_with_context().await;
+
+    let collection = Keypair::new();
+    let new_collection = Keypair::new();
+    let new_collection_authority = Keypair::new();
+    let asset = Keypair::new();
+    create_collection_with(&mut context, &collection, None, vec![]).await;
+    create_collection_with(
+        &mut context,
+        &new_collection,
+        Some(new_collection_authority.pubkey()),
+        vec![],
+    )
+    .await;
+    create_asset_in(&mut context, &asset, &collection, vec![]).await;
+
+    // The authority of the new collection must sign.
+    let ix = MoveToCollectionV1Builder::new()
+        .asset(asset.pubkey())
+        .collection(collection.pubkey())
+        .new_collection(new_collection.pubkey())
+        .payer(context.payer.pubkey())
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+    assert_custom_instruction_error!(0, error, MplCoreError::InvalidAuthority);
+
+    let ix = MoveToCollectionV1Builder::new()
+        .asset(asset.pubkey())
+        .collection(collection.pubkey())
+        .new_collection(new_collection.pubkey())
+        .payer(context.payer.pubkey())
+        .new_collection_authority(Some(new_collection_authority.pubkey()))
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &new_collection_authority],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let account = context
+        .banks_client
+        .get_account(asset.pubkey())
+        .await
+        .expect("get_account")
+        .expect("asset account not found");
+    let asset_data = Asset::from_bytes(&account.data).unwrap();
+    assert_eq!(
+        asset_data.base.update_authority,
+        UpdateAuthority::Collection(new_collection.pubkey())
+    );
+
+    let collection_data = fetch_collection(&mut context, collection.pubkey()).await;
+    assert_eq!(collection_data.base.current_size, 0);
+    let new_collection_data = fetch_collection(&mut context, new_collection.pubkey()).await;
+    assert_eq!(new_collection_data.base.current_size, 1);
+}
+
+/// Move a new asset with the given plugins into a new collection with the given plugins,
+/// returning the error of the rejected move.
+async fn move_error(
+    context: &mut ProgramTestContext,
+    new_collection_plugins: Vec<PluginAuthorityPair>,
+    asset_plugins: Vec<PluginAuthorityPair>,
+) -> BanksClientError {
+    let collection = Keypair::new();
+    let new_collection = Keypair::new();
+    let asset = Keypair::new();
+    create_collection_with(context, &collection, None, vec![]).await;
+    create_collection_with(context, &new_collection, None, new_collection_plugins).await;
+    create_asset_in(context, &asset, &collection, asset_plugins).await;
+
+    let ix = MoveToCollectionV1Builder::new()
+        .asset(asset.pubkey())
+        .collection(collection.pubkey())
+        .new_collection(new_collection.pubkey())
+        .payer(context.payer.pubkey())
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err()
+}
+
+#[tokio::test]
+async fn cannot_move_asset_to_collection_with_permanent_freeze() {
+    let mut context = program_test().start_with_context().await;
+
+    let error = move_error(
+        &mut context,
+        vec![PluginAuthorityPair {
+            plugin: Plugin::PermanentFreezeDelegate(PermanentFreezeDelegate { frozen: false }),
+            authority: None,
+        }],
+        vec![],
+    )
+    .await;
+
+    assert_custom_instruction_error!(0, error, MplCoreError::PermanentDelegatesPreventMove);
+}
+
+#[tokio::test]
+async fn cannot_move_asset_to_bubblegum_collection() {
+    let mut context = program_test().start_with_context().await;
+
+    let error = move_error(
+        &mut context,
+        vec![PluginAuthorityPair {
+            plugin: Plugin::BubblegumV2(BubblegumV2 {}),
+            authority: None,
+        }],
+        vec![],
+    )
+    .await;
+
+    assert_custom_instruction_error!(0, error, MplCoreError::BlockedByBubblegumV2);
+}
+
+#[tokio::test]
+async fn cannot_move_asset_with_plugins_rejected_by_new_collection() {
+    let mut context = program_test().start_with_context().await;
+
+    // The add blocker of the new collection rejects the authority-managed attributes.
+    let error = move_error(
+        &mut context,
+        vec![PluginAuthorityPair {
+            plugin: Plugin::AddBlocker(AddBlocker {}),
+            authority: None,
+        }],
+        vec![PluginAuthorityPair {
+            plugin: Plugin::Attributes(Attributes {
+                attribute_list: vec![Attribute {
+                    key: "brand".to_owned(),
+                    value: "first".to_owned(),
+                }],
+            }),
+            authority: None,
+        }],
+    )
+    .await;
+
+    assert_custom_instruction_error!(0, error, MplCoreError::InvalidAuthority);
+}
+
+#[tokio::test]
+async fn cannot_move_time_locked_asset_before_unlock() {
+    let mut context = program_test().start_with_context().await;
+
+    let error = move_error(
+        &mut context,
+        vec![],
+        vec![PluginAuthorityPair {
+            plugin: Plugin::TimeLock(TimeLock {
+                unlock_at: i64::MAX,
+            }),
+            authority: None,
+        }],
+    )
+    .await;
+
+    assert_custom_instruction_error!(0, error, MplCoreError::InvalidAuthority);
+}
@@ -2,18 +2,21 @@
 pub mod setup;
 use mpl_core::{
     accounts::{BaseAssetV1, PluginHeaderV1},
-    fetch_plugin, fetch_plugins, list_plugins,
+    errors::MplCoreError,
+    fetch_plugin, fetch_plugins,
+    instructions::{ApprovePluginAuthorityV1Builder, RemovePluginV1Builder, UpdatePluginV1Builder},
+    list_plugins,
     types::{
         Creator, FreezeDelegate, Plugin, PluginAuthority, PluginAuthorityPair, PluginType,
-        RegistryRecord, Royalties, RuleSet, UpdateAuthority,
+        RegistryRecord, Royalties, RuleSet, TimeLock, UpdateAuthority,
     },
-    DataBlob,
+    Asset, DataBlob,
 };
 pub use setup::*;
 
-use solana_program::account_info::AccountInfo;
+use solana_program::{account_info::AccountInfo, clock::Clock, instruction::AccountMeta};
 use solana_program_test::tokio;
-use solana_sdk::{signature::Keypair, signer::Signer};
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
 use std::mem::size_of;
 
 #[tokio::test]
@@ -318,3 +321,202 @@ async fn test_list_plugins() {
         vec![PluginType::FreezeDelegate, PluginType::Royalties]
     )
 }
+
+#[tokio::test]
+async fn update_plugin_with_multisig_authority() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::FreezeDelegate(FreezeDelegate { frozen: false }),
+                authority: None,
+            }],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let signer_a = Keypair::new();
+    let signer_b = Keypair::new();
+    let approve_ix = ApprovePluginAuthorityV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .plugin_type(PluginType::FreezeDelegate)
+        .new_authority(PluginAuthority::Multisig {
+            signers: vec![signer_a.pubkey(), signer_b.pubkey()],
+            threshold: 2,
+        })
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[approve_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // A single signer does not meet the threshold.
+    let update_ix = UpdatePluginV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .authority(Some(signer_a.pubkey()))
+        .plugin(Plugin::FreezeDelegate(FreezeDelegate { frozen: true }))
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[update_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &signer_a],
+        context.last_blockhash,
+    );
+
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_custom_instruction_error!(0, error, MplCoreError::NoApprovals);
+
+    let update_ix = UpdatePluginV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .authority(Some(signer_a.pubkey()))
+        .plugin(Plugin::FreezeDelegate(FreezeDelegate { frozen: true }))
+        .add_remaining_account(AccountMeta::new_readonly(signer_b.pubkey(), true))
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[update_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &signer_a, &signer_b],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let asset_account = context
+        .banks_client
+        .get_account(asset.pubkey())
+        .await
+        .expect("get_account")
+        .expect("asset account not found");
+    let asset_data = Asset::from_bytes(&asset_account.data).unwrap();
+    assert!(
+        asset_data
+            .plugin_list
+            .freeze_delegate
+            .unwrap()
+            .freeze_delegate
+            .frozen
+    );
+}
+
+#[tokio::test]
+async fn cannot_update_or_remove_time_lock_before_unlock() {
+    let mut context = program_test().start_with_context().await;
+
+    let clock: Clock = context.banks_client.get_sysvar().await.unwrap();
+    let unlock_at = clock.unix_timestamp + 1_000;
+
+    let asset = Keypair::new();
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::TimeLock(TimeLock { unlock_at }),
+                authority: None,
+            }],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    // The authority of the lock cannot move the unlock time forward.
+    let update_ix = UpdatePluginV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .plugin(Plugin::TimeLock(TimeLock {
+            unlock_at: clock.unix_timestamp,
+        }))
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[update_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_custom_instruction_error!(0, error, MplCoreError::InvalidAuthority);
+
+    // Nor can it remove the lock.
+    let remove_ix = RemovePluginV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .plugin_type(PluginType::TimeLock)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[remove_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_custom_instruction_error!(0, error, MplCoreError::InvalidAuthority);
+
+    let asset_account = context
+        .banks_client
+        .get_account(asset.pubkey())
+        .await
+        .expect("get_account")
+        .expect("asset account not found");
+    let asset_data = Asset::from_bytes(&asset_account.data).unwrap();
+    assert_eq!(
+        asset_data
+            .plugin_list
+            .time_lock
+            .unwrap()
+            .time_lock
+            .unlock_at,
+        unlock_at
+    );
+}
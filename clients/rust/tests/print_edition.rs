@@ -0,0 +1,246 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    errors::MplCoreError,
+    instructions::PrintEditionV1Builder,
+    types::{Edition, MasterEdition, Plugin, PluginAuthorityPair},
+    Asset,
+};
+pub use setup::*;
+
+use solana_program_test::tokio;
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+#[tokio::test]
+async fn print_editions_up_to_max_supply() {
+    let mut context = program_test().start_with_context().await;
+
+    let collection = Keypair::new();
+    create_collection(
+        &mut context,
+        CreateCollectionHelperArgs {
+            collection: &collection,
+            update_authority: None,
+            payer: None,
+            name: None,
+            uri: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::MasterEdition(MasterEdition {
+                    max_supply: Some(1),
+                    name: Some("Master Edition".to_owned()),
+                    uri: Some("https://example.com/master".to_owned()),
+                }),
+                authority: None,
+            }],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let asset = Keypair::new();
+    let print_ix = PrintEditionV1Builder::new()
+        .asset(asset.pubkey())
+        .collection(collection.pubkey())
+        .payer(context.payer.pubkey())
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[print_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &asset],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let asset_account = context
+        .banks_client
+        .get_account(asset.pubkey())
+        .await
+        .expect("get_account")
+        .expect("asset account not found");
+    let asset_data = Asset::from_bytes(&asset_account.data).unwrap();
+    assert_eq!(asset_data.base.name, "Master Edition");
+    assert_eq!(asset_data.base.uri, "https://example.com/master");
+    assert_eq!(
+        asset_data.plugin_list.edition.unwrap().edition,
+        Edition { number: 1 }
+    );
+
+    // The max supply has been printed.
+    let asset = Keypair::new();
+    let print_ix = PrintEditionV1Builder::new()
+        .asset(asset.pubkey())
+        .collection(collection.pubkey())
+        .payer(context.payer.pubkey())
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[print_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &asset],
+        context.last_blockhash,
+    );
+
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_custom_instruction_error!(0, error, MplCoreError::MaxSupplyReached);
+}
+
+#[tokio::test]
+async fn assets_created_into_master_edition_count_towards_max_supply() {
+    let mut context = program_test().start_with_context().await;
+
+    let collection = Keypair::new();
+    create_collection(
+        &mut context,
+        CreateCollectionHelperArgs {
+            collection: &collection,
+            update_authority: None,
+            payer: None,
+            name: None,
+            uri: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::MasterEdition(MasterEdition {
+                    max_supply: Some(2),
+                    name: None,
+                    uri: None,
+                }),
+                authority: None,
+            }],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    // The collection counts every asset minted into it, printed or not.
+    let asset = Keypair::new();
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: Some(collection.pubkey()),
+            plugins: vec![],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let asset = Keypair::new();
+    let print_ix = PrintEditionV1Builder::new()
+        .asset(asset.pubkey())
+        .collection(collection.pubkey())
+        .payer(context.payer.pubkey())
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[print_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &asset],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let asset_account = context
+        .banks_client
+        .get_account(asset.pubkey())
+        .await
+        .expect("get_account")
+        .expect("asset account not found");
+    let asset_data = Asset::from_bytes(&asset_account.data).unwrap();
+    assert_eq!(
+        asset_data.plugin_list.edition.unwrap().edition,
+        Edition { number: 2 }
+    );
+
+    let asset = Keypair::new();
+    let print_ix = PrintEditionV1Builder::new()
+        .asset(asset.pubkey())
+        .collection(collection.pubkey())
+        .payer(context.payer.pubkey())
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[print_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &asset],
+        context.last_blockhash,
+    );
+
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_custom_instruction_error!(0, error, MplCoreError::MaxSupplyReached);
+}
+
+#[tokio::test]
+async fn cannot_create_edition_of_master_edition_with_max_supply() {
+    let mut context = program_test().start_with_context().await;
+
+    let collection = Keypair::new();
+    create_collection(
+        &mut context,
+        CreateCollectionHelperArgs {
+            collection: &collection,
+            update_authority: None,
+            payer: None,
+            name: None,
+            uri: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::MasterEdition(MasterEdition {
+                    max_supply: Some(2),
+                    name: None,
+                    uri: None,
+                }),
+                authority: None,
+            }],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    // A printed edition would be numbered 2 as well.
+    let asset = Keypair::new();
+    let error = create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: Some(collection.pubkey()),
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::Edition(Edition { number: 2 }),
+                authority: None,
+            }],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap_err();
+
+    assert_custom_instruction_error!(0, error, MplCoreError::InvalidPlugin);
+}
@@ -136,6 +136,26 @@ pub async fn assert_asset(context: &mut ProgramTestContext, input: AssertAssetHe
                 }
                 assert_eq!(plugin.royalties, royalties);
             }
+            PluginAuthorityPair {
+                plugin: Plugin::TransferDelegate(transfer_delegate),
+                authority,
+            } => {
+                let plugin = asset.plugin_list.transfer_delegate.clone().unwrap();
+                if let Some(authority) = authority {
+                    assert_eq!(plugin.base.authority, authority.into());
+                }
+                assert_eq!(plugin.transfer_delegate, transfer_delegate);
+            }
+            PluginAuthorityPair {
+                plugin: Plugin::TransferDelegateV2(transfer_delegate_v2),
+                authority,
+            } => {
+                let plugin = asset.plugin_list.transfer_delegate_v2.clone().unwrap();
+                if let Some(authority) = authority {
+                    assert_eq!(plugin.base.authority, authority.into());
+                }
+                assert_eq!(plugin.transfer_delegate_v2, transfer_delegate_v2);
+            }
             _ => panic!("unsupported plugin type"),
         }
     }
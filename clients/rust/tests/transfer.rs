@@ -1,13 +1,37 @@
 #![cfg(feature = "test-sbf")]
 pub mod setup;
 use mpl_core::{
-    instructions::TransferV1Builder,
-    types::{Creator, Plugin, PluginAuthorityPair, Royalties, RuleSet, UpdateAuthority},
+    accounts::AssetSigner,
+    associated_token_address,
+    errors::MplCoreError,
+    instructions::{
+        AddPluginV1Builder, ExecuteBatchV1Builder, ExecuteV1Builder, RemovePluginV1Builder,
+        TransferBatchV1Builder, TransferV1Builder, TransferV2Builder,
+    },
+    lamport_payment, token_payment,
+    types::{
+        Creator, ExecuteBatchInstruction, Listing, ListingRecipient, PermanentTransferDelegate,
+        Plugin, PluginAuthority, PluginAuthorityPair, PluginType, Rental, Royalties, RuleSet,
+        Soulbound, TimeLock, TransferDelegate, TransferDelegateV2, UpdateAuthority,
+    },
+    Asset,
 };
 pub use setup::*;
 
-use solana_program_test::tokio;
+use solana_program::{
+    clock::Clock,
+    instruction::{AccountMeta, Instruction},
+    pubkey,
+    pubkey::Pubkey,
+};
+use solana_program_test::{tokio, BanksClientError, ProgramTestContext};
 use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+use solana_system_interface::{instruction as system_instruction, program as system_program};
+
+const INSTRUCTIONS_SYSVAR_ID: Pubkey = pubkey!("Sysvar1nstructions1111111111111111111111111");
+const SPL_TOKEN_ID: Pubkey = pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+const SPL_ASSOCIATED_TOKEN_ACCOUNT_ID: Pubkey =
+    pubkey!("ATokenGPvbdGVxr1b2hdsZrYYbPCZR8Ji4Pa5VWDTEwN");
 
 #[tokio::test]
 async fn transfer_asset_as_owner() {
@@ -140,3 +164,2004 @@ async fn transfer_asset_with_royalties() {
     )
     .await;
 }
+
+#[tokio::test]
+async fn transfer_batch_assets_as_owner() {
+    let mut context = program_test().start_with_context().await;
+
+    let collection = Keypair::new();
+    create_collection(
+        &mut context,
+        CreateCollectionHelperArgs {
+            collection: &collection,
+            update_authority: None,
+            payer: None,
+            name: None,
+            uri: None,
+            plugins: vec![],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let collection_asset = Keypair::new();
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &collection_asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: Some(collection.pubkey()),
+            plugins: vec![],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let asset = Keypair::new();
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    // Assets without a collection use the program ID in place of the collection.
+    let new_owner = Keypair::new();
+    let transfer_ix = TransferBatchV1Builder::new()
+        .payer(context.payer.pubkey())
+        .new_owner(new_owner.pubkey())
+        .num_assets(2)
+        .add_remaining_accounts(&[
+            AccountMeta::new(collection_asset.pubkey(), false),
+            AccountMeta::new_readonly(collection.pubkey(), false),
+            AccountMeta::new(asset.pubkey(), false),
+            AccountMeta::new_readonly(mpl_core::ID, false),
+        ])
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    assert_asset(
+        &mut context,
+        AssertAssetHelperArgs {
+            asset: collection_asset.pubkey(),
+            owner: new_owner.pubkey(),
+            update_authority: Some(UpdateAuthority::Collection(collection.pubkey())),
+            name: None,
+            uri: None,
+            plugins: vec![],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await;
+
+    let update_authority = context.payer.pubkey();
+    assert_asset(
+        &mut context,
+        AssertAssetHelperArgs {
+            asset: asset.pubkey(),
+            owner: new_owner.pubkey(),
+            update_authority: Some(UpdateAuthority::Address(update_authority)),
+            name: None,
+            uri: None,
+            plugins: vec![],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn cannot_transfer_soulbound_asset_as_permanent_delegate() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let owner = Keypair::new();
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: Some(owner.pubkey()),
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![
+                PluginAuthorityPair {
+                    plugin: Plugin::PermanentTransferDelegate(PermanentTransferDelegate {}),
+                    authority: None,
+                },
+                PluginAuthorityPair {
+                    plugin: Plugin::Soulbound(Soulbound {}),
+                    authority: None,
+                },
+            ],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    // The update authority is the permanent transfer delegate, but the soulbound plugin
+    // rejects the transfer anyway.
+    let new_owner = Keypair::new();
+    let transfer_ix = TransferV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .new_owner(new_owner.pubkey())
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_custom_instruction_error!(0, error, MplCoreError::InvalidAuthority);
+}
+
+/// Create an asset owned by `owner` without plugins.
+async fn create_owned_asset(context: &mut ProgramTestContext, asset: &Keypair, owner: &Keypair) {
+    create_asset(
+        context,
+        CreateAssetHelperArgs {
+            owner: Some(owner.pubkey()),
+            payer: None,
+            asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+}
+
+/// Transfer an asset by its owner to a new keypair.
+async fn transfer_by_owner(
+    context: &mut ProgramTestContext,
+    asset: &Keypair,
+    owner: &Keypair,
+) -> Result<(), BanksClientError> {
+    let transfer_ix = TransferV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .authority(Some(owner.pubkey()))
+        .new_owner(Keypair::new().pubkey())
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, owner],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await
+}
+
+#[tokio::test]
+async fn update_authority_can_add_and_remove_soulbound() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let owner = Keypair::new();
+    create_owned_asset(&mut context, &asset, &owner).await;
+
+    let add_ix = AddPluginV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .plugin(Plugin::Soulbound(Soulbound {}))
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[add_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let error = transfer_by_owner(&mut context, &asset, &owner)
+        .await
+        .unwrap_err();
+
+    assert_custom_instruction_error!(0, error, MplCoreError::InvalidAuthority);
+
+    // The asset is transferable again once its authority removes the plugin.
+    let remove_ix = RemovePluginV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .plugin_type(PluginType::Soulbound)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[remove_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    transfer_by_owner(&mut context, &asset, &owner)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn update_authority_can_add_time_lock_after_creation() {
+    let mut context = program_test().start_with_context().await;
+
+    let clock: Clock = context.banks_client.get_sysvar().await.unwrap();
+
+    let asset = Keypair::new();
+    let owner = Keypair::new();
+    create_owned_asset(&mut context, &asset, &owner).await;
+
+    let add_ix = AddPluginV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .plugin(Plugin::TimeLock(TimeLock {
+            unlock_at: clock.unix_timestamp + 1_000,
+        }))
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[add_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let error = transfer_by_owner(&mut context, &asset, &owner)
+        .await
+        .unwrap_err();
+
+    assert_custom_instruction_error!(0, error, MplCoreError::InvalidAuthority);
+}
+
+#[tokio::test]
+async fn transfer_time_locked_asset_after_unlock() {
+    let mut context = program_test().start_with_context().await;
+
+    let mut clock: Clock = context.banks_client.get_sysvar().await.unwrap();
+    let unlock_at = clock.unix_timestamp + 1_000;
+
+    let asset = Keypair::new();
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::TimeLock(TimeLock { unlock_at }),
+                authority: None,
+            }],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let new_owner = Keypair::new();
+    let transfer_ix = TransferV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .new_owner(new_owner.pubkey())
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_ix.clone()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_custom_instruction_error!(0, error, MplCoreError::InvalidAuthority);
+
+    // Move the clock to the unlock time.
+    clock.unix_timestamp = unlock_at;
+    context.set_sysvar(&clock);
+    context.get_new_latest_blockhash().await.unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let update_authority = context.payer.pubkey();
+    assert_asset(
+        &mut context,
+        AssertAssetHelperArgs {
+            asset: asset.pubkey(),
+            owner: new_owner.pubkey(),
+            update_authority: Some(UpdateAuthority::Address(update_authority)),
+            name: None,
+            uri: None,
+            plugins: vec![],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn return_rented_asset_to_lender_after_expiry() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    // The lender starts the rental and hands the asset over to the renter.
+    let lender = context.payer.pubkey();
+    let renter = Keypair::new();
+    let clock: Clock = context.banks_client.get_sysvar().await.unwrap();
+    let expiry_slot = clock.slot + 100;
+
+    let add_plugin_ix = AddPluginV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .plugin(Plugin::Rental(Rental {
+            lender,
+            renter: renter.pubkey(),
+            expiry_slot,
+        }))
+        .instruction();
+
+    let transfer_ix = TransferV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .new_owner(renter.pubkey())
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[add_plugin_ix, transfer_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // The renter cannot transfer the asset while it is rented.
+    let transfer_ix = TransferV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .authority(Some(renter.pubkey()))
+        .new_owner(Keypair::new().pubkey())
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &renter],
+        context.last_blockhash,
+    );
+
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_custom_instruction_error!(0, error, MplCoreError::InvalidAuthority);
+
+    // After expiry anyone can return the asset to the lender.
+    context.warp_to_slot(expiry_slot).unwrap();
+
+    let anyone = Keypair::new();
+    let transfer_ix = TransferV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .authority(Some(anyone.pubkey()))
+        .new_owner(lender)
+        .system_program(Some(system_program::ID))
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &anyone],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // The rental plugin is removed once the asset is returned.
+    let account = context
+        .banks_client
+        .get_account(asset.pubkey())
+        .await
+        .expect("get_account")
+        .expect("asset account not found");
+    let asset_data = Asset::from_bytes(&account.data).unwrap();
+    assert_eq!(asset_data.base.owner, lender);
+    assert!(asset_data.plugin_list.rental.is_none());
+}
+
+#[tokio::test]
+async fn buy_listed_asset_with_lamports() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let seller = Keypair::new();
+    let creator = Keypair::new();
+    let price = 100_000_000;
+    let listing = Listing {
+        price,
+        mint: None,
+        recipients: vec![ListingRecipient {
+            address: creator.pubkey(),
+            basis_points: 500,
+        }],
+    };
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: Some(seller.pubkey()),
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::Listing(listing.clone()),
+                authority: None,
+            }],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let buyer = context.payer.pubkey();
+    let transfer_ix = TransferV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(buyer)
+        .new_owner(buyer)
+        .system_program(Some(system_program::ID))
+        .add_remaining_account(AccountMeta::new_readonly(INSTRUCTIONS_SYSVAR_ID, false))
+        .instruction();
+
+    // Paying only the seller is not enough, the recipients must be paid as well.
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            lamport_payment(&buyer, &seller.pubkey(), price, &asset.pubkey()),
+            transfer_ix.clone(),
+        ],
+        Some(&buyer),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_custom_instruction_error!(1, error, MplCoreError::NoApprovals);
+
+    // Payments that do not name the asset are not counted.
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            system_instruction::transfer(&buyer, &creator.pubkey(), 5_000_000),
+            system_instruction::transfer(&buyer, &seller.pubkey(), 95_000_000),
+            transfer_ix.clone(),
+        ],
+        Some(&buyer),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_custom_instruction_error!(2, error, MplCoreError::NoApprovals);
+
+    let mut instructions =
+        listing.lamport_payment_instructions(&asset.pubkey(), &seller.pubkey(), &buyer);
+    instructions.push(transfer_ix);
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&buyer),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // The listing is removed once the asset is sold.
+    let account = context
+        .banks_client
+        .get_account(asset.pubkey())
+        .await
+        .expect("get_account")
+        .expect("asset account not found");
+    let asset_data = Asset::from_bytes(&account.data).unwrap();
+    assert_eq!(asset_data.base.owner, buyer);
+    assert!(asset_data.plugin_list.listing.is_none());
+}
+
+#[tokio::test]
+async fn buy_listed_asset_with_tokens() {
+    let mut context = program_test().start_with_context().await;
+
+    let mint = Keypair::new();
+    create_mint(&mut context, &mint, 6).await;
+
+    let asset = Keypair::new();
+    let seller = Keypair::new();
+    let creator = Keypair::new();
+    let price = 1_000_000;
+    let listing = Listing {
+        price,
+        mint: Some(mint.pubkey()),
+        recipients: vec![ListingRecipient {
+            address: creator.pubkey(),
+            basis_points: 500,
+        }],
+    };
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: Some(seller.pubkey()),
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::Listing(listing.clone()),
+                authority: None,
+            }],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let buyer = context.payer.pubkey();
+    let buyer_tokens = create_token_account(&mut context, &buyer, &mint.pubkey()).await;
+    let seller_tokens = create_token_account(&mut context, &seller.pubkey(), &mint.pubkey()).await;
+    let creator_tokens =
+        create_token_account(&mut context, &creator.pubkey(), &mint.pubkey()).await;
+    mint_to(&mut context, &mint.pubkey(), &buyer_tokens, price).await;
+
+    let transfer_ix = TransferV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(buyer)
+        .new_owner(buyer)
+        .system_program(Some(system_program::ID))
+        .add_remaining_account(AccountMeta::new_readonly(INSTRUCTIONS_SYSVAR_ID, false))
+        .instruction();
+
+    // Paying only the seller is not enough, the recipients must be paid as well.
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            token_payment(
+                &SPL_TOKEN_ID,
+                &buyer_tokens,
+                &mint.pubkey(),
+                &seller_tokens,
+                &buyer,
+                price,
+                6,
+                &asset.pubkey(),
+            ),
+            transfer_ix.clone(),
+        ],
+        Some(&buyer),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_custom_instruction_error!(1, error, MplCoreError::NoApprovals);
+
+    let mut instructions = listing
+        .token_payment_instructions(&asset.pubkey(), &seller.pubkey(), &buyer, &SPL_TOKEN_ID, 6)
+        .unwrap();
+    instructions.push(transfer_ix);
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&buyer),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    assert_eq!(token_balance(&mut context, &creator_tokens).await, 50_000);
+    assert_eq!(token_balance(&mut context, &seller_tokens).await, 950_000);
+
+    let account = context
+        .banks_client
+        .get_account(asset.pubkey())
+        .await
+        .expect("get_account")
+        .expect("asset account not found");
+    let asset_data = Asset::from_bytes(&account.data).unwrap();
+    assert_eq!(asset_data.base.owner, buyer);
+    assert!(asset_data.plugin_list.listing.is_none());
+}
+
+/// Create an SPL token mint with the payer as its mint authority.
+async fn create_mint(context: &mut ProgramTestContext, mint: &Keypair, decimals: u8) {
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let mut data = vec![20, decimals];
+    data.extend_from_slice(context.payer.pubkey().as_ref());
+    data.push(0);
+    let initialize_mint_ix = Instruction {
+        program_id: SPL_TOKEN_ID,
+        accounts: vec![AccountMeta::new(mint.pubkey(), false)],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            system_instruction::create_account(
+                &context.payer.pubkey(),
+                &mint.pubkey(),
+                rent.minimum_balance(82),
+                82,
+                &SPL_TOKEN_ID,
+            ),
+            initialize_mint_ix,
+        ],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, mint],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
+
+/// Create the associated token account of the wallet for the mint.
+async fn create_token_account(
+    context: &mut ProgramTestContext,
+    wallet: &Pubkey,
+    mint: &Pubkey,
+) -> Pubkey {
+    let token_account = associated_token_address(wallet, &SPL_TOKEN_ID, mint);
+    let create_ix = Instruction {
+        program_id: SPL_ASSOCIATED_TOKEN_ACCOUNT_ID,
+        accounts: vec![
+            AccountMeta::new(context.payer.pubkey(), true),
+            AccountMeta::new(token_account, false),
+            AccountMeta::new_readonly(*wallet, false),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(SPL_TOKEN_ID, false),
+        ],
+        data: vec![],
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[create_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+    token_account
+}
+
+/// Mint tokens to the token account, signed by the payer as the mint authority.
+async fn mint_to(
+    context: &mut ProgramTestContext,
+    mint: &Pubkey,
+    token_account: &Pubkey,
+    amount: u64,
+) {
+    let mut data = vec![7];
+    data.extend_from_slice(&amount.to_le_bytes());
+    let mint_to_ix = Instruction {
+        program_id: SPL_TOKEN_ID,
+        accounts: vec![
+            AccountMeta::new(*mint, false),
+            AccountMeta::new(*token_account, false),
+            AccountMeta::new_readonly(context.payer.pubkey(), true),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[mint_to_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
+
+/// Returns the token balance of the token account.
+async fn token_balance(context: &mut ProgramTestContext, token_account: &Pubkey) -> u64 {
+    let account = context
+        .banks_client
+        .get_account(*token_account)
+        .await
+        .expect("get_account")
+        .expect("token account not found");
+    u64::from_le_bytes(account.data[64..72].try_into().unwrap())
+}
+
+/// Create an asset owned by the seller and listed for `price` lamports without recipients.
+async fn create_listed_asset(
+    context: &mut ProgramTestContext,
+    asset: &Keypair,
+    seller: Pubkey,
+    price: u64,
+) {
+    create_asset(
+        context,
+        CreateAssetHelperArgs {
+            owner: Some(seller),
+            payer: None,
+            asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::Listing(Listing {
+                    price,
+                    mint: None,
+                    recipients: vec![],
+                }),
+                authority: None,
+            }],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+}
+
+/// Create an asset owned by the payer and return its funded asset signer.
+async fn create_buyer_asset(context: &mut ProgramTestContext, asset: &Keypair) -> Pubkey {
+    create_asset(
+        context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let (asset_signer, _) = AssetSigner::find_pda(&asset.pubkey());
+    airdrop(context, &asset_signer, 1_000_000_000)
+        .await
+        .unwrap();
+    asset_signer
+}
+
+/// A transfer of a listed asset to the asset signer, with the accounts the execute passes on.
+fn listed_transfer(asset: Pubkey, asset_signer: Pubkey) -> (Vec<u8>, Vec<AccountMeta>) {
+    let transfer_ix = TransferV1Builder::new()
+        .asset(asset)
+        .payer(asset_signer)
+        .new_owner(asset_signer)
+        .system_program(Some(system_program::ID))
+        .add_remaining_account(AccountMeta::new_readonly(INSTRUCTIONS_SYSVAR_ID, false))
+        .instruction();
+
+    // The asset signer signs through the execute.
+    let accounts = transfer_ix
+        .accounts
+        .into_iter()
+        .map(|account| AccountMeta {
+            is_signer: false,
+            ..account
+        })
+        .collect();
+    (transfer_ix.data, accounts)
+}
+
+#[tokio::test]
+async fn buy_listed_asset_through_execute() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let seller = Keypair::new().pubkey();
+    let price = 100_000_000;
+    create_listed_asset(&mut context, &asset, seller, price).await;
+
+    let buyer_asset = Keypair::new();
+    let asset_signer = create_buyer_asset(&mut context, &buyer_asset).await;
+
+    // The payment is matched to the transaction instruction that invokes the transfer.
+    let (data, accounts) = listed_transfer(asset.pubkey(), asset_signer);
+    let execute_ix = ExecuteV1Builder::new()
+        .asset(buyer_asset.pubkey())
+        .asset_signer(asset_signer)
+        .payer(context.payer.pubkey(), true)
+        .program_id(mpl_core::ID)
+        .instruction_data(data)
+        .add_remaining_accounts(&accounts)
+        .instruction();
+
+    let payer = context.payer.pubkey();
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            lamport_payment(&payer, &seller, price, &asset.pubkey()),
+            execute_ix,
+        ],
+        Some(&payer),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let account = context
+        .banks_client
+        .get_account(asset.pubkey())
+        .await
+        .expect("get_account")
+        .expect("asset account not found");
+    let asset_data = Asset::from_bytes(&account.data).unwrap();
+    assert_eq!(asset_data.base.owner, asset_signer);
+    assert!(asset_data.plugin_list.listing.is_none());
+}
+
+#[tokio::test]
+async fn buy_listed_assets_in_batch() {
+    let mut context = program_test().start_with_context().await;
+
+    let seller = Keypair::new().pubkey();
+    let price = 100_000_000;
+    let first_asset = Keypair::new();
+    create_listed_asset(&mut context, &first_asset, seller, price).await;
+    let second_asset = Keypair::new();
+    create_listed_asset(&mut context, &second_asset, seller, price).await;
+
+    // The system program is passed so the listings can be removed.
+    let buyer = context.payer.pubkey();
+    let transfer_ix = TransferBatchV1Builder::new()
+        .payer(buyer)
+        .new_owner(buyer)
+        .system_program(Some(system_program::ID))
+        .num_assets(2)
+        .add_remaining_accounts(&[
+            AccountMeta::new(first_asset.pubkey(), false),
+            AccountMeta::new_readonly(mpl_core::ID, false),
+            AccountMeta::new(second_asset.pubkey(), false),
+            AccountMeta::new_readonly(mpl_core::ID, false),
+            AccountMeta::new_readonly(INSTRUCTIONS_SYSVAR_ID, false),
+        ])
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            lamport_payment(&buyer, &seller, price, &first_asset.pubkey()),
+            lamport_payment(&buyer, &seller, price, &second_asset.pubkey()),
+            transfer_ix,
+        ],
+        Some(&buyer),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    for asset in [&first_asset, &second_asset] {
+        let account = context
+            .banks_client
+            .get_account(asset.pubkey())
+            .await
+            .expect("get_account")
+            .expect("asset account not found");
+        let asset_data = Asset::from_bytes(&account.data).unwrap();
+        assert_eq!(asset_data.base.owner, buyer);
+        assert!(asset_data.plugin_list.listing.is_none());
+    }
+}
+
+#[tokio::test]
+async fn cannot_buy_two_listed_assets_with_one_payment_in_batch_execute() {
+    let mut context = program_test().start_with_context().await;
+
+    let seller = Keypair::new().pubkey();
+    let price = 100_000_000;
+    let first_asset = Keypair::new();
+    create_listed_asset(&mut context, &first_asset, seller, price).await;
+    let second_asset = Keypair::new();
+    create_listed_asset(&mut context, &second_asset, seller, price).await;
+
+    let buyer_asset = Keypair::new();
+    let asset_signer = create_buyer_asset(&mut context, &buyer_asset).await;
+
+    let (first_data, first_accounts) = listed_transfer(first_asset.pubkey(), asset_signer);
+    let (second_data, second_accounts) = listed_transfer(second_asset.pubkey(), asset_signer);
+    let mut remaining_accounts = vec![AccountMeta::new_readonly(mpl_core::ID, false)];
+    remaining_accounts.extend(first_accounts.iter().cloned());
+    remaining_accounts.extend(second_accounts.iter().cloned());
+
+    let execute_ix = ExecuteBatchV1Builder::new()
+        .asset(buyer_asset.pubkey())
+        .asset_signer(asset_signer)
+        .payer(context.payer.pubkey(), true)
+        .instructions(vec![
+            ExecuteBatchInstruction {
+                program_index: 0,
+                accounts_start: 1,
+                accounts_len: first_accounts.len() as u8,
+                data: first_data,
+            },
+            ExecuteBatchInstruction {
+                program_index: 0,
+                accounts_start: 1 + first_accounts.len() as u8,
+                accounts_len: second_accounts.len() as u8,
+                data: second_data,
+            },
+        ])
+        .add_remaining_accounts(&remaining_accounts)
+        .instruction();
+
+    // The payment names the first asset, so the second asset is not paid for.
+    let payer = context.payer.pubkey();
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            lamport_payment(&payer, &seller, price, &first_asset.pubkey()),
+            execute_ix,
+        ],
+        Some(&payer),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_custom_instruction_error!(1, error, MplCoreError::NoApprovals);
+
+    let account = context
+        .banks_client
+        .get_account(first_asset.pubkey())
+        .await
+        .expect("get_account")
+        .expect("asset account not found");
+    let asset_data = Asset::from_bytes(&account.data).unwrap();
+    assert_eq!(asset_data.base.owner, seller);
+}
+
+#[tokio::test]
+async fn transfer_asset_with_enforced_royalties() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let creator = Keypair::new();
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![PluginAuthorityPair {
+                authority: None,
+                plugin: Plugin::Royalties(Royalties {
+                    basis_points: 500,
+                    creators: vec![Creator {
+                        address: creator.pubkey(),
+                        percentage: 100,
+                    }],
+                    rule_set: RuleSet::EnforcePayment,
+                }),
+            }],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    // Transfers that are not sales are rejected.
+    let new_owner = Keypair::new();
+    let transfer_ix = TransferV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .new_owner(new_owner.pubkey())
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_custom_instruction_error!(0, error, MplCoreError::InvalidAuthority);
+
+    // Neither are transfers declaring a sale price of zero.
+    let payer = context.payer.pubkey();
+    let transfer_ix = TransferV2Builder::new()
+        .asset(asset.pubkey())
+        .payer(payer)
+        .new_owner(new_owner.pubkey())
+        .sale_price(0)
+        .add_remaining_account(AccountMeta::new_readonly(INSTRUCTIONS_SYSVAR_ID, false))
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_ix],
+        Some(&payer),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_custom_instruction_error!(0, error, MplCoreError::InvalidAuthority);
+
+    let transfer_ix = TransferV2Builder::new()
+        .asset(asset.pubkey())
+        .payer(payer)
+        .new_owner(new_owner.pubkey())
+        .sale_price(100_000_000)
+        .add_remaining_account(AccountMeta::new_readonly(INSTRUCTIONS_SYSVAR_ID, false))
+        .instruction();
+
+    // The creator must be paid before the transfer.
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_ix.clone()],
+        Some(&payer),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_custom_instruction_error!(0, error, MplCoreError::InvalidAuthority);
+
+    // The creator is owed 5% of the sale price.
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            lamport_payment(&payer, &creator.pubkey(), 4_999_999, &asset.pubkey()),
+            transfer_ix.clone(),
+        ],
+        Some(&payer),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_custom_instruction_error!(1, error, MplCoreError::InvalidAuthority);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            lamport_payment(&payer, &creator.pubkey(), 5_000_000, &asset.pubkey()),
+            transfer_ix,
+        ],
+        Some(&payer),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let creator_account = context
+        .banks_client
+        .get_account(creator.pubkey())
+        .await
+        .expect("get_account")
+        .expect("creator account not found");
+    assert_eq!(creator_account.lamports, 5_000_000);
+
+    assert_asset(
+        &mut context,
+        AssertAssetHelperArgs {
+            asset: asset.pubkey(),
+            owner: new_owner.pubkey(),
+            update_authority: Some(UpdateAuthority::Address(payer)),
+            name: None,
+            uri: None,
+            plugins: vec![],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn transfer_asset_with_royalties_enforced_by_collection() {
+    let mut context = program_test().start_with_context().await;
+
+    let collection = Keypair::new();
+    let collection_creator = Keypair::new();
+    create_collection(
+        &mut context,
+        CreateCollectionHelperArgs {
+            collection: &collection,
+            update_authority: None,
+            payer: None,
+            name: None,
+            uri: None,
+            plugins: vec![PluginAuthorityPair {
+                authority: None,
+                plugin: Plugin::Royalties(Royalties {
+                    basis_points: 500,
+                    creators: vec![Creator {
+                        address: collection_creator.pubkey(),
+                        percentage: 100,
+                    }],
+                    rule_set: RuleSet::EnforcePayment,
+                }),
+            }],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    // Royalties on the asset without a rule set do not lift the collection's enforcement.
+    let asset = Keypair::new();
+    let asset_creator = Keypair::new();
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: Some(collection.pubkey()),
+            plugins: vec![PluginAuthorityPair {
+                authority: None,
+                plugin: Plugin::Royalties(Royalties {
+                    basis_points: 1000,
+                    creators: vec![Creator {
+                        address: asset_creator.pubkey(),
+                        percentage: 100,
+                    }],
+                    rule_set: RuleSet::None,
+                }),
+            }],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let payer = context.payer.pubkey();
+    let new_owner = Keypair::new();
+    let transfer_ix = TransferV2Builder::new()
+        .asset(asset.pubkey())
+        .collection(Some(collection.pubkey()))
+        .payer(payer)
+        .new_owner(new_owner.pubkey())
+        .sale_price(100_000_000)
+        .add_remaining_account(AccountMeta::new_readonly(INSTRUCTIONS_SYSVAR_ID, false))
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            lamport_payment(&payer, &asset_creator.pubkey(), 10_000_000, &asset.pubkey()),
+            transfer_ix.clone(),
+        ],
+        Some(&payer),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_custom_instruction_error!(1, error, MplCoreError::InvalidAuthority);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            lamport_payment(
+                &payer,
+                &collection_creator.pubkey(),
+                5_000_000,
+                &asset.pubkey(),
+            ),
+            transfer_ix,
+        ],
+        Some(&payer),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let account = context
+        .banks_client
+        .get_account(asset.pubkey())
+        .await
+        .expect("get_account")
+        .expect("asset account not found");
+    let asset_data = Asset::from_bytes(&account.data).unwrap();
+    assert_eq!(asset_data.base.owner, new_owner.pubkey());
+}
+
+#[tokio::test]
+async fn buy_listed_asset_with_enforced_royalties() {
+    let mut context = program_test().start_with_context().await;
+
+    let seller = Keypair::new();
+    let creator = Keypair::new();
+    let price = 100_000_000;
+    let plugins = vec![
+        PluginAuthorityPair {
+            plugin: Plugin::Royalties(Royalties {
+                basis_points: 500,
+                creators: vec![Creator {
+                    address: creator.pubkey(),
+                    percentage: 100,
+                }],
+                rule_set: RuleSet::EnforcePayment,
+            }),
+            authority: None,
+        },
+        PluginAuthorityPair {
+            plugin: Plugin::Listing(Listing {
+                price,
+                mint: None,
+                recipients: vec![ListingRecipient {
+                    address: creator.pubkey(),
+                    basis_points: 500,
+                }],
+            }),
+            authority: None,
+        },
+    ];
+
+    let first_asset = Keypair::new();
+    let second_asset = Keypair::new();
+    for asset in [&first_asset, &second_asset] {
+        create_asset(
+            &mut context,
+            CreateAssetHelperArgs {
+                owner: Some(seller.pubkey()),
+                payer: None,
+                asset,
+                data_state: None,
+                name: None,
+                uri: None,
+                authority: None,
+                update_authority: None,
+                collection: None,
+                plugins: plugins.clone(),
+                external_plugin_adapters: vec![],
+            },
+        )
+        .await
+        .unwrap();
+    }
+
+    // The listing price is the sale price when the asset is bought without a declared price.
+    let buyer = context.payer.pubkey();
+    let transfer_ix = TransferV1Builder::new()
+        .asset(first_asset.pubkey())
+        .payer(buyer)
+        .new_owner(buyer)
+        .system_program(Some(system_program::ID))
+        .add_remaining_account(AccountMeta::new_readonly(INSTRUCTIONS_SYSVAR_ID, false))
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            lamport_payment(&buyer, &creator.pubkey(), 5_000_000, &first_asset.pubkey()),
+            lamport_payment(&buyer, &seller.pubkey(), 95_000_000, &first_asset.pubkey()),
+            transfer_ix,
+        ],
+        Some(&buyer),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // The listing price is also the sale price when a price is declared.
+    let transfer_ix = TransferV2Builder::new()
+        .asset(second_asset.pubkey())
+        .payer(buyer)
+        .new_owner(buyer)
+        .sale_price(price)
+        .add_remaining_account(AccountMeta::new_readonly(INSTRUCTIONS_SYSVAR_ID, false))
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            lamport_payment(&buyer, &creator.pubkey(), 5_000_000, &second_asset.pubkey()),
+            lamport_payment(&buyer, &seller.pubkey(), 95_000_000, &second_asset.pubkey()),
+            transfer_ix,
+        ],
+        Some(&buyer),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // The creator is paid the royalties once per sale.
+    let creator_account = context
+        .banks_client
+        .get_account(creator.pubkey())
+        .await
+        .expect("get_account")
+        .expect("creator account not found");
+    assert_eq!(creator_account.lamports, 10_000_000);
+
+    for asset in [&first_asset, &second_asset] {
+        let account = context
+            .banks_client
+            .get_account(asset.pubkey())
+            .await
+            .expect("get_account")
+            .expect("asset account not found");
+        let asset_data = Asset::from_bytes(&account.data).unwrap();
+        assert_eq!(asset_data.base.owner, buyer);
+        assert!(asset_data.plugin_list.listing.is_none());
+    }
+}
+
+#[tokio::test]
+async fn cannot_transfer_asset_to_denied_wallet() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let creator = Keypair::new();
+    let denied_wallet = Keypair::new();
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![PluginAuthorityPair {
+                authority: None,
+                plugin: Plugin::Royalties(Royalties {
+                    basis_points: 500,
+                    creators: vec![Creator {
+                        address: creator.pubkey(),
+                        percentage: 100,
+                    }],
+                    rule_set: RuleSet::Composite(vec![
+                        RuleSet::ProgramDenyList(vec![]),
+                        RuleSet::WalletDenyList(vec![denied_wallet.pubkey()]),
+                    ]),
+                }),
+            }],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let transfer_ix = TransferV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .new_owner(denied_wallet.pubkey())
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_custom_instruction_error!(0, error, MplCoreError::InvalidAuthority);
+
+    // Wallets that are not on the deny list can still receive the asset.
+    let new_owner = Keypair::new();
+    let transfer_ix = TransferV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .new_owner(new_owner.pubkey())
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let update_authority = context.payer.pubkey();
+    assert_asset(
+        &mut context,
+        AssertAssetHelperArgs {
+            asset: asset.pubkey(),
+            owner: new_owner.pubkey(),
+            update_authority: Some(UpdateAuthority::Address(update_authority)),
+            name: None,
+            uri: None,
+            plugins: vec![],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn cannot_transfer_asset_owned_by_denied_wallet() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let creator = Keypair::new();
+    let denied_wallet = Keypair::new();
+    let delegate = Keypair::new();
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: Some(denied_wallet.pubkey()),
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![
+                PluginAuthorityPair {
+                    authority: None,
+                    plugin: Plugin::Royalties(Royalties {
+                        basis_points: 500,
+                        creators: vec![Creator {
+                            address: creator.pubkey(),
+                            percentage: 100,
+                        }],
+                        rule_set: RuleSet::WalletDenyList(vec![denied_wallet.pubkey()]),
+                    }),
+                },
+                PluginAuthorityPair {
+                    plugin: Plugin::TransferDelegate(TransferDelegate {}),
+                    authority: Some(PluginAuthority::Address {
+                        address: delegate.pubkey(),
+                    }),
+                },
+            ],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    // The owner is on the deny list even though the delegate signs.
+    let transfer_ix = TransferV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .authority(Some(delegate.pubkey()))
+        .new_owner(Keypair::new().pubkey())
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &delegate],
+        context.last_blockhash,
+    );
+
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_custom_instruction_error!(0, error, MplCoreError::InvalidAuthority);
+}
+
+#[tokio::test]
+async fn transfer_asset_as_transfer_delegate_added_with_init_authority() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    // The empty delegate is directly followed by the init authority in the instruction data.
+    let delegate = Keypair::new();
+    let add_plugin_ix = AddPluginV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .plugin(Plugin::TransferDelegate(TransferDelegate {}))
+        .init_authority(PluginAuthority::Address {
+            address: delegate.pubkey(),
+        })
+        .instruction();
+
+    let new_owner = Keypair::new();
+    let transfer_ix = TransferV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .authority(Some(delegate.pubkey()))
+        .new_owner(new_owner.pubkey())
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[add_plugin_ix, transfer_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &delegate],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let update_authority = context.payer.pubkey();
+    assert_asset(
+        &mut context,
+        AssertAssetHelperArgs {
+            asset: asset.pubkey(),
+            owner: new_owner.pubkey(),
+            update_authority: Some(UpdateAuthority::Address(update_authority)),
+            name: None,
+            uri: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::TransferDelegate(TransferDelegate {}),
+                authority: Some(PluginAuthority::Owner),
+            }],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn cannot_transfer_asset_as_expired_transfer_delegate() {
+    let mut context = program_test().start_with_context().await;
+
+    let mut clock: Clock = context.banks_client.get_sysvar().await.unwrap();
+    let expires_at = clock.unix_timestamp + 1_000;
+
+    let asset = Keypair::new();
+    let delegate = Keypair::new();
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::TransferDelegateV2(TransferDelegateV2 {
+                    expires_at: Some(expires_at),
+                    max_uses: None,
+                }),
+                authority: Some(PluginAuthority::Address {
+                    address: delegate.pubkey(),
+                }),
+            }],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    // Move the clock to the expiry time.
+    clock.unix_timestamp = expires_at;
+    context.set_sysvar(&clock);
+    context.get_new_latest_blockhash().await.unwrap();
+
+    let new_owner = Keypair::new();
+    let transfer_ix = TransferV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .authority(Some(delegate.pubkey()))
+        .new_owner(new_owner.pubkey())
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &delegate],
+        context.last_blockhash,
+    );
+
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_custom_instruction_error!(0, error, MplCoreError::NoApprovals);
+}
+
+#[tokio::test]
+async fn cannot_transfer_asset_as_exhausted_transfer_delegate() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let delegate = Keypair::new();
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::TransferDelegateV2(TransferDelegateV2 {
+                    expires_at: None,
+                    max_uses: Some(0),
+                }),
+                authority: Some(PluginAuthority::Address {
+                    address: delegate.pubkey(),
+                }),
+            }],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let transfer_ix = TransferV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .authority(Some(delegate.pubkey()))
+        .new_owner(Keypair::new().pubkey())
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &delegate],
+        context.last_blockhash,
+    );
+
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_custom_instruction_error!(0, error, MplCoreError::NoApprovals);
+}
+
+#[tokio::test]
+async fn transfer_asset_as_use_limited_transfer_delegate() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let delegate = Keypair::new();
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::TransferDelegateV2(TransferDelegateV2 {
+                    expires_at: None,
+                    max_uses: Some(2),
+                }),
+                authority: Some(PluginAuthority::Address {
+                    address: delegate.pubkey(),
+                }),
+            }],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    // A transfer resets the delegate even though it has a transfer left, so the new owner does
+    // not inherit an approval given by the previous owner.
+    let new_owner = Keypair::new();
+    let transfer_ix = TransferV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .authority(Some(delegate.pubkey()))
+        .new_owner(new_owner.pubkey())
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &delegate],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let update_authority = context.payer.pubkey();
+    assert_asset(
+        &mut context,
+        AssertAssetHelperArgs {
+            asset: asset.pubkey(),
+            owner: new_owner.pubkey(),
+            update_authority: Some(UpdateAuthority::Address(update_authority)),
+            name: None,
+            uri: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::TransferDelegateV2(TransferDelegateV2 {
+                    expires_at: None,
+                    max_uses: None,
+                }),
+                authority: Some(PluginAuthority::Owner),
+            }],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await;
+
+    // The previous owner's delegate cannot move the new owner's asset.
+    let transfer_ix = TransferV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .authority(Some(delegate.pubkey()))
+        .new_owner(delegate.pubkey())
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &delegate],
+        context.last_blockhash,
+    );
+
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_custom_instruction_error!(0, error, MplCoreError::NoApprovals);
+}
+
+#[tokio::test]
+async fn batch_transfer_uses_up_transfer_delegate() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    let delegate = Keypair::new();
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::TransferDelegateV2(TransferDelegateV2 {
+                    expires_at: None,
+                    max_uses: Some(1),
+                }),
+                authority: Some(PluginAuthority::Address {
+                    address: delegate.pubkey(),
+                }),
+            }],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let new_owner = Keypair::new();
+    let transfer_ix = TransferBatchV1Builder::new()
+        .payer(context.payer.pubkey())
+        .authority(Some(delegate.pubkey()))
+        .new_owner(new_owner.pubkey())
+        .num_assets(1)
+        .add_remaining_accounts(&[
+            AccountMeta::new(asset.pubkey(), false),
+            AccountMeta::new_readonly(mpl_core::ID, false),
+        ])
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &delegate],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let update_authority = context.payer.pubkey();
+    assert_asset(
+        &mut context,
+        AssertAssetHelperArgs {
+            asset: asset.pubkey(),
+            owner: new_owner.pubkey(),
+            update_authority: Some(UpdateAuthority::Address(update_authority)),
+            name: None,
+            uri: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::TransferDelegateV2(TransferDelegateV2 {
+                    expires_at: None,
+                    max_uses: None,
+                }),
+                authority: Some(PluginAuthority::Owner),
+            }],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await;
+}
@@ -0,0 +1,253 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+use mpl_core::{
+    errors::MplCoreError,
+    instructions::{UpdateCollectionV1Builder, UpdateV1Builder, UpdateV2Builder},
+    types::{Multisig, Plugin, PluginAuthorityPair, TimeLock, UpdateAuthority},
+    Asset, Collection,
+};
+pub use setup::*;
+
+use solana_program::instruction::AccountMeta;
+use solana_program_test::tokio;
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+#[tokio::test]
+async fn update_asset_with_multisig_update_authority() {
+    let mut context = program_test().start_with_context().await;
+
+    let asset = Keypair::new();
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let signer_a = Keypair::new();
+    let signer_b = Keypair::new();
+    let signer_c = Keypair::new();
+    let update_ix = UpdateV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .new_update_authority(UpdateAuthority::Multisig {
+            signers: vec![signer_a.pubkey(), signer_b.pubkey(), signer_c.pubkey()],
+            threshold: 2,
+        })
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[update_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // A single signer cannot update the asset.
+    let update_ix = UpdateV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .authority(Some(signer_a.pubkey()))
+        .new_name("Committee Asset".to_owned())
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[update_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &signer_a],
+        context.last_blockhash,
+    );
+
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_custom_instruction_error!(0, error, MplCoreError::NoApprovals);
+
+    let update_ix = UpdateV1Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .authority(Some(signer_a.pubkey()))
+        .new_name("Committee Asset".to_owned())
+        .add_remaining_account(AccountMeta::new_readonly(signer_b.pubkey(), true))
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[update_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &signer_a, &signer_b],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let asset_account = context
+        .banks_client
+        .get_account(asset.pubkey())
+        .await
+        .expect("get_account")
+        .expect("asset account not found");
+    let asset_data = Asset::from_bytes(&asset_account.data).unwrap();
+    assert_eq!(asset_data.base.name, "Committee Asset");
+}
+
+#[tokio::test]
+async fn update_collection_with_multisig_plugin() {
+    let mut context = program_test().start_with_context().await;
+
+    let collection = Keypair::new();
+    let signer_a = Keypair::new();
+    let signer_b = Keypair::new();
+    create_collection(
+        &mut context,
+        CreateCollectionHelperArgs {
+            collection: &collection,
+            update_authority: None,
+            payer: None,
+            name: None,
+            uri: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::Multisig(Multisig {
+                    signers: vec![signer_a.pubkey(), signer_b.pubkey()],
+                    threshold: 2,
+                }),
+                authority: None,
+            }],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    // The update authority address can no longer act alone.
+    let update_ix = UpdateCollectionV1Builder::new()
+        .collection(collection.pubkey())
+        .payer(context.payer.pubkey())
+        .new_name("Committee Collection".to_owned())
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[update_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_custom_instruction_error!(0, error, MplCoreError::NoApprovals);
+
+    let update_ix = UpdateCollectionV1Builder::new()
+        .collection(collection.pubkey())
+        .payer(context.payer.pubkey())
+        .authority(Some(signer_a.pubkey()))
+        .new_name("Committee Collection".to_owned())
+        .add_remaining_account(AccountMeta::new_readonly(signer_b.pubkey(), true))
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[update_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &signer_a, &signer_b],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let collection_account = context
+        .banks_client
+        .get_account(collection.pubkey())
+        .await
+        .expect("get_account")
+        .expect("collection account not found");
+    let collection_data = Collection::from_bytes(&collection_account.data).unwrap();
+    assert_eq!(collection_data.base.name, "Committee Collection");
+}
+
+#[tokio::test]
+async fn cannot_add_time_locked_asset_to_collection_before_unlock() {
+    let mut context = program_test().start_with_context().await;
+
+    let collection = Keypair::new();
+    create_collection(
+        &mut context,
+        CreateCollectionHelperArgs {
+            collection: &collection,
+            update_authority: None,
+            payer: None,
+            name: None,
+            uri: None,
+            plugins: vec![],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let asset = Keypair::new();
+    create_asset(
+        &mut context,
+        CreateAssetHelperArgs {
+            owner: None,
+            payer: None,
+            asset: &asset,
+            data_state: None,
+            name: None,
+            uri: None,
+            authority: None,
+            update_authority: None,
+            collection: None,
+            plugins: vec![PluginAuthorityPair {
+                plugin: Plugin::TimeLock(TimeLock {
+                    unlock_at: i64::MAX,
+                }),
+                authority: None,
+            }],
+            external_plugin_adapters: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let update_ix = UpdateV2Builder::new()
+        .asset(asset.pubkey())
+        .payer(context.payer.pubkey())
+        .new_collection(Some(collection.pubkey()))
+        .new_update_authority(UpdateAuthority::Collection(collection.pubkey()))
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[update_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_custom_instruction_error!(0, error, MplCoreError::InvalidAuthority);
+}
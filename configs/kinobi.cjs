@@ -66,6 +66,46 @@ kinobi.update(
                 ),
             ],
         },
+        groupMembershipV1: {
+            seeds: [
+                k.constantPdaSeedNodeFromString("mpl-core-group-membership"),
+                k.variablePdaSeedNode(
+                    "group",
+                    k.publicKeyTypeNode(),
+                    "The address of the group account"
+                ),
+                k.variablePdaSeedNode(
+                    "member",
+                    k.publicKeyTypeNode(),
+                    "The address of the member asset account"
+                ),
+            ],
+        },
+        groupDelegateV1: {
+            seeds: [
+                k.constantPdaSeedNodeFromString("mpl-core-group-delegate"),
+                k.variablePdaSeedNode(
+                    "group",
+                    k.publicKeyTypeNode(),
+                    "The address of the group account"
+                ),
+                k.variablePdaSeedNode(
+                    "delegate",
+                    k.publicKeyTypeNode(),
+                    "The address of the delegate"
+                ),
+            ],
+        },
+        groupAuthorityProposalV1: {
+            seeds: [
+                k.constantPdaSeedNodeFromString("mpl-core-group-authority-proposal"),
+                k.variablePdaSeedNode(
+                    "group",
+                    k.publicKeyTypeNode(),
+                    "The address of the group account"
+                ),
+            ],
+        },
     })
 );
 
@@ -166,6 +206,12 @@ kinobi.update(
                 groups: {
                     // defaultValue: k.arrayValueNode([]),
                 },
+                ancestors: {
+                    defaultValue: k.noneValueNode(),
+                },
+                descendants: {
+                    defaultValue: k.noneValueNode(),
+                },
             },
         },
         removeGroupsFromGroupV1: {
@@ -256,6 +302,63 @@ kinobi.update(
                 },
             },
         },
+        executeBatchV1: {
+            accounts: {
+                assetSigner: {
+                    defaultValue: k.pdaValueNode("assetSigner"),
+                },
+            },
+        },
+        executeCollectionV1: {
+            accounts: {
+                collectionSigner: {
+                    defaultValue: k.pdaValueNode("assetSigner", [
+                        k.pdaSeedValueNode("asset", k.accountValueNode("collection")),
+                    ]),
+                },
+            },
+        },
+        approveGroupDelegateV1: {
+            accounts: {
+                groupDelegate: {
+                    defaultValue: k.pdaValueNode("groupDelegateV1", [
+                        k.pdaSeedValueNode("group", k.accountValueNode("group")),
+                        k.pdaSeedValueNode("delegate", k.accountValueNode("delegate")),
+                    ]),
+                },
+            },
+        },
+        proposeGroupUpdateAuthorityV1: {
+            accounts: {
+                groupAuthorityProposal: {
+                    defaultValue: k.pdaValueNode("groupAuthorityProposalV1", [
+                        k.pdaSeedValueNode("group", k.accountValueNode("group")),
+                    ]),
+                },
+            },
+        },
+        acceptGroupUpdateAuthorityV1: {
+            accounts: {
+                groupAuthorityProposal: {
+                    defaultValue: k.pdaValueNode("groupAuthorityProposalV1", [
+                        k.pdaSeedValueNode("group", k.accountValueNode("group")),
+                    ]),
+                },
+            },
+        },
+        printEditionV1: {
+            arguments: {
+                name: {
+                    defaultValue: k.noneValueNode(),
+                },
+                uri: {
+                    defaultValue: k.noneValueNode(),
+                },
+                plugins: {
+                    defaultValue: k.noneValueNode(),
+                },
+            },
+        },
     })
 );
 
@@ -269,6 +372,17 @@ kinobi.update(
     })
 );
 
+// Groups stored before `membershipCount` was added end after `assets`, so the Rust client reads
+// the count through a hooked type. The JavaScript client hooks the whole group account data.
+const setMembershipCountType = (type) =>
+    new k.bottomUpTransformerVisitor([
+        {
+            select: "[structFieldTypeNode]membershipCount",
+            transform: (node) => k.structFieldTypeNode({ ...node, type }),
+        },
+    ]);
+kinobi.update(setMembershipCountType(k.definedTypeLinkNode("membershipCount")));
+
 // Render Rust.
 const crateDir = path.join(clientDir, "rust");
 const rustDir = path.join(clientDir, "rust", "src", "generated");
@@ -277,9 +391,16 @@ kinobi.accept(
         formatCode: true,
         crateFolder: crateDir,
         renderParentInstructions: true,
+        linkOverrides: {
+            definedTypes: {
+                membershipCount: "hooked",
+            },
+        },
     })
 );
 
+kinobi.update(setMembershipCountType(k.numberTypeNode("u32")));
+
 // rewrite the account names for custom account data
 kinobi.update(
     new k.updateAccountsVisitor({
@@ -232,6 +232,54 @@ pub enum MplCoreError {
     /// 56 - Bidirectional group relationship is inconsistent
     #[error("Bidirectional group relationship is inconsistent")]
     InconsistentGroupRelationship,
+
+    /// 57 - Invalid accounts provided for batch operation
+    #[error("Invalid accounts provided for batch operation")]
+    InvalidBatchAccounts,
+
+    /// 58 - Invalid multisig signers or threshold
+    #[error("Invalid multisig signers or threshold")]
+    InvalidMultisig,
+
+    /// 59 - The maximum supply of editions has been printed
+    #[error("The maximum supply of editions has been printed")]
+    MaxSupplyReached,
+
+    /// 60 - The program is not allowed by the execute guard
+    #[error("The program is not allowed by the execute guard")]
+    ProgramNotAllowed,
+
+    /// 61 - The asset signer spent more than the execute guard allows
+    #[error("The asset signer spent more than the execute guard allows")]
+    SpendLimitExceeded,
+
+    /// 62 - Invalid group membership account
+    #[error("Invalid group membership account")]
+    InvalidGroupMembership,
+
+    /// 63 - Invalid group delegate account
+    #[error("Invalid group delegate account")]
+    InvalidGroupDelegate,
+
+    /// 64 - Invalid group authority proposal account
+    #[error("Invalid group authority proposal account")]
+    InvalidGroupAuthorityProposal,
+
+    /// 65 - Adding the group would create a cycle in the group hierarchy
+    #[error("Adding the group would create a cycle in the group hierarchy")]
+    GroupCycleDetected,
+
+    /// 66 - Group ancestry proof is incomplete or invalid
+    #[error("Group ancestry proof is incomplete or invalid")]
+    InvalidGroupAncestry,
+
+    /// 67 - Missing group account required by the Groups plugin
+    #[error("Missing group account required by the Groups plugin")]
+    MissingGroupAccount,
+
+    /// 68 - Group is frozen
+    #[error("Group is frozen")]
+    GroupFrozen,
 }
 
 impl From<MplCoreError> for ProgramError {
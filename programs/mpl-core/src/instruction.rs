@@ -3,18 +3,23 @@ use borsh::{BorshDeserialize, BorshSerialize};
 use shank::{ShankContext, ShankInstruction};
 
 use crate::processor::{
-    AddAssetsToGroupV1Args, AddCollectionExternalPluginAdapterV1Args, AddCollectionPluginV1Args,
-    AddCollectionsToGroupV1Args, AddExternalPluginAdapterV1Args, AddGroupsToGroupV1Args,
-    AddPluginV1Args, ApproveCollectionPluginAuthorityV1Args, ApprovePluginAuthorityV1Args,
-    BurnCollectionV1Args, BurnV1Args, CloseGroupV1Args, CompressV1Args, CreateCollectionV1Args,
-    CreateCollectionV2Args, CreateGroupV1Args, CreateV1Args, CreateV2Args, DecompressV1Args,
-    ExecuteV1Args, RemoveAssetsFromGroupV1Args, RemoveCollectionExternalPluginAdapterV1Args,
+    AcceptGroupUpdateAuthorityV1Args, AddAssetsToGroupV1Args,
+    AddCollectionExternalPluginAdapterV1Args, AddCollectionPluginV1Args,
+    AddCollectionsToGroupV1Args, AddExternalPluginAdapterV1Args, AddGroupPluginV1Args,
+    AddGroupsToGroupV1Args, AddPluginV1Args, ApproveCollectionPluginAuthorityV1Args,
+    ApproveGroupDelegateV1Args, ApprovePluginAuthorityV1Args, BurnBatchV1Args,
+    BurnCollectionV1Args, BurnV1Args, CloseGroupV1Args, CloseGroupV2Args, CompressV1Args,
+    CreateBatchV1Args, CreateCollectionV1Args, CreateCollectionV2Args, CreateGroupV1Args,
+    CreateV1Args, CreateV2Args, DecompressV1Args, ExecuteBatchV1Args, ExecuteCollectionV1Args,
+    ExecuteV1Args, MoveToCollectionV1Args, PrintEditionV1Args, ProposeGroupUpdateAuthorityV1Args,
+    RemoveAssetsFromGroupV1Args, RemoveCollectionExternalPluginAdapterV1Args,
     RemoveCollectionPluginV1Args, RemoveCollectionsFromGroupV1Args,
-    RemoveExternalPluginAdapterV1Args, RemoveGroupsFromGroupV1Args, RemovePluginV1Args,
-    RevokeCollectionPluginAuthorityV1Args, RevokePluginAuthorityV1Args, TransferV1Args,
+    RemoveExternalPluginAdapterV1Args, RemoveGroupPluginV1Args, RemoveGroupsFromGroupV1Args,
+    RemovePluginV1Args, RevokeCollectionPluginAuthorityV1Args, RevokeGroupDelegateV1Args,
+    RevokePluginAuthorityV1Args, TransferBatchV1Args, TransferV1Args, TransferV2Args,
     UpdateCollectionExternalPluginAdapterV1Args, UpdateCollectionInfoV1Args,
     UpdateCollectionPluginV1Args, UpdateCollectionV1Args, UpdateExternalPluginAdapterV1Args,
-    UpdateGroupV1Args, UpdatePluginV1Args, UpdateV1Args, UpdateV2Args,
+    UpdateGroupPluginV1Args, UpdateGroupV1Args, UpdatePluginV1Args, UpdateV1Args, UpdateV2Args,
     WriteCollectionExternalPluginAdapterDataV1Args, WriteExternalPluginAdapterDataV1Args,
 };
 
@@ -145,6 +150,8 @@ pub(crate) enum MplAssetInstruction {
 
     // Transfer an asset.
     /// Transfer an asset by changing its owner.
+    /// Every group listed by the Groups plugin of the asset or its collection must be passed in the remaining accounts
+    /// to enforce their freezes.
     #[account(0, writable, name="asset", desc = "The address of the asset")]
     #[account(1, optional, name="collection", desc = "The collection to which the asset belongs")]
     #[account(2, writable, signer, name="payer", desc = "The account paying for the storage fees")]
@@ -312,34 +319,40 @@ pub(crate) enum MplAssetInstruction {
     UpdateCollectionInfoV1(UpdateCollectionInfoV1Args),
 
     /// Add collections to a group.
+    /// The `GroupDelegateV1` account of a delegate may lead the remaining accounts.
     #[account(0, writable, name="group", desc = "The address of the group to modify")]
     #[account(1, writable, signer, name="payer", desc = "The account paying for storage fees")]
-    #[account(2, optional, signer, name="authority", desc = "The group update authority and collection update authority or delegate")]
+    #[account(2, optional, signer, name="authority", desc = "The group update authority or delegate and collection update authority or delegate")]
     #[account(3, name="system_program", desc = "The system program")]
     AddCollectionsToGroupV1(AddCollectionsToGroupV1Args),
 
     /// Remove collections from a group.
+    /// The `GroupDelegateV1` account of a delegate may lead the remaining accounts.
     #[account(0, writable, name="group", desc = "The address of the group to modify")]
     #[account(1, writable, signer, name="payer", desc = "The account paying for storage fees")]
-    #[account(2, optional, signer, name="authority", desc = "The group update authority and collection update authority or delegate")]
+    #[account(2, optional, signer, name="authority", desc = "The group update authority or delegate and collection update authority or delegate")]
     #[account(3, name="system_program", desc = "The system program")]
     RemoveCollectionsFromGroupV1(RemoveCollectionsFromGroupV1Args),
 
     /// Add assets to a group.
+    /// The `GroupDelegateV1` account of a delegate may lead the remaining accounts.
     #[account(0, writable, name="group", desc = "The address of the group to modify")]
     #[account(1, writable, signer, name="payer", desc = "The account paying for storage fees")]
-    #[account(2, optional, signer, name="authority", desc = "The group update authority and asset update authority or delegate")]
+    #[account(2, optional, signer, name="authority", desc = "The group update authority or delegate and asset update authority or delegate")]
     #[account(3, name="system_program", desc = "The system program")]
     AddAssetsToGroupV1(AddAssetsToGroupV1Args),
 
     /// Remove assets from a group.
+    /// The `GroupDelegateV1` account of a delegate may lead the remaining accounts.
     #[account(0, writable, name="group", desc = "The address of the group to modify")]
     #[account(1, writable, signer, name="payer", desc = "The account paying for storage fees")]
-    #[account(2, optional, signer, name="authority", desc = "The group update authority and asset update authority or delegate")]
+    #[account(2, optional, signer, name="authority", desc = "The group update authority or delegate and asset update authority or delegate")]
     #[account(3, name="system_program", desc = "The system program")]
     RemoveAssetsFromGroupV1(RemoveAssetsFromGroupV1Args),
 
     /// Add groups to a parent group.
+    /// The groups above the parent group may follow the child groups in the remaining accounts as an ancestry proof,
+    /// followed by the groups below the child groups.
     #[account(0, writable, name="parent_group", desc = "The address of the parent group to modify")]
     #[account(1, writable, signer, name="payer", desc = "The account paying for storage fees")]
     #[account(2, optional, signer, name="authority", desc = "The update authority of the parent and child groups")]
@@ -367,10 +380,154 @@ pub(crate) enum MplAssetInstruction {
     CloseGroupV1(CloseGroupV1Args),
 
     /// Update an existing Group account.
+    /// A new update authority takes effect immediately; `ProposeGroupUpdateAuthorityV1` hands it over once accepted.
     #[account(0, writable, name="group", desc = "The address of the group to update")]
     #[account(1, writable, signer, name="payer", desc = "The account paying for the storage fees")]
     #[account(2, optional, signer, name="authority", desc = "The update authority of the group")]
     #[account(3, optional, name="new_update_authority", desc = "The new update authority of the group")]
     #[account(4, name="system_program", desc = "The system program")]
     UpdateGroupV1(UpdateGroupV1Args),
+
+    /// Transfer multiple assets to the same new owner.
+    /// The assets and their collections are passed as (asset, collection) pairs in the remaining accounts,
+    /// followed by every group listed by their Groups plugins to enforce their freezes.
+    #[account(0, writable, signer, name="payer", desc = "The account paying for the storage fees")]
+    #[account(1, optional, signer, name="authority", desc = "The owner or delegate of the assets")]
+    #[account(2, name="new_owner", desc = "The new owner to which to transfer the assets")]
+    #[account(3, optional, name="system_program", desc = "The system program")]
+    TransferBatchV1(TransferBatchV1Args),
+
+    /// Burn multiple assets that share the same collection.
+    /// The assets are passed in the remaining accounts.
+    #[account(0, optional, writable, name="collection", desc = "The collection to which the assets belong")]
+    #[account(1, writable, signer, name="payer", desc = "The account paying for the storage fees")]
+    #[account(2, optional, signer, name="authority", desc = "The owner or delegate of the assets")]
+    BurnBatchV1(BurnBatchV1Args),
+
+    /// Create multiple mpl-core Assets in the same collection.
+    /// The new asset accounts are passed as signers in the remaining accounts.
+    #[account(0, writable, name="collection", desc = "The collection to which the assets belong")]
+    #[account(1, optional, signer, name="authority", desc = "The authority signing for creation")]
+    #[account(2, writable, signer, name="payer", desc = "The account paying for the storage fees")]
+    #[account(3, name="system_program", desc = "The system program")]
+    CreateBatchV1(CreateBatchV1Args),
+
+    /// Transfer an asset by changing its owner, declaring the sale price of the transfer.
+    /// Royalties enforced on the sale price are not paid by the transfer: they must be paid to the creators by the
+    /// instructions preceding it, which are read from the instructions sysvar passed in the remaining accounts. Every
+    /// group listed by the Groups plugin of the asset or its collection must be passed there as well to enforce their freezes.
+    #[account(0, writable, name="asset", desc = "The address of the asset")]
+    #[account(1, optional, name="collection", desc = "The collection to which the asset belongs")]
+    #[account(2, writable, signer, name="payer", desc = "The account paying for the storage fees")]
+    #[account(3, optional, signer, name="authority", desc = "The owner or delegate of the asset")]
+    #[account(4, name="new_owner", desc = "The new owner to which to transfer the asset")]
+    #[account(5, name="system_program", desc = "The system program")]
+    #[account(6, optional, name="log_wrapper", desc = "The SPL Noop Program")]
+    TransferV2(TransferV2Args),
+
+    /// Print a numbered edition of a master edition collection.
+    /// The edition number is assigned from the number of assets minted into the collection.
+    #[account(0, writable, signer, name="asset", desc = "The address of the new edition")]
+    #[account(1, writable, name="collection", desc = "The master edition collection")]
+    #[account(2, optional, signer, name="authority", desc = "The authority signing for creation")]
+    #[account(3, writable, signer, name="payer", desc = "The account paying for the storage fees")]
+    #[account(4, optional, name="owner", desc = "The owner of the new edition. Defaults to the payer if not present.")]
+    #[account(5, name="system_program", desc = "The system program")]
+    #[account(6, optional, name="log_wrapper", desc = "The SPL Noop Program")]
+    PrintEditionV1(PrintEditionV1Args),
+
+    /// Execute multiple instructions on behalf of the owner.
+    /// The programs and accounts of the instructions are passed in the remaining accounts.
+    #[account(0, writable, name="asset", desc = "The address of the asset")]
+    #[account(1, optional, writable, name="collection", desc = "The collection to which the asset belongs")]
+    #[account(2, name="asset_signer", desc = "The signing PDA for the asset")]
+    #[account(3, writable, optional_signer, name="payer", desc = "The account paying for the storage fees")]
+    #[account(4, optional, signer, name="authority", desc = "The owner or delegate of the asset")]
+    #[account(5, name="system_program", desc = "The system program")]
+    ExecuteBatchV1(ExecuteBatchV1Args),
+
+    /// Execute an instruction on behalf of the collection.
+    #[account(0, writable, name="collection", desc = "The address of the collection")]
+    #[account(1, name="collection_signer", desc = "The signing PDA for the collection")]
+    #[account(2, writable, optional_signer, name="payer", desc = "The account paying for the storage fees")]
+    #[account(3, optional, signer, name="authority", desc = "The update authority or update delegate of the collection")]
+    #[account(4, name="system_program", desc = "The system program")]
+    #[account(5, name="program_id", desc = "The program id of the instruction")]
+    ExecuteCollectionV1(ExecuteCollectionV1Args),
+
+    /// Add a plugin or external plugin adapter to a group.
+    #[account(0, writable, name="group", desc = "The address of the group")]
+    #[account(1, writable, signer, name="payer", desc = "The account paying for the storage fees")]
+    #[account(2, optional, signer, name="authority", desc = "The update authority of the group")]
+    #[account(3, name="system_program", desc = "The system program")]
+    AddGroupPluginV1(AddGroupPluginV1Args),
+
+    /// Update a plugin or external plugin adapter on a group.
+    #[account(0, writable, name="group", desc = "The address of the group")]
+    #[account(1, writable, signer, name="payer", desc = "The account paying for the storage fees")]
+    #[account(2, optional, signer, name="authority", desc = "The authority of the plugin")]
+    #[account(3, name="system_program", desc = "The system program")]
+    UpdateGroupPluginV1(UpdateGroupPluginV1Args),
+
+    /// Remove a plugin or external plugin adapter from a group.
+    #[account(0, writable, name="group", desc = "The address of the group")]
+    #[account(1, writable, signer, name="payer", desc = "The account receiving reclaimed lamports")]
+    #[account(2, optional, signer, name="authority", desc = "The update authority of the group")]
+    #[account(3, name="system_program", desc = "The system program")]
+    RemoveGroupPluginV1(RemoveGroupPluginV1Args),
+
+    /// Approve a delegate to add and remove the assets and collections of a group.
+    /// The delegate lapses once the update authority of the group is handed over.
+    #[account(0, name="group", desc = "The address of the group")]
+    #[account(1, writable, name="group_delegate", desc = "The delegate account of the group")]
+    #[account(2, writable, signer, name="payer", desc = "The account paying for the storage fees")]
+    #[account(3, optional, signer, name="authority", desc = "The update authority of the group")]
+    #[account(4, name="delegate", desc = "The delegate to approve")]
+    #[account(5, name="system_program", desc = "The system program")]
+    ApproveGroupDelegateV1(ApproveGroupDelegateV1Args),
+
+    /// Revoke a delegate of a group.
+    #[account(0, name="group", desc = "The address of the group")]
+    #[account(1, writable, name="group_delegate", desc = "The delegate account of the group")]
+    #[account(2, writable, signer, name="payer", desc = "The account receiving reclaimed lamports")]
+    #[account(3, optional, signer, name="authority", desc = "The update authority of the group or the delegate")]
+    RevokeGroupDelegateV1(RevokeGroupDelegateV1Args),
+
+    /// Propose a new update authority for a group, which takes effect once accepted.
+    #[account(0, name="group", desc = "The address of the group")]
+    #[account(1, writable, name="group_authority_proposal", desc = "The update authority proposal account of the group")]
+    #[account(2, writable, signer, name="payer", desc = "The account paying for the storage fees")]
+    #[account(3, optional, signer, name="authority", desc = "The update authority of the group")]
+    #[account(4, name="new_update_authority", desc = "The proposed update authority of the group")]
+    #[account(5, name="system_program", desc = "The system program")]
+    ProposeGroupUpdateAuthorityV1(ProposeGroupUpdateAuthorityV1Args),
+
+    /// Accept a proposal to become the update authority of a group.
+    #[account(0, writable, name="group", desc = "The address of the group")]
+    #[account(1, writable, name="group_authority_proposal", desc = "The update authority proposal account of the group")]
+    #[account(2, writable, signer, name="payer", desc = "The account paying for the storage fees")]
+    #[account(3, signer, name="new_update_authority", desc = "The proposed update authority of the group")]
+    #[account(4, name="system_program", desc = "The system program")]
+    #[account(5, writable, name="proposal_payer", desc = "The account that paid for the proposal, receiving its reclaimed lamports")]
+    AcceptGroupUpdateAuthorityV1(AcceptGroupUpdateAuthorityV1Args),
+
+    /// Close a Group account, first detaching the members passed in the remaining accounts.
+    /// With `detach_only` the group is kept open so that large groups can be closed over several transactions.
+    #[account(0, writable, name="group", desc = "The address of the group to close")]
+    #[account(1, writable, signer, name="payer", desc = "The account receiving reclaimed lamports")]
+    #[account(2, optional, signer, name="authority", desc = "The update authority of the group")]
+    #[account(3, name="system_program", desc = "The system program")]
+    CloseGroupV2(CloseGroupV2Args),
+
+    /// Move an asset from its collection to another collection.
+    /// The plugins of the new collection must accept the plugins of the asset.
+    #[account(0, writable, name="asset", desc = "The address of the asset")]
+    #[account(1, writable, name="collection", desc = "The collection the asset is moved out of")]
+    #[account(2, writable, name="new_collection", desc = "The collection the asset is moved into")]
+    #[account(3, writable, signer, name="payer", desc = "The account paying for the storage fees")]
+    #[account(4, optional, signer, name="authority", desc = "The update authority or update delegate of the collection")]
+    #[account(5, optional, signer, name="new_collection_authority", desc = "The update authority or update delegate of the new collection. Defaults to the authority")]
+    #[account(6, name="system_program", desc = "The system program")]
+    #[account(7, optional, name="log_wrapper", desc = "The SPL Noop Program")]
+    MoveToCollectionV1(MoveToCollectionV1Args),
 }
@@ -50,7 +50,7 @@ impl PluginValidation for AppData {
 impl From<&AppDataInitInfo> for AppData {
     fn from(init_info: &AppDataInitInfo) -> Self {
         Self {
-            data_authority: init_info.data_authority,
+            data_authority: init_info.data_authority.clone(),
             schema: init_info.schema.unwrap_or_default(),
         }
     }
@@ -17,7 +17,7 @@ impl PluginValidation for DataSection {}
 impl From<&DataSectionInitInfo> for DataSection {
     fn from(init_info: &DataSectionInitInfo) -> Self {
         Self {
-            parent_key: init_info.parent_key,
+            parent_key: init_info.parent_key.clone(),
             schema: init_info.schema,
         }
     }
@@ -59,7 +59,7 @@ impl From<&LifecycleHookInitInfo> for LifecycleHook {
         Self {
             hooked_program: init_info.hooked_program,
             extra_accounts: init_info.extra_accounts.clone(),
-            data_authority: init_info.data_authority,
+            data_authority: init_info.data_authority.clone(),
             schema: init_info.schema.unwrap_or_default(),
         }
     }
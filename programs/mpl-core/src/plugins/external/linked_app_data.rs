@@ -45,7 +45,7 @@ impl PluginValidation for LinkedAppData {
 impl From<&LinkedAppDataInitInfo> for LinkedAppData {
     fn from(init_info: &LinkedAppDataInitInfo) -> Self {
         Self {
-            data_authority: init_info.data_authority,
+            data_authority: init_info.data_authority.clone(),
             schema: init_info.schema.unwrap_or_default(),
         }
     }
@@ -853,7 +853,7 @@ pub enum ExternalPluginAdapterKey {
 /// Key to point to the plugin that manages this data section.
 #[repr(C)]
 #[derive(
-    Clone, Copy, Debug, BorshSerialize, BorshDeserialize, Eq, PartialEq, EnumCount, PartialOrd, Ord,
+    Clone, Debug, BorshSerialize, BorshDeserialize, Eq, PartialEq, EnumCount, PartialOrd, Ord,
 )]
 pub enum LinkedDataKey {
     /// Lifecycle Hook.
@@ -921,16 +921,16 @@ impl From<&ExternalPluginAdapterInitInfo> for ExternalPluginAdapterKey {
                 ExternalPluginAdapterKey::Oracle(init_info.base_address)
             }
             ExternalPluginAdapterInitInfo::AppData(init_info) => {
-                ExternalPluginAdapterKey::AppData(init_info.data_authority)
+                ExternalPluginAdapterKey::AppData(init_info.data_authority.clone())
             }
             ExternalPluginAdapterInitInfo::LinkedLifecycleHook(init_info) => {
                 ExternalPluginAdapterKey::LinkedLifecycleHook(init_info.hooked_program)
             }
             ExternalPluginAdapterInitInfo::LinkedAppData(init_info) => {
-                ExternalPluginAdapterKey::LinkedAppData(init_info.data_authority)
+                ExternalPluginAdapterKey::LinkedAppData(init_info.data_authority.clone())
             }
             ExternalPluginAdapterInitInfo::DataSection(init_info) => {
-                ExternalPluginAdapterKey::DataSection(init_info.parent_key)
+                ExternalPluginAdapterKey::DataSection(init_info.parent_key.clone())
             }
             ExternalPluginAdapterInitInfo::AgentIdentity(_) => {
                 ExternalPluginAdapterKey::AgentIdentity
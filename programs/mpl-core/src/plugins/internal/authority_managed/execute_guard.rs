@@ -0,0 +1,114 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{msg, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{error::MplCoreError, plugins::PluginValidation, state::DataBlob};
+
+/// A program the asset signer is allowed to invoke, and how many lamports it may spend doing so
+/// in each execute instruction.
+#[repr(C)]
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, Debug, PartialEq, Eq)]
+pub struct ExecuteGuardProgram {
+    /// The address of the program.
+    pub program_id: Pubkey, // 32
+    /// The maximum number of lamports the asset signer may spend in calls to the program
+    /// within a single execute instruction. Spending is not tracked across instructions, so
+    /// every execute instruction may spend up to this limit again.
+    pub instruction_spend_limit: Option<u64>, // 1 + 8
+}
+
+impl ExecuteGuardProgram {
+    const BASE_LEN: usize = 32 // The program id
+    + 1; // The spend limit option
+}
+
+impl DataBlob for ExecuteGuardProgram {
+    fn len(&self) -> usize {
+        Self::BASE_LEN + self.instruction_spend_limit.map_or(0, |_| 8)
+    }
+}
+
+/// The ExecuteGuard plugin restricts the programs the asset signer may invoke through the
+/// **Execute** lifecycle event to an allowlist. When present on a collection it applies to
+/// every asset in the collection, and an asset in a guarded collection must pass both guards.
+/// The default authority for this plugin is the update authority.
+#[repr(C)]
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug, Default, PartialEq, Eq)]
+pub struct ExecuteGuard {
+    /// The programs the asset signer is allowed to invoke.
+    pub allowed_programs: Vec<ExecuteGuardProgram>, // 4
+}
+
+impl ExecuteGuard {
+    const BASE_LEN: usize = 4; // The allowed programs length
+
+    /// Check that the asset signer may invoke the program, returning the program's spend limit.
+    pub(crate) fn check_program(&self, program_id: &Pubkey) -> Result<Option<u64>, ProgramError> {
+        match self
+            .allowed_programs
+            .iter()
+            .find(|allowed| allowed.program_id == *program_id)
+        {
+            Some(allowed) => Ok(allowed.instruction_spend_limit),
+            None => {
+                msg!("ExecuteGuard: Program {} is not allowed", program_id);
+                Err(MplCoreError::ProgramNotAllowed.into())
+            }
+        }
+    }
+}
+
+impl DataBlob for ExecuteGuard {
+    fn len(&self) -> usize {
+        Self::BASE_LEN
+            + self
+                .allowed_programs
+                .iter()
+                .map(|allowed| allowed.len())
+                .sum::<usize>()
+    }
+}
+
+impl PluginValidation for ExecuteGuard {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_execute_guard_default_len() {
+        let execute_guard = ExecuteGuard::default();
+        let serialized = borsh::to_vec(&execute_guard).unwrap();
+        assert_eq!(serialized.len(), execute_guard.len());
+    }
+
+    #[test]
+    fn test_execute_guard_len() {
+        let execute_guard = ExecuteGuard {
+            allowed_programs: vec![
+                ExecuteGuardProgram {
+                    program_id: Pubkey::default(),
+                    instruction_spend_limit: None,
+                },
+                ExecuteGuardProgram {
+                    program_id: Pubkey::default(),
+                    instruction_spend_limit: Some(1_000_000),
+                },
+            ],
+        };
+        let serialized = borsh::to_vec(&execute_guard).unwrap();
+        assert_eq!(serialized.len(), execute_guard.len());
+    }
+
+    #[test]
+    fn test_execute_guard_check_program() {
+        let allowed = Pubkey::new_unique();
+        let execute_guard = ExecuteGuard {
+            allowed_programs: vec![ExecuteGuardProgram {
+                program_id: allowed,
+                instruction_spend_limit: Some(10),
+            }],
+        };
+        assert_eq!(execute_guard.check_program(&allowed).unwrap(), Some(10));
+        assert!(execute_guard.check_program(&Pubkey::new_unique()).is_err());
+    }
+}
@@ -1,14 +1,26 @@
 use crate::{
-    plugins::{abstain, reject, PluginValidation, PluginValidationContext, ValidationResult},
-    state::{DataBlob, Key},
+    error::MplCoreError,
+    plugins::{
+        abstain, fetch_plugin, reject, PermanentFreezeDelegate, PluginType, PluginValidation,
+        PluginValidationContext, ValidationResult,
+    },
+    state::{Authority, CollectionV1, DataBlob, GroupV1, Key},
+    utils::load_key,
 };
 use borsh::{BorshDeserialize, BorshSerialize};
-use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
 
 /// Groups plugin for collections. Stores the immediate parent group accounts this collection
 /// belongs to. Relationship updates are handled by specialized group instructions, and this
 /// plugin overrides `validate_burn` to reject burning the group member itself while the group
 /// set is non-empty.
+///
+/// A group frozen with a `PermanentFreezeDelegate` plugin freezes the transfer of its members
+/// and of the assets of its member collections, even when a delegate approves the transfer.
+/// Every group listed by the asset, and by its collection, must be passed as a remaining
+/// account of a transfer, so leaving out a frozen group cannot skip its freeze. The freeze does
+/// not change burning: the owner of an asset can always burn it, while a collection cannot be
+/// burned while it belongs to a group.
 #[repr(C)]
 #[derive(Clone, BorshSerialize, BorshDeserialize, Debug, PartialEq, Eq, Default)]
 pub struct Groups {
@@ -18,6 +30,59 @@ pub struct Groups {
 
 impl Groups {
     const BASE_LEN: usize = 4; // length of the groups vector
+
+    /// Whether the group account is a group frozen by its `PermanentFreezeDelegate` plugin.
+    fn is_group_frozen(group_info: &AccountInfo) -> Result<bool, ProgramError> {
+        // A closed group can no longer freeze its members.
+        if group_info.lamports() == 0 {
+            return Ok(false);
+        }
+
+        if group_info.owner != &crate::ID {
+            msg!(
+                "Error: Group {} is not owned by the program",
+                group_info.key
+            );
+            return Err(MplCoreError::IncorrectAccount.into());
+        }
+
+        match load_key(group_info, 0)? {
+            Key::Uninitialized => return Ok(false),
+            Key::GroupV1 => (),
+            _ => {
+                msg!("Error: Account {} is not a group", group_info.key);
+                return Err(MplCoreError::IncorrectAccount.into());
+            }
+        }
+
+        match fetch_plugin::<GroupV1, PermanentFreezeDelegate>(
+            group_info,
+            PluginType::PermanentFreezeDelegate,
+        ) {
+            Ok((_, permanent_freeze_delegate, _)) => Ok(permanent_freeze_delegate.frozen),
+            Err(_) => Ok(false),
+        }
+    }
+
+    /// Fail when one of the groups is frozen or its account is missing from `accounts`.
+    fn check_group_freezes(&self, accounts: &[AccountInfo]) -> Result<(), ProgramError> {
+        for group in self.groups.iter() {
+            let group_info = accounts
+                .iter()
+                .find(|account| account.key == group)
+                .ok_or_else(|| {
+                    msg!("Error: Missing group account {}", group);
+                    MplCoreError::MissingGroupAccount
+                })?;
+
+            if Self::is_group_frozen(group_info)? {
+                msg!("Error: Group {} is frozen", group);
+                return Err(MplCoreError::GroupFrozen.into());
+            }
+        }
+
+        Ok(())
+    }
 }
 
 impl DataBlob for Groups {
@@ -38,8 +103,11 @@ impl PluginValidation for Groups {
         // asset is the burn target (`asset_info` is set), and burning the asset does not remove
         // the collection from any group, so we abstain.
         let member_is_burn_target = match ctx.self_key {
-            // Plugin lives on the asset being burned (asset added directly to a group).
-            Key::AssetV1 => true,
+            // Plugin lives on the asset being burned (asset added directly to a group). The
+            // owner can still burn the asset, which leaves the group to detach it afterwards.
+            Key::AssetV1 => !ctx
+                .resolved_authorities
+                .is_some_and(|authorities| authorities.contains(&Authority::Owner)),
             // Plugin lives on the collection. This is the burn target only when no asset is
             // being burned (i.e. a collection burn).
             Key::CollectionV1 => ctx.asset_info.is_none(),
@@ -52,4 +120,24 @@ impl PluginValidation for Groups {
             abstain!()
         }
     }
+    fn validate_transfer(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        self.check_group_freezes(ctx.accounts)?;
+
+        // The Groups plugin of the asset takes the place of the one of its collection in the
+        // lifecycle checks, so the groups of the collection are checked here as well.
+        if ctx.self_key == Key::AssetV1 {
+            if let Some(collection_info) = ctx.collection_info {
+                if let Ok((_, collection_groups, _)) =
+                    fetch_plugin::<CollectionV1, Groups>(collection_info, PluginType::Groups)
+                {
+                    collection_groups.check_group_freezes(ctx.accounts)?;
+                }
+            }
+        }
+
+        abstain!()
+    }
 }
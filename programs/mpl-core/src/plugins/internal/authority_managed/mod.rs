@@ -1,17 +1,25 @@
 mod add_blocker;
 mod attributes;
+mod execute_guard;
 mod groups;
 mod immutable_metadata;
 mod master_edition;
+mod multisig;
 mod royalties;
+mod soulbound;
+mod time_lock;
 mod update_delegate;
 mod verified_creators;
 
 pub use add_blocker::*;
 pub use attributes::*;
+pub use execute_guard::*;
 pub use groups::*;
 pub use immutable_metadata::*;
 pub use master_edition::*;
+pub use multisig::*;
 pub use royalties::*;
+pub use soulbound::*;
+pub use time_lock::*;
 pub use update_delegate::*;
 pub use verified_creators::*;
@@ -0,0 +1,84 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{
+    plugins::{abstain, Plugin, PluginValidation, PluginValidationContext, ValidationResult},
+    state::{validate_multisig, DataBlob},
+};
+
+/// The multisig plugin makes a set of signers the update authority of a collection. While the
+/// plugin is present, the collection's update authority address can no longer act alone and
+/// at least `threshold` distinct signers must sign instead. This plugin is only available on
+/// collections; assets use `UpdateAuthority::Multisig`. The default authority for this plugin
+/// is the update authority.
+#[repr(C)]
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug, Default, PartialEq, Eq)]
+pub struct Multisig {
+    /// The addresses that can sign for the update authority.
+    pub signers: Vec<Pubkey>, // 4
+    /// The number of distinct signers required.
+    pub threshold: u8, // 1
+}
+
+impl Multisig {
+    const BASE_LEN: usize = 4 // The signers length
+    + 1; // The threshold
+}
+
+impl DataBlob for Multisig {
+    fn len(&self) -> usize {
+        Self::BASE_LEN + self.signers.len() * 32
+    }
+}
+
+impl PluginValidation for Multisig {
+    fn validate_create(
+        &self,
+        _ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        validate_multisig(&self.signers, self.threshold)?;
+        abstain!()
+    }
+
+    fn validate_add_plugin(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        if let Some(Plugin::Multisig(multisig)) = ctx.target_plugin {
+            validate_multisig(&multisig.signers, multisig.threshold)?;
+        }
+        abstain!()
+    }
+
+    fn validate_update_plugin(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        if let Some(Plugin::Multisig(multisig)) = ctx.target_plugin {
+            validate_multisig(&multisig.signers, multisig.threshold)?;
+        }
+        abstain!()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_multisig_default_len() {
+        let multisig = Multisig::default();
+        let serialized = borsh::to_vec(&multisig).unwrap();
+        assert_eq!(serialized.len(), multisig.len());
+    }
+
+    #[test]
+    fn test_multisig_len() {
+        let multisig = Multisig {
+            signers: vec![Pubkey::default(), Pubkey::default(), Pubkey::default()],
+            threshold: 2,
+        };
+        let serialized = borsh::to_vec(&multisig).unwrap();
+        assert_eq!(serialized.len(), multisig.len());
+    }
+}
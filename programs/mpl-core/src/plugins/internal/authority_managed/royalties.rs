@@ -1,14 +1,15 @@
 use std::collections::HashSet;
 
 use borsh::{BorshDeserialize, BorshSerialize};
-use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
 
 use crate::error::MplCoreError;
 
 use crate::plugins::{
-    abstain, reject, Plugin, PluginValidation, PluginValidationContext, ValidationResult,
+    abstain, fetch_wrapped_plugin, find_asset_payments, reject, Plugin, PluginType,
+    PluginValidation, PluginValidationContext, ValidationResult,
 };
-use crate::state::DataBlob;
+use crate::state::{AssetV1, CollectionV1, DataBlob, Key, SolanaAccount};
 
 /// The creator on an asset and whether or not they are verified.
 #[derive(Clone, BorshSerialize, BorshDeserialize, Debug, PartialEq, Eq)]
@@ -39,10 +40,48 @@ pub enum RuleSet {
     ProgramAllowList(Vec<Pubkey>), // 4
     /// Deny list of programs that are not allowed to transfer, receive, or send the asset.
     ProgramDenyList(Vec<Pubkey>), // 4
+    /// Royalties must be paid on the price of the asset's `Listing`, or otherwise on the non-zero
+    /// sale price declared with `TransferV2`. The royalty owed to each
+    /// creator must be paid by the instructions immediately preceding the transfer, which name
+    /// the asset as their last account like `Listing` payments do. Transfers that are not sales
+    /// or that underpay a creator are rejected.
+    EnforcePayment, // 1
+    /// Allow list of wallets that are allowed to receive the asset.
+    WalletAllowList(Vec<Pubkey>), // 4
+    /// Deny list of wallets that are not allowed to own, transfer, or receive the asset.
+    WalletDenyList(Vec<Pubkey>), // 4
+    /// A combination of rule sets that must all pass.
+    Composite(Vec<RuleSet>), // 4
 }
 
 impl RuleSet {
     const BASE_LEN: usize = 1; // The rule set discriminator
+
+    /// The maximum number of `Composite` rule sets nested within each other.
+    const MAX_COMPOSITE_DEPTH: usize = 2;
+
+    /// Returns the number of `Composite` rule sets nested within each other.
+    fn composite_depth(&self) -> usize {
+        match self {
+            RuleSet::Composite(rule_sets) => {
+                1 + rule_sets
+                    .iter()
+                    .map(RuleSet::composite_depth)
+                    .max()
+                    .unwrap_or(0)
+            }
+            _ => 0,
+        }
+    }
+
+    /// Returns true if the rule set requires royalties to be paid on a declared sale price.
+    pub(crate) fn enforces_payment(&self) -> bool {
+        match self {
+            RuleSet::EnforcePayment => true,
+            RuleSet::Composite(rule_sets) => rule_sets.iter().any(RuleSet::enforces_payment),
+            _ => false,
+        }
+    }
 }
 
 impl DataBlob for RuleSet {
@@ -51,7 +90,15 @@ impl DataBlob for RuleSet {
             + match self {
                 RuleSet::ProgramAllowList(allow_list) => 4 + allow_list.len() * 32,
                 RuleSet::ProgramDenyList(deny_list) => 4 + deny_list.len() * 32,
-                RuleSet::None => 0,
+                RuleSet::WalletAllowList(allow_list) => 4 + allow_list.len() * 32,
+                RuleSet::WalletDenyList(deny_list) => 4 + deny_list.len() * 32,
+                RuleSet::Composite(rule_sets) => {
+                    4 + rule_sets
+                        .iter()
+                        .map(|rule_set| rule_set.len())
+                        .sum::<usize>()
+                }
+                RuleSet::None | RuleSet::EnforcePayment => 0,
             }
     }
 }
@@ -76,7 +123,140 @@ impl DataBlob for Royalties {
     }
 }
 
-fn validate_royalties(royalties: &Royalties) -> Result<ValidationResult, ProgramError> {
+impl Royalties {
+    /// Returns the royalty owed to each creator on the given sale price.
+    pub(crate) fn creator_payments(
+        &self,
+        sale_price: u64,
+    ) -> Result<Vec<(Pubkey, u64)>, ProgramError> {
+        self.creators
+            .iter()
+            .map(|creator| {
+                let amount = (sale_price as u128)
+                    .checked_mul(self.basis_points as u128)
+                    .and_then(|amount| amount.checked_mul(creator.percentage as u128))
+                    .ok_or(MplCoreError::NumericalOverflow)?
+                    / 1_000_000;
+                Ok((creator.address, amount as u64))
+            })
+            .collect()
+    }
+
+    /// Returns true if the royalty owed to each creator on the sale price was paid by the
+    /// instructions immediately preceding the transfer.
+    fn is_paid(&self, ctx: &PluginValidationContext) -> Result<bool, ProgramError> {
+        let asset_info = ctx.asset_info.ok_or(MplCoreError::InvalidAsset)?;
+
+        // A listed asset is sold for its listing price, so a lower price cannot be declared
+        // for it. Otherwise the sale price declared with `TransferV2` is paid in lamports, and a
+        // price of zero is not a sale.
+        let (sale_price, mint) =
+            match fetch_wrapped_plugin::<AssetV1>(asset_info, None, PluginType::Listing) {
+                Ok((_, Plugin::Listing(listing))) => (listing.price, listing.mint),
+                _ => match ctx.sale_price {
+                    Some(sale_price) if sale_price > 0 => (sale_price, None),
+                    _ => {
+                        msg!("Royalties: A sale price must be declared");
+                        return Ok(false);
+                    }
+                },
+            };
+
+        let owed = self.creator_payments(sale_price)?;
+        let creators: Vec<Pubkey> = owed.iter().map(|(creator, _)| *creator).collect();
+        let paid =
+            match find_asset_payments(ctx.accounts, asset_info.key, mint.as_ref(), &creators)? {
+                Some(paid) => paid,
+                None => {
+                    msg!("Royalties: The instructions sysvar is required to check payments");
+                    return Ok(false);
+                }
+            };
+
+        for ((creator, owed), (_, paid)) in owed.iter().zip(paid.iter()) {
+            if paid < owed {
+                msg!(
+                    "Royalties: Creator {} is owed {} but was paid {}",
+                    creator,
+                    owed,
+                    paid
+                );
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// Validates a transfer against the given rule set.
+    fn validate_rule_set(
+        &self,
+        rule_set: &RuleSet,
+        ctx: &PluginValidationContext,
+        new_owner: &AccountInfo,
+    ) -> Result<ValidationResult, ProgramError> {
+        match rule_set {
+            RuleSet::None => abstain!(),
+            RuleSet::ProgramAllowList(allow_list) => {
+                if allow_list.contains(ctx.authority_info.owner)
+                    && allow_list.contains(new_owner.owner)
+                {
+                    abstain!()
+                } else {
+                    reject!()
+                }
+            }
+            RuleSet::ProgramDenyList(deny_list) => {
+                if deny_list.contains(ctx.authority_info.owner)
+                    || deny_list.contains(new_owner.owner)
+                {
+                    reject!()
+                } else {
+                    abstain!()
+                }
+            }
+            RuleSet::EnforcePayment => {
+                if self.is_paid(ctx)? {
+                    abstain!()
+                } else {
+                    reject!()
+                }
+            }
+            RuleSet::WalletAllowList(allow_list) => {
+                if allow_list.contains(new_owner.key) {
+                    abstain!()
+                } else {
+                    reject!()
+                }
+            }
+            RuleSet::WalletDenyList(deny_list) => {
+                let asset_info = ctx.asset_info.ok_or(MplCoreError::InvalidAsset)?;
+                let owner = AssetV1::load(asset_info, 0)?.owner;
+                if deny_list.contains(ctx.authority_info.key)
+                    || deny_list.contains(&owner)
+                    || deny_list.contains(new_owner.key)
+                {
+                    reject!()
+                } else {
+                    abstain!()
+                }
+            }
+            RuleSet::Composite(rule_sets) => {
+                for rule_set in rule_sets {
+                    if self.validate_rule_set(rule_set, ctx, new_owner)?
+                        == ValidationResult::Rejected
+                    {
+                        return reject!();
+                    }
+                }
+
+                abstain!()
+            }
+        }
+    }
+}
+
+pub(crate) fn validate_royalties(royalties: &Royalties) -> Result<ValidationResult, ProgramError> {
     if royalties.basis_points > 10000 {
         // TODO propagate a more useful error
         return Err(MplCoreError::InvalidPluginSetting.into());
@@ -90,6 +270,9 @@ fn validate_royalties(royalties: &Royalties) -> Result<ValidationResult, Program
         // TODO propagate a more useful error
         return Err(MplCoreError::InvalidPluginSetting.into());
     }
+    if royalties.rule_set.composite_depth() > RuleSet::MAX_COMPOSITE_DEPTH {
+        return Err(MplCoreError::InvalidPluginSetting.into());
+    }
     // check unique creators array
     let mut seen_addresses = HashSet::new();
     if !royalties
@@ -117,27 +300,32 @@ impl PluginValidation for Royalties {
         ctx: &PluginValidationContext,
     ) -> Result<ValidationResult, ProgramError> {
         let new_owner = ctx.new_owner.ok_or(MplCoreError::MissingNewOwner)?;
-        match &self.rule_set {
-            RuleSet::None => abstain!(),
-            RuleSet::ProgramAllowList(allow_list) => {
-                if allow_list.contains(ctx.authority_info.owner)
-                    && allow_list.contains(new_owner.owner)
-                {
-                    abstain!()
-                } else {
-                    reject!()
-                }
-            }
-            RuleSet::ProgramDenyList(deny_list) => {
-                if deny_list.contains(ctx.authority_info.owner)
-                    || deny_list.contains(new_owner.owner)
+        let result = self.validate_rule_set(&self.rule_set, ctx, new_owner)?;
+        if result == ValidationResult::Rejected {
+            return reject!();
+        }
+
+        // Royalties on the asset override those on its collection, except that a payment
+        // enforced by the collection must still be made.
+        if ctx.self_key == Key::AssetV1 {
+            if let Some(collection_info) = ctx.collection_info {
+                if let Ok((_, Plugin::Royalties(collection_royalties))) =
+                    fetch_wrapped_plugin::<CollectionV1>(
+                        collection_info,
+                        None,
+                        PluginType::Royalties,
+                    )
                 {
-                    reject!()
-                } else {
-                    abstain!()
+                    if collection_royalties.rule_set.enforces_payment()
+                        && !collection_royalties.is_paid(ctx)?
+                    {
+                        return reject!();
+                    }
                 }
             }
         }
+
+        Ok(result)
     }
 
     fn validate_add_plugin(
@@ -198,6 +386,14 @@ mod tests {
         let rule_sets = vec![
             RuleSet::ProgramAllowList(vec![Pubkey::default()]),
             RuleSet::ProgramDenyList(vec![Pubkey::default(), Pubkey::default()]),
+            RuleSet::EnforcePayment,
+            RuleSet::WalletAllowList(vec![Pubkey::default()]),
+            RuleSet::WalletDenyList(vec![Pubkey::default(), Pubkey::default()]),
+            RuleSet::Composite(vec![
+                RuleSet::ProgramDenyList(vec![Pubkey::default()]),
+                RuleSet::WalletDenyList(vec![Pubkey::default()]),
+                RuleSet::EnforcePayment,
+            ]),
         ];
         for rule_set in rule_sets {
             let serialized = borsh::to_vec(&rule_set).unwrap();
@@ -205,6 +401,27 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_rule_set_composite_depth() {
+        let nested = RuleSet::Composite(vec![RuleSet::Composite(vec![RuleSet::EnforcePayment])]);
+        let royalties = Royalties {
+            basis_points: 500,
+            creators: vec![Creator {
+                address: Pubkey::default(),
+                percentage: 100,
+            }],
+            rule_set: nested.clone(),
+        };
+        assert_eq!(nested.composite_depth(), 2);
+        assert!(validate_royalties(&royalties).is_ok());
+
+        let too_deep = Royalties {
+            rule_set: RuleSet::Composite(vec![RuleSet::None, nested]),
+            ..royalties
+        };
+        assert!(validate_royalties(&too_deep).is_err());
+    }
+
     #[test]
     fn test_royalties_len() {
         let royalties = vec![
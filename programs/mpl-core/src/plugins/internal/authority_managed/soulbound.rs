@@ -0,0 +1,43 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::program_error::ProgramError;
+
+use crate::{
+    plugins::{reject, PluginValidation, PluginValidationContext, ValidationResult},
+    state::DataBlob,
+};
+
+/// The Soulbound plugin makes an asset non-transferable for as long as the plugin is present,
+/// while still allowing it to be burned and to execute. The rejection cannot be overridden by
+/// a permanent transfer delegate. The default authority for this plugin is the update authority.
+#[repr(C)]
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, Debug, Default, PartialEq, Eq)]
+pub struct Soulbound {}
+
+impl DataBlob for Soulbound {
+    fn len(&self) -> usize {
+        // Stateless data blob
+        0
+    }
+}
+
+impl PluginValidation for Soulbound {
+    fn validate_transfer(
+        &self,
+        _ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        solana_program::msg!("Soulbound: Rejected");
+        reject!()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_soulbound_len() {
+        let soulbound = Soulbound::default();
+        let serialized = borsh::to_vec(&soulbound).unwrap();
+        assert_eq!(serialized.len(), soulbound.len());
+    }
+}
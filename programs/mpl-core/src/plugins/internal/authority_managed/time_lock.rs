@@ -0,0 +1,130 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{clock::Clock, program_error::ProgramError, sysvar::Sysvar};
+
+use crate::{
+    error::MplCoreError,
+    plugins::{
+        abstain, reject, PluginType, PluginValidation, PluginValidationContext, ValidationResult,
+    },
+    state::{AssetV1, DataBlob, SolanaAccount, UpdateAuthority},
+};
+
+/// The TimeLock plugin makes an asset non-transferable and non-burnable until the unlock
+/// time. The plugin itself cannot be updated or removed and the asset cannot change
+/// collection before the unlock time, so the lock cannot be bypassed by its authority.
+/// The default authority for this plugin is the update authority.
+#[repr(C)]
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, Debug, Default, PartialEq, Eq)]
+pub struct TimeLock {
+    /// The unix timestamp at which the asset unlocks.
+    pub unlock_at: i64, // 8
+}
+
+impl TimeLock {
+    const BASE_LEN: usize = 8; // The unlock_at i64
+
+    /// Returns true if the current cluster time is before the unlock time.
+    fn is_locked(&self) -> Result<bool, ProgramError> {
+        Ok(Clock::get()?.unix_timestamp < self.unlock_at)
+    }
+}
+
+impl DataBlob for TimeLock {
+    fn len(&self) -> usize {
+        Self::BASE_LEN
+    }
+}
+
+impl PluginValidation for TimeLock {
+    fn validate_remove_plugin(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        if ctx.target_plugin.is_some()
+            && PluginType::from(ctx.target_plugin.unwrap()) == PluginType::TimeLock
+            && self.is_locked()?
+        {
+            solana_program::msg!("TimeLock: Rejected");
+            reject!()
+        } else {
+            abstain!()
+        }
+    }
+
+    fn validate_update_plugin(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        if ctx.target_plugin.is_some()
+            && PluginType::from(ctx.target_plugin.unwrap()) == PluginType::TimeLock
+            && self.is_locked()?
+        {
+            solana_program::msg!("TimeLock: Rejected");
+            reject!()
+        } else {
+            abstain!()
+        }
+    }
+
+    fn validate_update(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        // Moving the asset into, out of or between collections would let it shed
+        // or swap a lock inherited from its collection.
+        if let Some(new_asset_authority) = ctx.new_asset_authority {
+            let asset_info = ctx.asset_info.ok_or(MplCoreError::MissingAsset)?;
+            let asset = AssetV1::load(asset_info, 0)?;
+            let changes_collection = match (&asset.update_authority, new_asset_authority) {
+                (UpdateAuthority::Collection(current), UpdateAuthority::Collection(new)) => {
+                    current != new
+                }
+                (UpdateAuthority::Collection(_), _) | (_, UpdateAuthority::Collection(_)) => true,
+                _ => false,
+            };
+
+            if changes_collection && self.is_locked()? {
+                solana_program::msg!("TimeLock: Rejected");
+                return reject!();
+            }
+        }
+
+        abstain!()
+    }
+
+    fn validate_burn(
+        &self,
+        _ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        if self.is_locked()? {
+            solana_program::msg!("TimeLock: Rejected");
+            reject!()
+        } else {
+            abstain!()
+        }
+    }
+
+    fn validate_transfer(
+        &self,
+        _ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        if self.is_locked()? {
+            solana_program::msg!("TimeLock: Rejected");
+            reject!()
+        } else {
+            abstain!()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_time_lock_len() {
+        let time_lock = TimeLock { unlock_at: 1 };
+        let serialized = borsh::to_vec(&time_lock).unwrap();
+        assert_eq!(serialized.len(), time_lock.len());
+    }
+}
@@ -205,6 +205,25 @@ impl PluginValidation for UpdateDelegate {
         }
     }
 
+    fn validate_execute(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        // Update delegates can only execute on behalf of the collection, never for an asset.
+        if ctx.asset_info.is_none()
+            && ((ctx.resolved_authorities.is_some()
+                && ctx
+                    .resolved_authorities
+                    .unwrap()
+                    .contains(ctx.self_authority))
+                || self.additional_delegates.contains(ctx.authority_info.key))
+        {
+            approve!()
+        } else {
+            abstain!()
+        }
+    }
+
     fn validate_update_plugin(
         &self,
         ctx: &PluginValidationContext,
@@ -0,0 +1,69 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{msg, program_error::ProgramError};
+
+use crate::{
+    plugins::{abstain, approve, PluginValidation, PluginValidationContext, ValidationResult},
+    state::DataBlob,
+};
+
+use super::delegate_limits::{is_within_limits, limits_len};
+
+/// This plugin manages additional permissions to burn like the `BurnDelegate` plugin,
+/// with an approval that can be limited to a number of uses or to expire at a given time.
+#[repr(C)]
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, Default, Debug, PartialEq, Eq)]
+pub struct BurnDelegateV2 {
+    /// The unix timestamp after which the delegate can no longer burn the asset.
+    pub expires_at: Option<i64>, // 1 + 8
+    /// The number of uses the delegate has left.
+    pub max_uses: Option<u32>, // 1 + 4
+}
+
+impl DataBlob for BurnDelegateV2 {
+    fn len(&self) -> usize {
+        limits_len(&self.expires_at, &self.max_uses)
+    }
+}
+
+impl PluginValidation for BurnDelegateV2 {
+    fn validate_burn(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        if ctx.resolved_authorities.is_some()
+            && ctx
+                .resolved_authorities
+                .unwrap()
+                .contains(ctx.self_authority)
+        {
+            if !is_within_limits(&self.expires_at, &self.max_uses)? {
+                msg!("BurnDelegateV2: Delegate has expired or has no uses left");
+                return abstain!();
+            }
+
+            approve!()
+        } else {
+            abstain!()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_burn_delegate_v2_len() {
+        let burn_delegates = vec![
+            BurnDelegateV2::default(),
+            BurnDelegateV2 {
+                expires_at: Some(1_700_000_000),
+                max_uses: Some(1),
+            },
+        ];
+        for burn_delegate in burn_delegates {
+            let serialized = borsh::to_vec(&burn_delegate).unwrap();
+            assert_eq!(serialized.len(), burn_delegate.len());
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use solana_program::{clock::Clock, program_error::ProgramError, sysvar::Sysvar};
+
+/// Returns true if the delegate has neither expired nor used up its uses.
+pub(crate) fn is_within_limits(
+    expires_at: &Option<i64>,
+    max_uses: &Option<u32>,
+) -> Result<bool, ProgramError> {
+    if *max_uses == Some(0) {
+        return Ok(false);
+    }
+
+    match expires_at {
+        Some(expires_at) => Ok(Clock::get()?.unix_timestamp < *expires_at),
+        None => Ok(true),
+    }
+}
+
+/// The serialized size of the optional expiry and use limit of a delegate.
+pub(crate) fn limits_len(expires_at: &Option<i64>, max_uses: &Option<u32>) -> usize {
+    1 + expires_at.map_or(0, |_| 8) // The expiry
+    + 1 + max_uses.map_or(0, |_| 4) // The use limit
+}
@@ -0,0 +1,229 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{
+    error::MplCoreError,
+    plugins::{
+        abstain, approve, find_asset_payments, Plugin, PluginValidation, PluginValidationContext,
+        ValidationResult,
+    },
+    state::{AssetV1, DataBlob, SolanaAccount},
+};
+
+/// The listing plugin allows anybody to buy the asset for the listed price. The price is split
+/// between the recipients stored in the listing, such as the creators the seller pays
+/// royalties to, and the owner, who receives the rest. The listing is removed when the asset
+/// is transferred. The default authority for this plugin is the owner.
+///
+/// A transfer is approved when the instructions immediately preceding the transaction
+/// instruction that performs it pay each recipient their share and the owner the rest. Each
+/// payment is a system program `Transfer` of lamports, or an SPL token `TransferChecked` of the
+/// mint to the associated token account of the recipient, with the asset appended as the last
+/// account of the instruction. The system and token programs ignore the extra account, which
+/// ties the payment to the one asset being bought.
+#[repr(C)]
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug, Default, PartialEq, Eq)]
+pub struct Listing {
+    /// The price of the asset, in lamports or in base units of the mint.
+    pub price: u64, // 8
+    /// The SPL token mint the price is denominated in, or `None` for lamports.
+    pub mint: Option<Pubkey>, // 1 + 32
+    /// The recipients of a share of the price other than the owner.
+    pub recipients: Vec<ListingRecipient>, // 4 + len * 34
+}
+
+/// A recipient of a share of the listing price.
+#[repr(C)]
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug, Default, PartialEq, Eq)]
+pub struct ListingRecipient {
+    /// The address of the recipient.
+    pub address: Pubkey, // 32
+    /// The share of the price paid to the recipient, in basis points.
+    pub basis_points: u16, // 2
+}
+
+impl ListingRecipient {
+    const LEN: usize = 32 // The address
+    + 2; // The basis points
+}
+
+impl Listing {
+    const BASE_LEN: usize = 8 // The price
+    + 1 // The mint option
+    + 4; // The recipients length
+
+    /// Returns the amount owed to each recipient of the sale, keyed by recipient address.
+    fn required_payments(&self, seller: Pubkey) -> Result<Vec<(Pubkey, u64)>, ProgramError> {
+        let mut payments: Vec<(Pubkey, u64)> = Vec::with_capacity(self.recipients.len() + 1);
+        let mut paid_to_recipients = 0u64;
+        for recipient in &self.recipients {
+            let amount = ((self.price as u128)
+                .checked_mul(recipient.basis_points as u128)
+                .ok_or(MplCoreError::NumericalOverflow)?
+                / 10_000) as u64;
+            paid_to_recipients = paid_to_recipients
+                .checked_add(amount)
+                .ok_or(MplCoreError::NumericalOverflow)?;
+            add_payment(&mut payments, recipient.address, amount)?;
+        }
+
+        let seller_amount = self
+            .price
+            .checked_sub(paid_to_recipients)
+            .ok_or(MplCoreError::NumericalOverflow)?;
+        add_payment(&mut payments, seller, seller_amount)?;
+
+        payments.retain(|(_, amount)| *amount > 0);
+        Ok(payments)
+    }
+
+    /// Returns true if the instructions immediately preceding the current transaction
+    /// instruction pay the full listing price of the asset to the owner and the recipients.
+    fn is_paid(&self, ctx: &PluginValidationContext) -> Result<bool, ProgramError> {
+        let asset_info = ctx.asset_info.ok_or(MplCoreError::InvalidAsset)?;
+        let asset = AssetV1::load(asset_info, 0)?;
+        let required = self.required_payments(asset.owner)?;
+
+        let recipients: Vec<Pubkey> = required.iter().map(|(recipient, _)| *recipient).collect();
+        let paid = match find_asset_payments(
+            ctx.accounts,
+            asset_info.key,
+            self.mint.as_ref(),
+            &recipients,
+        )? {
+            Some(paid) => paid,
+            None => return Ok(false),
+        };
+
+        Ok(required
+            .iter()
+            .zip(paid.iter())
+            .all(|((_, owed), (_, paid))| paid >= owed))
+    }
+}
+
+impl DataBlob for Listing {
+    fn len(&self) -> usize {
+        Self::BASE_LEN + self.mint.map_or(0, |_| 32) + self.recipients.len() * ListingRecipient::LEN
+    }
+}
+
+fn add_payment(
+    payments: &mut Vec<(Pubkey, u64)>,
+    recipient: Pubkey,
+    amount: u64,
+) -> Result<(), ProgramError> {
+    match payments
+        .iter_mut()
+        .find(|(address, _)| *address == recipient)
+    {
+        Some((_, total)) => {
+            *total = total
+                .checked_add(amount)
+                .ok_or(MplCoreError::NumericalOverflow)?
+        }
+        None => payments.push((recipient, amount)),
+    }
+
+    Ok(())
+}
+
+fn validate_listing(listing: &Listing) -> Result<ValidationResult, ProgramError> {
+    if listing.price == 0 {
+        return Err(MplCoreError::InvalidPluginSetting.into());
+    }
+
+    // The recipients cannot be owed more than the price.
+    let total_basis_points = listing
+        .recipients
+        .iter()
+        .try_fold(0u16, |total, recipient| {
+            total.checked_add(recipient.basis_points)
+        });
+    if !matches!(total_basis_points, Some(total) if total <= 10_000) {
+        return Err(MplCoreError::InvalidPluginSetting.into());
+    }
+
+    abstain!()
+}
+
+impl PluginValidation for Listing {
+    fn validate_create(
+        &self,
+        _ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        validate_listing(self)
+    }
+
+    fn validate_add_plugin(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        match ctx.target_plugin {
+            Some(Plugin::Listing(listing)) => validate_listing(listing),
+            _ => abstain!(),
+        }
+    }
+
+    fn validate_update_plugin(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        match ctx.target_plugin {
+            Some(Plugin::Listing(listing)) => validate_listing(listing),
+            _ => abstain!(),
+        }
+    }
+
+    fn validate_transfer(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        if self.is_paid(ctx)? {
+            approve!()
+        } else {
+            abstain!()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_listing_default_len() {
+        let listing = Listing::default();
+        let serialized = borsh::to_vec(&listing).unwrap();
+        assert_eq!(serialized.len(), listing.len());
+    }
+
+    #[test]
+    fn test_listing_len() {
+        let listing = Listing {
+            price: 1_000_000,
+            mint: Some(Pubkey::default()),
+            recipients: vec![ListingRecipient::default(), ListingRecipient::default()],
+        };
+        let serialized = borsh::to_vec(&listing).unwrap();
+        assert_eq!(serialized.len(), listing.len());
+    }
+
+    #[test]
+    fn test_listing_required_payments() {
+        let seller = Pubkey::new_unique();
+        let creator = Pubkey::new_unique();
+        let listing = Listing {
+            price: 1_000_000,
+            mint: None,
+            recipients: vec![ListingRecipient {
+                address: creator,
+                basis_points: 500,
+            }],
+        };
+        assert_eq!(
+            listing.required_payments(seller).unwrap(),
+            vec![(creator, 50_000), (seller, 950_000)]
+        );
+    }
+}
@@ -1,11 +1,21 @@
 mod autograph;
 mod burn_delegate;
+mod burn_delegate_v2;
+mod delegate_limits;
 mod freeze_delegate;
 mod freeze_execute;
+mod listing;
+mod rental;
 mod transfer_delegate;
+mod transfer_delegate_v2;
 
 pub use autograph::*;
 pub use burn_delegate::*;
+pub use burn_delegate_v2::*;
+pub(crate) use delegate_limits::*;
 pub use freeze_delegate::*;
 pub use freeze_execute::*;
+pub use listing::*;
+pub use rental::*;
 pub use transfer_delegate::*;
+pub use transfer_delegate_v2::*;
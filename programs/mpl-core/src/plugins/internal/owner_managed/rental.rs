@@ -0,0 +1,165 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, program_error::ProgramError, pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+use crate::{
+    error::MplCoreError,
+    plugins::{
+        abstain, force_approve, reject, Plugin, PluginType, PluginValidation,
+        PluginValidationContext, ValidationResult,
+    },
+    state::{AssetV1, DataBlob, SolanaAccount},
+};
+
+/// The rental plugin allows the lender to lend the asset to the renter until the expiry slot.
+/// While the asset is rented it cannot be transferred, burned or have this plugin changed.
+/// Once the rental has expired anyone can transfer the asset back to the lender, after which
+/// the plugin is removed. The default authority for this plugin is the owner.
+#[repr(C)]
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, Debug, Default, PartialEq, Eq)]
+pub struct Rental {
+    /// The address the asset is returned to when the rental ends.
+    pub lender: Pubkey, // 32
+    /// The address the asset is lent to.
+    pub renter: Pubkey, // 32
+    /// The slot at which the rental expires.
+    pub expiry_slot: u64, // 8
+}
+
+impl Rental {
+    const BASE_LEN: usize = 32 // The lender
+    + 32 // The renter
+    + 8; // The expiry slot
+
+    /// Returns true if the rental has expired.
+    fn is_expired(&self) -> Result<bool, ProgramError> {
+        Ok(Clock::get()?.slot >= self.expiry_slot)
+    }
+
+    /// Returns true if the asset is currently held by the lender.
+    fn is_held_by_lender(&self, asset_info: Option<&AccountInfo>) -> Result<bool, ProgramError> {
+        let asset_info = asset_info.ok_or(MplCoreError::InvalidAsset)?;
+        Ok(AssetV1::load(asset_info, 0)?.owner == self.lender)
+    }
+
+    /// Validates a new rental, which can only be started by the lender while they own the
+    /// asset, for a renter other than the lender and an expiry slot in the future.
+    fn validate_rental(
+        &self,
+        asset_info: Option<&AccountInfo>,
+    ) -> Result<ValidationResult, ProgramError> {
+        if self.lender == self.renter
+            || self.is_expired()?
+            || !self.is_held_by_lender(asset_info)?
+        {
+            solana_program::msg!("Rental: Rejected");
+            reject!()
+        } else {
+            abstain!()
+        }
+    }
+}
+
+impl DataBlob for Rental {
+    fn len(&self) -> usize {
+        Self::BASE_LEN
+    }
+}
+
+impl PluginValidation for Rental {
+    fn validate_create(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        self.validate_rental(ctx.asset_info)
+    }
+
+    fn validate_add_plugin(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        match ctx.target_plugin {
+            Some(Plugin::Rental(rental)) => rental.validate_rental(ctx.asset_info),
+            _ => abstain!(),
+        }
+    }
+
+    fn validate_remove_plugin(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        if ctx.target_plugin.is_some()
+            && PluginType::from(ctx.target_plugin.unwrap()) == PluginType::Rental
+            && !self.is_held_by_lender(ctx.asset_info)?
+        {
+            solana_program::msg!("Rental: Rejected");
+            reject!()
+        } else {
+            abstain!()
+        }
+    }
+
+    fn validate_update_plugin(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        if ctx.target_plugin.is_some()
+            && PluginType::from(ctx.target_plugin.unwrap()) == PluginType::Rental
+            && !self.is_held_by_lender(ctx.asset_info)?
+        {
+            solana_program::msg!("Rental: Rejected");
+            reject!()
+        } else {
+            abstain!()
+        }
+    }
+
+    fn validate_burn(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        if self.is_held_by_lender(ctx.asset_info)? {
+            abstain!()
+        } else {
+            solana_program::msg!("Rental: Rejected");
+            reject!()
+        }
+    }
+
+    fn validate_transfer(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        let new_owner = ctx.new_owner.ok_or(MplCoreError::MissingNewOwner)?;
+
+        if self.is_held_by_lender(ctx.asset_info)? {
+            // The lender can only hand the asset over to the renter.
+            if *new_owner.key == self.renter {
+                abstain!()
+            } else {
+                solana_program::msg!("Rental: Rejected");
+                reject!()
+            }
+        } else if *new_owner.key == self.lender && self.is_expired()? {
+            // Anyone can return the asset to the lender once the rental has expired.
+            force_approve!()
+        } else {
+            solana_program::msg!("Rental: Rejected");
+            reject!()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rental_len() {
+        let rental = Rental::default();
+        let serialized = borsh::to_vec(&rental).unwrap();
+        assert_eq!(serialized.len(), rental.len());
+    }
+}
@@ -0,0 +1,92 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{msg, program_error::ProgramError};
+
+use crate::state::DataBlob;
+
+use crate::plugins::{
+    abstain, approve, PluginValidation, PluginValidationContext, ValidationResult,
+};
+
+use super::delegate_limits::{is_within_limits, limits_len};
+
+/// This plugin manages the ability to transfer an asset like the `TransferDelegate`
+/// plugin, with an approval that can be limited to a number of transfers or to
+/// expire at a given time.
+#[repr(C)]
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, Default, Debug, PartialEq, Eq)]
+pub struct TransferDelegateV2 {
+    /// The unix timestamp after which the delegate can no longer transfer the asset.
+    pub expires_at: Option<i64>, // 1 + 8
+    /// The number of transfers the delegate has left.
+    pub max_uses: Option<u32>, // 1 + 4
+}
+
+impl TransferDelegateV2 {
+    /// Use up one of the delegate's transfers, returning true if the plugin changed.
+    pub(crate) fn consume_use(&mut self) -> bool {
+        match self.max_uses {
+            Some(max_uses) => {
+                self.max_uses = Some(max_uses.saturating_sub(1));
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl DataBlob for TransferDelegateV2 {
+    fn len(&self) -> usize {
+        limits_len(&self.expires_at, &self.max_uses)
+    }
+}
+
+impl PluginValidation for TransferDelegateV2 {
+    fn validate_transfer(
+        &self,
+        ctx: &PluginValidationContext,
+    ) -> Result<ValidationResult, ProgramError> {
+        if ctx.resolved_authorities.is_some()
+            && ctx
+                .resolved_authorities
+                .unwrap()
+                .contains(ctx.self_authority)
+        {
+            if !is_within_limits(&self.expires_at, &self.max_uses)? {
+                msg!("TransferDelegateV2: Delegate has expired or has no uses left");
+                return abstain!();
+            }
+
+            return approve!();
+        }
+
+        abstain!()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transfer_delegate_v2_len() {
+        let transfer_delegates = vec![
+            TransferDelegateV2::default(),
+            TransferDelegateV2 {
+                expires_at: Some(1_700_000_000),
+                max_uses: None,
+            },
+            TransferDelegateV2 {
+                expires_at: None,
+                max_uses: Some(3),
+            },
+            TransferDelegateV2 {
+                expires_at: Some(1_700_000_000),
+                max_uses: Some(3),
+            },
+        ];
+        for transfer_delegate in transfer_delegates {
+            let serialized = borsh::to_vec(&transfer_delegate).unwrap();
+            assert_eq!(serialized.len(), transfer_delegate.len());
+        }
+    }
+}
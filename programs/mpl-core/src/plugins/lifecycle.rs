@@ -10,6 +10,7 @@ use crate::{
         PluginType, RegistryRecord,
     },
     state::{Authority, DataBlob, Key, UpdateAuthority},
+    utils::resolve_plugin_authorities,
 };
 
 /// Lifecycle permissions
@@ -25,6 +26,8 @@ pub enum CheckResult {
     None,
     /// Certain plugins can force approve a lifecycle action.
     CanForceApprove,
+    /// Certain plugins can reject a lifecycle action even when it has been force approved.
+    CanForceReject,
 }
 
 /// Lifecycle permissions for adapter, third party plugins.
@@ -107,6 +110,8 @@ impl PluginType {
             PluginType::Edition => CheckResult::CanReject,
             PluginType::BubblegumV2 => CheckResult::CanReject,
             PluginType::PermanentFreezeExecute => CheckResult::CanReject,
+            PluginType::TimeLock => CheckResult::CanReject,
+            PluginType::Rental => CheckResult::CanReject,
             // We default to CanReject because Plugins with Authority::None cannot be removed.
             _ => CheckResult::CanReject,
         }
@@ -146,6 +151,9 @@ impl PluginType {
             PluginType::UpdateDelegate => CheckResult::CanApprove,
             PluginType::Autograph => CheckResult::CanReject,
             PluginType::VerifiedCreators => CheckResult::CanReject,
+            PluginType::Listing => CheckResult::CanReject,
+            PluginType::Multisig => CheckResult::CanReject,
+            PluginType::Rental => CheckResult::CanReject,
             _ => CheckResult::None,
         }
     }
@@ -156,6 +164,7 @@ impl PluginType {
         match plugin_type {
             PluginType::ImmutableMetadata => CheckResult::CanReject,
             PluginType::UpdateDelegate => CheckResult::CanApprove,
+            PluginType::TimeLock => CheckResult::CanReject,
             _ => CheckResult::None,
         }
     }
@@ -167,7 +176,10 @@ impl PluginType {
             PluginType::BurnDelegate => CheckResult::CanApprove,
             PluginType::PermanentFreezeDelegate => CheckResult::CanReject,
             PluginType::PermanentBurnDelegate => CheckResult::CanApprove,
+            PluginType::BurnDelegateV2 => CheckResult::CanApprove,
             PluginType::Groups => CheckResult::CanReject,
+            PluginType::TimeLock => CheckResult::CanForceReject,
+            PluginType::Rental => CheckResult::CanReject,
             _ => CheckResult::None,
         }
     }
@@ -180,6 +192,12 @@ impl PluginType {
             PluginType::TransferDelegate => CheckResult::CanApprove,
             PluginType::PermanentFreezeDelegate => CheckResult::CanReject,
             PluginType::PermanentTransferDelegate => CheckResult::CanApprove,
+            PluginType::TransferDelegateV2 => CheckResult::CanApprove,
+            PluginType::Soulbound => CheckResult::CanForceReject,
+            PluginType::TimeLock => CheckResult::CanForceReject,
+            PluginType::Rental => CheckResult::CanApprove,
+            PluginType::Listing => CheckResult::CanApprove,
+            PluginType::Groups => CheckResult::CanForceReject,
             _ => CheckResult::None,
         }
     }
@@ -210,6 +228,16 @@ impl PluginType {
         }
     }
 
+    /// Check permissions for the execute lifecycle event on behalf of a collection.
+    pub fn check_collection_execute(plugin_type: &PluginType) -> CheckResult {
+        #[allow(clippy::match_single_binding)]
+        match plugin_type {
+            PluginType::UpdateDelegate => CheckResult::CanApprove,
+            PluginType::PermanentFreezeExecute => CheckResult::CanReject,
+            _ => CheckResult::None,
+        }
+    }
+
     /// Check permissions for the add external plugin adapter lifecycle event.
     pub fn check_add_external_plugin_adapter(plugin_type: &PluginType) -> CheckResult {
         #[allow(clippy::match_single_binding)]
@@ -523,6 +551,8 @@ pub(crate) struct PluginValidationContext<'a, 'b> {
     pub resolved_authorities: Option<&'b [Authority]>,
     /// The new owner account for transfers
     pub new_owner: Option<&'a AccountInfo<'a>>,
+    /// The sale price declared for transfers
+    pub sale_price: Option<u64>,
     /// The new asset authority address.
     pub new_asset_authority: Option<&'b UpdateAuthority>,
     /// The new collection authority address.
@@ -678,6 +708,7 @@ pub(crate) fn validate_plugin_checks<'a>(
     checks: &BTreeMap<PluginType, (Key, CheckResult, RegistryRecord)>,
     authority: &'a AccountInfo<'a>,
     new_owner: Option<&'a AccountInfo<'a>>,
+    sale_price: Option<u64>,
     new_asset_authority: Option<&UpdateAuthority>,
     new_collection_authority: Option<&Pubkey>,
     new_plugin: Option<&Plugin>,
@@ -694,10 +725,17 @@ pub(crate) fn validate_plugin_checks<'a>(
 ) -> Result<ValidationResult, ProgramError> {
     let mut approved = false;
     let mut rejected = false;
+    let mut force_approved = false;
+    let mut force_rejected = false;
     for (check_key, check_result, registry_record) in checks.values() {
+        // Once force approved, only plugins that can override a force approval are validated.
+        if force_approved && *check_result != CheckResult::CanForceReject {
+            continue;
+        }
+
         if matches!(
             check_result,
-            CheckResult::CanApprove | CheckResult::CanReject
+            CheckResult::CanApprove | CheckResult::CanReject | CheckResult::CanForceReject
         ) {
             let account = match check_key {
                 Key::CollectionV1 => collection.ok_or(MplCoreError::InvalidCollection)?,
@@ -705,6 +743,13 @@ pub(crate) fn validate_plugin_checks<'a>(
                 _ => unreachable!(),
             };
 
+            let record_authorities = resolve_plugin_authorities(
+                resolved_authorities,
+                &registry_record.authority,
+                authority,
+                accounts,
+            );
+
             let validation_ctx = PluginValidationContext {
                 accounts,
                 asset_info: asset,
@@ -712,8 +757,9 @@ pub(crate) fn validate_plugin_checks<'a>(
                 self_key: *check_key,
                 self_authority: &registry_record.authority,
                 authority_info: authority,
-                resolved_authorities: Some(resolved_authorities),
+                resolved_authorities: Some(&record_authorities),
                 new_owner,
+                sale_price,
                 new_asset_authority,
                 new_collection_authority,
                 target_plugin: new_plugin,
@@ -727,15 +773,22 @@ pub(crate) fn validate_plugin_checks<'a>(
                 &validation_ctx,
             )?;
             match result {
-                ValidationResult::Rejected => rejected = true,
+                ValidationResult::Rejected => {
+                    rejected = true;
+                    force_rejected |= *check_result == CheckResult::CanForceReject;
+                }
                 ValidationResult::Approved => approved = true,
                 ValidationResult::Pass => continue,
-                ValidationResult::ForceApproved => return force_approve!(),
+                ValidationResult::ForceApproved => force_approved = true,
             }
         }
     }
 
-    if rejected {
+    if force_rejected {
+        reject!()
+    } else if force_approved {
+        force_approve!()
+    } else if rejected {
         reject!()
     } else if approved {
         approve!()
@@ -779,6 +832,13 @@ pub(crate) fn validate_external_plugin_adapter_checks<'a>(
                 _ => unreachable!(),
             };
 
+            let record_authorities = resolve_plugin_authorities(
+                resolved_authorities,
+                &external_registry_record.authority,
+                authority,
+                accounts,
+            );
+
             let validation_ctx = PluginValidationContext {
                 accounts,
                 asset_info: asset,
@@ -786,8 +846,9 @@ pub(crate) fn validate_external_plugin_adapter_checks<'a>(
                 self_key: *check_key,
                 self_authority: &external_registry_record.authority,
                 authority_info: authority,
-                resolved_authorities: Some(resolved_authorities),
+                resolved_authorities: Some(&record_authorities),
                 new_owner,
+                sale_price: None,
                 new_asset_authority,
                 new_collection_authority,
                 target_plugin: new_plugin,
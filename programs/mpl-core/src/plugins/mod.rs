@@ -68,6 +68,22 @@ pub enum Plugin {
     PermanentFreezeExecute(PermanentFreezeExecute),
     /// Groups plugin stores parent group memberships of a collection for taxonomy purposes
     Groups(Groups),
+    /// Soulbound plugin makes an asset non-transferable while still allowing burn and execute.
+    Soulbound(Soulbound),
+    /// TimeLock plugin makes an asset non-transferable and non-burnable until an unlock time.
+    TimeLock(TimeLock),
+    /// Rental plugin lends an asset to a renter until an expiry slot, after which it can be returned to the lender.
+    Rental(Rental),
+    /// Listing plugin allows anybody to buy the asset by paying the listed price in the same transaction.
+    Listing(Listing),
+    /// Multisig plugin makes a set of signers the update authority of a collection.
+    Multisig(Multisig),
+    /// ExecuteGuard plugin restricts the programs the asset signer may invoke.
+    ExecuteGuard(ExecuteGuard),
+    /// Transfer Delegate V2 plugin is a transfer delegate whose approval can expire or be limited to a number of transfers.
+    TransferDelegateV2(TransferDelegateV2),
+    /// Burn Delegate V2 plugin is a burn delegate whose approval can expire or be limited to a number of uses.
+    BurnDelegateV2(BurnDelegateV2),
 }
 impl Plugin {
     /// Get the default authority for a plugin which defines who must allow the plugin to be created.
@@ -114,6 +130,14 @@ impl Plugin {
             Plugin::FreezeExecute(inner) => inner,
             Plugin::PermanentFreezeExecute(inner) => inner,
             Plugin::Groups(inner) => inner,
+            Plugin::Soulbound(inner) => inner,
+            Plugin::TimeLock(inner) => inner,
+            Plugin::Rental(inner) => inner,
+            Plugin::Listing(inner) => inner,
+            Plugin::Multisig(inner) => inner,
+            Plugin::ExecuteGuard(inner) => inner,
+            Plugin::TransferDelegateV2(inner) => inner,
+            Plugin::BurnDelegateV2(inner) => inner,
         }
     }
 }
@@ -151,6 +175,14 @@ impl DataBlob for Plugin {
                     permanent_freeze_execute.len()
                 },
                 Plugin::Groups(groups) => groups.len(),
+                Plugin::Soulbound(soulbound) => soulbound.len(),
+                Plugin::TimeLock(time_lock) => time_lock.len(),
+                Plugin::Rental(rental) => rental.len(),
+                Plugin::Listing(listing) => listing.len(),
+                Plugin::Multisig(multisig) => multisig.len(),
+                Plugin::ExecuteGuard(execute_guard) => execute_guard.len(),
+                Plugin::TransferDelegateV2(transfer_delegate) => transfer_delegate.len(),
+                Plugin::BurnDelegateV2(burn_delegate) => burn_delegate.len(),
             }
     }
 }
@@ -211,6 +243,22 @@ pub enum PluginType {
     PermanentFreezeExecute,
     /// Groups plugin.
     Groups,
+    /// Soulbound plugin.
+    Soulbound,
+    /// TimeLock plugin.
+    TimeLock,
+    /// Rental plugin.
+    Rental,
+    /// Listing plugin.
+    Listing,
+    /// Multisig plugin.
+    Multisig,
+    /// ExecuteGuard plugin.
+    ExecuteGuard,
+    /// Transfer Delegate V2 plugin.
+    TransferDelegateV2,
+    /// Burn Delegate V2 plugin.
+    BurnDelegateV2,
 }
 
 impl PluginType {
@@ -254,6 +302,14 @@ impl From<&Plugin> for PluginType {
             Plugin::FreezeExecute(_) => PluginType::FreezeExecute,
             Plugin::PermanentFreezeExecute(_) => PluginType::PermanentFreezeExecute,
             Plugin::Groups(_) => PluginType::Groups,
+            Plugin::Soulbound(_) => PluginType::Soulbound,
+            Plugin::TimeLock(_) => PluginType::TimeLock,
+            Plugin::Rental(_) => PluginType::Rental,
+            Plugin::Listing(_) => PluginType::Listing,
+            Plugin::Multisig(_) => PluginType::Multisig,
+            Plugin::ExecuteGuard(_) => PluginType::ExecuteGuard,
+            Plugin::TransferDelegateV2(_) => PluginType::TransferDelegateV2,
+            Plugin::BurnDelegateV2(_) => PluginType::BurnDelegateV2,
         }
     }
 }
@@ -283,6 +339,14 @@ impl PluginType {
             PluginType::FreezeExecute => Authority::Owner,
             PluginType::PermanentFreezeExecute => Authority::UpdateAuthority,
             PluginType::Groups => Authority::UpdateAuthority,
+            PluginType::Soulbound => Authority::UpdateAuthority,
+            PluginType::TimeLock => Authority::UpdateAuthority,
+            PluginType::Rental => Authority::Owner,
+            PluginType::Listing => Authority::Owner,
+            PluginType::Multisig => Authority::UpdateAuthority,
+            PluginType::ExecuteGuard => Authority::UpdateAuthority,
+            PluginType::TransferDelegateV2 => Authority::Owner,
+            PluginType::BurnDelegateV2 => Authority::Owner,
         }
     }
 }
@@ -337,6 +401,14 @@ mod test {
             Plugin::FreezeExecute(FreezeExecute { frozen: false }),
             Plugin::PermanentFreezeExecute(PermanentFreezeExecute { frozen: false }),
             Plugin::Groups(Groups { groups: vec![] }),
+            Plugin::Soulbound(Soulbound {}),
+            Plugin::TimeLock(TimeLock { unlock_at: 0 }),
+            Plugin::Rental(Rental::default()),
+            Plugin::Listing(Listing::default()),
+            Plugin::Multisig(Multisig::default()),
+            Plugin::ExecuteGuard(ExecuteGuard::default()),
+            Plugin::TransferDelegateV2(TransferDelegateV2::default()),
+            Plugin::BurnDelegateV2(BurnDelegateV2::default()),
         ];
 
         assert_eq!(
@@ -460,6 +532,38 @@ mod test {
             vec![Plugin::PermanentFreezeExecute(PermanentFreezeExecute {
                 frozen: true,
             })],
+            vec![Plugin::Soulbound(Soulbound {})],
+            vec![Plugin::TimeLock(TimeLock {
+                unlock_at: i64::MAX,
+            })],
+            vec![Plugin::Rental(Rental {
+                lender: Pubkey::default(),
+                renter: Pubkey::default(),
+                expiry_slot: u64::MAX,
+            })],
+            vec![Plugin::Listing(Listing {
+                price: u64::MAX,
+                mint: Some(Pubkey::default()),
+                recipients: vec![ListingRecipient::default()],
+            })],
+            vec![Plugin::Multisig(Multisig {
+                signers: vec![Pubkey::default(), Pubkey::default()],
+                threshold: 2,
+            })],
+            vec![Plugin::ExecuteGuard(ExecuteGuard {
+                allowed_programs: vec![ExecuteGuardProgram {
+                    program_id: Pubkey::default(),
+                    instruction_spend_limit: Some(u64::MAX),
+                }],
+            })],
+            vec![Plugin::TransferDelegateV2(TransferDelegateV2 {
+                expires_at: Some(i64::MAX),
+                max_uses: Some(u32::MAX),
+            })],
+            vec![Plugin::BurnDelegateV2(BurnDelegateV2 {
+                expires_at: Some(i64::MAX),
+                max_uses: None,
+            })],
         ];
 
         assert_eq!(
@@ -182,6 +182,11 @@ pub struct ExternalRegistryRecord {
 }
 
 impl ExternalRegistryRecord {
+    /// Associated function for sorting `ExternalRegistryRecords` by offset.
+    pub fn compare_offsets(a: &ExternalRegistryRecord, b: &ExternalRegistryRecord) -> Ordering {
+        a.offset.cmp(&b.offset)
+    }
+
     /// Update the adapter registry record with the new info, if relevant.
     pub fn update(&mut self, update_info: &ExternalPluginAdapterUpdateInfo) -> ProgramResult {
         match update_info {
@@ -1,7 +1,9 @@
 use borsh::BorshDeserialize;
+use solana_instructions_sysvar::{load_current_index_checked, load_instruction_at_checked};
 use solana_program::{
     account_info::AccountInfo,
     entrypoint::ProgramResult,
+    instruction::Instruction,
     program_error::ProgramError,
     program_memory::{sol_memcpy, sol_memmove},
     pubkey::Pubkey,
@@ -11,7 +13,7 @@ use std::collections::HashSet;
 use crate::{
     error::MplCoreError,
     plugins::{ExternalCheckResult, HookableLifecycleEvent},
-    state::{AssetV1, Authority, CoreAsset, DataBlob, Key, SolanaAccount},
+    state::{validate_multisig, AssetV1, Authority, CoreAsset, DataBlob, Key, SolanaAccount},
     utils::resize_or_reallocate_account,
 };
 
@@ -149,7 +151,11 @@ pub fn fetch_plugin<T: DataBlob + SolanaAccount, U: BorshDeserialize>(
     )?;
 
     // Return the plugin and its authority.
-    Ok((registry_record.authority, inner, registry_record.offset))
+    Ok((
+        registry_record.authority.clone(),
+        inner,
+        registry_record.offset,
+    ))
 }
 
 /// Fetch the plugin from the registry.
@@ -182,10 +188,126 @@ pub fn fetch_wrapped_plugin<T: DataBlob + SolanaAccount>(
         .ok_or(MplCoreError::PluginNotFound)?;
 
     // Deserialize the plugin.
-    let plugin = Plugin::deserialize(&mut &(*account.data).borrow()[registry_record.offset..])?;
+    let plugin = Plugin::load(account, registry_record.offset)?;
 
     // Return the plugin and its authority.
-    Ok((registry_record.authority, plugin))
+    Ok((registry_record.authority.clone(), plugin))
+}
+
+/// The SPL Token program ID.
+const SPL_TOKEN_ID: Pubkey = solana_program::pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+
+/// The SPL Token 2022 program ID.
+const SPL_TOKEN_2022_ID: Pubkey =
+    solana_program::pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+
+/// The SPL Associated Token Account program ID.
+const SPL_ASSOCIATED_TOKEN_ACCOUNT_ID: Pubkey =
+    solana_program::pubkey!("ATokenGPvbdGVxr1b2hdsZrYYbPCZR8Ji4Pa5VWDTEwN");
+
+/// The system program `Transfer` instruction discriminator.
+const SYSTEM_TRANSFER_DISCRIMINATOR: u32 = 2;
+
+/// The SPL Token `TransferChecked` instruction discriminator.
+const TOKEN_TRANSFER_CHECKED_DISCRIMINATOR: u8 = 12;
+
+/// Returns the asset, recipient and amount of a payment instruction. A payment is a system
+/// program `Transfer` of lamports, or an SPL token `TransferChecked` of the mint to the
+/// associated token account of one of the recipients, with the asset appended as the last
+/// account of the instruction. The system and token programs ignore the extra account.
+fn parse_asset_payment(
+    instruction: &Instruction,
+    mint: Option<&Pubkey>,
+    recipients: &[Pubkey],
+) -> Option<(Pubkey, Pubkey, u64)> {
+    let asset = instruction.accounts.last()?.pubkey;
+    match mint {
+        None => {
+            if instruction.program_id != solana_system_interface::program::ID
+                || instruction.data.len() != 12
+                || instruction.accounts.len() < 3
+                || u32::from_le_bytes(instruction.data[0..4].try_into().ok()?)
+                    != SYSTEM_TRANSFER_DISCRIMINATOR
+            {
+                return None;
+            }
+
+            let lamports = u64::from_le_bytes(instruction.data[4..12].try_into().ok()?);
+            Some((asset, instruction.accounts[1].pubkey, lamports))
+        }
+        Some(mint) => {
+            if (instruction.program_id != SPL_TOKEN_ID
+                && instruction.program_id != SPL_TOKEN_2022_ID)
+                || instruction.data.len() < 10
+                || instruction.accounts.len() < 5
+                || instruction.data[0] != TOKEN_TRANSFER_CHECKED_DISCRIMINATOR
+                || instruction.accounts[1].pubkey != *mint
+            {
+                return None;
+            }
+
+            let amount = u64::from_le_bytes(instruction.data[1..9].try_into().ok()?);
+            let destination = instruction.accounts[2].pubkey;
+            recipients.iter().find_map(|recipient| {
+                let (associated_token_account, _) = Pubkey::find_program_address(
+                    &[
+                        recipient.as_ref(),
+                        instruction.program_id.as_ref(),
+                        mint.as_ref(),
+                    ],
+                    &SPL_ASSOCIATED_TOKEN_ACCOUNT_ID,
+                );
+                (associated_token_account == destination).then_some((asset, *recipient, amount))
+            })
+        }
+    }
+}
+
+/// Returns the total paid to each recipient for the asset by the payment instructions
+/// immediately preceding the current transaction instruction, in lamports or in base units of
+/// the mint. Returns `None` if the instructions sysvar is not passed in.
+///
+/// A transfer invoked by another program, such as from an `ExecuteBatchV1`, is matched to the
+/// payments preceding the transaction instruction that invoked it. Only payments naming the
+/// asset are counted, so a payment cannot be used for several assets.
+pub(crate) fn find_asset_payments(
+    accounts: &[AccountInfo],
+    asset: &Pubkey,
+    mint: Option<&Pubkey>,
+    recipients: &[Pubkey],
+) -> Result<Option<Vec<(Pubkey, u64)>>, ProgramError> {
+    let instructions_sysvar = match accounts
+        .iter()
+        .find(|account| solana_instructions_sysvar::check_id(account.key))
+    {
+        Some(instructions_sysvar) => instructions_sysvar,
+        None => return Ok(None),
+    };
+
+    let mut payments: Vec<(Pubkey, u64)> =
+        recipients.iter().map(|recipient| (*recipient, 0)).collect();
+    let current_index = load_current_index_checked(instructions_sysvar)? as usize;
+    for index in (0..current_index).rev() {
+        let instruction = load_instruction_at_checked(index, instructions_sysvar)?;
+        let (paid_asset, recipient, amount) =
+            match parse_asset_payment(&instruction, mint, recipients) {
+                Some(payment) => payment,
+                None => break,
+            };
+
+        if paid_asset != *asset {
+            continue;
+        }
+
+        if let Some((_, paid)) = payments
+            .iter_mut()
+            .find(|(address, _)| *address == recipient)
+        {
+            *paid = paid.saturating_add(amount);
+        }
+    }
+
+    Ok(Some(payments))
 }
 
 /// Fetch the external plugin adapter from the registry.
@@ -275,6 +397,10 @@ pub fn initialize_plugin<'a, T: DataBlob + SolanaAccount>(
     let plugin_type = plugin.into();
     let plugin_size = plugin.len();
 
+    if let Authority::Multisig { signers, threshold } = authority {
+        validate_multisig(signers, *threshold)?;
+    }
+
     // You cannot add a duplicate plugin.
     if plugin_registry
         .registry
@@ -289,7 +415,7 @@ pub fn initialize_plugin<'a, T: DataBlob + SolanaAccount>(
     let new_registry_record = RegistryRecord {
         plugin_type,
         offset: old_registry_offset,
-        authority: *authority,
+        authority: authority.clone(),
     };
 
     let size_increase = plugin_size
@@ -355,21 +481,21 @@ pub fn initialize_external_plugin_adapter<'a, T: DataBlob + SolanaAccount>(
         ExternalPluginAdapterInitInfo::LifecycleHook(init_info) => {
             validate_lifecycle_checks(&init_info.lifecycle_checks, false)?;
             (
-                init_info.init_plugin_authority,
+                init_info.init_plugin_authority.clone(),
                 Some(init_info.lifecycle_checks.clone()),
             )
         }
         ExternalPluginAdapterInitInfo::LinkedLifecycleHook(init_info) => {
             validate_lifecycle_checks(&init_info.lifecycle_checks, false)?;
             (
-                init_info.init_plugin_authority,
+                init_info.init_plugin_authority.clone(),
                 Some(init_info.lifecycle_checks.clone()),
             )
         }
         ExternalPluginAdapterInitInfo::Oracle(init_info) => {
             validate_lifecycle_checks(&init_info.lifecycle_checks, true)?;
             (
-                init_info.init_plugin_authority,
+                init_info.init_plugin_authority.clone(),
                 Some(init_info.lifecycle_checks.clone()),
             )
         }
@@ -380,13 +506,13 @@ pub fn initialize_external_plugin_adapter<'a, T: DataBlob + SolanaAccount>(
         | ExternalPluginAdapterInitInfo::LinkedAppData(LinkedAppDataInitInfo {
             init_plugin_authority,
             ..
-        }) => (*init_plugin_authority, None),
+        }) => (init_plugin_authority.clone(), None),
         // The DataSection is only updated via its managing plugin so it has no authority.
         ExternalPluginAdapterInitInfo::DataSection(_) => (Some(Authority::None), None),
         ExternalPluginAdapterInitInfo::AgentIdentity(init_info) => {
             validate_lifecycle_checks(&init_info.lifecycle_checks, false)?;
             (
-                init_info.init_plugin_authority,
+                init_info.init_plugin_authority.clone(),
                 Some(init_info.lifecycle_checks.clone()),
             )
         }
@@ -780,13 +906,17 @@ pub fn approve_authority_on_plugin<'a, T: CoreAsset>(
         .find(|record| record.plugin_type == *plugin_type)
         .ok_or(MplCoreError::PluginNotFound)?;
 
+    if let Authority::Multisig { signers, threshold } = new_authority {
+        validate_multisig(signers, *threshold)?;
+    }
+
     let old_authority_bytes = borsh::to_vec(&registry_record.authority)?;
     let new_authority_bytes = borsh::to_vec(new_authority)?;
     let size_diff = (new_authority_bytes.len() as isize)
         .checked_sub(old_authority_bytes.len() as isize)
         .ok_or(MplCoreError::NumericalOverflow)?;
 
-    registry_record.authority = *new_authority;
+    registry_record.authority = new_authority.clone();
 
     if size_diff != 0 {
         let new_size = (account.data_len() as isize)
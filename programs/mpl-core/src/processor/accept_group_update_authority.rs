@@ -0,0 +1,84 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use mpl_utils::{assert_derivation, assert_signer};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+};
+
+use crate::{
+    error::MplCoreError,
+    instruction::accounts::AcceptGroupUpdateAuthorityV1Accounts,
+    state::{GroupAuthorityProposalV1, GroupV1, SolanaAccount, GROUP_AUTHORITY_PROPOSAL_PREFIX},
+    utils::{close_program_account, save_group},
+};
+
+/// Arguments for the `AcceptGroupUpdateAuthorityV1` instruction.
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone, Default)]
+pub(crate) struct AcceptGroupUpdateAuthorityV1Args {}
+
+/// Processor for the `AcceptGroupUpdateAuthorityV1` instruction.
+pub(crate) fn accept_group_update_authority_v1<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    _args: AcceptGroupUpdateAuthorityV1Args,
+) -> ProgramResult {
+    // Derive the typed account context from the raw slice.
+    let ctx = AcceptGroupUpdateAuthorityV1Accounts::context(accounts)?;
+
+    // Basic guards.
+    assert_signer(ctx.accounts.payer)?;
+    assert_signer(ctx.accounts.new_update_authority)?;
+
+    // Ensure the canonical system program is provided.
+    if ctx.accounts.system_program.key != &solana_system_interface::program::ID {
+        return Err(MplCoreError::InvalidSystemProgram.into());
+    }
+
+    if !ctx.accounts.group.is_writable
+        || !ctx.accounts.group_authority_proposal.is_writable
+        || !ctx.accounts.proposal_payer.is_writable
+    {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    assert_derivation(
+        &crate::ID,
+        ctx.accounts.group_authority_proposal,
+        &[
+            GROUP_AUTHORITY_PROPOSAL_PREFIX.as_bytes(),
+            ctx.accounts.group.key.as_ref(),
+        ],
+        MplCoreError::InvalidGroupAuthorityProposal,
+    )?;
+    let proposal = GroupAuthorityProposalV1::load(ctx.accounts.group_authority_proposal, 0)?;
+
+    if proposal.new_update_authority != *ctx.accounts.new_update_authority.key {
+        return Err(MplCoreError::InvalidAuthority.into());
+    }
+
+    if proposal.payer != *ctx.accounts.proposal_payer.key {
+        msg!("Error: The proposal payer must receive the reclaimed lamports");
+        return Err(MplCoreError::InvalidGroupAuthorityProposal.into());
+    }
+
+    let mut group = GroupV1::load(ctx.accounts.group, 0)?;
+
+    // The proposal lapses if the update authority changed after it was made.
+    if proposal.update_authority != group.update_authority {
+        msg!("Error: The update authority of the group changed after the proposal");
+        return Err(MplCoreError::InvalidGroupAuthorityProposal.into());
+    }
+
+    group.update_authority = proposal.new_update_authority;
+    save_group(
+        ctx.accounts.group,
+        &group,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+    )?;
+
+    // Close the proposal account, transferring rent-exempt lamports back to its payer.
+    close_program_account(
+        ctx.accounts.group_authority_proposal,
+        ctx.accounts.proposal_payer,
+    )
+}
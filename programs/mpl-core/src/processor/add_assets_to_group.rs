@@ -4,15 +4,15 @@ use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
 };
 
-use super::groups_plugin_utils::process_asset_groups_plugin_add;
+use super::groups_plugin_utils::{is_asset_in_groups_plugin, process_asset_groups_plugin_add};
 use crate::{
     error::MplCoreError,
     instruction::accounts::AddAssetsToGroupV1Accounts,
     instruction::accounts::Context,
     state::{GroupV1, Key, SolanaAccount, MAX_GROUP_VECTOR_SIZE},
     utils::{
-        is_valid_asset_authority, is_valid_group_authority, load_key, resolve_authority,
-        save_flat_group,
+        create_group_membership, is_valid_asset_authority, is_valid_group_member_authority,
+        load_key, resolve_authority, save_group, split_group_delegate,
     },
 };
 
@@ -28,6 +28,10 @@ pub(crate) struct AddAssetsToGroupV1Args {}
 /// are needed for authority resolution when an asset's update authority is
 /// `UpdateAuthority::Collection`.  Accounts are classified by their on-chain
 /// key discriminator; only `AssetV1` accounts are processed as group members.
+///
+/// An asset may be followed by its `GroupMembershipV1` PDA, in which case the
+/// membership is stored in that account instead of the group's `assets` vector
+/// and does not count towards `MAX_GROUP_VECTOR_SIZE`.
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn add_assets_to_group_v1<'a>(
     accounts: &'a [AccountInfo<'a>],
@@ -39,7 +43,7 @@ pub(crate) fn add_assets_to_group_v1<'a>(
     let payer_info = ctx.accounts.payer;
     let authority_info_opt = ctx.accounts.authority;
     let system_program_info = ctx.accounts.system_program;
-    let remaining_accounts = ctx.remaining_accounts;
+    let (delegate_record_info, remaining_accounts) = split_group_delegate(ctx.remaining_accounts)?;
 
     assert_signer(payer_info)?;
     let authority_info = resolve_authority(payer_info, authority_info_opt)?;
@@ -57,18 +61,34 @@ pub(crate) fn add_assets_to_group_v1<'a>(
 
     let mut group = GroupV1::load(group_info, 0)?;
 
-    if !is_valid_group_authority(group_info, authority_info)? {
+    if !is_valid_group_member_authority(group_info, authority_info, delegate_record_info)? {
         msg!("Error: Invalid authority for group");
         return Err(MplCoreError::InvalidAuthority.into());
     }
 
     // Remaining accounts may include supplemental collection accounts used for
-    // authority resolution when assets are collection-managed.
-    let mut asset_accounts: Vec<&AccountInfo> = Vec::with_capacity(remaining_accounts.len());
+    // authority resolution when assets are collection-managed, and membership
+    // accounts following the assets they are created for.
+    let mut asset_accounts: Vec<(&AccountInfo, Option<&AccountInfo>)> =
+        Vec::with_capacity(remaining_accounts.len());
     for account_info in remaining_accounts.iter() {
-        match load_key(account_info, 0)? {
-            Key::AssetV1 => asset_accounts.push(account_info),
+        // A membership account is uninitialized until it is created below.
+        let key = if account_info.data_is_empty() {
+            Key::Uninitialized
+        } else {
+            load_key(account_info, 0)?
+        };
+
+        match key {
+            Key::AssetV1 => asset_accounts.push((account_info, None)),
             Key::CollectionV1 => {}
+            Key::Uninitialized | Key::GroupMembershipV1 => match asset_accounts.last_mut() {
+                Some((_, membership_info @ None)) => *membership_info = Some(account_info),
+                _ => {
+                    msg!("Error: Membership account must follow the asset it is for");
+                    return Err(MplCoreError::InvalidGroupMembership.into());
+                }
+            },
             _ => {
                 msg!("Error: Expected remaining account to be AssetV1 or CollectionV1");
                 return Err(MplCoreError::IncorrectAccount.into());
@@ -80,7 +100,7 @@ pub(crate) fn add_assets_to_group_v1<'a>(
         return Err(MplCoreError::IncorrectAccount.into());
     }
 
-    for asset_info in asset_accounts {
+    for (asset_info, membership_info) in asset_accounts {
         if !asset_info.is_writable {
             msg!("Error: Asset account must be writable");
             return Err(ProgramError::InvalidAccountData);
@@ -94,11 +114,32 @@ pub(crate) fn add_assets_to_group_v1<'a>(
             return Err(MplCoreError::DuplicateEntry.into());
         }
 
-        if group.assets.len() >= MAX_GROUP_VECTOR_SIZE {
-            return Err(MplCoreError::GroupVectorFull.into());
-        }
+        if let Some(membership_info) = membership_info {
+            create_group_membership(
+                membership_info,
+                group_info.key,
+                asset_info.key,
+                payer_info,
+                system_program_info,
+            )?;
+            group.membership_count = Some(
+                group
+                    .membership_count()
+                    .checked_add(1)
+                    .ok_or(MplCoreError::NumericalOverflow)?,
+            );
+        } else {
+            // The asset may already be a member through its membership account.
+            if is_asset_in_groups_plugin(asset_info, group_info.key)? {
+                return Err(MplCoreError::DuplicateEntry.into());
+            }
 
-        group.assets.push(*asset_info.key);
+            if group.assets.len() >= MAX_GROUP_VECTOR_SIZE {
+                return Err(MplCoreError::GroupVectorFull.into());
+            }
+
+            group.assets.push(*asset_info.key);
+        }
 
         process_asset_groups_plugin_add(
             asset_info,
@@ -108,7 +149,7 @@ pub(crate) fn add_assets_to_group_v1<'a>(
         )?;
     }
 
-    save_flat_group(group_info, &group, payer_info, system_program_info)?;
+    save_group(group_info, &group, payer_info, system_program_info)?;
 
     Ok(())
 }
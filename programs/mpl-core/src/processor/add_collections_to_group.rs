@@ -10,7 +10,8 @@ use crate::{
     instruction::accounts::{AddCollectionsToGroupV1Accounts, Context},
     state::{CollectionV1, GroupV1, SolanaAccount, MAX_GROUP_VECTOR_SIZE},
     utils::{
-        is_valid_collection_authority, is_valid_group_authority, resolve_authority, save_flat_group,
+        is_valid_collection_authority, is_valid_group_member_authority, resolve_authority,
+        save_group, split_group_delegate,
     },
 };
 
@@ -35,7 +36,7 @@ pub(crate) fn add_collections_to_group_v1<'a>(
     let system_program_info = ctx.accounts.system_program;
 
     // Dynamic list of collection accounts passed after the fixed accounts.
-    let remaining_accounts = ctx.remaining_accounts;
+    let (delegate_record_info, remaining_accounts) = split_group_delegate(ctx.remaining_accounts)?;
 
     // Basic guards.
     assert_signer(payer_info)?;
@@ -55,8 +56,8 @@ pub(crate) fn add_collections_to_group_v1<'a>(
     // Deserialize group.
     let mut group = GroupV1::load(group_info, 0)?;
 
-    // Authority check: must be the group's update authority.
-    if !is_valid_group_authority(group_info, authority_info)? {
+    // Authority check: must be the group's update authority or a delegate of the group.
+    if !is_valid_group_member_authority(group_info, authority_info, delegate_record_info)? {
         msg!("Error: Invalid authority for group account");
         return Err(MplCoreError::InvalidAuthority.into());
     }
@@ -73,7 +74,7 @@ pub(crate) fn add_collections_to_group_v1<'a>(
         let _collection_core = CollectionV1::load(collection_info, 0)?;
 
         // Authority must be update authority of the collection as well.
-        if !is_valid_collection_authority(collection_info, authority_info)? {
+        if !is_valid_collection_authority(collection_info, authority_info, accounts)? {
             msg!("Error: Signer is not collection update authority/delegate");
             return Err(MplCoreError::InvalidAuthority.into());
         }
@@ -99,7 +100,7 @@ pub(crate) fn add_collections_to_group_v1<'a>(
         // The collection core itself does not change; no reserialization needed.
     }
 
-    save_flat_group(group_info, &group, payer_info, system_program_info)?;
+    save_group(group_info, &group, payer_info, system_program_info)?;
 
     Ok(())
 }
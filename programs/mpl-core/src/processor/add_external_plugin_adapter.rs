@@ -65,17 +65,19 @@ pub(crate) fn add_external_plugin_adapter<'a>(
     let external_plugin_adapter = ExternalPluginAdapter::from(&args.init_info);
     let external_plugin_adapter_authority = match &args.init_info {
         ExternalPluginAdapterInitInfo::LifecycleHook(lifecycle_hook) => {
-            lifecycle_hook.init_plugin_authority
+            lifecycle_hook.init_plugin_authority.clone()
         }
-        ExternalPluginAdapterInitInfo::Oracle(oracle) => oracle.init_plugin_authority,
-        ExternalPluginAdapterInitInfo::AppData(app_data) => app_data.init_plugin_authority,
+        ExternalPluginAdapterInitInfo::Oracle(oracle) => oracle.init_plugin_authority.clone(),
+        ExternalPluginAdapterInitInfo::AppData(app_data) => app_data.init_plugin_authority.clone(),
         ExternalPluginAdapterInitInfo::LinkedLifecycleHook(lifecycle_hook) => {
-            lifecycle_hook.init_plugin_authority
+            lifecycle_hook.init_plugin_authority.clone()
+        }
+        ExternalPluginAdapterInitInfo::LinkedAppData(app_data) => {
+            app_data.init_plugin_authority.clone()
         }
-        ExternalPluginAdapterInitInfo::LinkedAppData(app_data) => app_data.init_plugin_authority,
         ExternalPluginAdapterInitInfo::DataSection(_) => unreachable!(),
         ExternalPluginAdapterInitInfo::AgentIdentity(agent_identity) => {
-            agent_identity.init_plugin_authority
+            agent_identity.init_plugin_authority.clone()
         }
     }
     .unwrap_or(Authority::UpdateAuthority);
@@ -88,6 +90,7 @@ pub(crate) fn add_external_plugin_adapter<'a>(
         authority_info: authority,
         resolved_authorities: None,
         new_owner: None,
+        sale_price: None,
         new_asset_authority: None,
         new_collection_authority: None,
         target_plugin: None,
@@ -115,6 +118,7 @@ pub(crate) fn add_external_plugin_adapter<'a>(
         None,
         None,
         None,
+        None,
         Some(&external_plugin_adapter),
         Some(&external_plugin_adapter_authority),
         AssetV1::check_add_external_plugin_adapter,
@@ -178,14 +182,16 @@ pub(crate) fn add_collection_external_plugin_adapter<'a>(
     let external_plugin_adapter = ExternalPluginAdapter::from(&args.init_info);
     let external_plugin_adapter_authority = match &args.init_info {
         ExternalPluginAdapterInitInfo::LifecycleHook(lifecycle_hook) => {
-            lifecycle_hook.init_plugin_authority
+            lifecycle_hook.init_plugin_authority.clone()
         }
-        ExternalPluginAdapterInitInfo::Oracle(oracle) => oracle.init_plugin_authority,
-        ExternalPluginAdapterInitInfo::AppData(app_data) => app_data.init_plugin_authority,
+        ExternalPluginAdapterInitInfo::Oracle(oracle) => oracle.init_plugin_authority.clone(),
+        ExternalPluginAdapterInitInfo::AppData(app_data) => app_data.init_plugin_authority.clone(),
         ExternalPluginAdapterInitInfo::LinkedLifecycleHook(lifecycle_hook) => {
-            lifecycle_hook.init_plugin_authority
+            lifecycle_hook.init_plugin_authority.clone()
+        }
+        ExternalPluginAdapterInitInfo::LinkedAppData(app_data) => {
+            app_data.init_plugin_authority.clone()
         }
-        ExternalPluginAdapterInitInfo::LinkedAppData(app_data) => app_data.init_plugin_authority,
         ExternalPluginAdapterInitInfo::DataSection(_)
         | ExternalPluginAdapterInitInfo::AgentIdentity(_) => unreachable!(),
     }
@@ -199,6 +205,7 @@ pub(crate) fn add_collection_external_plugin_adapter<'a>(
         authority_info: authority,
         resolved_authorities: None,
         new_owner: None,
+        sale_price: None,
         new_asset_authority: None,
         new_collection_authority: None,
         target_plugin: None,
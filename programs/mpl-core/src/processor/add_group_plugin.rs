@@ -0,0 +1,152 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use mpl_utils::assert_signer;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+};
+
+use crate::{
+    error::MplCoreError,
+    instruction::accounts::AddGroupPluginV1Accounts,
+    plugins::{
+        create_meta_idempotent, initialize_external_plugin_adapter, initialize_plugin,
+        validate_royalties, ExternalPluginAdapterInitInfo, Plugin,
+    },
+    state::{Authority, GroupV1, SolanaAccount},
+    utils::{is_valid_group_authority, resolve_authority, save_group},
+};
+
+/// A plugin or external plugin adapter to add to a group.
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub enum GroupPluginInitInfo {
+    /// A plugin, with an optional authority overriding the plugin's default authority.
+    Plugin {
+        /// The plugin to add.
+        plugin: Plugin,
+        /// The authority of the plugin.
+        init_authority: Option<Authority>,
+    },
+    /// An external plugin adapter.
+    ExternalPluginAdapter(ExternalPluginAdapterInitInfo),
+}
+
+/// Arguments for the `AddGroupPluginV1` instruction.
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub(crate) struct AddGroupPluginV1Args {
+    /// The plugin or external plugin adapter to add.
+    pub(crate) init_info: GroupPluginInitInfo,
+}
+
+/// Processor for the `AddGroupPluginV1` instruction.
+pub(crate) fn add_group_plugin_v1<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: AddGroupPluginV1Args,
+) -> ProgramResult {
+    // Derive the typed account context from the raw slice.
+    let ctx = AddGroupPluginV1Accounts::context(accounts)?;
+
+    // Basic guards.
+    assert_signer(ctx.accounts.payer)?;
+    let authority = resolve_authority(ctx.accounts.payer, ctx.accounts.authority)?;
+
+    // Ensure the canonical system program is provided.
+    if ctx.accounts.system_program.key != &solana_system_interface::program::ID {
+        return Err(MplCoreError::InvalidSystemProgram.into());
+    }
+
+    if !ctx.accounts.group.is_writable {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Ensure the signer is the update authority of the group.
+    if !is_valid_group_authority(ctx.accounts.group, authority)? {
+        return Err(MplCoreError::InvalidAuthority.into());
+    }
+
+    // Plugins always follow the membership count, so a group stored before the count was added
+    // is saved with one first.
+    let mut group = GroupV1::load(ctx.accounts.group, 0)?;
+    if group.membership_count.is_none() {
+        group.membership_count = Some(0);
+        save_group(
+            ctx.accounts.group,
+            &group,
+            ctx.accounts.payer,
+            ctx.accounts.system_program,
+        )?;
+    }
+
+    let (_, header_offset, mut plugin_header, mut plugin_registry) =
+        create_meta_idempotent::<GroupV1>(
+            ctx.accounts.group,
+            ctx.accounts.payer,
+            ctx.accounts.system_program,
+        )?;
+
+    match &args.init_info {
+        GroupPluginInitInfo::Plugin {
+            plugin,
+            init_authority,
+        } => {
+            match plugin {
+                Plugin::Attributes(_) => {}
+                Plugin::Royalties(royalties) => {
+                    validate_royalties(royalties)?;
+                }
+                // Freezes the transfer of every asset in the group or in its collections.
+                Plugin::PermanentFreezeDelegate(_) => {}
+                _ => {
+                    msg!("Error: Plugin is not supported on groups");
+                    return Err(MplCoreError::InvalidPlugin.into());
+                }
+            }
+
+            // Groups have no owner, so the plugin must be managed by an authority.
+            let plugin_authority = init_authority.clone().unwrap_or(plugin.manager());
+            if plugin_authority == Authority::Owner {
+                return Err(MplCoreError::InvalidAuthority.into());
+            }
+
+            initialize_plugin::<GroupV1>(
+                plugin,
+                &plugin_authority,
+                header_offset,
+                &mut plugin_header,
+                &mut plugin_registry,
+                ctx.accounts.group,
+                ctx.accounts.payer,
+                ctx.accounts.system_program,
+            )
+        }
+        GroupPluginInitInfo::ExternalPluginAdapter(init_info) => {
+            let plugin_authority = match init_info {
+                ExternalPluginAdapterInitInfo::AppData(app_data) => {
+                    app_data.init_plugin_authority.clone()
+                }
+                ExternalPluginAdapterInitInfo::LinkedAppData(app_data) => {
+                    app_data.init_plugin_authority.clone()
+                }
+                _ => {
+                    msg!("Error: External plugin adapter is not supported on groups");
+                    return Err(MplCoreError::InvalidPluginAdapterTarget.into());
+                }
+            };
+
+            if plugin_authority == Some(Authority::Owner) {
+                return Err(MplCoreError::InvalidAuthority.into());
+            }
+
+            initialize_external_plugin_adapter::<GroupV1>(
+                init_info,
+                header_offset,
+                &mut plugin_header,
+                &mut plugin_registry,
+                ctx.accounts.group,
+                ctx.accounts.payer,
+                ctx.accounts.system_program,
+                None,
+            )
+        }
+    }
+}
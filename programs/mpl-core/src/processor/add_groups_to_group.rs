@@ -4,20 +4,64 @@ use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
     pubkey::Pubkey,
 };
+use std::{
+    collections::BTreeMap,
+    io::{ErrorKind, Read},
+};
 
 use crate::{
     error::MplCoreError,
     instruction::accounts::{AddGroupsToGroupV1Accounts, Context},
     state::{GroupV1, SolanaAccount, MAX_GROUP_NESTING_DEPTH, MAX_GROUP_VECTOR_SIZE},
-    utils::{is_valid_group_authority, resolve_authority, save_flat_group},
+    utils::{is_valid_group_authority, resolve_authority, save_group},
 };
 
 /// Arguments for the `AddGroupsToGroupV1` instruction.
 #[repr(C)]
-#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+#[derive(BorshSerialize, PartialEq, Eq, Debug, Clone)]
 pub(crate) struct AddGroupsToGroupV1Args {
     /// The list of child groups to add to the parent group.
     pub(crate) groups: Vec<Pubkey>,
+    /// Every group above the parent group, proving that the new children do not create a cycle
+    /// and stay within `MAX_GROUP_NESTING_DEPTH`.
+    pub(crate) ancestors: Option<Vec<Pubkey>>,
+    /// Every group below the new children, proving that the groups below them also stay within
+    /// `MAX_GROUP_NESTING_DEPTH`.  Only used along with `ancestors`, and only needed when a new
+    /// child has child groups of its own.
+    pub(crate) descendants: Option<Vec<Pubkey>>,
+}
+
+impl BorshDeserialize for AddGroupsToGroupV1Args {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> std::io::Result<Self> {
+        let groups = Vec::<Pubkey>::deserialize_reader(reader)?;
+
+        // Instructions built before the ancestry proof end after the groups, and instructions
+        // built before the descendant proof end after the ancestors.
+        let ancestors = deserialize_trailing_keys(reader)?;
+        let descendants = deserialize_trailing_keys(reader)?;
+
+        Ok(Self {
+            groups,
+            ancestors,
+            descendants,
+        })
+    }
+}
+
+/// Deserialize an optional list of keys that may be missing from the end of the data.
+fn deserialize_trailing_keys<R: Read>(reader: &mut R) -> std::io::Result<Option<Vec<Pubkey>>> {
+    let mut option_tag = [0u8; 1];
+    match reader.read(&mut option_tag)? {
+        0 => Ok(None),
+        _ => match option_tag[0] {
+            0 => Ok(None),
+            1 => Ok(Some(Vec::<Pubkey>::deserialize_reader(reader)?)),
+            _ => Err(std::io::Error::new(
+                ErrorKind::InvalidData,
+                "Invalid option tag for group proof",
+            )),
+        },
+    }
 }
 
 /// Processor for the `AddGroupsToGroupV1` instruction.
@@ -32,12 +76,15 @@ pub(crate) fn add_groups_to_group_v1<'a>(
     //   2. [signer] Optional authority (group update authority)
     //   3. [] System program
     //   4..N [writable] Child group accounts, one for each pubkey in args.groups
+    //   N..M [] Ancestor group accounts, one for each pubkey in args.ancestors
+    //   M..P [] Descendant group accounts, one for each pubkey in args.descendants
     let ctx: Context<AddGroupsToGroupV1Accounts> = AddGroupsToGroupV1Accounts::context(accounts)?;
     let parent_group_info = ctx.accounts.parent_group;
     let payer_info = ctx.accounts.payer;
     let authority_info_opt = ctx.accounts.authority;
     let system_program_info = ctx.accounts.system_program;
-    let child_group_accounts = ctx.remaining_accounts;
+    let ancestor_keys = args.ancestors.as_deref().unwrap_or_default();
+    let descendant_keys = args.descendants.as_deref().unwrap_or_default();
 
     // Basic guards.
     assert_signer(payer_info)?;
@@ -55,14 +102,17 @@ pub(crate) fn add_groups_to_group_v1<'a>(
     }
 
     // Validate arg count matches remaining accounts.
-    if child_group_accounts.len() != args.groups.len() {
+    let key_count = args.groups.len() + ancestor_keys.len() + descendant_keys.len();
+    if ctx.remaining_accounts.len() != key_count {
         msg!(
             "Error: Number of group accounts ({}) does not match number of pubkeys in args ({}).",
-            child_group_accounts.len(),
-            args.groups.len()
+            ctx.remaining_accounts.len(),
+            key_count
         );
         return Err(ProgramError::NotEnoughAccountKeys);
     }
+    let (child_group_accounts, proof_accounts) = ctx.remaining_accounts.split_at(args.groups.len());
+    let (ancestor_accounts, descendant_accounts) = proof_accounts.split_at(ancestor_keys.len());
 
     // Deserialize parent group.
     let mut parent_group = GroupV1::load(parent_group_info, 0)?;
@@ -73,6 +123,27 @@ pub(crate) fn add_groups_to_group_v1<'a>(
         return Err(MplCoreError::InvalidAuthority.into());
     }
 
+    // Walk the ancestry proof of the parent group, if provided.
+    let ancestry = match args.ancestors {
+        Some(_) => {
+            let ancestors = load_group_proof(ancestor_accounts, ancestor_keys)?;
+            let (ancestors, depth) = parent_group.ancestry(&ancestors)?;
+            if depth >= MAX_GROUP_NESTING_DEPTH {
+                msg!("Error: Parent group is at the maximum nesting depth");
+                return Err(MplCoreError::GroupNestingDepthExceeded.into());
+            }
+
+            Some((ancestors, depth))
+        }
+        None => None,
+    };
+
+    // The descendant proof is only checked against the depth of the parent group.
+    let descendants = match ancestry {
+        Some(_) => load_group_proof(descendant_accounts, descendant_keys)?,
+        None => BTreeMap::new(),
+    };
+
     // Process each child group account.
     for (i, child_info) in child_group_accounts.iter().enumerate() {
         // Ensure account key matches expected pubkey.
@@ -95,9 +166,33 @@ pub(crate) fn add_groups_to_group_v1<'a>(
             return Err(MplCoreError::IncorrectAccount.into());
         }
 
+        if ancestry
+            .as_ref()
+            .is_some_and(|(ancestors, _)| ancestors.contains(child_info.key))
+        {
+            msg!("Error: Child group is an ancestor of the parent group");
+            return Err(MplCoreError::GroupCycleDetected.into());
+        }
+
         // Deserialize child group.
         let mut child_group = GroupV1::load(child_info, 0)?;
 
+        // The groups below the child move down along with it.
+        if let Some((_, depth)) = ancestry {
+            let (child_descendants, height) = child_group.descent(&descendants)?;
+            if child_descendants.contains(parent_group_info.key) {
+                msg!("Error: Parent group is a descendant of the child group");
+                return Err(MplCoreError::GroupCycleDetected.into());
+            }
+
+            if depth + 1 + height > MAX_GROUP_NESTING_DEPTH {
+                msg!(
+                    "Error: Child group and its descendants would exceed the maximum nesting depth"
+                );
+                return Err(MplCoreError::GroupNestingDepthExceeded.into());
+            }
+        }
+
         // Authority must also be the child group's update authority.
         if !is_valid_group_authority(child_info, authority_info)? {
             msg!("Error: Signer is not child group update authority");
@@ -121,11 +216,11 @@ pub(crate) fn add_groups_to_group_v1<'a>(
 
         if !child_group.parent_groups.contains(parent_group_info.key) {
             child_group.parent_groups.push(*parent_group_info.key);
-            save_flat_group(child_info, &child_group, payer_info, system_program_info)?;
+            save_group(child_info, &child_group, payer_info, system_program_info)?;
         }
     }
 
-    save_flat_group(
+    save_group(
         parent_group_info,
         &parent_group,
         payer_info,
@@ -134,3 +229,24 @@ pub(crate) fn add_groups_to_group_v1<'a>(
 
     Ok(())
 }
+
+/// Load the groups of an ancestry or descendant proof, checking them against their keys.
+fn load_group_proof(
+    proof_accounts: &[AccountInfo],
+    proof_keys: &[Pubkey],
+) -> Result<BTreeMap<Pubkey, GroupV1>, ProgramError> {
+    let mut proof = BTreeMap::new();
+    for (i, proof_info) in proof_accounts.iter().enumerate() {
+        if proof_info.key != &proof_keys[i] {
+            msg!(
+                "Error: Proof group account at position {} does not match provided pubkey list",
+                i
+            );
+            return Err(MplCoreError::IncorrectAccount.into());
+        }
+
+        proof.insert(*proof_info.key, GroupV1::load(proof_info, 0)?);
+    }
+
+    Ok(proof)
+}
@@ -49,7 +49,10 @@ pub(crate) fn add_plugin<'a>(
 
     // TODO move into plugin validation when asset/collection is part of validation context
     let plugin_type = PluginType::from(&args.plugin);
-    if plugin_type == PluginType::MasterEdition || plugin_type == PluginType::Groups {
+    if plugin_type == PluginType::MasterEdition
+        || plugin_type == PluginType::Groups
+        || plugin_type == PluginType::Multisig
+    {
         return Err(MplCoreError::InvalidPlugin.into());
     }
 
@@ -66,6 +69,7 @@ pub(crate) fn add_plugin<'a>(
         authority_info: authority,
         resolved_authorities: None,
         new_owner: None,
+        sale_price: None,
         new_asset_authority: None,
         new_collection_authority: None,
         target_plugin: Some(&args.plugin),
@@ -85,6 +89,7 @@ pub(crate) fn add_plugin<'a>(
         ctx.accounts.collection,
         None,
         None,
+        None,
         Some(&args.plugin),
         Some(&target_plugin_authority),
         None,
@@ -107,7 +112,7 @@ pub(crate) fn add_plugin<'a>(
         ctx.accounts.payer,
         ctx.accounts.system_program,
         &args.plugin,
-        &args.init_authority.unwrap_or(args.plugin.manager()),
+        &target_plugin_authority,
     )
 }
 
@@ -154,6 +159,7 @@ pub(crate) fn add_collection_plugin<'a>(
         authority_info: authority,
         resolved_authorities: None,
         new_owner: None,
+        sale_price: None,
         new_asset_authority: None,
         new_collection_authority: None,
         target_plugin: Some(&args.plugin),
@@ -193,7 +199,7 @@ pub(crate) fn add_collection_plugin<'a>(
         ctx.accounts.payer,
         ctx.accounts.system_program,
         &args.plugin,
-        &args.init_authority.unwrap_or(args.plugin.manager()),
+        &target_plugin_authority,
     )
 }
 
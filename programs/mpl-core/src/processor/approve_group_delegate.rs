@@ -0,0 +1,52 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use mpl_utils::assert_signer;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+};
+
+use crate::{
+    error::MplCoreError,
+    instruction::accounts::ApproveGroupDelegateV1Accounts,
+    utils::{create_group_delegate, is_valid_group_authority, resolve_authority},
+};
+
+/// Arguments for the `ApproveGroupDelegateV1` instruction.
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone, Default)]
+pub(crate) struct ApproveGroupDelegateV1Args {}
+
+/// Processor for the `ApproveGroupDelegateV1` instruction.
+pub(crate) fn approve_group_delegate_v1<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    _args: ApproveGroupDelegateV1Args,
+) -> ProgramResult {
+    // Derive the typed account context from the raw slice.
+    let ctx = ApproveGroupDelegateV1Accounts::context(accounts)?;
+
+    // Basic guards.
+    assert_signer(ctx.accounts.payer)?;
+    let authority = resolve_authority(ctx.accounts.payer, ctx.accounts.authority)?;
+
+    // Ensure the canonical system program is provided.
+    if ctx.accounts.system_program.key != &solana_system_interface::program::ID {
+        return Err(MplCoreError::InvalidSystemProgram.into());
+    }
+
+    if !ctx.accounts.group_delegate.is_writable {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Ensure the signer is the update authority of the group.
+    if !is_valid_group_authority(ctx.accounts.group, authority)? {
+        return Err(MplCoreError::InvalidAuthority.into());
+    }
+
+    create_group_delegate(
+        ctx.accounts.group_delegate,
+        ctx.accounts.group.key,
+        ctx.accounts.delegate.key,
+        authority.key,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+    )
+}
@@ -63,6 +63,7 @@ pub(crate) fn approve_plugin_authority<'a>(
         ctx.accounts.collection,
         None,
         None,
+        None,
         Some(&plugin),
         Some(&plugin_authority),
         None,
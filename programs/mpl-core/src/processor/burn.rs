@@ -9,7 +9,8 @@ use crate::{
     state::{AssetV1, CollectionV1, CompressionProof, Key, SolanaAccount, Wrappable},
     utils::{
         close_program_account, load_key, rebuild_account_state_from_proof_data, resolve_authority,
-        validate_asset_permissions, validate_collection_permissions, verify_proof,
+        resolve_collection_update_authority, validate_asset_permissions,
+        validate_collection_permissions, verify_proof,
     },
 };
 
@@ -55,12 +56,14 @@ pub(crate) fn burn<'a>(accounts: &'a [AccountInfo<'a>], args: BurnV1Args) -> Pro
                 .ok_or(MplCoreError::MissingSystemProgram)?;
 
             // Verify the proof and rebuild Asset struct in account space.
-            let (asset, plugins) = verify_proof(ctx.accounts.asset, &compression_proof)?;
+            let (asset, plugins, external_plugin_adapters) =
+                verify_proof(ctx.accounts.asset, &compression_proof)?;
 
             // Use the data from the compression proof to rebuild the account.  Only needed for validation.
             rebuild_account_state_from_proof_data(
                 asset,
                 plugins,
+                external_plugin_adapters,
                 ctx.accounts.asset,
                 ctx.accounts.payer,
                 system_program,
@@ -94,6 +97,7 @@ pub(crate) fn burn<'a>(accounts: &'a [AccountInfo<'a>], args: BurnV1Args) -> Pro
         None,
         None,
         None,
+        None,
         AssetV1::check_burn,
         CollectionV1::check_burn,
         PluginType::check_burn,
@@ -140,7 +144,9 @@ pub(crate) fn burn_collection<'a>(
     }
 
     // If the update authority is the one burning the collection, and the collection is empty, then it can be burned.
-    if authority.key != &collection.update_authority {
+    let resolved_collection =
+        resolve_collection_update_authority(ctx.accounts.collection, authority, accounts)?;
+    if authority.key != &resolved_collection.update_authority {
         return Err(MplCoreError::InvalidAuthority.into());
     }
 
@@ -0,0 +1,96 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use mpl_utils::assert_signer;
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg};
+
+use crate::{
+    error::MplCoreError,
+    instruction::accounts::{BurnBatchV1Accounts, Context},
+    plugins::{ExternalPluginAdapter, HookableLifecycleEvent, Plugin, PluginType},
+    state::{AssetV1, CollectionV1, Key, SolanaAccount},
+    utils::{close_program_account, load_key, resolve_authority, validate_asset_permissions},
+};
+
+/// Arguments for the `BurnBatchV1` instruction.
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub(crate) struct BurnBatchV1Args {
+    /// The number of asset accounts at the start of the remaining accounts.
+    pub(crate) num_assets: u8,
+}
+
+/// Processor for the `BurnBatchV1` instruction.
+///
+/// The first `num_assets` remaining accounts are the assets to burn, all of which must belong
+/// to the same collection (or to none). Any remaining accounts after the assets are available
+/// to external plugin adapters.
+pub(crate) fn burn_batch<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: BurnBatchV1Args,
+) -> ProgramResult {
+    // Accounts.
+    let ctx: Context<BurnBatchV1Accounts> = BurnBatchV1Accounts::context(accounts)?;
+    let collection = if let Some(collection) = ctx.accounts.collection {
+        Some(CollectionV1::load(collection, 0)?)
+    } else {
+        None
+    };
+
+    // Guards.
+    assert_signer(ctx.accounts.payer)?;
+    let authority = resolve_authority(ctx.accounts.payer, ctx.accounts.authority)?;
+
+    let num_assets = args.num_assets as usize;
+    if num_assets == 0 || ctx.remaining_accounts.len() < num_assets {
+        msg!("Error: Expected {} asset accounts", args.num_assets);
+        return Err(MplCoreError::InvalidBatchAccounts.into());
+    }
+
+    for asset_info in ctx.remaining_accounts[..num_assets].iter() {
+        match load_key(asset_info, 0)? {
+            Key::AssetV1 => (),
+            Key::HashedAssetV1 => {
+                msg!("Error: Burning compressed is currently not available");
+                return Err(MplCoreError::NotAvailable.into());
+            }
+            // Burned assets are left as a one byte `Key::Uninitialized` tombstone until they
+            // are collected, so an asset listed twice is rejected here.
+            Key::Uninitialized => {
+                msg!("Error: Asset {} has already been burned", asset_info.key);
+                return Err(MplCoreError::IncorrectAccount.into());
+            }
+            _ => return Err(MplCoreError::IncorrectAccount.into()),
+        }
+
+        // Validate asset permissions.
+        validate_asset_permissions(
+            accounts,
+            authority,
+            asset_info,
+            ctx.accounts.collection,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            AssetV1::check_burn,
+            CollectionV1::check_burn,
+            PluginType::check_burn,
+            AssetV1::validate_burn,
+            CollectionV1::validate_burn,
+            Plugin::validate_burn,
+            Some(ExternalPluginAdapter::validate_burn),
+            Some(HookableLifecycleEvent::Burn),
+        )?;
+
+        close_program_account(asset_info, ctx.accounts.payer)?;
+    }
+
+    if let Some(mut collection) = collection {
+        collection.decrement_size_by(args.num_assets as u32)?;
+        collection.save(ctx.accounts.collection.unwrap(), 0)?;
+    };
+
+    Ok(())
+}
@@ -1,14 +1,18 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use mpl_utils::assert_signer;
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
 };
 
+use super::groups_plugin_utils::detach_from_groups_plugin;
 use crate::{
     error::MplCoreError,
-    instruction::accounts::CloseGroupV1Accounts,
-    state::{GroupV1, SolanaAccount},
-    utils::{close_program_account, is_valid_group_authority, resolve_authority},
+    instruction::accounts::{CloseGroupV1Accounts, CloseGroupV2Accounts},
+    state::{AssetV1, CollectionV1, GroupMembershipV1, GroupV1, Key, SolanaAccount},
+    utils::{
+        close_program_account, is_closed_account, is_valid_group_authority, load_key,
+        resolve_authority, save_group,
+    },
 };
 
 /// Arguments for the `CloseGroupV1` instruction.
@@ -21,6 +25,10 @@ use crate::{
 pub(crate) struct CloseGroupV1Args {}
 
 /// Processor for the `CloseGroupV1` instruction.
+///
+/// Members stored in `GroupMembershipV1` accounts are counted by the group and
+/// must be removed before the group is closed. `CloseGroupV2` detaches members
+/// as part of closing the group instead.
 pub(crate) fn close_group_v1<'a>(
     accounts: &'a [AccountInfo<'a>],
     _args: CloseGroupV1Args,
@@ -48,7 +56,8 @@ pub(crate) fn close_group_v1<'a>(
     if !(group.collections.is_empty()
         && group.groups.is_empty()
         && group.parent_groups.is_empty()
-        && group.assets.is_empty())
+        && group.assets.is_empty()
+        && group.membership_count() == 0)
     {
         return Err(MplCoreError::GroupMustBeEmpty.into());
     }
@@ -56,3 +65,201 @@ pub(crate) fn close_group_v1<'a>(
     // Close the group account, transferring rent-exempt lamports back to the payer.
     close_program_account(ctx.accounts.group, ctx.accounts.payer)
 }
+
+/// Arguments for the `CloseGroupV2` instruction.
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone, Default)]
+pub(crate) struct CloseGroupV2Args {
+    /// Only detach the provided members and keep the group open, so that a group with more
+    /// members than fit in one transaction can be closed over several transactions.
+    pub(crate) detach_only: bool,
+}
+
+/// Processor for the `CloseGroupV2` instruction.
+///
+/// Remaining accounts are the members to detach from the group: collections, assets, child
+/// groups and parent groups. The `GroupMembershipV1` account of an asset that is not in the
+/// group's `assets` vector must be passed alongside the asset and is closed, including for assets
+/// burned while in the group, whether or not their lamports were collected. Only references to the group being closed are removed, so the
+/// group update authority alone may detach them. The group is only closed once every membership
+/// account it counts has been closed.
+pub(crate) fn close_group_v2<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: CloseGroupV2Args,
+) -> ProgramResult {
+    let ctx = CloseGroupV2Accounts::context(accounts)?;
+    let group_info = ctx.accounts.group;
+    let payer_info = ctx.accounts.payer;
+    let system_program_info = ctx.accounts.system_program;
+
+    // Basic guards.
+    assert_signer(payer_info)?;
+    let authority = resolve_authority(payer_info, ctx.accounts.authority)?;
+
+    if system_program_info.key != &solana_system_interface::program::ID {
+        return Err(MplCoreError::InvalidSystemProgram.into());
+    }
+
+    if !group_info.is_writable {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let mut group = GroupV1::load(group_info, 0)?;
+
+    if !is_valid_group_authority(group_info, authority)? {
+        return Err(MplCoreError::InvalidAuthority.into());
+    }
+
+    // Membership accounts are consumed by the asset they belong to.
+    let mut memberships: Vec<(GroupMembershipV1, &AccountInfo)> = Vec::new();
+    for member_info in ctx.remaining_accounts.iter() {
+        if member_info.owner != &crate::ID || load_key(member_info, 0)? != Key::GroupMembershipV1 {
+            continue;
+        }
+
+        let membership = GroupMembershipV1::load(member_info, 0)?;
+        if membership.group != *group_info.key {
+            msg!("Error: Membership account is not of the provided group");
+            return Err(MplCoreError::InvalidGroupMembership.into());
+        }
+
+        memberships.push((membership, member_info));
+    }
+
+    for member_info in ctx.remaining_accounts.iter() {
+        // A burned asset whose lamports were collected belongs to the system program.
+        let key = if is_closed_account(member_info) {
+            Key::Uninitialized
+        } else if member_info.owner != &crate::ID {
+            msg!("Error: Member account is not owned by the program");
+            return Err(MplCoreError::IncorrectAccount.into());
+        } else {
+            load_key(member_info, 0)?
+        };
+        if key == Key::GroupMembershipV1 {
+            continue;
+        }
+
+        if !member_info.is_writable {
+            msg!("Error: Member account must be writable");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        match key {
+            Key::CollectionV1 => {
+                if let Some(pos) = group
+                    .collections
+                    .iter()
+                    .position(|pk| pk == member_info.key)
+                {
+                    group.collections.remove(pos);
+                } else {
+                    msg!("Error: Collection is not a child of the provided group");
+                    return Err(MplCoreError::IncorrectAccount.into());
+                }
+
+                detach_from_groups_plugin::<CollectionV1>(
+                    member_info,
+                    group_info.key,
+                    payer_info,
+                    system_program_info,
+                )?;
+            }
+            // An asset burned by its owner while in the group leaves an uninitialized account,
+            // which is only detached from the group.
+            Key::AssetV1 | Key::Uninitialized => {
+                if let Some(pos) = group.assets.iter().position(|pk| pk == member_info.key) {
+                    group.assets.remove(pos);
+                } else if let Some(index) = memberships
+                    .iter()
+                    .position(|(membership, _)| membership.member == *member_info.key)
+                {
+                    let (_, membership_info) = memberships.remove(index);
+                    close_program_account(membership_info, payer_info)?;
+                    group.membership_count = Some(
+                        group
+                            .membership_count()
+                            .checked_sub(1)
+                            .ok_or(MplCoreError::NumericalOverflow)?,
+                    );
+                } else {
+                    msg!("Error: Asset is not a child of the provided group");
+                    return Err(MplCoreError::IncorrectAccount.into());
+                }
+
+                if key == Key::AssetV1 {
+                    detach_from_groups_plugin::<AssetV1>(
+                        member_info,
+                        group_info.key,
+                        payer_info,
+                        system_program_info,
+                    )?;
+                }
+            }
+            Key::GroupV1 => {
+                let mut related_group = GroupV1::load(member_info, 0)?;
+
+                if let Some(pos) = group.groups.iter().position(|pk| pk == member_info.key) {
+                    group.groups.remove(pos);
+                    match related_group
+                        .parent_groups
+                        .iter()
+                        .position(|pk| pk == group_info.key)
+                    {
+                        Some(pos) => related_group.parent_groups.remove(pos),
+                        None => {
+                            msg!("Error: Group not found in child's parent_groups");
+                            return Err(MplCoreError::InconsistentGroupRelationship.into());
+                        }
+                    };
+                } else if let Some(pos) = group
+                    .parent_groups
+                    .iter()
+                    .position(|pk| pk == member_info.key)
+                {
+                    group.parent_groups.remove(pos);
+                    match related_group
+                        .groups
+                        .iter()
+                        .position(|pk| pk == group_info.key)
+                    {
+                        Some(pos) => related_group.groups.remove(pos),
+                        None => {
+                            msg!("Error: Group not found in parent's groups");
+                            return Err(MplCoreError::InconsistentGroupRelationship.into());
+                        }
+                    };
+                } else {
+                    msg!("Error: Group is not related to the provided group");
+                    return Err(MplCoreError::IncorrectAccount.into());
+                }
+
+                save_group(member_info, &related_group, payer_info, system_program_info)?;
+            }
+            _ => {
+                msg!("Error: Unexpected member account");
+                return Err(MplCoreError::IncorrectAccount.into());
+            }
+        }
+    }
+
+    if !memberships.is_empty() {
+        msg!("Error: Membership account provided without its asset");
+        return Err(MplCoreError::InvalidGroupMembership.into());
+    }
+
+    if args.detach_only {
+        return save_group(group_info, &group, payer_info, system_program_info);
+    }
+
+    if !(group.collections.is_empty()
+        && group.groups.is_empty()
+        && group.parent_groups.is_empty()
+        && group.assets.is_empty()
+        && group.membership_count() == 0)
+    {
+        return Err(MplCoreError::GroupMustBeEmpty.into());
+    }
+
+    close_program_account(group_info, payer_info)
+}
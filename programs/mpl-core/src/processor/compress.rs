@@ -1,6 +1,6 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use mpl_utils::assert_signer;
-use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg};
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult};
 
 use crate::{
     error::MplCoreError,
@@ -54,6 +54,7 @@ pub(crate) fn compress<'a>(
                 None,
                 None,
                 None,
+                None,
                 AssetV1::check_compress,
                 CollectionV1::check_compress,
                 PluginType::check_compress,
@@ -74,11 +75,7 @@ pub(crate) fn compress<'a>(
             )?;
 
             // Send the spl-noop event for indexing the compressed asset.
-            compression_proof.wrap()?;
-
-            // TODO Enable compression.
-            msg!("Error: Compression currently not available");
-            Err(MplCoreError::NotAvailable.into())
+            compression_proof.wrap()
         }
         Key::HashedAssetV1 => Err(MplCoreError::AlreadyCompressed.into()),
         _ => Err(MplCoreError::IncorrectAccount.into()),
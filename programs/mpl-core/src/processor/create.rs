@@ -6,6 +6,7 @@ use solana_program::{
 };
 use solana_system_interface::instruction as system_instruction;
 
+use super::reject_unprinted_edition;
 use crate::{
     error::MplCoreError,
     instruction::accounts::CreateV2Accounts,
@@ -66,7 +67,6 @@ pub(crate) fn process_create<'a>(
 ) -> ProgramResult {
     // Accounts.
     let ctx = CreateV2Accounts::context(accounts)?;
-    let rent = Rent::get()?;
 
     // Guards.
     assert_signer(ctx.accounts.asset)?;
@@ -113,9 +113,50 @@ pub(crate) fn process_create<'a>(
         args.uri.clone(),
     );
 
-    let serialized_data = borsh::to_vec(&new_asset)?;
+    if let Some(plugins) = &args.plugins {
+        reject_unprinted_edition(ctx.accounts.collection, plugins)?;
+    }
+
+    create_asset_account(
+        accounts,
+        ctx.accounts.asset,
+        ctx.accounts.collection,
+        authority,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+        &new_asset,
+        args.data_state,
+        args.plugins,
+        args.external_plugin_adapters,
+    )?;
+
+    if let Some(mut collection) = collection {
+        collection.increment_minted()?;
+        collection.increment_size()?;
+        collection.save(ctx.accounts.collection.unwrap(), 0)?;
+    };
+
+    Ok(())
+}
+
+/// Create and validate a single asset account, initializing its plugins and external plugin
+/// adapters. The caller is responsible for updating the collection counters.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn create_asset_account<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    asset_info: &'a AccountInfo<'a>,
+    collection_info: Option<&'a AccountInfo<'a>>,
+    authority: &'a AccountInfo<'a>,
+    payer: &'a AccountInfo<'a>,
+    system_program: &'a AccountInfo<'a>,
+    new_asset: &AssetV1,
+    data_state: DataState,
+    plugins: Option<Vec<PluginAuthorityPair>>,
+    external_plugin_adapters: Option<Vec<ExternalPluginAdapterInitInfo>>,
+) -> ProgramResult {
+    let serialized_data = borsh::to_vec(new_asset)?;
 
-    let serialized_data = match args.data_state {
+    let serialized_data = match data_state {
         DataState::AccountState => serialized_data,
         DataState::LedgerState => {
             // TODO: Implement minting compressed.
@@ -124,41 +165,39 @@ pub(crate) fn process_create<'a>(
         }
     };
 
+    let rent = Rent::get()?;
     let lamports = rent.minimum_balance(serialized_data.len()) + get_create_fee()?;
 
     // CPI to the System Program.
     invoke(
         &system_instruction::create_account(
-            ctx.accounts.payer.key,
-            ctx.accounts.asset.key,
+            payer.key,
+            asset_info.key,
             lamports,
             serialized_data.len() as u64,
             &crate::ID,
         ),
-        &[
-            ctx.accounts.payer.clone(),
-            ctx.accounts.asset.clone(),
-            ctx.accounts.system_program.clone(),
-        ],
+        &[payer.clone(), asset_info.clone(), system_program.clone()],
     )?;
 
     // SAFETY: `serialized_data` cannot alias the account data, and the asset
     // account was just created with `serialized_data.len()` bytes.
     unsafe {
         sol_memcpy(
-            &mut ctx.accounts.asset.try_borrow_mut_data()?,
+            &mut asset_info.try_borrow_mut_data()?,
             &serialized_data,
             serialized_data.len(),
         );
     }
 
-    if args.data_state == DataState::AccountState {
+    if data_state == DataState::AccountState {
         // Validate asset permissions.
         let _ = validate_asset_permissions(
             accounts,
             authority,
-            ctx.accounts.asset,
-            ctx.accounts.collection,
+            asset_info,
+            collection_info,
+            None,
             None,
             None,
             None,
@@ -179,21 +218,17 @@ pub(crate) fn process_create<'a>(
         let mut approved = true;
         let mut force_approved = false;
 
-        if let Some(plugins) = args.plugins {
+        if let Some(plugins) = plugins {
             if !plugins.is_empty() {
                 let (header_offset, mut plugin_header, mut plugin_registry) =
-                    create_plugin_meta::<AssetV1>(
-                        &new_asset,
-                        ctx.accounts.asset,
-                        ctx.accounts.payer,
-                        ctx.accounts.system_program,
-                    )?;
+                    create_plugin_meta::<AssetV1>(new_asset, asset_info, payer, system_program)?;
                 for plugin in &plugins {
                     // TODO move into plugin validation when asset/collection is part of validation context
                     let plugin_type = PluginType::from(&plugin.plugin);
                     if plugin_type == PluginType::MasterEdition
                         || plugin_type == PluginType::BubblegumV2
                         || plugin_type == PluginType::Groups
+                        || plugin_type == PluginType::Multisig
                     {
                         return Err(MplCoreError::InvalidPlugin.into());
                     }
@@ -202,13 +237,17 @@ pub(crate) fn process_create<'a>(
                     {
                         let validation_ctx = PluginValidationContext {
                             accounts,
-                            asset_info: Some(ctx.accounts.asset),
-                            collection_info: ctx.accounts.collection,
+                            asset_info: Some(asset_info),
+                            collection_info,
                             self_key: Key::AssetV1,
-                            self_authority: &plugin.authority.unwrap_or(plugin.plugin.manager()),
+                            self_authority: &plugin
+                                .authority
+                                .clone()
+                                .unwrap_or(plugin.plugin.manager()),
                             authority_info: authority,
                             resolved_authorities: None,
                             new_owner: None,
+                            sale_price: None,
                             new_asset_authority: None,
                             new_collection_authority: None,
                             target_plugin: None,
@@ -224,26 +263,22 @@ pub(crate) fn process_create<'a>(
                     }
                     initialize_plugin::<AssetV1>(
                         &plugin.plugin,
-                        &plugin.authority.unwrap_or(plugin.plugin.manager()),
+                        &plugin.authority.clone().unwrap_or(plugin.plugin.manager()),
                         header_offset,
                         &mut plugin_header,
                         &mut plugin_registry,
-                        ctx.accounts.asset,
-                        ctx.accounts.payer,
-                        ctx.accounts.system_program,
+                        asset_info,
+                        payer,
+                        system_program,
                     )?;
                 }
             }
         }
 
-        if let Some(plugins) = args.external_plugin_adapters {
+        if let Some(plugins) = external_plugin_adapters {
             if !plugins.is_empty() {
                 let (_, header_offset, mut plugin_header, mut plugin_registry) =
-                    create_meta_idempotent::<AssetV1>(
-                        ctx.accounts.asset,
-                        ctx.accounts.payer,
-                        ctx.accounts.system_program,
-                    )?;
+                    create_meta_idempotent::<AssetV1>(asset_info, payer, system_program)?;
                 for plugin_init_info in &plugins {
                     let external_check_result_bits = ExternalCheckResultBits::from(
                         ExternalPluginAdapter::check_create(plugin_init_info),
@@ -264,14 +299,15 @@ pub(crate) fn process_create<'a>(
                     if external_check_result_bits.can_reject() {
                         let validation_ctx = PluginValidationContext {
                             accounts,
-                            asset_info: Some(ctx.accounts.asset),
-                            collection_info: ctx.accounts.collection,
+                            asset_info: Some(asset_info),
+                            collection_info,
                             self_key: Key::AssetV1,
                             // External plugin adapters are always managed by the update authority.
                             self_authority: &Authority::UpdateAuthority,
                             authority_info: authority,
                             resolved_authorities: None,
                             new_owner: None,
+                            sale_price: None,
                             new_asset_authority: None,
                             new_collection_authority: None,
                             target_plugin: None,
@@ -292,9 +328,9 @@ pub(crate) fn process_create<'a>(
                         header_offset,
                         &mut plugin_header,
                         &mut plugin_registry,
-                        ctx.accounts.asset,
-                        ctx.accounts.payer,
-                        ctx.accounts.system_program,
+                        asset_info,
+                        payer,
+                        system_program,
                         None,
                     )?;
                 }
@@ -306,11 +342,5 @@ pub(crate) fn process_create<'a>(
         }
     }
 
-    if let Some(mut collection) = collection {
-        collection.increment_minted()?;
-        collection.increment_size()?;
-        collection.save(ctx.accounts.collection.unwrap(), 0)?;
-    };
-
     Ok(())
 }
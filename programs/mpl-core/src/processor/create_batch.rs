@@ -0,0 +1,91 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use mpl_utils::assert_signer;
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg, pubkey::Pubkey};
+
+use super::{create_asset_account, reject_unprinted_edition};
+use crate::{
+    error::MplCoreError,
+    instruction::accounts::{Context, CreateBatchV1Accounts},
+    plugins::PluginAuthorityPair,
+    state::{AssetV1, CollectionV1, DataState, SolanaAccount, UpdateAuthority},
+    utils::resolve_authority,
+};
+
+/// The data for a single asset created by the `CreateBatchV1` instruction.
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub(crate) struct AssetInitInfo {
+    /// The owner of the new asset.
+    pub(crate) owner: Pubkey,
+    /// The name of the new asset.
+    pub(crate) name: String,
+    /// The URI of the new asset.
+    pub(crate) uri: String,
+    /// The plugins to initialize on the new asset.
+    pub(crate) plugins: Vec<PluginAuthorityPair>,
+}
+
+/// Arguments for the `CreateBatchV1` instruction.
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub(crate) struct CreateBatchV1Args {
+    pub(crate) assets: Vec<AssetInitInfo>,
+}
+
+/// Processor for the `CreateBatchV1` instruction.
+///
+/// The first `assets.len()` remaining accounts are the new asset accounts, in the same order
+/// as the `assets` argument. Any remaining accounts after the assets are available to
+/// external plugin adapters.
+pub(crate) fn create_batch<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: CreateBatchV1Args,
+) -> ProgramResult {
+    // Accounts.
+    let ctx: Context<CreateBatchV1Accounts> = CreateBatchV1Accounts::context(accounts)?;
+
+    // Guards.
+    assert_signer(ctx.accounts.payer)?;
+    let authority = resolve_authority(ctx.accounts.payer, ctx.accounts.authority)?;
+
+    if *ctx.accounts.system_program.key != solana_system_interface::program::ID {
+        return Err(MplCoreError::InvalidSystemProgram.into());
+    }
+
+    let num_assets = args.assets.len();
+    if num_assets == 0 || ctx.remaining_accounts.len() < num_assets {
+        msg!("Error: Expected {} asset accounts", num_assets);
+        return Err(MplCoreError::InvalidBatchAccounts.into());
+    }
+
+    let mut collection = CollectionV1::load(ctx.accounts.collection, 0)?;
+
+    for (asset_info, asset_args) in ctx.remaining_accounts[..num_assets].iter().zip(args.assets) {
+        assert_signer(asset_info)?;
+        reject_unprinted_edition(Some(ctx.accounts.collection), &asset_args.plugins)?;
+
+        let new_asset = AssetV1::new(
+            asset_args.owner,
+            UpdateAuthority::Collection(*ctx.accounts.collection.key),
+            asset_args.name,
+            asset_args.uri,
+        );
+
+        create_asset_account(
+            accounts,
+            asset_info,
+            Some(ctx.accounts.collection),
+            authority,
+            ctx.accounts.payer,
+            ctx.accounts.system_program,
+            &new_asset,
+            DataState::AccountState,
+            Some(asset_args.plugins),
+            None,
+        )?;
+    }
+
+    collection.increment_minted_by(num_assets as u32)?;
+    collection.increment_size_by(num_assets as u32)?;
+    collection.save(ctx.accounts.collection, 0)
+}
@@ -167,10 +167,14 @@ pub(crate) fn process_create_collection<'a>(
                         asset_info: None,
                         collection_info: Some(ctx.accounts.collection),
                         self_key: Key::CollectionV1,
-                        self_authority: &plugin.authority.unwrap_or(plugin.plugin.manager()),
+                        self_authority: &plugin
+                            .authority
+                            .clone()
+                            .unwrap_or(plugin.plugin.manager()),
                         authority_info: ctx.accounts.payer,
                         resolved_authorities: None,
                         new_owner: None,
+                        sale_price: None,
                         new_asset_authority: None,
                         new_collection_authority: None,
                         target_plugin: None,
@@ -194,7 +198,7 @@ pub(crate) fn process_create_collection<'a>(
                     }
                     plugin.plugin.manager()
                 } else {
-                    plugin.authority.unwrap_or(plugin.plugin.manager())
+                    plugin.authority.clone().unwrap_or(plugin.plugin.manager())
                 };
 
                 initialize_plugin::<CollectionV1>(
@@ -15,7 +15,7 @@ use crate::{
     state::{GroupV1, Key, SolanaAccount, MAX_GROUP_NESTING_DEPTH, MAX_GROUP_VECTOR_SIZE},
     utils::{
         is_valid_asset_authority, is_valid_collection_authority, is_valid_group_authority,
-        load_key, resolve_authority, save_flat_group,
+        load_key, resolve_authority, save_group,
     },
 };
 
@@ -143,12 +143,7 @@ pub(crate) fn create_group_v1<'a>(
         ],
     )?;
 
-    save_flat_group(
-        ctx.accounts.group,
-        &new_group,
-        ctx.accounts.payer,
-        ctx.accounts.system_program,
-    )?;
+    new_group.save(ctx.accounts.group, 0)?;
 
     // ----------------------------------------------------------------------
     // POST-CREATION LINKING LOGIC
@@ -195,7 +190,7 @@ pub(crate) fn create_group_v1<'a>(
         }
 
         // Authority check (collection update authority or delegate)
-        if !is_valid_collection_authority(collection_info, authority_info)? {
+        if !is_valid_collection_authority(collection_info, authority_info, accounts)? {
             msg!("Error: Signer is not collection update authority/delegate");
             return Err(MplCoreError::InvalidAuthority.into());
         }
@@ -239,7 +234,7 @@ pub(crate) fn create_group_v1<'a>(
             }
 
             child_group.parent_groups.push(*ctx.accounts.group.key);
-            save_flat_group(
+            save_group(
                 child_info,
                 &child_group,
                 ctx.accounts.payer,
@@ -278,7 +273,7 @@ pub(crate) fn create_group_v1<'a>(
             }
 
             parent_group.groups.push(*ctx.accounts.group.key);
-            save_flat_group(
+            save_group(
                 parent_info,
                 &parent_group,
                 ctx.accounts.payer,
@@ -1,6 +1,6 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use mpl_utils::assert_signer;
-use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg};
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult};
 
 use crate::{
     error::MplCoreError,
@@ -43,7 +43,8 @@ pub(crate) fn decompress<'a>(
     match load_key(ctx.accounts.asset, 0)? {
         Key::HashedAssetV1 => {
             // Verify the proof and rebuild `Asset`` struct in account space.
-            let (mut asset, plugins) = verify_proof(ctx.accounts.asset, &args.compression_proof)?;
+            let (mut asset, plugins, external_plugin_adapters) =
+                verify_proof(ctx.accounts.asset, &args.compression_proof)?;
 
             // Increment sequence number.  Note `Asset`` will always be `Some(_)`` here
             // after rebuilding from a compression proof.
@@ -53,6 +54,7 @@ pub(crate) fn decompress<'a>(
             rebuild_account_state_from_proof_data(
                 asset,
                 plugins,
+                external_plugin_adapters,
                 ctx.accounts.asset,
                 ctx.accounts.payer,
                 ctx.accounts.system_program,
@@ -70,6 +72,7 @@ pub(crate) fn decompress<'a>(
                 None,
                 None,
                 None,
+                None,
                 AssetV1::check_decompress,
                 CollectionV1::check_decompress,
                 PluginType::check_decompress,
@@ -80,9 +83,7 @@ pub(crate) fn decompress<'a>(
                 None,
             )?;
 
-            // TODO Enable compression.
-            msg!("Error: Decompression currently not available");
-            Err(MplCoreError::NotAvailable.into())
+            Ok(())
         }
         Key::AssetV1 => Err(MplCoreError::AlreadyDecompressed.into()),
         _ => Err(MplCoreError::IncorrectAccount.into()),
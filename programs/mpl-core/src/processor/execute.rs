@@ -7,16 +7,24 @@ use solana_program::{
     instruction::{AccountMeta, Instruction},
     msg,
     program::{invoke, invoke_signed},
+    program_error::ProgramError,
     pubkey::Pubkey,
 };
 use solana_system_interface::instruction as system_instruction;
 
 use crate::{
     error::MplCoreError,
-    instruction::accounts::ExecuteV1Accounts,
-    plugins::{ExternalPluginAdapter, HookableLifecycleEvent, Plugin, PluginType},
-    state::{get_execute_fee, AssetV1, CollectionV1, Key},
-    utils::{load_key, resolve_authority, validate_asset_permissions},
+    instruction::accounts::{
+        ExecuteBatchV1Accounts, ExecuteCollectionV1Accounts, ExecuteV1Accounts,
+    },
+    plugins::{
+        fetch_plugin, ExecuteGuard, ExternalPluginAdapter, HookableLifecycleEvent, Plugin,
+        PluginType,
+    },
+    state::{get_execute_fee, AssetV1, CollectionV1, DataBlob, Key, SolanaAccount},
+    utils::{
+        load_key, resolve_authority, validate_asset_permissions, validate_collection_permissions,
+    },
 };
 
 const PREFIX: &str = "mpl-core-execute";
@@ -27,38 +35,246 @@ pub(crate) struct ExecuteV1Args {
     pub instruction_data: Vec<u8>,
 }
 
+/// An instruction executed by the asset signer as part of an `ExecuteBatchV1`.
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct ExecuteBatchInstruction {
+    /// The index in the remaining accounts of the program to invoke.
+    pub program_index: u8,
+    /// The index in the remaining accounts of the first account of the instruction.
+    pub accounts_start: u8,
+    /// The number of accounts of the instruction.
+    pub accounts_len: u8,
+    /// The instruction data.
+    pub data: Vec<u8>,
+}
+
+/// Arguments for the `ExecuteBatchV1` instruction.
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub(crate) struct ExecuteBatchV1Args {
+    /// The instructions to execute, in order.
+    pub(crate) instructions: Vec<ExecuteBatchInstruction>,
+}
+
+/// Arguments for the `ExecuteCollectionV1` instruction.
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub(crate) struct ExecuteCollectionV1Args {
+    /// The instruction data of the instruction to execute.
+    pub(crate) instruction_data: Vec<u8>,
+}
+
 pub(crate) fn execute<'a>(accounts: &'a [AccountInfo<'a>], args: ExecuteV1Args) -> ProgramResult {
     // Accounts.
     let ctx = ExecuteV1Accounts::context(accounts)?;
 
+    let bump = validate_execute_and_pay_fee(
+        accounts,
+        ctx.accounts.asset,
+        ctx.accounts.collection,
+        ctx.accounts.asset_signer,
+        ctx.accounts.payer,
+        ctx.accounts.authority,
+        ctx.accounts.system_program,
+    )?;
+
+    // If the first remaining account is an ExecutionDelegateRecordV1, strip it
+    // before passing to the CPI -- it was only needed for plugin validation.
+    let cpi_accounts = if let Some(first) = ctx.remaining_accounts.first() {
+        if first.owner == &mpl_agent_tools::ID
+            && first.data_len() > 0
+            && first.data.borrow()[0] == AgentKey::ExecutionDelegateRecordV1 as u8
+        {
+            &ctx.remaining_accounts[1..]
+        } else {
+            ctx.remaining_accounts
+        }
+    } else {
+        ctx.remaining_accounts
+    };
+
+    let mut execute_guards =
+        ExecuteGuards::fetch(Some(ctx.accounts.asset), ctx.accounts.collection)?;
+
+    process_execute(
+        ctx.accounts.asset.key,
+        ctx.accounts.asset_signer,
+        ctx.accounts.program_id.key,
+        args.instruction_data,
+        cpi_accounts,
+        bump,
+        &mut execute_guards,
+    )
+}
+
+/// Processor for the `ExecuteBatchV1` instruction.
+///
+/// Each instruction invokes the program at `program_index` in the remaining accounts with the
+/// `accounts_len` remaining accounts starting at `accounts_start`.  The asset is validated and
+/// the execute fee is charged once for the whole batch.
+///
+/// An instruction may invoke mpl-core itself, including on the executing asset or its
+/// collection.  The execute guards and their running spend totals are loaded once before the
+/// first instruction and held in memory, so an instruction that updates or removes a guard
+/// mid-batch only takes effect from the next execute; the rest of the batch is still checked
+/// against the guards as they were when it started.
+pub(crate) fn execute_batch<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: ExecuteBatchV1Args,
+) -> ProgramResult {
+    // Accounts.
+    let ctx = ExecuteBatchV1Accounts::context(accounts)?;
+
+    if args.instructions.is_empty() {
+        msg!("Error: No instructions to execute");
+        return Err(MplCoreError::InvalidBatchAccounts.into());
+    }
+
+    // Check every instruction's accounts before anything is invoked.
+    for instruction in args.instructions.iter() {
+        let accounts_end = instruction.accounts_start as usize + instruction.accounts_len as usize;
+        if instruction.program_index as usize >= ctx.remaining_accounts.len()
+            || accounts_end > ctx.remaining_accounts.len()
+        {
+            msg!(
+                "Error: Instruction accounts are out of range of the {} remaining accounts",
+                ctx.remaining_accounts.len()
+            );
+            return Err(MplCoreError::InvalidBatchAccounts.into());
+        }
+    }
+
+    let bump = validate_execute_and_pay_fee(
+        accounts,
+        ctx.accounts.asset,
+        ctx.accounts.collection,
+        ctx.accounts.asset_signer,
+        ctx.accounts.payer,
+        ctx.accounts.authority,
+        ctx.accounts.system_program,
+    )?;
+
+    let mut execute_guards =
+        ExecuteGuards::fetch(Some(ctx.accounts.asset), ctx.accounts.collection)?;
+
+    for instruction in args.instructions {
+        let accounts_start = instruction.accounts_start as usize;
+        let accounts_end = accounts_start + instruction.accounts_len as usize;
+
+        process_execute(
+            ctx.accounts.asset.key,
+            ctx.accounts.asset_signer,
+            ctx.remaining_accounts[instruction.program_index as usize].key,
+            instruction.data,
+            &ctx.remaining_accounts[accounts_start..accounts_end],
+            bump,
+            &mut execute_guards,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Processor for the `ExecuteCollectionV1` instruction.
+///
+/// The collection signer PDA is derived from the collection key with the same prefix as the
+/// asset signer.  The collection update authority or an update delegate may execute.
+pub(crate) fn execute_collection<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: ExecuteCollectionV1Args,
+) -> ProgramResult {
+    // Accounts.
+    let ctx = ExecuteCollectionV1Accounts::context(accounts)?;
+
     // Guards.
-    if ctx.accounts.asset.owner != &crate::ID {
-        return Err(MplCoreError::InvalidAsset.into());
+    if ctx.accounts.collection.owner != &crate::ID {
+        return Err(MplCoreError::InvalidCollection.into());
     }
 
     let bump = assert_derivation(
         &crate::ID,
-        ctx.accounts.asset_signer,
-        &[PREFIX.as_bytes(), ctx.accounts.asset.key.as_ref()],
+        ctx.accounts.collection_signer,
+        &[PREFIX.as_bytes(), ctx.accounts.collection.key.as_ref()],
         MplCoreError::InvalidExecutePda,
     )?;
 
-    let payer_is_pda = ctx.accounts.payer.key == ctx.accounts.asset_signer.key;
-
-    let authority = if payer_is_pda {
-        let authority = ctx.accounts.authority.ok_or(MplCoreError::MissingSigner)?;
-        assert_signer(authority)?;
-        authority
-    } else {
-        assert_signer(ctx.accounts.payer)?;
-        resolve_authority(ctx.accounts.payer, ctx.accounts.authority)?
-    };
+    let payer_is_pda = ctx.accounts.payer.key == ctx.accounts.collection_signer.key;
+    let authority =
+        resolve_execute_authority(ctx.accounts.payer, ctx.accounts.authority, payer_is_pda)?;
 
     if *ctx.accounts.system_program.key != solana_system_interface::program::ID {
         return Err(MplCoreError::InvalidSystemProgram.into());
     }
 
-    if let Key::HashedAssetV1 = load_key(ctx.accounts.asset, 0)? {
+    let _ = validate_collection_permissions(
+        accounts,
+        authority,
+        ctx.accounts.collection,
+        None,
+        None,
+        None,
+        None,
+        None,
+        CollectionV1::check_execute,
+        PluginType::check_collection_execute,
+        CollectionV1::validate_collection_execute,
+        Plugin::validate_execute,
+        Some(ExternalPluginAdapter::validate_execute),
+        Some(HookableLifecycleEvent::Execute),
+    )?;
+
+    pay_execute_fee(
+        ctx.accounts.payer,
+        ctx.accounts.collection,
+        payer_is_pda,
+        bump,
+    )?;
+
+    let mut execute_guards = ExecuteGuards::fetch(None, Some(ctx.accounts.collection))?;
+
+    process_execute(
+        ctx.accounts.collection.key,
+        ctx.accounts.collection_signer,
+        ctx.accounts.program_id.key,
+        args.instruction_data,
+        ctx.remaining_accounts,
+        bump,
+        &mut execute_guards,
+    )
+}
+
+/// Validate the execute lifecycle event for the asset and charge the execute fee, returning
+/// the bump of the asset signer PDA.
+fn validate_execute_and_pay_fee<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    asset_info: &'a AccountInfo<'a>,
+    collection_info: Option<&'a AccountInfo<'a>>,
+    asset_signer_info: &'a AccountInfo<'a>,
+    payer_info: &'a AccountInfo<'a>,
+    authority_info: Option<&'a AccountInfo<'a>>,
+    system_program_info: &'a AccountInfo<'a>,
+) -> Result<u8, ProgramError> {
+    // Guards.
+    if asset_info.owner != &crate::ID {
+        return Err(MplCoreError::InvalidAsset.into());
+    }
+
+    let bump = assert_derivation(
+        &crate::ID,
+        asset_signer_info,
+        &[PREFIX.as_bytes(), asset_info.key.as_ref()],
+        MplCoreError::InvalidExecutePda,
+    )?;
+
+    let payer_is_pda = payer_info.key == asset_signer_info.key;
+    let authority = resolve_execute_authority(payer_info, authority_info, payer_is_pda)?;
+
+    if *system_program_info.key != solana_system_interface::program::ID {
+        return Err(MplCoreError::InvalidSystemProgram.into());
+    }
+
+    if let Key::HashedAssetV1 = load_key(asset_info, 0)? {
         msg!("Error: Update plugin for compressed is not available");
         return Err(MplCoreError::NotAvailable.into());
     }
@@ -66,8 +282,9 @@ pub(crate) fn execute<'a>(accounts: &'a [AccountInfo<'a>], args: ExecuteV1Args)
     let (mut asset, _, _) = validate_asset_permissions(
         accounts,
         authority,
-        ctx.accounts.asset,
-        ctx.accounts.collection,
+        asset_info,
+        collection_info,
+        None,
         None,
         None,
         None,
@@ -85,67 +302,167 @@ pub(crate) fn execute<'a>(accounts: &'a [AccountInfo<'a>], args: ExecuteV1Args)
     )?;
 
     // Increment sequence number and save only if it is `Some(_)`.
-    asset.increment_seq_and_save(ctx.accounts.asset)?;
+    asset.increment_seq_and_save(asset_info)?;
+
+    pay_execute_fee(payer_info, asset_info, payer_is_pda, bump)?;
+
+    Ok(bump)
+}
+
+/// Resolve the authority of an execute instruction.  When the signer PDA pays the execute fee
+/// the authority must sign separately.
+fn resolve_execute_authority<'a>(
+    payer_info: &'a AccountInfo<'a>,
+    authority_info: Option<&'a AccountInfo<'a>>,
+    payer_is_pda: bool,
+) -> Result<&'a AccountInfo<'a>, ProgramError> {
+    if payer_is_pda {
+        let authority = authority_info.ok_or(MplCoreError::MissingSigner)?;
+        assert_signer(authority)?;
+        Ok(authority)
+    } else {
+        assert_signer(payer_info)?;
+        resolve_authority(payer_info, authority_info)
+    }
+}
 
+/// Charge the execute fee to the payer, transferring it to the asset or collection.
+fn pay_execute_fee<'a>(
+    payer_info: &AccountInfo<'a>,
+    account_info: &AccountInfo<'a>,
+    payer_is_pda: bool,
+    bump: u8,
+) -> ProgramResult {
     let fee = get_execute_fee()?;
-    let transfer_ix =
-        system_instruction::transfer(ctx.accounts.payer.key, ctx.accounts.asset.key, fee);
+    let transfer_ix = system_instruction::transfer(payer_info.key, account_info.key, fee);
 
     if payer_is_pda {
-        // Payer is the asset signer PDA -- use invoke_signed so the PDA can
+        // Payer is the signer PDA -- use invoke_signed so the PDA can
         // pay the execute fee from its own lamports.
         invoke_signed(
             &transfer_ix,
-            &[ctx.accounts.payer.clone(), ctx.accounts.asset.clone()],
-            &[&[PREFIX.as_bytes(), ctx.accounts.asset.key.as_ref(), &[bump]]],
-        )?;
+            &[payer_info.clone(), account_info.clone()],
+            &[&[PREFIX.as_bytes(), account_info.key.as_ref(), &[bump]]],
+        )
     } else {
-        invoke(
-            &transfer_ix,
-            &[ctx.accounts.payer.clone(), ctx.accounts.asset.clone()],
-        )?;
+        invoke(&transfer_ix, &[payer_info.clone(), account_info.clone()])
     }
+}
 
-    // If the first remaining account is an ExecutionDelegateRecordV1, strip it
-    // before passing to the CPI -- it was only needed for plugin validation.
-    let cpi_accounts = if let Some(first) = ctx.remaining_accounts.first() {
-        if first.owner == &mpl_agent_tools::ID
-            && first.data_len() > 0
-            && first.data.borrow()[0] == AgentKey::ExecutionDelegateRecordV1 as u8
-        {
-            &ctx.remaining_accounts[1..]
-        } else {
-            ctx.remaining_accounts
+/// The execute guards of an asset and its collection, and the lamports spent by the signer
+/// PDA per program within the current instruction.
+struct ExecuteGuards {
+    guards: Vec<ExecuteGuard>,
+    spent: Vec<(Pubkey, u64)>,
+}
+
+impl ExecuteGuards {
+    /// Fetch the execute guards of the asset and its collection.
+    fn fetch(
+        asset_info: Option<&AccountInfo>,
+        collection_info: Option<&AccountInfo>,
+    ) -> Result<Self, ProgramError> {
+        let mut guards = vec![];
+
+        if let Some(asset_info) = asset_info {
+            if let Some(guard) = fetch_execute_guard::<AssetV1>(asset_info)? {
+                guards.push(guard);
+            }
         }
-    } else {
-        ctx.remaining_accounts
-    };
 
-    process_execute(
-        ctx.accounts.asset.key,
-        ctx.accounts.asset_signer.key,
-        ctx.accounts.program_id.key,
-        args.instruction_data,
-        cpi_accounts,
-        bump,
-    )
+        if let Some(collection_info) = collection_info {
+            if let Some(guard) = fetch_execute_guard::<CollectionV1>(collection_info)? {
+                guards.push(guard);
+            }
+        }
+
+        Ok(Self {
+            guards,
+            spent: vec![],
+        })
+    }
+
+    /// Check that every guard allows the program, returning the spend limits that apply to it.
+    fn check_program(&self, program_id: &Pubkey) -> Result<Vec<u64>, ProgramError> {
+        let mut spend_limits = vec![];
+        for guard in self.guards.iter() {
+            if let Some(spend_limit) = guard.check_program(program_id)? {
+                spend_limits.push(spend_limit);
+            }
+        }
+
+        Ok(spend_limits)
+    }
+
+    /// Record the lamports spent by the asset signer in a call to the program, and check the
+    /// total against the spend limits of the program.
+    fn record_spend(
+        &mut self,
+        program_id: &Pubkey,
+        lamports: u64,
+        spend_limits: &[u64],
+    ) -> ProgramResult {
+        let total = match self.spent.iter_mut().find(|(key, _)| key == program_id) {
+            Some((_, spent)) => {
+                *spent = spent.saturating_add(lamports);
+                *spent
+            }
+            None => {
+                self.spent.push((*program_id, lamports));
+                lamports
+            }
+        };
+
+        if spend_limits.iter().any(|spend_limit| total > *spend_limit) {
+            msg!(
+                "ExecuteGuard: Spent {} lamports in calls to program {}",
+                total,
+                program_id
+            );
+            return Err(MplCoreError::SpendLimitExceeded.into());
+        }
+
+        Ok(())
+    }
 }
 
+/// Fetch the execute guard of an asset or collection, if it has one.
+fn fetch_execute_guard<T: DataBlob + SolanaAccount>(
+    account_info: &AccountInfo,
+) -> Result<Option<ExecuteGuard>, ProgramError> {
+    match fetch_plugin::<T, ExecuteGuard>(account_info, PluginType::ExecuteGuard) {
+        Ok((_, guard, _)) => Ok(Some(guard)),
+        Err(ProgramError::Custom(code))
+            if code == MplCoreError::PluginNotFound as u32
+                || code == MplCoreError::PluginsNotInitialized as u32 =>
+        {
+            Ok(None)
+        }
+        Err(err) => Err(err),
+    }
+}
+
+/// Invoke the program as the signer PDA derived from the asset or collection key.
 fn process_execute(
-    asset_key: &Pubkey,
-    asset_signer: &Pubkey,
+    signer_seed: &Pubkey,
+    signer_info: &AccountInfo,
     program_id: &Pubkey,
     instruction_data: Vec<u8>,
     remaining_accounts: &[AccountInfo],
     bump: u8,
+    execute_guards: &mut ExecuteGuards,
 ) -> ProgramResult {
+    let spend_limits = execute_guards.check_program(program_id)?;
+    let signer = signer_info.key;
+    let lamports_before = signer_info.lamports();
+
     invoke_signed(
         &Instruction {
             program_id: *program_id,
             accounts: remaining_accounts
                 .iter()
                 .map(|account| {
-                    if *account.key == *asset_signer {
+                    if *account.key == *signer {
                         AccountMeta {
                             pubkey: *account.key,
                             is_signer: true,
@@ -163,6 +480,13 @@ fn process_execute(
             data: instruction_data,
         },
         remaining_accounts,
-        &[&[PREFIX.as_bytes(), asset_key.as_ref(), &[bump]]],
-    )
+        &[&[PREFIX.as_bytes(), signer_seed.as_ref(), &[bump]]],
+    )?;
+
+    if !spend_limits.is_empty() {
+        let spent = lamports_before.saturating_sub(signer_info.lamports());
+        execute_guards.record_spend(program_id, spent, &spend_limits)?;
+    }
+
+    Ok(())
 }
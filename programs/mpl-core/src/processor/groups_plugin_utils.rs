@@ -1,17 +1,17 @@
 use borsh::BorshDeserialize;
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, program_memory::sol_memmove,
-    pubkey::Pubkey,
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    program_memory::sol_memmove, pubkey::Pubkey,
 };
 
 use crate::{
     error::MplCoreError,
     plugins::{
-        create_meta_idempotent, initialize_plugin, Groups, Plugin, PluginHeaderV1,
-        PluginRegistryV1, PluginType, RegistryRecord,
+        create_meta_idempotent, delete_plugin, fetch_plugin, initialize_plugin, Groups, Plugin,
+        PluginHeaderV1, PluginRegistryV1, PluginType, RegistryRecord,
     },
-    state::{AssetV1, CollectionV1, SolanaAccount},
-    utils::resize_or_reallocate_account,
+    state::{AssetV1, CollectionV1, DataBlob, SolanaAccount},
+    utils::{fetch_core_data, resize_or_reallocate_account},
 };
 
 /// Add the parent group pubkey to the collection's Groups plugin, creating the plugin if necessary.
@@ -78,6 +78,23 @@ pub(crate) fn process_collection_groups_plugin_add<'a>(
     Ok(())
 }
 
+/// Check whether the asset's Groups plugin lists the group.
+pub(crate) fn is_asset_in_groups_plugin(
+    asset_info: &AccountInfo,
+    group: &Pubkey,
+) -> Result<bool, ProgramError> {
+    match fetch_plugin::<AssetV1, Groups>(asset_info, PluginType::Groups) {
+        Ok((_, groups, _)) => Ok(groups.groups.contains(group)),
+        Err(ProgramError::Custom(code))
+            if code == MplCoreError::PluginNotFound as u32
+                || code == MplCoreError::PluginsNotInitialized as u32 =>
+        {
+            Ok(false)
+        }
+        Err(err) => Err(err),
+    }
+}
+
 /// Add the parent group pubkey to the asset's Groups plugin, creating the plugin if necessary.
 pub(crate) fn process_asset_groups_plugin_add<'a>(
     asset_info: &AccountInfo<'a>,
@@ -136,6 +153,65 @@ pub(crate) fn process_asset_groups_plugin_add<'a>(
     Ok(())
 }
 
+/// Remove the group pubkey from the member's Groups plugin, deleting the plugin once it lists
+/// no groups so that its space is reclaimed.  Unlike the single-kind remove helpers, this never
+/// creates plugin metadata, so the member account is only ever shrunk.
+pub(crate) fn detach_from_groups_plugin<'a, T: DataBlob + SolanaAccount>(
+    member_info: &AccountInfo<'a>,
+    group: &Pubkey,
+    payer_info: &AccountInfo<'a>,
+    system_program_info: &AccountInfo<'a>,
+) -> ProgramResult {
+    let (member_core, plugin_header, plugin_registry) = fetch_core_data::<T>(member_info)?;
+    let (mut plugin_header, mut plugin_registry) = match (plugin_header, plugin_registry) {
+        (Some(plugin_header), Some(plugin_registry)) => (plugin_header, plugin_registry),
+        _ => return Ok(()),
+    };
+
+    let record = match plugin_registry
+        .registry
+        .iter()
+        .find(|r| r.plugin_type == PluginType::Groups)
+        .cloned()
+    {
+        Some(record) => record,
+        None => return Ok(()),
+    };
+
+    let mut plugin = Plugin::load(member_info, record.offset)?;
+    let now_empty = if let Plugin::Groups(inner) = &mut plugin {
+        if let Some(pos) = inner.groups.iter().position(|pk| pk == group) {
+            inner.groups.remove(pos);
+        } else {
+            return Ok(());
+        }
+        inner.groups.is_empty()
+    } else {
+        return Err(MplCoreError::InvalidPlugin.into());
+    };
+
+    if now_empty {
+        delete_plugin(
+            &PluginType::Groups,
+            &member_core,
+            member_info,
+            payer_info,
+            system_program_info,
+        )
+    } else {
+        save_updated_groups_plugin(
+            member_info,
+            payer_info,
+            system_program_info,
+            &plugin,
+            &record,
+            &mut plugin_header,
+            &mut plugin_registry,
+            member_core.len(),
+        )
+    }
+}
+
 /// Shared helper that persists a modified Groups plugin back to an account,
 /// handling the resize, memmove, and registry offset bump when the serialized
 /// size changes. Uses shrink-before-move / grow-after-move ordering so that
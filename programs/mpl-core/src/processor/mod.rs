@@ -1,59 +1,83 @@
+mod accept_group_update_authority;
 mod add_assets_to_group;
 mod add_collections_to_group;
 mod add_external_plugin_adapter;
+mod add_group_plugin;
 mod add_groups_to_group;
 mod add_plugin;
+mod approve_group_delegate;
 mod approve_plugin_authority;
 mod burn;
+mod burn_batch;
 mod close_group;
 mod collect;
 mod compress;
 mod create;
+mod create_batch;
 mod create_collection;
 mod create_group;
 mod decompress;
 mod execute;
 mod groups_plugin_utils;
+mod move_to_collection;
+mod print_edition;
+mod propose_group_update_authority;
 mod remove_assets_from_group;
 mod remove_collections_from_group;
 mod remove_external_plugin_adapter;
+mod remove_group_plugin;
 mod remove_groups_from_group;
 mod remove_plugin;
+mod revoke_group_delegate;
 mod revoke_plugin_authority;
 mod transfer;
+mod transfer_batch;
 mod update;
 mod update_collection_info;
 mod update_external_plugin_adapter;
 mod update_group;
+mod update_group_plugin;
 mod update_plugin;
 mod write_external_plugin_adapter_data;
 
+pub(crate) use accept_group_update_authority::*;
 pub(crate) use add_assets_to_group::*;
 pub(crate) use add_collections_to_group::*;
 pub(crate) use add_external_plugin_adapter::*;
+pub(crate) use add_group_plugin::*;
 pub(crate) use add_groups_to_group::*;
 pub(crate) use add_plugin::*;
+pub(crate) use approve_group_delegate::*;
 pub(crate) use approve_plugin_authority::*;
 pub(crate) use burn::*;
+pub(crate) use burn_batch::*;
 pub(crate) use close_group::*;
 pub(crate) use collect::*;
 pub(crate) use compress::*;
 pub(crate) use create::*;
+pub(crate) use create_batch::*;
 pub(crate) use create_collection::*;
 pub(crate) use create_group::*;
 pub(crate) use decompress::*;
 pub(crate) use execute::*;
+pub(crate) use move_to_collection::*;
+pub(crate) use print_edition::*;
+pub(crate) use propose_group_update_authority::*;
 pub(crate) use remove_assets_from_group::*;
 pub(crate) use remove_collections_from_group::*;
 pub(crate) use remove_external_plugin_adapter::*;
+pub(crate) use remove_group_plugin::*;
 pub(crate) use remove_groups_from_group::*;
 pub(crate) use remove_plugin::*;
+pub(crate) use revoke_group_delegate::*;
 pub(crate) use revoke_plugin_authority::*;
 pub(crate) use transfer::*;
+pub(crate) use transfer_batch::*;
 pub(crate) use update::*;
 pub(crate) use update_collection_info::*;
 pub(crate) use update_external_plugin_adapter::*;
 pub(crate) use update_group::*;
+pub(crate) use update_group_plugin::*;
 pub(crate) use update_plugin::*;
 pub(crate) use write_external_plugin_adapter_data::*;
 
@@ -238,5 +262,69 @@ pub fn process_instruction<'a>(
             msg!("Instruction: RemoveAssetsFromGroup");
             remove_assets_from_group_v1(accounts, args)
         }
+        MplAssetInstruction::TransferBatchV1(args) => {
+            msg!("Instruction: TransferBatch");
+            transfer_batch(accounts, args)
+        }
+        MplAssetInstruction::BurnBatchV1(args) => {
+            msg!("Instruction: BurnBatch");
+            burn_batch(accounts, args)
+        }
+        MplAssetInstruction::CreateBatchV1(args) => {
+            msg!("Instruction: CreateBatch");
+            create_batch(accounts, args)
+        }
+        MplAssetInstruction::TransferV2(args) => {
+            msg!("Instruction: TransferV2");
+            transfer_v2(accounts, args)
+        }
+        MplAssetInstruction::PrintEditionV1(args) => {
+            msg!("Instruction: PrintEdition");
+            print_edition(accounts, args)
+        }
+        MplAssetInstruction::ExecuteBatchV1(args) => {
+            msg!("Instruction: ExecuteBatch");
+            execute_batch(accounts, args)
+        }
+        MplAssetInstruction::ExecuteCollectionV1(args) => {
+            msg!("Instruction: ExecuteCollection");
+            execute_collection(accounts, args)
+        }
+        MplAssetInstruction::AddGroupPluginV1(args) => {
+            msg!("Instruction: AddGroupPlugin");
+            add_group_plugin_v1(accounts, args)
+        }
+        MplAssetInstruction::UpdateGroupPluginV1(args) => {
+            msg!("Instruction: UpdateGroupPlugin");
+            update_group_plugin_v1(accounts, args)
+        }
+        MplAssetInstruction::RemoveGroupPluginV1(args) => {
+            msg!("Instruction: RemoveGroupPlugin");
+            remove_group_plugin_v1(accounts, args)
+        }
+        MplAssetInstruction::ApproveGroupDelegateV1(args) => {
+            msg!("Instruction: ApproveGroupDelegate");
+            approve_group_delegate_v1(accounts, args)
+        }
+        MplAssetInstruction::RevokeGroupDelegateV1(args) => {
+            msg!("Instruction: RevokeGroupDelegate");
+            revoke_group_delegate_v1(accounts, args)
+        }
+        MplAssetInstruction::ProposeGroupUpdateAuthorityV1(args) => {
+            msg!("Instruction: ProposeGroupUpdateAuthority");
+            propose_group_update_authority_v1(accounts, args)
+        }
+        MplAssetInstruction::AcceptGroupUpdateAuthorityV1(args) => {
+            msg!("Instruction: AcceptGroupUpdateAuthority");
+            accept_group_update_authority_v1(accounts, args)
+        }
+        MplAssetInstruction::CloseGroupV2(args) => {
+            msg!("Instruction: CloseGroupV2");
+            close_group_v2(accounts, args)
+        }
+        MplAssetInstruction::MoveToCollectionV1(args) => {
+            msg!("Instruction: MoveToCollection");
+            move_to_collection_v1(accounts, args)
+        }
     }
 }
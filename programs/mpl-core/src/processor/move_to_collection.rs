@@ -0,0 +1,186 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use mpl_utils::assert_signer;
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg};
+use std::collections::BTreeMap;
+
+use super::validate_new_collection;
+use crate::{
+    error::MplCoreError,
+    instruction::accounts::{Context, MoveToCollectionV1Accounts},
+    plugins::{
+        validate_plugin_checks, ExternalPluginAdapter, HookableLifecycleEvent, Plugin, PluginType,
+        ValidationResult,
+    },
+    state::{AssetV1, CollectionV1, DataBlob, Key, SolanaAccount, UpdateAuthority},
+    utils::{
+        fetch_core_data, load_key, resize_and_save_core, resolve_authority,
+        resolve_pubkey_to_authorities, validate_asset_permissions,
+    },
+};
+
+/// Arguments for the `MoveToCollectionV1` instruction.
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone, Default)]
+pub(crate) struct MoveToCollectionV1Args {}
+
+/// Processor for the `MoveToCollectionV1` instruction.
+///
+/// The asset leaves its collection with the approval of the collection's authority, as with
+/// `UpdateV2`, and joins the new collection with the approval of the new collection's authority.
+/// The plugins of the new collection must also accept every plugin on the asset.
+pub(crate) fn move_to_collection_v1<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    _args: MoveToCollectionV1Args,
+) -> ProgramResult {
+    // Accounts.
+    let ctx: Context<MoveToCollectionV1Accounts> = MoveToCollectionV1Accounts::context(accounts)?;
+
+    // Guards.
+    assert_signer(ctx.accounts.payer)?;
+    let authority = resolve_authority(ctx.accounts.payer, ctx.accounts.authority)?;
+    let new_collection_authority =
+        resolve_authority(authority, ctx.accounts.new_collection_authority)?;
+
+    if ctx.accounts.system_program.key != &solana_system_interface::program::ID {
+        return Err(MplCoreError::InvalidSystemProgram.into());
+    }
+
+    if let Some(log_wrapper) = ctx.accounts.log_wrapper {
+        if log_wrapper.key != &crate::SPL_NOOP_ID {
+            return Err(MplCoreError::InvalidLogWrapperProgram.into());
+        }
+    }
+
+    match load_key(ctx.accounts.asset, 0)? {
+        Key::AssetV1 => (),
+        Key::HashedAssetV1 => {
+            msg!("Error: Moving compressed is currently not available");
+            return Err(MplCoreError::NotAvailable.into());
+        }
+        _ => return Err(MplCoreError::IncorrectAccount.into()),
+    }
+
+    if ctx.accounts.collection.key == ctx.accounts.new_collection.key {
+        msg!("Error: Asset is already in the collection");
+        return Err(MplCoreError::InvalidCollection.into());
+    }
+
+    let asset = AssetV1::load(ctx.accounts.asset, 0)?;
+    if asset.update_authority != UpdateAuthority::Collection(*ctx.accounts.collection.key) {
+        return Err(MplCoreError::InvalidCollection.into());
+    }
+
+    // The asset leaves its collection as it would with `UpdateV2`.
+    let new_update_authority = UpdateAuthority::Collection(*ctx.accounts.new_collection.key);
+    let (mut asset, plugin_header, plugin_registry) = validate_asset_permissions(
+        accounts,
+        authority,
+        ctx.accounts.asset,
+        Some(ctx.accounts.collection),
+        None,
+        None,
+        Some(&new_update_authority),
+        None,
+        None,
+        None,
+        None,
+        AssetV1::check_update,
+        CollectionV1::check_update,
+        PluginType::check_update,
+        AssetV1::validate_update,
+        CollectionV1::validate_update,
+        Plugin::validate_update,
+        Some(ExternalPluginAdapter::validate_update),
+        Some(HookableLifecycleEvent::Update),
+    )?;
+
+    // The authority of the new collection must approve adding the asset.
+    let (mut new_collection, _, new_collection_registry) =
+        fetch_core_data::<CollectionV1>(ctx.accounts.new_collection)?;
+    let plugin_set = validate_new_collection(
+        ctx.accounts.new_collection,
+        &new_collection,
+        new_collection_authority,
+        accounts,
+    )?;
+
+    if plugin_set.contains(&PluginType::BubblegumV2) {
+        msg!("Error: Cannot move an asset into a Bubblegum V2 collection");
+        return Err(MplCoreError::BlockedByBubblegumV2.into());
+    }
+
+    // The plugins of the new collection validate the plugins of the asset as if they were
+    // being added to an asset of the collection.
+    if let (Some(plugin_registry), Some(new_collection_registry)) =
+        (plugin_registry.as_ref(), new_collection_registry.as_ref())
+    {
+        let mut checks = BTreeMap::new();
+        new_collection_registry.check_registry(
+            Key::CollectionV1,
+            PluginType::check_add_plugin,
+            &mut checks,
+        );
+
+        let mut moved_asset = asset.clone();
+        moved_asset.update_authority = new_update_authority.clone();
+        let resolved_authorities = resolve_pubkey_to_authorities(
+            accounts,
+            new_collection_authority,
+            Some(ctx.accounts.new_collection),
+            &moved_asset,
+        )?;
+
+        for record in plugin_registry.registry.iter() {
+            let plugin = Plugin::load(ctx.accounts.asset, record.offset)?;
+            let result = validate_plugin_checks(
+                accounts,
+                &checks,
+                new_collection_authority,
+                None,
+                None,
+                None,
+                None,
+                Some(&plugin),
+                Some(&record.authority),
+                None,
+                None,
+                Some(ctx.accounts.asset),
+                Some(ctx.accounts.new_collection),
+                &resolved_authorities,
+                Plugin::validate_add_plugin,
+            )?;
+
+            if result == ValidationResult::Rejected {
+                msg!(
+                    "Error: {:?} plugin is rejected by the new collection",
+                    record.plugin_type
+                );
+                return Err(MplCoreError::InvalidAuthority.into());
+            }
+        }
+    }
+
+    // Update the size of both collections.
+    let mut collection = CollectionV1::load(ctx.accounts.collection, 0)?;
+    collection.decrement_size()?;
+    collection.save(ctx.accounts.collection, 0)?;
+
+    new_collection.increment_size()?;
+    new_collection.save(ctx.accounts.new_collection, 0)?;
+
+    // Increment sequence number and save only if it is `Some(_)`.
+    asset.increment_seq_and_save(ctx.accounts.asset)?;
+
+    let asset_size = asset.len() as isize;
+    asset.update_authority = new_update_authority;
+
+    resize_and_save_core(
+        asset,
+        &plugin_header,
+        &plugin_registry,
+        asset_size,
+        ctx.accounts.asset,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+    )
+}
@@ -0,0 +1,154 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use mpl_utils::assert_signer;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+};
+
+use super::create_asset_account;
+use crate::{
+    error::MplCoreError,
+    instruction::accounts::{Context, PrintEditionV1Accounts},
+    plugins::{fetch_plugin, Edition, MasterEdition, Plugin, PluginAuthorityPair, PluginType},
+    state::{AssetV1, CollectionV1, DataState, SolanaAccount, UpdateAuthority},
+    utils::resolve_authority,
+};
+
+/// Arguments for the `PrintEditionV1` instruction.
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub(crate) struct PrintEditionV1Args {
+    /// The name of the edition. Defaults to the master edition name, then the collection name.
+    pub(crate) name: Option<String>,
+    /// The URI of the edition. Defaults to the master edition URI, then the collection URI.
+    pub(crate) uri: Option<String>,
+    /// Additional plugins to initialize on the edition.
+    pub(crate) plugins: Option<Vec<PluginAuthorityPair>>,
+}
+
+/// Reject an `Edition` plugin on an asset created into a master edition collection with a max
+/// supply other than through `PrintEditionV1`, as its number could later be printed again.
+pub(crate) fn reject_unprinted_edition(
+    collection_info: Option<&AccountInfo>,
+    plugins: &[PluginAuthorityPair],
+) -> ProgramResult {
+    let Some(collection_info) = collection_info else {
+        return Ok(());
+    };
+
+    if !plugins
+        .iter()
+        .any(|plugin| PluginType::from(&plugin.plugin) == PluginType::Edition)
+    {
+        return Ok(());
+    }
+
+    match fetch_plugin::<CollectionV1, MasterEdition>(collection_info, PluginType::MasterEdition) {
+        Ok((
+            _,
+            MasterEdition {
+                max_supply: Some(_),
+                ..
+            },
+            _,
+        )) => {
+            msg!("Error: Editions of a master edition with a max supply must be printed");
+            Err(MplCoreError::InvalidPlugin.into())
+        }
+        Ok(_) => Ok(()),
+        Err(error) if error == ProgramError::from(MplCoreError::PluginNotFound) => Ok(()),
+        Err(error) => Err(error),
+    }
+}
+
+/// Processor for the `PrintEditionV1` instruction.
+///
+/// The edition is numbered from the number of assets minted into the master edition
+/// collection, and printing is rejected once the max supply of the master edition is reached.
+/// Assets created into the collection without printing also count towards the numbering and
+/// the max supply, as the `MasterEdition` plugin has no counter of its own. Assets moved into
+/// the collection are not minted into it, so they do not count.
+///
+/// Editions can only be created directly into a master edition without a max supply. Their
+/// numbers, and those of editions moved into the collection, are not checked, so a printed
+/// edition may share its number with one of them.
+pub(crate) fn print_edition<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: PrintEditionV1Args,
+) -> ProgramResult {
+    // Accounts.
+    let ctx: Context<PrintEditionV1Accounts> = PrintEditionV1Accounts::context(accounts)?;
+
+    // Guards.
+    assert_signer(ctx.accounts.asset)?;
+    assert_signer(ctx.accounts.payer)?;
+    let authority = resolve_authority(ctx.accounts.payer, ctx.accounts.authority)?;
+
+    if *ctx.accounts.system_program.key != solana_system_interface::program::ID {
+        return Err(MplCoreError::InvalidSystemProgram.into());
+    }
+
+    if let Some(log_wrapper) = ctx.accounts.log_wrapper {
+        if log_wrapper.key != &crate::SPL_NOOP_ID {
+            return Err(MplCoreError::InvalidLogWrapperProgram.into());
+        }
+    }
+
+    let mut collection = CollectionV1::load(ctx.accounts.collection, 0)?;
+    let (_, master_edition, _) = fetch_plugin::<CollectionV1, MasterEdition>(
+        ctx.accounts.collection,
+        PluginType::MasterEdition,
+    )?;
+
+    if let Some(max_supply) = master_edition.max_supply {
+        if collection.num_minted >= max_supply {
+            msg!("Error: All {} editions have been printed", max_supply);
+            return Err(MplCoreError::MaxSupplyReached.into());
+        }
+    }
+
+    let number = collection
+        .num_minted
+        .checked_add(1)
+        .ok_or(MplCoreError::NumericalOverflow)?;
+
+    // The edition number is assigned here and cannot be supplied by the caller.
+    let mut plugins = args.plugins.unwrap_or_default();
+    if plugins
+        .iter()
+        .any(|plugin| PluginType::from(&plugin.plugin) == PluginType::Edition)
+    {
+        return Err(MplCoreError::InvalidPlugin.into());
+    }
+    plugins.push(PluginAuthorityPair {
+        plugin: Plugin::Edition(Edition { number }),
+        authority: None,
+    });
+
+    let new_asset = AssetV1::new(
+        *ctx.accounts.owner.unwrap_or(ctx.accounts.payer).key,
+        UpdateAuthority::Collection(*ctx.accounts.collection.key),
+        args.name
+            .or(master_edition.name)
+            .unwrap_or_else(|| collection.name.clone()),
+        args.uri
+            .or(master_edition.uri)
+            .unwrap_or_else(|| collection.uri.clone()),
+    );
+
+    create_asset_account(
+        accounts,
+        ctx.accounts.asset,
+        Some(ctx.accounts.collection),
+        authority,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+        &new_asset,
+        DataState::AccountState,
+        Some(plugins),
+        None,
+    )?;
+
+    collection.increment_minted()?;
+    collection.increment_size()?;
+    collection.save(ctx.accounts.collection, 0)
+}
@@ -0,0 +1,86 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use mpl_utils::{assert_derivation, assert_signer};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+};
+
+use crate::{
+    error::MplCoreError,
+    instruction::accounts::ProposeGroupUpdateAuthorityV1Accounts,
+    state::{
+        GroupAuthorityProposalV1, GroupV1, Key, SolanaAccount, GROUP_AUTHORITY_PROPOSAL_PREFIX,
+    },
+    utils::{create_group_record, is_valid_group_authority, load_key, resolve_authority},
+};
+
+/// Arguments for the `ProposeGroupUpdateAuthorityV1` instruction.
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone, Default)]
+pub(crate) struct ProposeGroupUpdateAuthorityV1Args {}
+
+/// Processor for the `ProposeGroupUpdateAuthorityV1` instruction.
+///
+/// Proposing a new update authority replaces any pending proposal for the group.
+pub(crate) fn propose_group_update_authority_v1<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    _args: ProposeGroupUpdateAuthorityV1Args,
+) -> ProgramResult {
+    // Derive the typed account context from the raw slice.
+    let ctx = ProposeGroupUpdateAuthorityV1Accounts::context(accounts)?;
+
+    // Basic guards.
+    assert_signer(ctx.accounts.payer)?;
+    let authority = resolve_authority(ctx.accounts.payer, ctx.accounts.authority)?;
+
+    // Ensure the canonical system program is provided.
+    if ctx.accounts.system_program.key != &solana_system_interface::program::ID {
+        return Err(MplCoreError::InvalidSystemProgram.into());
+    }
+
+    if !ctx.accounts.group_authority_proposal.is_writable {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Ensure the signer is the update authority of the group.
+    let group = GroupV1::load(ctx.accounts.group, 0)?;
+    if !is_valid_group_authority(ctx.accounts.group, authority)? {
+        return Err(MplCoreError::InvalidAuthority.into());
+    }
+
+    let mut proposal = GroupAuthorityProposalV1::new(
+        *ctx.accounts.group.key,
+        group.update_authority,
+        *ctx.accounts.new_update_authority.key,
+        *ctx.accounts.payer.key,
+    );
+    let seeds: &[&[u8]] = &[
+        GROUP_AUTHORITY_PROPOSAL_PREFIX.as_bytes(),
+        ctx.accounts.group.key.as_ref(),
+    ];
+
+    // A pending proposal is replaced in place, and its rent is still refunded to the account
+    // that paid for it.
+    if ctx.accounts.group_authority_proposal.owner == &crate::ID
+        && load_key(ctx.accounts.group_authority_proposal, 0)? == Key::GroupAuthorityProposalV1
+    {
+        assert_derivation(
+            &crate::ID,
+            ctx.accounts.group_authority_proposal,
+            seeds,
+            MplCoreError::InvalidGroupAuthorityProposal,
+        )?;
+
+        proposal.payer =
+            GroupAuthorityProposalV1::load(ctx.accounts.group_authority_proposal, 0)?.payer;
+        return proposal.save(ctx.accounts.group_authority_proposal, 0);
+    }
+
+    create_group_record(
+        ctx.accounts.group_authority_proposal,
+        &proposal,
+        seeds,
+        MplCoreError::InvalidGroupAuthorityProposal,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+    )
+}
@@ -10,10 +10,11 @@ use crate::{
     error::MplCoreError,
     instruction::accounts::{Context, RemoveAssetsFromGroupV1Accounts},
     plugins::{create_meta_idempotent, Plugin, PluginType},
-    state::{AssetV1, GroupV1, Key, SolanaAccount},
+    state::{AssetV1, GroupMembershipV1, GroupV1, Key, SolanaAccount},
     utils::{
-        is_valid_asset_authority, is_valid_group_authority, load_key, resolve_authority,
-        save_flat_group,
+        close_program_account, is_closed_account, is_valid_asset_authority,
+        is_valid_group_member_authority, load_key, resolve_authority, save_group,
+        split_group_delegate,
     },
 };
 
@@ -26,7 +27,10 @@ pub(crate) struct RemoveAssetsFromGroupV1Args {
 
 /// Remaining accounts: first the `AssetV1` accounts matching `args.assets` in
 /// order, then optionally any read-only `CollectionV1` accounts needed for
-/// authority resolution of collection-managed assets.
+/// authority resolution of collection-managed assets, and the writable
+/// `GroupMembershipV1` accounts of assets that are not in the group's `assets`
+/// vector. Assets burned while in the group are detached by the group
+/// authority alone.
 pub(crate) fn remove_assets_from_group_v1<'a>(
     accounts: &'a [AccountInfo<'a>],
     args: RemoveAssetsFromGroupV1Args,
@@ -37,7 +41,7 @@ pub(crate) fn remove_assets_from_group_v1<'a>(
     let payer_info = ctx.accounts.payer;
     let authority_info_opt = ctx.accounts.authority;
     let system_program_info = ctx.accounts.system_program;
-    let asset_accounts = ctx.remaining_accounts;
+    let (delegate_record_info, asset_accounts) = split_group_delegate(ctx.remaining_accounts)?;
 
     assert_signer(payer_info)?;
     let authority_info = resolve_authority(payer_info, authority_info_opt)?;
@@ -56,17 +60,34 @@ pub(crate) fn remove_assets_from_group_v1<'a>(
     }
 
     // Supplemental remaining accounts (after the explicit asset list) are
-    // allowed, but they must be collections. These are used when validating
-    // collection-managed asset authority.
+    // allowed, but they must be collections or membership accounts. Collections
+    // are used when validating collection-managed asset authority.
+    let mut memberships: Vec<(GroupMembershipV1, &AccountInfo)> = Vec::new();
     for supplemental_info in asset_accounts.iter().skip(args.assets.len()) {
-        if load_key(supplemental_info, 0)? != Key::CollectionV1 {
-            msg!("Error: unexpected supplemental remaining account");
-            return Err(MplCoreError::IncorrectAccount.into());
+        match load_key(supplemental_info, 0)? {
+            Key::CollectionV1 => {}
+            Key::GroupMembershipV1 => {
+                if supplemental_info.owner != &crate::ID {
+                    return Err(MplCoreError::InvalidGroupMembership.into());
+                }
+
+                let membership = GroupMembershipV1::load(supplemental_info, 0)?;
+                if membership.group != *group_info.key {
+                    msg!("Error: Membership account is not of the provided group");
+                    return Err(MplCoreError::InvalidGroupMembership.into());
+                }
+
+                memberships.push((membership, supplemental_info));
+            }
+            _ => {
+                msg!("Error: unexpected supplemental remaining account");
+                return Err(MplCoreError::IncorrectAccount.into());
+            }
         }
     }
 
     let mut group = GroupV1::load(group_info, 0)?;
-    if !is_valid_group_authority(group_info, authority_info)? {
+    if !is_valid_group_member_authority(group_info, authority_info, delegate_record_info)? {
         return Err(MplCoreError::InvalidAuthority.into());
     }
 
@@ -80,27 +101,46 @@ pub(crate) fn remove_assets_from_group_v1<'a>(
             return Err(ProgramError::InvalidAccountData);
         }
 
-        if !is_valid_asset_authority(asset_info, authority_info, accounts)? {
+        // An asset burned by its owner while in the group is only detached from the group,
+        // including once its lamports were collected.
+        let burned = is_closed_account(asset_info);
+
+        if !burned && !is_valid_asset_authority(asset_info, authority_info, accounts)? {
             return Err(MplCoreError::InvalidAuthority.into());
         }
 
-        // remove asset from group list
+        // remove asset from group list, or close its membership account
         if let Some(pos) = group.assets.iter().position(|pk| pk == asset_info.key) {
             group.assets.remove(pos);
+        } else if let Some(index) = memberships
+            .iter()
+            .position(|(membership, _)| membership.member == *asset_info.key)
+        {
+            // Each membership is closed once, so a repeated asset is not a member anymore.
+            let (_, membership_info) = memberships.remove(index);
+            close_program_account(membership_info, payer_info)?;
+            group.membership_count = Some(
+                group
+                    .membership_count()
+                    .checked_sub(1)
+                    .ok_or(MplCoreError::NumericalOverflow)?,
+            );
         } else {
             msg!("Error: Asset is not a child of the provided group");
             return Err(MplCoreError::IncorrectAccount.into());
         }
 
-        process_asset_groups_plugin_remove(
-            asset_info,
-            *group_info.key,
-            payer_info,
-            system_program_info,
-        )?;
+        if !burned {
+            process_asset_groups_plugin_remove(
+                asset_info,
+                *group_info.key,
+                payer_info,
+                system_program_info,
+            )?;
+        }
     }
 
-    save_flat_group(group_info, &group, payer_info, system_program_info)?;
+    save_group(group_info, &group, payer_info, system_program_info)?;
     Ok(())
 }
 
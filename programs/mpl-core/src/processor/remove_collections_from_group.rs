@@ -12,7 +12,8 @@ use crate::{
     plugins::{create_meta_idempotent, Plugin, PluginType},
     state::{CollectionV1, GroupV1, SolanaAccount},
     utils::{
-        is_valid_collection_authority, is_valid_group_authority, resolve_authority, save_flat_group,
+        is_valid_collection_authority, is_valid_group_member_authority, resolve_authority,
+        save_group, split_group_delegate,
     },
 };
 
@@ -42,7 +43,7 @@ pub(crate) fn remove_collections_from_group_v1<'a>(
     let payer_info = ctx.accounts.payer;
     let authority_info_opt = ctx.accounts.authority;
     let system_program_info = ctx.accounts.system_program;
-    let collection_accounts = ctx.remaining_accounts;
+    let (delegate_record_info, collection_accounts) = split_group_delegate(ctx.remaining_accounts)?;
 
     // Basic guards
     assert_signer(payer_info)?;
@@ -68,8 +69,8 @@ pub(crate) fn remove_collections_from_group_v1<'a>(
     // Deserialize group.
     let mut group = GroupV1::load(group_info, 0)?;
 
-    // Authority check: must be the group's update authority.
-    if !is_valid_group_authority(group_info, authority_info)? {
+    // Authority check: must be the group's update authority or a delegate of the group.
+    if !is_valid_group_member_authority(group_info, authority_info, delegate_record_info)? {
         msg!("Error: Invalid authority for group account");
         return Err(MplCoreError::InvalidAuthority.into());
     }
@@ -90,7 +91,7 @@ pub(crate) fn remove_collections_from_group_v1<'a>(
 
         let _collection_core = CollectionV1::load(collection_info, 0)?;
 
-        if !is_valid_collection_authority(collection_info, authority_info)? {
+        if !is_valid_collection_authority(collection_info, authority_info, accounts)? {
             msg!("Error: Signer is not collection update authority/delegate");
             return Err(MplCoreError::InvalidAuthority.into());
         }
@@ -116,7 +117,7 @@ pub(crate) fn remove_collections_from_group_v1<'a>(
         )?;
     }
 
-    save_flat_group(group_info, &group, payer_info, system_program_info)?;
+    save_group(group_info, &group, payer_info, system_program_info)?;
 
     Ok(())
 }
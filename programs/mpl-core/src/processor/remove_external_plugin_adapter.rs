@@ -73,6 +73,7 @@ pub(crate) fn remove_external_plugin_adapter<'a>(
         None,
         None,
         None,
+        None,
         Some(&plugin_to_remove),
         Some(&record.authority),
         AssetV1::check_remove_external_plugin_adapter,
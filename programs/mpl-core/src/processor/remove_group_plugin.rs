@@ -0,0 +1,92 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use mpl_utils::assert_signer;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+};
+
+use crate::{
+    error::MplCoreError,
+    instruction::accounts::RemoveGroupPluginV1Accounts,
+    plugins::{
+        delete_external_plugin_adapter, delete_plugin, fetch_plugin, ExternalPluginAdapterKey,
+        PermanentFreezeDelegate, PluginType,
+    },
+    state::{GroupV1, SolanaAccount},
+    utils::{is_valid_group_authority, resolve_authority},
+};
+
+/// The key of a plugin or external plugin adapter on a group.
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub enum GroupPluginKey {
+    /// The type of a plugin.
+    Plugin(PluginType),
+    /// The key of an external plugin adapter.
+    ExternalPluginAdapter(ExternalPluginAdapterKey),
+}
+
+/// Arguments for the `RemoveGroupPluginV1` instruction.
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub(crate) struct RemoveGroupPluginV1Args {
+    /// The plugin or external plugin adapter to remove.
+    pub(crate) key: GroupPluginKey,
+}
+
+/// Processor for the `RemoveGroupPluginV1` instruction.
+pub(crate) fn remove_group_plugin_v1<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: RemoveGroupPluginV1Args,
+) -> ProgramResult {
+    // Derive the typed account context from the raw slice.
+    let ctx = RemoveGroupPluginV1Accounts::context(accounts)?;
+
+    // Basic guards.
+    assert_signer(ctx.accounts.payer)?;
+    let authority = resolve_authority(ctx.accounts.payer, ctx.accounts.authority)?;
+
+    // Ensure the canonical system program is provided.
+    if ctx.accounts.system_program.key != &solana_system_interface::program::ID {
+        return Err(MplCoreError::InvalidSystemProgram.into());
+    }
+
+    if !ctx.accounts.group.is_writable {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Ensure the signer is the update authority of the group.
+    if !is_valid_group_authority(ctx.accounts.group, authority)? {
+        return Err(MplCoreError::InvalidAuthority.into());
+    }
+
+    let group = GroupV1::load(ctx.accounts.group, 0)?;
+
+    // A frozen group must be thawed before its freeze can be removed.
+    if args.key == GroupPluginKey::Plugin(PluginType::PermanentFreezeDelegate) {
+        let (_, permanent_freeze_delegate, _) = fetch_plugin::<GroupV1, PermanentFreezeDelegate>(
+            ctx.accounts.group,
+            PluginType::PermanentFreezeDelegate,
+        )?;
+        if permanent_freeze_delegate.frozen {
+            msg!("Error: Cannot remove the freeze of a frozen group");
+            return Err(MplCoreError::GroupFrozen.into());
+        }
+    }
+
+    match &args.key {
+        GroupPluginKey::Plugin(plugin_type) => delete_plugin(
+            plugin_type,
+            &group,
+            ctx.accounts.group,
+            ctx.accounts.payer,
+            ctx.accounts.system_program,
+        ),
+        GroupPluginKey::ExternalPluginAdapter(key) => delete_external_plugin_adapter(
+            key,
+            &group,
+            ctx.accounts.group,
+            ctx.accounts.payer,
+            ctx.accounts.system_program,
+        ),
+    }
+}
@@ -9,7 +9,7 @@ use crate::{
     error::MplCoreError,
     instruction::accounts::{Context, RemoveGroupsFromGroupV1Accounts},
     state::{GroupV1, SolanaAccount},
-    utils::{is_valid_group_authority, resolve_authority, save_flat_group},
+    utils::{is_valid_group_authority, resolve_authority, save_group},
 };
 
 /// Arguments for the `RemoveGroupsFromGroupV1` instruction.
@@ -115,14 +115,14 @@ pub(crate) fn remove_groups_from_group_v1<'a>(
             .position(|pk| pk == parent_group_info.key)
         {
             child_group.parent_groups.remove(pos);
-            save_flat_group(child_info, &child_group, payer_info, system_program_info)?;
+            save_group(child_info, &child_group, payer_info, system_program_info)?;
         } else {
             msg!("Error: Bidirectional relationship inconsistent — parent not found in child's parent_groups");
             return Err(MplCoreError::InconsistentGroupRelationship.into());
         }
     }
 
-    save_flat_group(
+    save_group(
         parent_group_info,
         &parent_group,
         payer_info,
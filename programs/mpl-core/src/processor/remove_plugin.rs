@@ -68,6 +68,7 @@ pub(crate) fn remove_plugin<'a>(
         ctx.accounts.collection,
         None,
         None,
+        None,
         Some(&plugin_to_remove),
         Some(&plugin_authority),
         None,
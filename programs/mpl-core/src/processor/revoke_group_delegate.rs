@@ -0,0 +1,51 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use mpl_utils::assert_signer;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+};
+
+use crate::{
+    error::MplCoreError,
+    instruction::accounts::RevokeGroupDelegateV1Accounts,
+    state::{GroupDelegateV1, SolanaAccount},
+    utils::{close_program_account, is_valid_group_authority, resolve_authority},
+};
+
+/// Arguments for the `RevokeGroupDelegateV1` instruction.
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone, Default)]
+pub(crate) struct RevokeGroupDelegateV1Args {}
+
+/// Processor for the `RevokeGroupDelegateV1` instruction.
+///
+/// A delegate may be revoked by the update authority of the group or by the delegate itself.
+pub(crate) fn revoke_group_delegate_v1<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    _args: RevokeGroupDelegateV1Args,
+) -> ProgramResult {
+    // Derive the typed account context from the raw slice.
+    let ctx = RevokeGroupDelegateV1Accounts::context(accounts)?;
+
+    // Basic guards.
+    assert_signer(ctx.accounts.payer)?;
+    let authority = resolve_authority(ctx.accounts.payer, ctx.accounts.authority)?;
+
+    if !ctx.accounts.group_delegate.is_writable {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let delegate_record = GroupDelegateV1::load(ctx.accounts.group_delegate, 0)?;
+    if delegate_record.group != *ctx.accounts.group.key {
+        return Err(MplCoreError::InvalidGroupDelegate.into());
+    }
+
+    // Ensure the signer is the update authority of the group or the delegate.
+    if authority.key != &delegate_record.delegate
+        && !is_valid_group_authority(ctx.accounts.group, authority)?
+    {
+        return Err(MplCoreError::InvalidAuthority.into());
+    }
+
+    // Close the delegate account, transferring rent-exempt lamports back to the payer.
+    close_program_account(ctx.accounts.group_delegate, ctx.accounts.payer)
+}
@@ -69,6 +69,7 @@ pub(crate) fn revoke_plugin_authority<'a>(
         ctx.accounts.collection,
         None,
         None,
+        None,
         Some(&plugin),
         Some(&plugin_authority),
         None,
@@ -87,7 +88,7 @@ pub(crate) fn revoke_plugin_authority<'a>(
     asset.increment_seq_and_save(ctx.accounts.asset)?;
 
     let resolved_authorities =
-        resolve_pubkey_to_authorities(authority, ctx.accounts.collection, &asset)?;
+        resolve_pubkey_to_authorities(accounts, authority, ctx.accounts.collection, &asset)?;
     let payer = if resolved_authorities.contains(&plugin.manager()) {
         ctx.accounts.payer
     } else {
@@ -163,7 +164,7 @@ pub(crate) fn revoke_collection_plugin_authority<'a>(
     )?;
 
     let resolved_authorities =
-        resolve_pubkey_to_authorities_collection(authority, ctx.accounts.collection)?;
+        resolve_pubkey_to_authorities_collection(accounts, authority, ctx.accounts.collection)?;
     let payer = if resolved_authorities.contains(&plugin.manager()) {
         ctx.accounts.payer
     } else {
@@ -1,15 +1,24 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use mpl_utils::assert_signer;
-use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg};
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg, pubkey::Pubkey};
+
+use super::process_update_plugin;
 
 use crate::{
     error::MplCoreError,
-    instruction::accounts::TransferV1Accounts,
-    plugins::{ExternalPluginAdapter, HookableLifecycleEvent, Plugin, PluginType},
-    state::{AssetV1, Authority, CollectionV1, CompressionProof, Key, SolanaAccount, Wrappable},
+    instruction::accounts::{Context, TransferV1Accounts, TransferV2Accounts},
+    plugins::{
+        delete_plugin, is_within_limits, BurnDelegateV2, ExternalPluginAdapter,
+        HookableLifecycleEvent, Plugin, PluginHeaderV1, PluginRegistryV1, PluginType,
+        TransferDelegateV2,
+    },
+    state::{
+        AssetV1, Authority, CollectionV1, CompressionProof, DataBlob, Key, SolanaAccount, Wrappable,
+    },
     utils::{
         compress_into_account_space, load_key, rebuild_account_state_from_proof_data,
-        resolve_authority, validate_asset_permissions, verify_proof,
+        resolve_authority, resolve_plugin_authorities, resolve_pubkey_to_authorities,
+        validate_asset_permissions, verify_proof,
     },
 };
 
@@ -19,6 +28,15 @@ pub struct TransferV1Args {
     compression_proof: Option<CompressionProof>,
 }
 
+/// Arguments for the `TransferV2` instruction.
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub(crate) struct TransferV2Args {
+    /// The sale price of the asset in lamports, used to calculate enforced royalties unless the
+    /// asset is listed.
+    pub(crate) sale_price: u64,
+}
+
 pub(crate) fn transfer<'a>(accounts: &'a [AccountInfo<'a>], args: TransferV1Args) -> ProgramResult {
     // Accounts.
     let ctx = TransferV1Accounts::context(accounts)?;
@@ -53,7 +71,8 @@ pub(crate) fn transfer<'a>(accounts: &'a [AccountInfo<'a>], args: TransferV1Args
                 .ok_or(MplCoreError::MissingSystemProgram)?;
 
             // Verify the proof and rebuild Asset struct in account space.
-            let (mut asset, plugins) = verify_proof(ctx.accounts.asset, &compression_proof)?;
+            let (mut asset, plugins, external_plugin_adapters) =
+                verify_proof(ctx.accounts.asset, &compression_proof)?;
 
             // Set the new owner.
             asset.owner = *ctx.accounts.new_owner.key;
@@ -62,6 +81,7 @@ pub(crate) fn transfer<'a>(accounts: &'a [AccountInfo<'a>], args: TransferV1Args
             rebuild_account_state_from_proof_data(
                 asset,
                 plugins,
+                external_plugin_adapters,
                 ctx.accounts.asset,
                 ctx.accounts.payer,
                 system_program,
@@ -87,6 +107,7 @@ pub(crate) fn transfer<'a>(accounts: &'a [AccountInfo<'a>], args: TransferV1Args
         None,
         None,
         None,
+        None,
         AssetV1::check_transfer,
         CollectionV1::check_transfer,
         PluginType::check_transfer,
@@ -97,19 +118,26 @@ pub(crate) fn transfer<'a>(accounts: &'a [AccountInfo<'a>], args: TransferV1Args
         Some(HookableLifecycleEvent::Transfer),
     )?;
 
-    // Reset every owner-managed plugin in the registry.
-    if let (Some(plugin_header), Some(mut plugin_registry)) =
-        (plugin_header, plugin_registry.clone())
-    {
-        plugin_registry.registry.iter_mut().for_each(|record| {
-            if record.plugin_type.manager() == Authority::Owner {
-                record.authority = Authority::Owner;
-            }
-        });
+    reset_owner_managed_plugins(
+        accounts,
+        authority,
+        &asset,
+        ctx.accounts.asset,
+        ctx.accounts.collection,
+        plugin_header,
+        plugin_registry.clone(),
+    )?;
 
-        // Save the plugin registry.
-        plugin_registry.save(ctx.accounts.asset, plugin_header.plugin_registry_offset)?;
-    }
+    remove_plugins_ended_by_transfer(
+        &asset,
+        ctx.accounts.asset,
+        plugin_registry.as_ref(),
+        ctx.accounts.new_owner.key,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+    )?;
+
+    clear_reset_delegate_limits(&asset, ctx.accounts.asset, ctx.accounts.payer)?;
 
     // Set the new owner.
     asset.owner = *ctx.accounts.new_owner.key;
@@ -142,3 +170,262 @@ pub(crate) fn transfer<'a>(accounts: &'a [AccountInfo<'a>], args: TransferV1Args
         _ => unreachable!(),
     }
 }
+
+/// Processor for the `TransferV2` instruction.
+///
+/// Royalties enforced on the declared sale price must be paid to the creators by the
+/// instructions preceding the transfer, which is checked by the `Royalties` plugin.
+pub(crate) fn transfer_v2<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: TransferV2Args,
+) -> ProgramResult {
+    // Accounts.
+    let ctx: Context<TransferV2Accounts> = TransferV2Accounts::context(accounts)?;
+
+    // Guards.
+    assert_signer(ctx.accounts.payer)?;
+    let authority = resolve_authority(ctx.accounts.payer, ctx.accounts.authority)?;
+
+    if ctx.accounts.system_program.key != &solana_system_interface::program::ID {
+        return Err(MplCoreError::InvalidSystemProgram.into());
+    }
+
+    if let Some(log_wrapper) = ctx.accounts.log_wrapper {
+        if log_wrapper.key != &crate::SPL_NOOP_ID {
+            return Err(MplCoreError::InvalidLogWrapperProgram.into());
+        }
+    }
+
+    match load_key(ctx.accounts.asset, 0)? {
+        Key::AssetV1 => (),
+        Key::HashedAssetV1 => {
+            msg!("Error: Transferring compressed is currently not available");
+            return Err(MplCoreError::NotAvailable.into());
+        }
+        _ => return Err(MplCoreError::IncorrectAccount.into()),
+    }
+
+    // Validate asset permissions.
+    let (mut asset, plugin_header, plugin_registry) = validate_asset_permissions(
+        accounts,
+        authority,
+        ctx.accounts.asset,
+        ctx.accounts.collection,
+        Some(ctx.accounts.new_owner),
+        Some(args.sale_price),
+        None,
+        None,
+        None,
+        None,
+        None,
+        AssetV1::check_transfer,
+        CollectionV1::check_transfer,
+        PluginType::check_transfer,
+        AssetV1::validate_transfer,
+        CollectionV1::validate_transfer,
+        Plugin::validate_transfer,
+        Some(ExternalPluginAdapter::validate_transfer),
+        Some(HookableLifecycleEvent::Transfer),
+    )?;
+
+    reset_owner_managed_plugins(
+        accounts,
+        authority,
+        &asset,
+        ctx.accounts.asset,
+        ctx.accounts.collection,
+        plugin_header,
+        plugin_registry.clone(),
+    )?;
+
+    remove_plugins_ended_by_transfer(
+        &asset,
+        ctx.accounts.asset,
+        plugin_registry.as_ref(),
+        ctx.accounts.new_owner.key,
+        ctx.accounts.payer,
+        Some(ctx.accounts.system_program),
+    )?;
+
+    clear_reset_delegate_limits(&asset, ctx.accounts.asset, ctx.accounts.payer)?;
+
+    // Set the new owner.
+    asset.owner = *ctx.accounts.new_owner.key;
+
+    // Increment sequence number only if it is `Some(_)`.
+    asset.seq = asset.seq.map(|seq| seq.saturating_add(1));
+    asset.save(ctx.accounts.asset, 0)
+}
+
+/// Use up one of the uses of the transfer delegate if it signed the transfer.
+fn consume_transfer_delegate_use(
+    accounts: &[AccountInfo],
+    authority_info: &AccountInfo,
+    asset: &AssetV1,
+    asset_info: &AccountInfo,
+    collection_info: Option<&AccountInfo>,
+    plugin_registry: Option<&PluginRegistryV1>,
+) -> ProgramResult {
+    let record = match plugin_registry.and_then(|plugin_registry| {
+        plugin_registry
+            .registry
+            .iter()
+            .find(|record| record.plugin_type == PluginType::TransferDelegateV2)
+    }) {
+        Some(record) if record.authority != Authority::Owner => record,
+        _ => return Ok(()),
+    };
+
+    // Only a transfer signed by the delegate uses it up.
+    let authorities =
+        resolve_pubkey_to_authorities(accounts, authority_info, collection_info, asset)?;
+    let record_authorities =
+        resolve_plugin_authorities(&authorities, &record.authority, authority_info, accounts);
+    if !record_authorities.contains(&record.authority) {
+        return Ok(());
+    }
+
+    let mut transfer_delegate = match Plugin::load(asset_info, record.offset)? {
+        Plugin::TransferDelegateV2(transfer_delegate)
+            if is_within_limits(&transfer_delegate.expires_at, &transfer_delegate.max_uses)? =>
+        {
+            transfer_delegate
+        }
+        _ => return Ok(()),
+    };
+
+    if transfer_delegate.consume_use() {
+        Plugin::TransferDelegateV2(transfer_delegate).save(asset_info, record.offset)?;
+    }
+
+    Ok(())
+}
+
+/// Reset every owner-managed plugin in the registry after a transfer, after using up one of the
+/// uses of the transfer delegate if it made the transfer. Approvals given by the previous owner,
+/// including a transfer delegate with uses left, never carry over to the new owner.
+pub(crate) fn reset_owner_managed_plugins(
+    accounts: &[AccountInfo],
+    authority_info: &AccountInfo,
+    asset: &AssetV1,
+    asset_info: &AccountInfo,
+    collection_info: Option<&AccountInfo>,
+    plugin_header: Option<PluginHeaderV1>,
+    plugin_registry: Option<PluginRegistryV1>,
+) -> ProgramResult {
+    consume_transfer_delegate_use(
+        accounts,
+        authority_info,
+        asset,
+        asset_info,
+        collection_info,
+        plugin_registry.as_ref(),
+    )?;
+
+    if let (Some(plugin_header), Some(mut plugin_registry)) = (plugin_header, plugin_registry) {
+        plugin_registry.registry.iter_mut().for_each(|record| {
+            if record.plugin_type.manager() == Authority::Owner {
+                record.authority = Authority::Owner;
+            }
+        });
+
+        // Save the plugin registry.
+        plugin_registry.save(asset_info, plugin_header.plugin_registry_offset)?;
+    }
+
+    Ok(())
+}
+
+/// Clear the limits of the delegates whose approval was reset by a transfer, so that they do
+/// not carry over to the delegates the new owner approves. Clearing only shrinks the account,
+/// so no system program is needed.
+pub(crate) fn clear_reset_delegate_limits<'a>(
+    asset: &AssetV1,
+    asset_info: &AccountInfo<'a>,
+    payer: &AccountInfo<'a>,
+) -> ProgramResult {
+    if asset.len() == asset_info.data_len() {
+        return Ok(());
+    }
+
+    for plugin_type in [PluginType::TransferDelegateV2, PluginType::BurnDelegateV2] {
+        let plugin_header = PluginHeaderV1::load(asset_info, asset.len())?;
+        let plugin_registry =
+            PluginRegistryV1::load(asset_info, plugin_header.plugin_registry_offset)?;
+
+        let offset = match plugin_registry
+            .registry
+            .iter()
+            .find(|record| record.plugin_type == plugin_type)
+        {
+            Some(record) if record.authority == Authority::Owner => record.offset,
+            _ => continue,
+        };
+
+        let cleared_plugin = match Plugin::load(asset_info, offset)? {
+            Plugin::TransferDelegateV2(transfer_delegate)
+                if transfer_delegate != TransferDelegateV2::default() =>
+            {
+                Plugin::TransferDelegateV2(TransferDelegateV2::default())
+            }
+            Plugin::BurnDelegateV2(burn_delegate) if burn_delegate != BurnDelegateV2::default() => {
+                Plugin::BurnDelegateV2(BurnDelegateV2::default())
+            }
+            _ => continue,
+        };
+
+        process_update_plugin(
+            asset.clone(),
+            cleared_plugin,
+            Some(plugin_header),
+            Some(plugin_registry),
+            asset_info,
+            payer,
+            None,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Remove the plugins that end when the asset changes hands. A listing is consumed by any
+/// transfer, and a rental ends when the asset is returned to the lender.
+pub(crate) fn remove_plugins_ended_by_transfer<'a>(
+    asset: &AssetV1,
+    asset_info: &AccountInfo<'a>,
+    plugin_registry: Option<&PluginRegistryV1>,
+    new_owner: &Pubkey,
+    payer: &AccountInfo<'a>,
+    system_program: Option<&AccountInfo<'a>>,
+) -> ProgramResult {
+    let plugin_registry = match plugin_registry {
+        Some(plugin_registry) => plugin_registry,
+        None => return Ok(()),
+    };
+
+    let mut ended_plugins = vec![];
+    for record in plugin_registry.registry.iter() {
+        match record.plugin_type {
+            PluginType::Listing => ended_plugins.push(PluginType::Listing),
+            PluginType::Rental => {
+                if let Plugin::Rental(rental) = Plugin::load(asset_info, record.offset)? {
+                    if rental.lender == *new_owner && asset.owner != rental.lender {
+                        ended_plugins.push(PluginType::Rental);
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+
+    if ended_plugins.is_empty() {
+        return Ok(());
+    }
+
+    let system_program = system_program.ok_or(MplCoreError::MissingSystemProgram)?;
+    for plugin_type in ended_plugins.iter() {
+        delete_plugin(plugin_type, asset, asset_info, payer, system_program)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,124 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use mpl_utils::assert_signer;
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg};
+
+use super::{
+    clear_reset_delegate_limits, remove_plugins_ended_by_transfer, reset_owner_managed_plugins,
+};
+use crate::{
+    error::MplCoreError,
+    instruction::accounts::{Context, TransferBatchV1Accounts},
+    plugins::{ExternalPluginAdapter, HookableLifecycleEvent, Plugin, PluginType},
+    state::{AssetV1, CollectionV1, Key, SolanaAccount},
+    utils::{load_key, resolve_authority, validate_asset_permissions},
+};
+
+/// Arguments for the `TransferBatchV1` instruction.
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub(crate) struct TransferBatchV1Args {
+    /// The number of (asset, collection) pairs at the start of the remaining accounts.
+    pub(crate) num_assets: u8,
+}
+
+/// Processor for the `TransferBatchV1` instruction.
+///
+/// The first `num_assets * 2` remaining accounts are (asset, collection) pairs. Assets that
+/// do not belong to a collection use the mpl-core program ID in place of the collection.
+/// Any remaining accounts after the pairs are available to external plugin adapters.
+pub(crate) fn transfer_batch<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: TransferBatchV1Args,
+) -> ProgramResult {
+    // Accounts.
+    let ctx: Context<TransferBatchV1Accounts> = TransferBatchV1Accounts::context(accounts)?;
+
+    // Guards.
+    assert_signer(ctx.accounts.payer)?;
+    let authority = resolve_authority(ctx.accounts.payer, ctx.accounts.authority)?;
+
+    if let Some(system_program) = ctx.accounts.system_program {
+        if system_program.key != &solana_system_interface::program::ID {
+            return Err(MplCoreError::InvalidSystemProgram.into());
+        }
+    }
+
+    let num_accounts = (args.num_assets as usize) * 2;
+    if args.num_assets == 0 || ctx.remaining_accounts.len() < num_accounts {
+        msg!(
+            "Error: Expected {} asset and collection pairs",
+            args.num_assets
+        );
+        return Err(MplCoreError::InvalidBatchAccounts.into());
+    }
+
+    for pair in ctx.remaining_accounts[..num_accounts].chunks_exact(2) {
+        let asset_info = &pair[0];
+        let collection_info = if pair[1].key == &crate::ID {
+            None
+        } else {
+            Some(&pair[1])
+        };
+
+        match load_key(asset_info, 0)? {
+            Key::AssetV1 => (),
+            Key::HashedAssetV1 => {
+                msg!("Error: Transferring compressed is currently not available");
+                return Err(MplCoreError::NotAvailable.into());
+            }
+            _ => return Err(MplCoreError::IncorrectAccount.into()),
+        }
+
+        // Validate asset permissions.
+        let (mut asset, plugin_header, plugin_registry) = validate_asset_permissions(
+            accounts,
+            authority,
+            asset_info,
+            collection_info,
+            Some(ctx.accounts.new_owner),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            AssetV1::check_transfer,
+            CollectionV1::check_transfer,
+            PluginType::check_transfer,
+            AssetV1::validate_transfer,
+            CollectionV1::validate_transfer,
+            Plugin::validate_transfer,
+            Some(ExternalPluginAdapter::validate_transfer),
+            Some(HookableLifecycleEvent::Transfer),
+        )?;
+
+        reset_owner_managed_plugins(
+            accounts,
+            authority,
+            &asset,
+            asset_info,
+            collection_info,
+            plugin_header,
+            plugin_registry.clone(),
+        )?;
+
+        remove_plugins_ended_by_transfer(
+            &asset,
+            asset_info,
+            plugin_registry.as_ref(),
+            ctx.accounts.new_owner.key,
+            ctx.accounts.payer,
+            ctx.accounts.system_program,
+        )?;
+        clear_reset_delegate_limits(&asset, asset_info, ctx.accounts.payer)?;
+
+        // Set the new owner.
+        asset.owner = *ctx.accounts.new_owner.key;
+
+        // Increment sequence number only if it is `Some(_)`.
+        asset.seq = asset.seq.map(|seq| seq.saturating_add(1));
+        asset.save(asset_info, 0)?;
+    }
+
+    Ok(())
+}
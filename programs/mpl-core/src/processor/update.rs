@@ -1,7 +1,7 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use mpl_utils::assert_signer;
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_memory::sol_memmove,
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
 };
 use std::collections::HashSet;
 
@@ -12,12 +12,15 @@ use crate::{
     },
     plugins::{
         fetch_plugin, list_plugins, ExternalPluginAdapter, HookableLifecycleEvent, Plugin,
-        PluginHeaderV1, PluginRegistryV1, PluginType, UpdateDelegate, PERMANENT_DELEGATES,
+        PluginType, UpdateDelegate, PERMANENT_DELEGATES,
+    },
+    state::{
+        validate_multisig, AssetV1, CollectionV1, DataBlob, Key, SolanaAccount, UpdateAuthority,
     },
-    state::{AssetV1, CollectionV1, DataBlob, Key, SolanaAccount, UpdateAuthority},
     utils::{
-        assert_collection_authority, load_key, resize_or_reallocate_account, resolve_authority,
-        validate_asset_permissions, validate_collection_permissions,
+        assert_collection_authority, load_key, resize_and_save_core, resolve_authority,
+        resolve_collection_update_authority, validate_asset_permissions,
+        validate_collection_permissions,
     },
 };
 
@@ -113,6 +116,7 @@ fn update<'a>(
         ctx.accounts.asset,
         ctx.accounts.collection,
         None,
+        None,
         args.new_update_authority.as_ref(),
         None,
         None,
@@ -135,6 +139,10 @@ fn update<'a>(
 
     let mut dirty = false;
     if let Some(new_update_authority) = args.new_update_authority {
+        if let UpdateAuthority::Multisig { signers, threshold } = &new_update_authority {
+            validate_multisig(signers, *threshold)?;
+        }
+
         // If asset is currently in a collection, remove it from the collection.
         // This block is executed when we want to go from collection to no collection, or collection to
         // new collection.  The permission for this block is established by `validate_asset_permissions`.
@@ -187,53 +195,13 @@ fn update<'a>(
                     // Deserialize the collection.
                     let mut new_collection = CollectionV1::load(new_collection_account, 0)?;
 
-                    // Get a set of all the plugins on the collection (if any).
-                    let plugin_set: HashSet<_> =
-                        if new_collection_account.data_len() > new_collection.len() {
-                            let plugin_list = list_plugins::<CollectionV1>(new_collection_account)?;
-                            plugin_list.into_iter().collect()
-                        } else {
-                            HashSet::new()
-                        };
-
-                    // Cannot move to a collection with permanent delegates.
-                    if PERMANENT_DELEGATES.iter().any(|p| plugin_set.contains(p)) {
-                        return Err(MplCoreError::PermanentDelegatesPreventMove.into());
-                    }
-
-                    // Create a default update delegate to be updated with the fetched plugin.
-                    let mut plugin: UpdateDelegate = UpdateDelegate::default();
-
-                    // Make sure the authority has authority to add the asset to the new collection.
-                    if plugin_set.contains(&PluginType::UpdateDelegate) {
-                        // Fetch the update delegate on the new collection.
-                        // Do not ignore the return plugin as we need to check the additional delegates.
-                        let (plugin_authority, fetched_plugin, _) =
-                            fetch_plugin::<CollectionV1, UpdateDelegate>(
-                                new_collection_account,
-                                PluginType::UpdateDelegate,
-                            )?;
-
-                        plugin = fetched_plugin;
-
-                        if (assert_collection_authority(
-                            &new_collection,
-                            authority,
-                            &plugin_authority,
-                        )
-                        .is_err()
-                            && authority.key != &new_collection.update_authority)
-                            && !plugin.additional_delegates.contains(authority.key)
-                        {
-                            solana_program::msg!("UA: Rejected");
-                            return Err(MplCoreError::InvalidAuthority.into());
-                        }
-                    } else if authority.key != &new_collection.update_authority
-                        && !plugin.additional_delegates.contains(authority.key)
-                    {
-                        solana_program::msg!("UA: Rejected");
-                        return Err(MplCoreError::InvalidAuthority.into());
-                    }
+                    // Make sure the asset may be added to the new collection.
+                    validate_new_collection(
+                        new_collection_account,
+                        &new_collection,
+                        authority,
+                        accounts,
+                    )?;
 
                     new_collection.increment_size()?;
                     new_collection.save(new_collection_account, 0)?;
@@ -253,7 +221,7 @@ fn update<'a>(
         dirty = true;
     }
     if dirty {
-        process_update(
+        resize_and_save_core(
             asset,
             &plugin_header,
             &plugin_registry,
@@ -267,6 +235,69 @@ fn update<'a>(
     Ok(())
 }
 
+/// Validate that the authority may add an existing asset to the new collection, as its update
+/// authority or update delegate, and that the new collection has no permanent delegates.
+/// Returns the plugins on the new collection.
+pub(crate) fn validate_new_collection<'a>(
+    new_collection_account: &'a AccountInfo<'a>,
+    new_collection: &CollectionV1,
+    authority: &'a AccountInfo<'a>,
+    accounts: &'a [AccountInfo<'a>],
+) -> Result<HashSet<PluginType>, ProgramError> {
+    // Resolve the update authority of the collection for the authority checks.
+    let resolved_new_collection =
+        resolve_collection_update_authority(new_collection_account, authority, accounts)?;
+
+    // Get a set of all the plugins on the collection (if any).
+    let plugin_set: HashSet<_> = if new_collection_account.data_len() > new_collection.len() {
+        let plugin_list = list_plugins::<CollectionV1>(new_collection_account)?;
+        plugin_list.into_iter().collect()
+    } else {
+        HashSet::new()
+    };
+
+    // Cannot move to a collection with permanent delegates.
+    if PERMANENT_DELEGATES.iter().any(|p| plugin_set.contains(p)) {
+        return Err(MplCoreError::PermanentDelegatesPreventMove.into());
+    }
+
+    // Create a default update delegate to be updated with the fetched plugin.
+    let mut plugin: UpdateDelegate = UpdateDelegate::default();
+
+    // Make sure the authority has authority to add the asset to the new collection.
+    if plugin_set.contains(&PluginType::UpdateDelegate) {
+        // Fetch the update delegate on the new collection.
+        // Do not ignore the return plugin as we need to check the additional delegates.
+        let (plugin_authority, fetched_plugin, _) = fetch_plugin::<CollectionV1, UpdateDelegate>(
+            new_collection_account,
+            PluginType::UpdateDelegate,
+        )?;
+
+        plugin = fetched_plugin;
+
+        if (assert_collection_authority(
+            &resolved_new_collection,
+            authority,
+            &plugin_authority,
+            accounts,
+        )
+        .is_err()
+            && authority.key != &resolved_new_collection.update_authority)
+            && !plugin.additional_delegates.contains(authority.key)
+        {
+            solana_program::msg!("UA: Rejected");
+            return Err(MplCoreError::InvalidAuthority.into());
+        }
+    } else if authority.key != &resolved_new_collection.update_authority
+        && !plugin.additional_delegates.contains(authority.key)
+    {
+        solana_program::msg!("UA: Rejected");
+        return Err(MplCoreError::InvalidAuthority.into());
+    }
+
+    Ok(plugin_set)
+}
+
 #[repr(C)]
 #[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
 pub(crate) struct UpdateCollectionV1Args {
@@ -328,7 +359,7 @@ pub(crate) fn update_collection<'a>(
         dirty = true;
     }
     if dirty {
-        process_update(
+        resize_and_save_core(
             collection,
             &plugin_header,
             &plugin_registry,
@@ -341,89 +372,3 @@ pub(crate) fn update_collection<'a>(
 
     Ok(())
 }
-
-fn process_update<'a, T: DataBlob + SolanaAccount>(
-    core: T,
-    plugin_header: &Option<PluginHeaderV1>,
-    plugin_registry: &Option<PluginRegistryV1>,
-    core_size: isize,
-    account: &AccountInfo<'a>,
-    payer: &AccountInfo<'a>,
-    system_program: &AccountInfo<'a>,
-) -> ProgramResult {
-    if let (Some(mut plugin_header), Some(mut plugin_registry)) =
-        (plugin_header.clone(), plugin_registry.clone())
-    {
-        // The new size of the asset and new offset of the plugin header.
-        let new_core_size = core.len() as isize;
-
-        // The difference in size between the new and old asset which is used to calculate the new size of the account.
-        let size_diff = new_core_size
-            .checked_sub(core_size)
-            .ok_or(MplCoreError::NumericalOverflow)?;
-
-        // The new size of the account.
-        let new_size = (account.data_len() as isize)
-            .checked_add(size_diff)
-            .ok_or(MplCoreError::NumericalOverflow)?;
-
-        // The new offset of the plugin registry is the old offset plus the size difference.
-        let registry_offset = plugin_header.plugin_registry_offset;
-        let new_registry_offset = (registry_offset as isize)
-            .checked_add(size_diff)
-            .ok_or(MplCoreError::NumericalOverflow)?;
-        plugin_header.plugin_registry_offset = new_registry_offset as usize;
-
-        // The offset of the first plugin is the core size plus the size of the plugin header.
-        let plugin_offset = core_size
-            .checked_add(plugin_header.len() as isize)
-            .ok_or(MplCoreError::NumericalOverflow)?;
-
-        let new_plugin_offset = plugin_offset
-            .checked_add(size_diff)
-            .ok_or(MplCoreError::NumericalOverflow)?;
-
-        if size_diff > 0 {
-            // Growing: realloc first to make room for the rightward shift.
-            resize_or_reallocate_account(account, payer, system_program, new_size as usize)?;
-        }
-
-        let copy_len = (registry_offset as usize).saturating_sub(plugin_offset as usize);
-
-        if copy_len > 0 {
-            // SAFETY: When growing, the account was resized above so the destination
-            // region [new_plugin_offset, new_plugin_offset + copy_len) is in bounds.
-            // When shrinking, the account is still the original size, so the source
-            // region [plugin_offset, plugin_offset + copy_len) = [plugin_offset,
-            // registry_offset) is in bounds. `sol_memmove` correctly handles
-            // overlapping regions.
-            unsafe {
-                let base = account.data.borrow_mut().as_mut_ptr();
-                sol_memmove(
-                    base.add(new_plugin_offset as usize),
-                    base.add(plugin_offset as usize),
-                    copy_len,
-                );
-            }
-        }
-
-        if size_diff < 0 {
-            // Shrinking: realloc after memmove so the trailing plugin bytes are
-            // preserved while the buffer still has its full pre-shrink length.
-            resize_or_reallocate_account(account, payer, system_program, new_size as usize)?;
-        }
-
-        plugin_header.save(account, new_core_size as usize)?;
-
-        // Move offsets for existing registry records.
-        plugin_registry.bump_offsets(core_size as usize, size_diff)?;
-
-        plugin_registry.save(account, new_registry_offset as usize)?;
-    } else {
-        resize_or_reallocate_account(account, payer, system_program, core.len())?;
-    }
-
-    core.save(account, 0)?;
-
-    Ok(())
-}
@@ -59,7 +59,7 @@ pub(crate) fn update_external_plugin_adapter<'a>(
     let (mut asset, plugin_header, plugin_registry) =
         fetch_core_data::<AssetV1>(ctx.accounts.asset)?;
     let resolved_authorities =
-        resolve_pubkey_to_authorities(authority, ctx.accounts.collection, &asset)?;
+        resolve_pubkey_to_authorities(accounts, authority, ctx.accounts.collection, &asset)?;
     let (external_registry_record, external_plugin_adapter) =
         fetch_wrapped_external_plugin_adapter::<AssetV1>(ctx.accounts.asset, None, &args.key)?;
     let mut incoming_external_plugin_adapter = external_plugin_adapter.clone();
@@ -74,6 +74,7 @@ pub(crate) fn update_external_plugin_adapter<'a>(
         authority_info: authority,
         resolved_authorities: Some(&resolved_authorities),
         new_owner: None,
+        sale_price: None,
         new_asset_authority: None,
         new_collection_authority: None,
         target_plugin: None,
@@ -139,7 +140,7 @@ pub(crate) fn update_collection_external_plugin_adapter<'a>(
     let (collection, plugin_header, plugin_registry) =
         fetch_core_data::<CollectionV1>(ctx.accounts.collection)?;
     let resolved_authorities =
-        resolve_pubkey_to_authorities_collection(authority, ctx.accounts.collection)?;
+        resolve_pubkey_to_authorities_collection(accounts, authority, ctx.accounts.collection)?;
     let (external_registry_record, external_plugin_adapter) =
         fetch_wrapped_external_plugin_adapter::<CollectionV1>(
             ctx.accounts.collection,
@@ -158,6 +159,7 @@ pub(crate) fn update_collection_external_plugin_adapter<'a>(
         authority_info: authority,
         resolved_authorities: Some(&resolved_authorities),
         new_owner: None,
+        sale_price: None,
         new_asset_authority: None,
         new_collection_authority: None,
         target_plugin: None,
@@ -188,7 +190,7 @@ pub(crate) fn update_collection_external_plugin_adapter<'a>(
 }
 
 #[allow(clippy::too_many_arguments)]
-fn process_update_external_plugin_adapter<'a, T: DataBlob + SolanaAccount>(
+pub(crate) fn process_update_external_plugin_adapter<'a, T: DataBlob + SolanaAccount>(
     core: T,
     plugin: ExternalPluginAdapter,
     key: ExternalPluginAdapterKey,
@@ -8,7 +8,7 @@ use crate::{
     error::MplCoreError,
     instruction::accounts::UpdateGroupV1Accounts,
     state::{GroupV1, SolanaAccount},
-    utils::{is_valid_group_authority, resolve_authority, save_flat_group},
+    utils::{is_valid_group_authority, resolve_authority, save_group},
 };
 
 /// Arguments for the `UpdateGroupV1` instruction.
@@ -22,6 +22,9 @@ pub(crate) struct UpdateGroupV1Args {
 }
 
 /// Processor for the `UpdateGroupV1` instruction.
+///
+/// A new update authority passed here takes effect at once. `ProposeGroupUpdateAuthorityV1`
+/// and `AcceptGroupUpdateAuthorityV1` hand it over only once the new authority accepts.
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn update_group_v1<'a>(
     accounts: &'a [AccountInfo<'a>],
@@ -73,7 +76,7 @@ pub(crate) fn update_group_v1<'a>(
 
     // Persist state changes if anything was updated.
     if dirty {
-        save_flat_group(
+        save_group(
             ctx.accounts.group,
             &group,
             ctx.accounts.payer,
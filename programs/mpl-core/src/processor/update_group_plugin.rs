@@ -0,0 +1,114 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use mpl_utils::assert_signer;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+};
+
+use crate::{
+    error::MplCoreError,
+    instruction::accounts::UpdateGroupPluginV1Accounts,
+    plugins::{
+        fetch_wrapped_external_plugin_adapter, fetch_wrapped_plugin, validate_royalties,
+        ExternalPluginAdapterKey, ExternalPluginAdapterUpdateInfo, Plugin, PluginType,
+    },
+    processor::{process_update_external_plugin_adapter, process_update_plugin},
+    state::GroupV1,
+    utils::{assert_authority, fetch_core_data, resolve_authority},
+};
+
+/// An update to a plugin or external plugin adapter on a group.
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub enum GroupPluginUpdateInfo {
+    /// The new data of a plugin, replacing the plugin of the same type.
+    Plugin(Plugin),
+    /// An update to an external plugin adapter.
+    ExternalPluginAdapter {
+        /// The key of the external plugin adapter to update.
+        key: ExternalPluginAdapterKey,
+        /// The fields of the external plugin adapter to update.
+        update_info: ExternalPluginAdapterUpdateInfo,
+    },
+}
+
+/// Arguments for the `UpdateGroupPluginV1` instruction.
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub(crate) struct UpdateGroupPluginV1Args {
+    /// The update to apply.
+    pub(crate) update_info: GroupPluginUpdateInfo,
+}
+
+/// Processor for the `UpdateGroupPluginV1` instruction.
+pub(crate) fn update_group_plugin_v1<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: UpdateGroupPluginV1Args,
+) -> ProgramResult {
+    // Derive the typed account context from the raw slice.
+    let ctx = UpdateGroupPluginV1Accounts::context(accounts)?;
+
+    // Basic guards.
+    assert_signer(ctx.accounts.payer)?;
+    let authority = resolve_authority(ctx.accounts.payer, ctx.accounts.authority)?;
+
+    // Ensure the canonical system program is provided.
+    if ctx.accounts.system_program.key != &solana_system_interface::program::ID {
+        return Err(MplCoreError::InvalidSystemProgram.into());
+    }
+
+    if !ctx.accounts.group.is_writable {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let (group, plugin_header, plugin_registry) = fetch_core_data::<GroupV1>(ctx.accounts.group)?;
+
+    match args.update_info {
+        GroupPluginUpdateInfo::Plugin(plugin) => {
+            let (plugin_authority, _) = fetch_wrapped_plugin::<GroupV1>(
+                ctx.accounts.group,
+                Some(&group),
+                PluginType::from(&plugin),
+            )?;
+
+            // Ensure the signer is the authority of the plugin.
+            assert_authority(&group, authority, &plugin_authority, accounts)?;
+
+            if let Plugin::Royalties(royalties) = &plugin {
+                validate_royalties(royalties)?;
+            }
+
+            process_update_plugin(
+                group,
+                plugin,
+                plugin_header,
+                plugin_registry,
+                ctx.accounts.group,
+                ctx.accounts.payer,
+                Some(ctx.accounts.system_program),
+            )
+        }
+        GroupPluginUpdateInfo::ExternalPluginAdapter { key, update_info } => {
+            let (registry_record, external_plugin_adapter) =
+                fetch_wrapped_external_plugin_adapter::<GroupV1>(
+                    ctx.accounts.group,
+                    Some(&group),
+                    &key,
+                )?;
+
+            // Ensure the signer is the authority of the external plugin adapter.
+            assert_authority(&group, authority, &registry_record.authority, accounts)?;
+
+            process_update_external_plugin_adapter(
+                group,
+                external_plugin_adapter,
+                key,
+                update_info,
+                plugin_header,
+                plugin_registry,
+                ctx.accounts.group,
+                ctx.accounts.payer,
+                ctx.accounts.system_program,
+            )
+        }
+    }
+}
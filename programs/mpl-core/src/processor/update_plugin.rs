@@ -10,8 +10,8 @@ use crate::{
     plugins::{fetch_wrapped_plugin, Plugin, PluginHeaderV1, PluginRegistryV1, PluginType},
     state::{AssetV1, CollectionV1, DataBlob, Key, SolanaAccount},
     utils::{
-        load_key, resize_or_reallocate_account, resolve_authority, validate_asset_permissions,
-        validate_collection_permissions,
+        load_key, resize_or_reallocate_account, resolve_authority, shrink_account,
+        validate_asset_permissions, validate_collection_permissions,
     },
 };
 
@@ -62,6 +62,7 @@ pub(crate) fn update_plugin<'a>(
         ctx.accounts.collection,
         None,
         None,
+        None,
         Some(&args.plugin),
         Some(&target_plugin_authority),
         None,
@@ -86,7 +87,7 @@ pub(crate) fn update_plugin<'a>(
         plugin_registry,
         ctx.accounts.asset,
         ctx.accounts.payer,
-        ctx.accounts.system_program,
+        Some(ctx.accounts.system_program),
     )
 }
 
@@ -153,18 +154,18 @@ pub(crate) fn update_collection_plugin<'a>(
         plugin_registry,
         ctx.accounts.collection,
         ctx.accounts.payer,
-        ctx.accounts.system_program,
+        Some(ctx.accounts.system_program),
     )
 }
 
-fn process_update_plugin<'a, T: DataBlob + SolanaAccount>(
+pub(crate) fn process_update_plugin<'a, T: DataBlob + SolanaAccount>(
     core: T,
     new_plugin: Plugin,
     plugin_header: Option<PluginHeaderV1>,
     plugin_registry: Option<PluginRegistryV1>,
     account: &AccountInfo<'a>,
     payer: &AccountInfo<'a>,
-    system_program: &AccountInfo<'a>,
+    system_program: Option<&AccountInfo<'a>>,
 ) -> ProgramResult {
     let mut plugin_registry = plugin_registry.ok_or(MplCoreError::PluginsNotInitialized)?;
     let mut plugin_header = plugin_header.ok_or(MplCoreError::PluginsNotInitialized)?;
@@ -210,6 +211,7 @@ fn process_update_plugin<'a, T: DataBlob + SolanaAccount>(
 
     if size_diff > 0 {
         // Growing: realloc first to make room for the rightward shift.
+        let system_program = system_program.ok_or(MplCoreError::MissingSystemProgram)?;
         resize_or_reallocate_account(account, payer, system_program, new_size as usize)?;
     }
 
@@ -229,7 +231,7 @@ fn process_update_plugin<'a, T: DataBlob + SolanaAccount>(
 
     if size_diff < 0 {
         // Shrinking: realloc after memmove to preserve data before truncation.
-        resize_or_reallocate_account(account, payer, system_program, new_size as usize)?;
+        shrink_account(account, payer, new_size as usize)?;
     }
 
     plugin_header.save(account, core.len())?;
@@ -45,7 +45,8 @@ pub(crate) fn write_external_plugin_adapter_data<'a>(
 
     let (asset, mut header, mut registry) = fetch_core_data::<AssetV1>(ctx.accounts.asset)?;
 
-    let authorities = resolve_pubkey_to_authorities(authority, ctx.accounts.collection, &asset)?;
+    let authorities =
+        resolve_pubkey_to_authorities(accounts, authority, ctx.accounts.collection, &asset)?;
 
     if ctx.accounts.system_program.key != &solana_system_interface::program::ID {
         return Err(MplCoreError::InvalidSystemProgram.into());
@@ -115,7 +116,8 @@ pub(crate) fn write_collection_external_plugin_adapter_data<'a>(
     let (collection, mut header, mut registry) =
         fetch_core_data::<CollectionV1>(ctx.accounts.collection)?;
 
-    let authorities = resolve_pubkey_to_authorities_collection(authority, ctx.accounts.collection)?;
+    let authorities =
+        resolve_pubkey_to_authorities_collection(accounts, authority, ctx.accounts.collection)?;
 
     if ctx.accounts.system_program.key != &solana_system_interface::program::ID {
         return Err(MplCoreError::InvalidSystemProgram.into());
@@ -222,7 +224,7 @@ fn process_write_external_plugin_data<'a, T: DataBlob + SolanaAccount>(
                 account,
                 Some(core),
                 &ExternalPluginAdapterKey::DataSection(LinkedDataKey::LinkedAppData(
-                    app_data.data_authority,
+                    app_data.data_authority.clone(),
                 )),
             ) {
                 Ok((section_record, _)) => match (data, buffer) {
@@ -258,7 +260,9 @@ fn process_write_external_plugin_data<'a, T: DataBlob + SolanaAccount>(
                     match (data, buffer) {
                         (Some(data), None) => initialize_external_plugin_adapter::<T>(
                             &ExternalPluginAdapterInitInfo::DataSection(DataSectionInitInfo {
-                                parent_key: LinkedDataKey::LinkedAppData(app_data.data_authority),
+                                parent_key: LinkedDataKey::LinkedAppData(
+                                    app_data.data_authority.clone(),
+                                ),
                                 schema: app_data.schema,
                             }),
                             header_offset,
@@ -271,7 +275,9 @@ fn process_write_external_plugin_data<'a, T: DataBlob + SolanaAccount>(
                         ),
                         (None, Some(buffer)) => initialize_external_plugin_adapter::<T>(
                             &ExternalPluginAdapterInitInfo::DataSection(DataSectionInitInfo {
-                                parent_key: LinkedDataKey::LinkedAppData(app_data.data_authority),
+                                parent_key: LinkedDataKey::LinkedAppData(
+                                    app_data.data_authority.clone(),
+                                ),
                                 schema: app_data.schema,
                             }),
                             header_offset,
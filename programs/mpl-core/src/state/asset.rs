@@ -384,9 +384,10 @@ impl DataBlob for AssetV1 {
     fn len(&self) -> usize {
         let mut size = AssetV1::BASE_LEN + self.name.len() + self.uri.len();
 
-        if let UpdateAuthority::Address(_) | UpdateAuthority::Collection(_) = self.update_authority
-        {
-            size += 32;
+        match &self.update_authority {
+            UpdateAuthority::Address(_) | UpdateAuthority::Collection(_) => size += 32,
+            UpdateAuthority::Multisig { signers, .. } => size += 4 + signers.len() * 32 + 1,
+            UpdateAuthority::None => (),
         }
 
         if self.seq.is_some() {
@@ -456,6 +457,17 @@ mod tests {
                 uri: "test2".to_string(),
                 seq: Some(1),
             },
+            AssetV1 {
+                key: Key::AssetV1,
+                owner: Pubkey::default(),
+                update_authority: UpdateAuthority::Multisig {
+                    signers: vec![Pubkey::default(), Pubkey::default()],
+                    threshold: 2,
+                },
+                name: "test3".to_string(),
+                uri: "test3".to_string(),
+                seq: None,
+            },
         ];
         for asset in assets {
             let serialized = borsh::to_vec(&asset).unwrap();
@@ -300,6 +300,20 @@ impl CollectionV1 {
         abstain!()
     }
 
+    /// Validate the execute lifecycle event on behalf of the collection.
+    pub fn validate_collection_execute(
+        &self,
+        authority_info: &AccountInfo,
+        _: Option<&Plugin>,
+        _: Option<&ExternalPluginAdapter>,
+    ) -> Result<ValidationResult, ProgramError> {
+        if authority_info.key == &self.update_authority {
+            approve!()
+        } else {
+            abstain!()
+        }
+    }
+
     /// Validate the add external plugin adapter lifecycle event.
     pub fn validate_add_external_plugin_adapter(
         &self,
@@ -349,6 +363,16 @@ impl CollectionV1 {
         Ok(())
     }
 
+    /// Increment number of minted items of the Collection by the given amount
+    pub fn increment_minted_by(&mut self, amount: u32) -> Result<(), ProgramError> {
+        self.num_minted = self
+            .num_minted
+            .checked_add(amount)
+            .ok_or(MplCoreError::NumericalOverflowError)?;
+
+        Ok(())
+    }
+
     /// Increment current size of the Collection
     pub fn increment_size(&mut self) -> Result<(), ProgramError> {
         self.current_size = self
@@ -359,6 +383,16 @@ impl CollectionV1 {
         Ok(())
     }
 
+    /// Increment current size of the Collection by the given amount
+    pub fn increment_size_by(&mut self, amount: u32) -> Result<(), ProgramError> {
+        self.current_size = self
+            .current_size
+            .checked_add(amount)
+            .ok_or(MplCoreError::NumericalOverflowError)?;
+
+        Ok(())
+    }
+
     /// Decrement current size of the Collection
     pub fn decrement_size(&mut self) -> Result<(), ProgramError> {
         self.current_size = self
@@ -368,6 +402,16 @@ impl CollectionV1 {
 
         Ok(())
     }
+
+    /// Decrement current size of the Collection by the given amount
+    pub fn decrement_size_by(&mut self, amount: u32) -> Result<(), ProgramError> {
+        self.current_size = self
+            .current_size
+            .checked_sub(amount)
+            .ok_or(MplCoreError::NumericalOverflowError)?;
+
+        Ok(())
+    }
 }
 
 impl DataBlob for CollectionV1 {
@@ -1,7 +1,9 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::pubkey::Pubkey;
 
-use crate::state::{AssetV1, HashablePluginSchema, UpdateAuthority, Wrappable};
+use crate::state::{
+    AssetV1, HashableExternalPluginAdapterSchema, HashablePluginSchema, UpdateAuthority, Wrappable,
+};
 
 /// A simple struct to store the compression proof of an asset.
 #[repr(C)]
@@ -19,12 +21,19 @@ pub struct CompressionProof {
     pub seq: u64, //8
     /// The plugins for the asset.
     pub plugins: Vec<HashablePluginSchema>, //4
+    /// The external plugin adapters for the asset.
+    pub external_plugin_adapters: Vec<HashableExternalPluginAdapterSchema>, //4
 }
 
 impl CompressionProof {
     /// Create a new `CompressionProof`.  Note this uses a passed-in `seq` rather than
     /// the one contained in `asset` to avoid errors.
-    pub fn new(asset: AssetV1, seq: u64, plugins: Vec<HashablePluginSchema>) -> Self {
+    pub fn new(
+        asset: AssetV1,
+        seq: u64,
+        plugins: Vec<HashablePluginSchema>,
+        external_plugin_adapters: Vec<HashableExternalPluginAdapterSchema>,
+    ) -> Self {
         Self {
             owner: asset.owner,
             update_authority: asset.update_authority,
@@ -32,6 +41,7 @@ impl CompressionProof {
             uri: asset.uri,
             seq,
             plugins,
+            external_plugin_adapters,
         }
     }
 }
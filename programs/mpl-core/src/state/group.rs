@@ -1,21 +1,26 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use shank::ShankAccount;
 use solana_program::pubkey::Pubkey;
+use std::{
+    collections::{BTreeMap, HashSet},
+    io::{Read, Write},
+};
 
 use super::{CoreAsset, DataBlob, Key, SolanaAccount, UpdateAuthority};
+use crate::error::MplCoreError;
 
 /// Maximum number of entries per vector in a `GroupV1` account (collections, groups,
 /// parent_groups, assets). Prevents unbounded growth that could cause compute
 /// budget exhaustion on subsequent operations.
 pub const MAX_GROUP_VECTOR_SIZE: usize = 256;
 
-/// Maximum number of parent groups a single group may belong to. Acts as a
-/// practical depth limit for group nesting since on-chain traversal is not
-/// feasible.
+/// Maximum number of parent groups a single group may belong to. When an
+/// ancestry proof is passed to `AddGroupsToGroupV1`, it is also the maximum
+/// length of the chain of parents above a group.
 pub const MAX_GROUP_NESTING_DEPTH: usize = 8;
 
 /// The representation of a taxonomy group which can reference collections and other groups.
-#[derive(Clone, BorshSerialize, BorshDeserialize, Debug, ShankAccount)]
+#[derive(Clone, Debug, ShankAccount)]
 pub struct GroupV1 {
     /// The account discriminator.
     pub key: Key, // 1
@@ -34,6 +39,10 @@ pub struct GroupV1 {
     pub parent_groups: Vec<Pubkey>, // 4 + 32 * N
     /// Assets that are direct members of this group.
     pub assets: Vec<Pubkey>, // 4 + 32 * N
+    /// The number of members stored in `GroupMembershipV1` accounts. Groups stored before the
+    /// count was added end after `assets`, and have no count until they are next saved.
+    #[idl_type("u32")]
+    pub membership_count: Option<u32>, // 4
 }
 
 impl GroupV1 {
@@ -66,8 +75,69 @@ impl GroupV1 {
             groups,
             parent_groups,
             assets,
+            membership_count: Some(0),
         }
     }
+
+    /// The number of members stored in `GroupMembershipV1` accounts.
+    pub fn membership_count(&self) -> u32 {
+        self.membership_count.unwrap_or_default()
+    }
+
+    /// Walk up the hierarchy from this group through `parent_groups`, using `ancestors` as the
+    /// proof of every group above it. Returns the ancestors of this group and its depth, the
+    /// length of the longest chain of parents above it.
+    pub(crate) fn ancestry(
+        &self,
+        ancestors: &BTreeMap<Pubkey, GroupV1>,
+    ) -> Result<(HashSet<Pubkey>, usize), MplCoreError> {
+        walk_groups(&self.parent_groups, ancestors, |group| &group.parent_groups)
+    }
+
+    /// Walk down the hierarchy from this group through `groups`, using `descendants` as the
+    /// proof of every group below it. Returns the descendants of this group and its height, the
+    /// length of the longest chain of child groups below it.
+    pub(crate) fn descent(
+        &self,
+        descendants: &BTreeMap<Pubkey, GroupV1>,
+    ) -> Result<(HashSet<Pubkey>, usize), MplCoreError> {
+        walk_groups(&self.groups, descendants, |group| &group.groups)
+    }
+}
+
+/// Walk the hierarchy level by level from `start`, following `next` through the groups of
+/// `proof`. Returns the groups visited and the number of levels walked.
+fn walk_groups(
+    start: &[Pubkey],
+    proof: &BTreeMap<Pubkey, GroupV1>,
+    next: fn(&GroupV1) -> &Vec<Pubkey>,
+) -> Result<(HashSet<Pubkey>, usize), MplCoreError> {
+    let mut seen = HashSet::new();
+    let mut level: Vec<Pubkey> = start.to_vec();
+    let mut depth = 0;
+
+    while !level.is_empty() {
+        // A cycle in the proof never runs out of groups either.
+        depth += 1;
+        if depth > MAX_GROUP_NESTING_DEPTH {
+            return Err(MplCoreError::GroupNestingDepthExceeded);
+        }
+
+        let mut next_level = Vec::new();
+        for key in level.iter() {
+            let group = proof.get(key).ok_or(MplCoreError::InvalidGroupAncestry)?;
+            seen.insert(*key);
+
+            for related in next(group).iter() {
+                if !next_level.contains(related) {
+                    next_level.push(*related);
+                }
+            }
+        }
+        level = next_level;
+    }
+
+    Ok((seen, depth))
 }
 
 impl DataBlob for GroupV1 {
@@ -79,6 +149,63 @@ impl DataBlob for GroupV1 {
             + 32 * self.groups.len()
             + 32 * self.parent_groups.len()
             + 32 * self.assets.len()
+            + self.membership_count.map_or(0, |_| 4)
+    }
+}
+
+impl BorshSerialize for GroupV1 {
+    fn serialize<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        self.key.serialize(writer)?;
+        self.update_authority.serialize(writer)?;
+        self.name.serialize(writer)?;
+        self.uri.serialize(writer)?;
+        self.collections.serialize(writer)?;
+        self.groups.serialize(writer)?;
+        self.parent_groups.serialize(writer)?;
+        self.assets.serialize(writer)?;
+
+        // Groups without a count keep their original layout.
+        if let Some(membership_count) = self.membership_count {
+            membership_count.serialize(writer)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl BorshDeserialize for GroupV1 {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> std::io::Result<Self> {
+        let key = Key::deserialize_reader(reader)?;
+        let update_authority = Pubkey::deserialize_reader(reader)?;
+        let name = String::deserialize_reader(reader)?;
+        let uri = String::deserialize_reader(reader)?;
+        let collections = Vec::<Pubkey>::deserialize_reader(reader)?;
+        let groups = Vec::<Pubkey>::deserialize_reader(reader)?;
+        let parent_groups = Vec::<Pubkey>::deserialize_reader(reader)?;
+        let assets = Vec::<Pubkey>::deserialize_reader(reader)?;
+
+        // Groups stored before the count was added end after the assets. They never have
+        // plugins, as adding a plugin saves the count first.
+        let mut count_bytes = [0u8; 4];
+        let membership_count = match reader.read(&mut count_bytes[..1])? {
+            0 => None,
+            _ => {
+                reader.read_exact(&mut count_bytes[1..])?;
+                Some(u32::from_le_bytes(count_bytes))
+            }
+        };
+
+        Ok(Self {
+            key,
+            update_authority,
+            name,
+            uri,
+            collections,
+            groups,
+            parent_groups,
+            assets,
+            membership_count,
+        })
     }
 }
 
@@ -140,6 +267,7 @@ mod tests {
                 groups: vec![],
                 parent_groups: vec![],
                 assets: vec![],
+                membership_count: Some(0),
             },
             GroupV1 {
                 key: Key::GroupV1,
@@ -150,6 +278,18 @@ mod tests {
                 groups: vec![Pubkey::new_unique()],
                 parent_groups: vec![],
                 assets: vec![],
+                membership_count: Some(3),
+            },
+            GroupV1 {
+                key: Key::GroupV1,
+                update_authority: Pubkey::default(),
+                name: "test".to_string(),
+                uri: "test".to_string(),
+                collections: vec![],
+                groups: vec![],
+                parent_groups: vec![],
+                assets: vec![Pubkey::new_unique()],
+                membership_count: None,
             },
         ];
         for group in groups {
@@ -157,4 +297,120 @@ mod tests {
             assert_eq!(serialized.len(), group.len());
         }
     }
+
+    #[test]
+    fn test_group_without_membership_count() {
+        let mut group = GroupV1::new(
+            Pubkey::new_unique(),
+            "group".to_string(),
+            "uri".to_string(),
+            vec![],
+            vec![],
+            vec![],
+            vec![Pubkey::new_unique()],
+        );
+        group.membership_count = None;
+
+        // A group stored before the count was added reads back without a count.
+        let stored = borsh::to_vec(&group).unwrap();
+        let loaded = GroupV1::deserialize(&mut stored.as_slice()).unwrap();
+        assert_eq!(loaded.membership_count, None);
+        assert_eq!(loaded.membership_count(), 0);
+        assert_eq!(loaded.len(), stored.len());
+
+        // Once counted, the count is stored after the assets.
+        group.membership_count = Some(7);
+        let stored = borsh::to_vec(&group).unwrap();
+        let loaded = GroupV1::deserialize(&mut stored.as_slice()).unwrap();
+        assert_eq!(loaded.membership_count, Some(7));
+        assert_eq!(loaded.len(), stored.len());
+    }
+
+    fn group_with_parents(parent_groups: Vec<Pubkey>) -> GroupV1 {
+        GroupV1::new(
+            Pubkey::default(),
+            "".to_string(),
+            "".to_string(),
+            vec![],
+            vec![],
+            parent_groups,
+            vec![],
+        )
+    }
+
+    #[test]
+    fn test_group_ancestry() {
+        // root <- middle <- leaf, and root <- leaf.
+        let root = Pubkey::new_unique();
+        let middle = Pubkey::new_unique();
+        let leaf = group_with_parents(vec![middle, root]);
+
+        let mut ancestors = BTreeMap::new();
+        ancestors.insert(root, group_with_parents(vec![]));
+        ancestors.insert(middle, group_with_parents(vec![root]));
+
+        let (seen, depth) = leaf.ancestry(&ancestors).unwrap();
+        assert_eq!(seen, HashSet::from([root, middle]));
+        assert_eq!(depth, 2);
+
+        // A missing ancestor makes the proof incomplete.
+        ancestors.remove(&root);
+        assert_eq!(
+            leaf.ancestry(&ancestors).unwrap_err(),
+            MplCoreError::InvalidGroupAncestry
+        );
+    }
+
+    #[test]
+    fn test_group_descent() {
+        // parent -> middle -> leaf, and parent -> leaf.
+        let middle = Pubkey::new_unique();
+        let leaf = Pubkey::new_unique();
+        let mut parent = group_with_parents(vec![]);
+        parent.groups = vec![middle, leaf];
+
+        let mut middle_group = group_with_parents(vec![]);
+        middle_group.groups = vec![leaf];
+
+        let mut descendants = BTreeMap::new();
+        descendants.insert(middle, middle_group);
+        descendants.insert(leaf, group_with_parents(vec![]));
+
+        let (seen, height) = parent.descent(&descendants).unwrap();
+        assert_eq!(seen, HashSet::from([middle, leaf]));
+        assert_eq!(height, 2);
+
+        // A group without child groups needs no proof.
+        assert_eq!(
+            group_with_parents(vec![])
+                .descent(&BTreeMap::new())
+                .unwrap()
+                .1,
+            0
+        );
+
+        // A missing descendant makes the proof incomplete.
+        descendants.remove(&leaf);
+        assert_eq!(
+            parent.descent(&descendants).unwrap_err(),
+            MplCoreError::InvalidGroupAncestry
+        );
+    }
+
+    #[test]
+    fn test_group_ancestry_cycle() {
+        let a = Pubkey::new_unique();
+        let b = Pubkey::new_unique();
+
+        let mut ancestors = BTreeMap::new();
+        ancestors.insert(a, group_with_parents(vec![b]));
+        ancestors.insert(b, group_with_parents(vec![a]));
+
+        assert_eq!(
+            group_with_parents(vec![a])
+                .ancestry(&ancestors)
+                .unwrap_err(),
+            MplCoreError::GroupNestingDepthExceeded
+        );
+    }
 }
@@ -0,0 +1,79 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use shank::ShankAccount;
+use solana_program::pubkey::Pubkey;
+
+use super::{DataBlob, Key, SolanaAccount};
+
+/// The seed prefix of a group authority proposal account.
+pub const GROUP_AUTHORITY_PROPOSAL_PREFIX: &str = "mpl-core-group-authority-proposal";
+
+/// A proposed change of the update authority of a group, which takes effect only once the
+/// proposed update authority accepts it.  The account is a PDA derived from the group, so a
+/// group has at most one pending proposal.
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug, ShankAccount, PartialEq, Eq)]
+pub struct GroupAuthorityProposalV1 {
+    /// The account discriminator.
+    pub key: Key, // 1
+    /// The group whose update authority is changed.
+    pub group: Pubkey, // 32
+    /// The update authority of the group when the proposal was made.  The proposal lapses if
+    /// the update authority changes before it is accepted.
+    pub update_authority: Pubkey, // 32
+    /// The proposed update authority.
+    pub new_update_authority: Pubkey, // 32
+    /// The account that paid for the proposal account, which is refunded when it is closed.
+    pub payer: Pubkey, // 32
+}
+
+impl GroupAuthorityProposalV1 {
+    const BASE_LEN: usize = 1 // Key
+        + 32 // Group
+        + 32 // Update authority
+        + 32 // New update authority
+        + 32; // Payer
+
+    /// Create a new `GroupAuthorityProposalV1` instance.
+    pub fn new(
+        group: Pubkey,
+        update_authority: Pubkey,
+        new_update_authority: Pubkey,
+        payer: Pubkey,
+    ) -> Self {
+        Self {
+            key: Key::GroupAuthorityProposalV1,
+            group,
+            update_authority,
+            new_update_authority,
+            payer,
+        }
+    }
+}
+
+impl DataBlob for GroupAuthorityProposalV1 {
+    fn len(&self) -> usize {
+        Self::BASE_LEN
+    }
+}
+
+impl SolanaAccount for GroupAuthorityProposalV1 {
+    fn key() -> Key {
+        Key::GroupAuthorityProposalV1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_group_authority_proposal_len() {
+        let proposal = GroupAuthorityProposalV1::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let serialized = borsh::to_vec(&proposal).unwrap();
+        assert_eq!(serialized.len(), proposal.len());
+    }
+}
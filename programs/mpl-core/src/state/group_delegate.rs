@@ -0,0 +1,69 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use shank::ShankAccount;
+use solana_program::pubkey::Pubkey;
+
+use super::{DataBlob, Key, SolanaAccount};
+
+/// The seed prefix of a group delegate account.
+pub const GROUP_DELEGATE_PREFIX: &str = "mpl-core-group-delegate";
+
+/// A delegate approved by the update authority of a group to add and remove the group's
+/// assets and collections.  A delegate cannot update the group, its plugins or its nested
+/// groups, and lapses when the update authority of the group is handed over.  The account is
+/// a PDA derived from the group and the delegate.
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug, ShankAccount, PartialEq, Eq)]
+pub struct GroupDelegateV1 {
+    /// The account discriminator.
+    pub key: Key, // 1
+    /// The group the delegate manages.
+    pub group: Pubkey, // 32
+    /// The delegate.
+    pub delegate: Pubkey, // 32
+    /// The update authority of the group that approved the delegate.
+    pub update_authority: Pubkey, // 32
+}
+
+impl GroupDelegateV1 {
+    const BASE_LEN: usize = 1 // Key
+        + 32 // Group
+        + 32 // Delegate
+        + 32; // Update Authority
+
+    /// Create a new `GroupDelegateV1` instance.
+    pub fn new(group: Pubkey, delegate: Pubkey, update_authority: Pubkey) -> Self {
+        Self {
+            key: Key::GroupDelegateV1,
+            group,
+            delegate,
+            update_authority,
+        }
+    }
+}
+
+impl DataBlob for GroupDelegateV1 {
+    fn len(&self) -> usize {
+        Self::BASE_LEN
+    }
+}
+
+impl SolanaAccount for GroupDelegateV1 {
+    fn key() -> Key {
+        Key::GroupDelegateV1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_group_delegate_len() {
+        let delegate = GroupDelegateV1::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let serialized = borsh::to_vec(&delegate).unwrap();
+        assert_eq!(serialized.len(), delegate.len());
+    }
+}
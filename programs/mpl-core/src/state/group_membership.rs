@@ -0,0 +1,62 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use shank::ShankAccount;
+use solana_program::pubkey::Pubkey;
+
+use super::{DataBlob, Key, SolanaAccount};
+
+/// The seed prefix of a group membership account.
+pub const GROUP_MEMBERSHIP_PREFIX: &str = "mpl-core-group-membership";
+
+/// The membership of an asset in a group stored in its own account instead of an entry in
+/// the group's `assets` vector, so that the number of assets in a group is not bounded by
+/// `MAX_GROUP_VECTOR_SIZE`.  Only assets have membership accounts; collections and nested
+/// groups are always stored in the group's inline vectors.  The account is a PDA derived
+/// from the group and the asset.
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug, ShankAccount, PartialEq, Eq)]
+pub struct GroupMembershipV1 {
+    /// The account discriminator.
+    pub key: Key, // 1
+    /// The group the member belongs to.
+    pub group: Pubkey, // 32
+    /// The asset that is a member of the group.
+    pub member: Pubkey, // 32
+}
+
+impl GroupMembershipV1 {
+    const BASE_LEN: usize = 1 // Key
+        + 32 // Group
+        + 32; // Member
+
+    /// Create a new `GroupMembershipV1` instance.
+    pub fn new(group: Pubkey, member: Pubkey) -> Self {
+        Self {
+            key: Key::GroupMembershipV1,
+            group,
+            member,
+        }
+    }
+}
+
+impl DataBlob for GroupMembershipV1 {
+    fn len(&self) -> usize {
+        Self::BASE_LEN
+    }
+}
+
+impl SolanaAccount for GroupMembershipV1 {
+    fn key() -> Key {
+        Key::GroupMembershipV1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_group_membership_len() {
+        let membership = GroupMembershipV1::new(Pubkey::new_unique(), Pubkey::new_unique());
+        let serialized = borsh::to_vec(&membership).unwrap();
+        assert_eq!(serialized.len(), membership.len());
+    }
+}
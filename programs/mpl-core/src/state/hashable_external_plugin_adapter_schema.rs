@@ -0,0 +1,122 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use std::cmp::Ordering;
+
+use crate::{
+    plugins::{
+        AgentIdentityInitInfo, AppDataInitInfo, DataSectionInitInfo, ExternalCheckResult,
+        ExternalPluginAdapter, ExternalPluginAdapterInitInfo, HookableLifecycleEvent,
+        LifecycleHookInitInfo, LinkedAppDataInitInfo, LinkedLifecycleHookInitInfo, OracleInitInfo,
+    },
+    state::{Authority, Compressible},
+};
+
+/// A versioned type that stores an external plugin adapter's registry data, deserialized
+/// adapter and data into a schema that will be later hashed into a hashed asset.  Also used
+/// in `CompressionProof`.
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug, PartialEq, Eq)]
+pub enum HashableExternalPluginAdapterSchema {
+    /// Version 1 of the schema.
+    V1(HashableExternalPluginAdapterSchemaV1),
+}
+
+impl HashableExternalPluginAdapterSchema {
+    /// The order the external plugin adapter is stored in the account.
+    pub fn index(&self) -> usize {
+        match self {
+            Self::V1(schema) => schema.index,
+        }
+    }
+
+    /// Associated function for sorting `HashableExternalPluginAdapterSchema` by index.
+    pub fn compare_indeces(
+        a: &HashableExternalPluginAdapterSchema,
+        b: &HashableExternalPluginAdapterSchema,
+    ) -> Ordering {
+        a.index().cmp(&b.index())
+    }
+}
+
+impl Compressible for HashableExternalPluginAdapterSchema {}
+
+/// Version 1 of the hashable external plugin adapter schema.
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug, PartialEq, Eq)]
+pub struct HashableExternalPluginAdapterSchemaV1 {
+    /// This is the order the external plugin adapters are stored in the account, allowing
+    /// us to keep track of their order in the hashing.
+    pub index: usize,
+    /// The authority who has permission to utilize the external plugin adapter.
+    pub authority: Authority,
+    /// The lifecycle events for which the external plugin adapter is active.
+    pub lifecycle_checks: Option<Vec<(HookableLifecycleEvent, ExternalCheckResult)>>,
+    /// The deserialized external plugin adapter.
+    pub adapter: ExternalPluginAdapter,
+    /// The data stored by the external plugin adapter, if it stores any.
+    pub data: Option<Vec<u8>>,
+}
+
+impl HashableExternalPluginAdapterSchemaV1 {
+    /// The information needed to initialize the external plugin adapter again.
+    pub(crate) fn init_info(&self) -> ExternalPluginAdapterInitInfo {
+        let init_plugin_authority = Some(self.authority.clone());
+        let lifecycle_checks = self.lifecycle_checks.clone().unwrap_or_default();
+
+        match &self.adapter {
+            ExternalPluginAdapter::LifecycleHook(lifecycle_hook) => {
+                ExternalPluginAdapterInitInfo::LifecycleHook(LifecycleHookInitInfo {
+                    hooked_program: lifecycle_hook.hooked_program,
+                    init_plugin_authority,
+                    lifecycle_checks,
+                    extra_accounts: lifecycle_hook.extra_accounts.clone(),
+                    data_authority: lifecycle_hook.data_authority.clone(),
+                    schema: Some(lifecycle_hook.schema),
+                })
+            }
+            ExternalPluginAdapter::Oracle(oracle) => {
+                ExternalPluginAdapterInitInfo::Oracle(OracleInitInfo {
+                    base_address: oracle.base_address,
+                    init_plugin_authority,
+                    lifecycle_checks,
+                    base_address_config: oracle.base_address_config.clone(),
+                    results_offset: Some(oracle.results_offset),
+                })
+            }
+            ExternalPluginAdapter::AppData(app_data) => {
+                ExternalPluginAdapterInitInfo::AppData(AppDataInitInfo {
+                    data_authority: app_data.data_authority.clone(),
+                    init_plugin_authority,
+                    schema: Some(app_data.schema),
+                })
+            }
+            ExternalPluginAdapter::LinkedLifecycleHook(lifecycle_hook) => {
+                ExternalPluginAdapterInitInfo::LinkedLifecycleHook(LinkedLifecycleHookInitInfo {
+                    hooked_program: lifecycle_hook.hooked_program,
+                    init_plugin_authority,
+                    lifecycle_checks,
+                    extra_accounts: lifecycle_hook.extra_accounts.clone(),
+                    data_authority: lifecycle_hook.data_authority.clone(),
+                    schema: Some(lifecycle_hook.schema),
+                })
+            }
+            ExternalPluginAdapter::LinkedAppData(app_data) => {
+                ExternalPluginAdapterInitInfo::LinkedAppData(LinkedAppDataInitInfo {
+                    data_authority: app_data.data_authority.clone(),
+                    init_plugin_authority,
+                    schema: Some(app_data.schema),
+                })
+            }
+            ExternalPluginAdapter::DataSection(data_section) => {
+                ExternalPluginAdapterInitInfo::DataSection(DataSectionInitInfo {
+                    parent_key: data_section.parent_key.clone(),
+                    schema: data_section.schema,
+                })
+            }
+            ExternalPluginAdapter::AgentIdentity(agent_identity) => {
+                ExternalPluginAdapterInitInfo::AgentIdentity(AgentIdentityInitInfo {
+                    uri: agent_identity.uri.clone(),
+                    init_plugin_authority,
+                    lifecycle_checks,
+                })
+            }
+        }
+    }
+}
@@ -7,7 +7,7 @@ use crate::state::Compressible;
 pub struct HashedAssetSchema {
     /// The hash of the asset.
     pub asset_hash: [u8; 32],
-    /// A vec of plugin hashes.
+    /// A vec of plugin hashes, followed by the hashes of any external plugin adapters.
     pub plugin_hashes: Vec<[u8; 32]>,
 }
 
@@ -10,6 +10,9 @@ pub use collection::*;
 mod compression_proof;
 pub use compression_proof::*;
 
+mod hashable_external_plugin_adapter_schema;
+pub use hashable_external_plugin_adapter_schema::*;
+
 mod hashable_plugin_schema;
 pub use hashable_plugin_schema::*;
 
@@ -29,6 +32,15 @@ pub use update_authority::*;
 mod group;
 pub use group::*;
 
+mod group_authority_proposal;
+pub use group_authority_proposal::*;
+
+mod group_delegate;
+pub use group_delegate::*;
+
+mod group_membership;
+pub use group_membership::*;
+
 use borsh::{BorshDeserialize, BorshSerialize};
 use num_derive::{FromPrimitive, ToPrimitive};
 use solana_program::pubkey::Pubkey;
@@ -46,7 +58,7 @@ pub enum DataState {
 /// Variants representing the different types of authority that can have permissions over plugins.
 #[repr(u8)]
 #[derive(
-    Copy, Clone, BorshSerialize, BorshDeserialize, Debug, Eq, PartialEq, PartialOrd, Ord, EnumCount,
+    Clone, BorshSerialize, BorshDeserialize, Debug, Eq, PartialEq, PartialOrd, Ord, EnumCount,
 )]
 pub enum Authority {
     /// No authority, used for immutability.
@@ -60,6 +72,13 @@ pub enum Authority {
         /// The address of the authority.
         address: Pubkey,
     },
+    /// A set of signers that is the authority over a plugin when at least `threshold` of them sign.
+    Multisig {
+        /// The addresses that can sign for the authority.
+        signers: Vec<Pubkey>,
+        /// The number of distinct signers required.
+        threshold: u8,
+    },
 }
 
 impl Authority {
@@ -69,10 +88,10 @@ impl Authority {
 impl DataBlob for Authority {
     fn len(&self) -> usize {
         Self::BASE_LEN
-            + if let Authority::Address { .. } = self {
-                32
-            } else {
-                0
+            + match self {
+                Authority::Address { .. } => 32,
+                Authority::Multisig { signers, .. } => 4 + signers.len() * 32 + 1,
+                Authority::None | Authority::Owner | Authority::UpdateAuthority => 0,
             }
     }
 }
@@ -105,6 +124,12 @@ pub enum Key {
     CollectionV1,
     /// A discriminator indicating the group.
     GroupV1,
+    /// A discriminator indicating a group membership.
+    GroupMembershipV1,
+    /// A discriminator indicating a group delegate.
+    GroupDelegateV1,
+    /// A discriminator indicating a proposed group update authority.
+    GroupAuthorityProposalV1,
 }
 
 impl Key {
@@ -132,6 +157,10 @@ mod tests {
             Authority::Address {
                 address: Pubkey::default(),
             },
+            Authority::Multisig {
+                signers: vec![Pubkey::default(), Pubkey::default()],
+                threshold: 2,
+            },
         ];
         assert_eq!(
             authorities.len(),
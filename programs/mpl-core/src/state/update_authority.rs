@@ -1,5 +1,8 @@
 use borsh::{BorshDeserialize, BorshSerialize};
-use solana_program::pubkey::Pubkey;
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+use std::collections::HashSet;
+
+use crate::error::MplCoreError;
 
 /// An enum representing the types of accounts that can update data on an asset.
 #[derive(Clone, BorshSerialize, BorshDeserialize, Debug, Eq, PartialEq)]
@@ -10,15 +13,35 @@ pub enum UpdateAuthority {
     Address(Pubkey),
     /// Authority delegated to a collection.
     Collection(Pubkey),
+    /// Authority shared by a set of signers, of which at least `threshold` must sign.
+    Multisig {
+        /// The addresses that can sign for the update authority.
+        signers: Vec<Pubkey>,
+        /// The number of distinct signers required.
+        threshold: u8,
+    },
 }
 
 impl UpdateAuthority {
     /// Get the address of the update authority.
     pub fn key(&self) -> Pubkey {
         match self {
-            Self::None => Pubkey::default(),
+            Self::None | Self::Multisig { .. } => Pubkey::default(),
             Self::Address(address) => *address,
             Self::Collection(address) => *address,
         }
     }
 }
+
+/// Validate the signers and threshold of a multisig authority.
+pub(crate) fn validate_multisig(signers: &[Pubkey], threshold: u8) -> Result<(), ProgramError> {
+    let mut seen_signers = HashSet::new();
+    if threshold == 0
+        || threshold as usize > signers.len()
+        || !signers.iter().all(|signer| seen_signers.insert(signer))
+    {
+        return Err(MplCoreError::InvalidMultisig.into());
+    }
+
+    Ok(())
+}
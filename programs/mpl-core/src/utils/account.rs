@@ -34,6 +34,37 @@ pub(crate) fn close_program_account<'a>(
     Ok(())
 }
 
+/// Check whether an account was closed by the program, either still holding the uninitialized
+/// key or assigned back to the system program once its lamports were collected.
+pub(crate) fn is_closed_account(account_info: &AccountInfo) -> bool {
+    let data = account_info.data.borrow();
+    let uninitialized = data
+        .first()
+        .is_none_or(|key| Some(*key) == Key::Uninitialized.to_u8());
+
+    (account_info.owner == &crate::ID && !data.is_empty() && uninitialized)
+        || (account_info.owner == &solana_system_interface::program::ID && uninitialized)
+}
+
+/// Shrink an account, returning the rent it no longer needs to the funding account.
+pub(crate) fn shrink_account<'a>(
+    target_account: &AccountInfo<'a>,
+    funding_account: &AccountInfo<'a>,
+    new_size: usize,
+) -> ProgramResult {
+    let rent = Rent::get()?;
+    let lamports_diff = rent
+        .minimum_balance(target_account.data_len())
+        .saturating_sub(rent.minimum_balance(new_size));
+
+    **funding_account.try_borrow_mut_lamports()? += lamports_diff;
+    **target_account.try_borrow_mut_lamports()? -= lamports_diff;
+
+    target_account.resize(new_size)?;
+
+    Ok(())
+}
+
 /// Resize an account using realloc and retain any lamport overages, modified from Solana Cookbook
 pub(crate) fn resize_or_reallocate_account<'a>(
     target_account: &AccountInfo<'a>,
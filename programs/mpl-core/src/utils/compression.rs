@@ -7,20 +7,24 @@ use solana_program::{
 use crate::{
     error::MplCoreError,
     plugins::{
-        create_meta_idempotent, initialize_plugin, Plugin, PluginRegistryV1, RegistryRecord,
+        create_meta_idempotent, initialize_external_plugin_adapter, initialize_plugin,
+        ExternalPluginAdapter, ExternalRegistryRecord, Plugin, PluginRegistryV1, RegistryRecord,
     },
     state::{
-        AssetV1, Compressible, CompressionProof, HashablePluginSchema, HashedAssetSchema,
+        AssetV1, Compressible, CompressionProof, HashableExternalPluginAdapterSchema,
+        HashableExternalPluginAdapterSchemaV1, HashablePluginSchema, HashedAssetSchema,
         HashedAssetV1, SolanaAccount,
     },
 };
 
 use super::resize_or_reallocate_account;
 
-/// Take an `Asset` and Vec of `HashablePluginSchema` and rebuild the asset in account space.
+/// Take an `Asset`, Vec of `HashablePluginSchema` and Vec of
+/// `HashableExternalPluginAdapterSchema` and rebuild the asset in account space.
 pub(crate) fn rebuild_account_state_from_proof_data<'a>(
     asset: AssetV1,
     plugins: Vec<HashablePluginSchema>,
+    external_plugin_adapters: Vec<HashableExternalPluginAdapterSchema>,
     asset_info: &AccountInfo<'a>,
     payer: &AccountInfo<'a>,
     system_program: &AccountInfo<'a>,
@@ -38,8 +42,8 @@ pub(crate) fn rebuild_account_state_from_proof_data<'a>(
         );
     }
 
-    // Add the plugins.
-    if !plugins.is_empty() {
+    // Add the plugins and external plugin adapters.
+    if !plugins.is_empty() || !external_plugin_adapters.is_empty() {
         let (_, header_offset, mut plugin_header, mut plugin_registry) =
             create_meta_idempotent::<AssetV1>(asset_info, payer, system_program)?;
 
@@ -55,6 +59,23 @@ pub(crate) fn rebuild_account_state_from_proof_data<'a>(
                 system_program,
             )?;
         }
+
+        for external_plugin_adapter in external_plugin_adapters {
+            match external_plugin_adapter {
+                HashableExternalPluginAdapterSchema::V1(schema) => {
+                    initialize_external_plugin_adapter::<AssetV1>(
+                        &schema.init_info(),
+                        header_offset,
+                        &mut plugin_header,
+                        &mut plugin_registry,
+                        asset_info,
+                        payer,
+                        system_program,
+                        schema.data.as_deref(),
+                    )?;
+                }
+            }
+        }
     }
 
     Ok(())
@@ -73,16 +94,17 @@ pub(crate) fn compress_into_account_space<'a>(
     asset.seq = Some(seq);
 
     let asset_hash = asset.hash()?;
-    let mut compression_proof = CompressionProof::new(asset, seq, vec![]);
+    let mut compression_proof = CompressionProof::new(asset, seq, vec![], vec![]);
     let mut plugin_hashes = vec![];
     if let Some(plugin_registry) = plugin_registry {
         let mut registry_records = plugin_registry.registry;
+        let mut external_registry_records = plugin_registry.external_registry;
 
         // It should already be sorted but we just want to make sure.
         registry_records.sort_by(RegistryRecord::compare_offsets);
 
         for (i, record) in registry_records.into_iter().enumerate() {
-            let plugin = Plugin::deserialize(&mut &(*asset_info.data).borrow()[record.offset..])?;
+            let plugin = Plugin::load(asset_info, record.offset)?;
 
             let hashable_plugin_schema = HashablePluginSchema {
                 index: i,
@@ -95,6 +117,42 @@ pub(crate) fn compress_into_account_space<'a>(
 
             compression_proof.plugins.push(hashable_plugin_schema);
         }
+
+        // The external plugin adapters are hashed after the plugins.
+        external_registry_records.sort_by(ExternalRegistryRecord::compare_offsets);
+
+        for (i, record) in external_registry_records.into_iter().enumerate() {
+            let adapter = ExternalPluginAdapter::deserialize(
+                &mut &(*asset_info.data).borrow()[record.offset..],
+            )?;
+
+            let data = match (record.data_offset, record.data_len) {
+                (Some(data_offset), Some(data_len)) => Some(
+                    (*asset_info.data).borrow()[data_offset
+                        ..data_offset
+                            .checked_add(data_len)
+                            .ok_or(MplCoreError::NumericalOverflow)?]
+                        .to_vec(),
+                ),
+                _ => None,
+            };
+
+            let hashable_external_plugin_adapter_schema =
+                HashableExternalPluginAdapterSchema::V1(HashableExternalPluginAdapterSchemaV1 {
+                    index: i,
+                    authority: record.authority,
+                    lifecycle_checks: record.lifecycle_checks,
+                    adapter,
+                    data,
+                });
+
+            let external_plugin_adapter_hash = hashable_external_plugin_adapter_schema.hash()?;
+            plugin_hashes.push(external_plugin_adapter_hash);
+
+            compression_proof
+                .external_plugin_adapters
+                .push(hashable_external_plugin_adapter_schema);
+        }
     }
 
     let hashed_asset_schema = HashedAssetSchema {
@@ -124,16 +182,32 @@ pub(crate) fn compress_into_account_space<'a>(
 pub(crate) fn verify_proof(
     hashed_asset: &AccountInfo,
     compression_proof: &CompressionProof,
-) -> Result<(AssetV1, Vec<HashablePluginSchema>), ProgramError> {
+) -> Result<
+    (
+        AssetV1,
+        Vec<HashablePluginSchema>,
+        Vec<HashableExternalPluginAdapterSchema>,
+    ),
+    ProgramError,
+> {
     let asset = AssetV1::from(compression_proof.clone());
     let asset_hash = asset.hash()?;
 
     let mut sorted_plugins = compression_proof.plugins.clone();
     sorted_plugins.sort_by(HashablePluginSchema::compare_indeces);
 
+    let mut sorted_external_plugin_adapters = compression_proof.external_plugin_adapters.clone();
+    sorted_external_plugin_adapters.sort_by(HashableExternalPluginAdapterSchema::compare_indeces);
+
+    // The external plugin adapters are hashed after the plugins.
     let plugin_hashes = sorted_plugins
         .iter()
         .map(|plugin| plugin.hash())
+        .chain(
+            sorted_external_plugin_adapters
+                .iter()
+                .map(|external_plugin_adapter| external_plugin_adapter.hash()),
+        )
         .collect::<Result<Vec<[u8; 32]>, ProgramError>>()?;
 
     let hashed_asset_schema = HashedAssetSchema {
@@ -148,5 +222,5 @@ pub(crate) fn verify_proof(
         return Err(MplCoreError::IncorrectAssetHash.into());
     }
 
-    Ok((asset, sorted_plugins))
+    Ok((asset, sorted_plugins, sorted_external_plugin_adapters))
 }
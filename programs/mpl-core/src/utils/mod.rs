@@ -13,17 +13,22 @@ use crate::{
         PluginType, PluginValidationContext, RegistryRecord, ValidationResult,
     },
     state::{
-        AssetV1, Authority, CollectionV1, CoreAsset, DataBlob, GroupV1, Key, SolanaAccount,
-        UpdateAuthority,
+        AssetV1, Authority, CollectionV1, CoreAsset, DataBlob, GroupDelegateV1, GroupMembershipV1,
+        GroupV1, Key, SolanaAccount, UpdateAuthority, GROUP_DELEGATE_PREFIX,
+        GROUP_MEMBERSHIP_PREFIX,
     },
 };
-use mpl_utils::assert_signer;
+use mpl_utils::{assert_derivation, assert_signer, create_or_allocate_account_raw};
 use num_traits::FromPrimitive;
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
-    pubkey::Pubkey,
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program::invoke_signed,
+    program_error::ProgramError, program_memory::sol_memmove, pubkey::Pubkey,
+};
+use solana_system_interface::instruction as system_instruction;
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, HashSet},
 };
-use std::collections::BTreeMap;
 
 /// Load the one byte key from the account data at the given offset.
 pub fn load_key(account: &AccountInfo, offset: usize) -> Result<Key, ProgramError> {
@@ -38,6 +43,7 @@ pub fn assert_authority<T: CoreAsset>(
     asset: &T,
     authority_info: &AccountInfo,
     authority: &Authority,
+    accounts: &[AccountInfo],
 ) -> ProgramResult {
     match authority {
         Authority::None => (),
@@ -46,16 +52,28 @@ pub fn assert_authority<T: CoreAsset>(
                 return Ok(());
             }
         }
-        Authority::UpdateAuthority => {
-            if asset.update_authority().key() == *authority_info.key {
-                return Ok(());
+        Authority::UpdateAuthority => match asset.update_authority() {
+            UpdateAuthority::Multisig { signers, threshold } => {
+                if is_multisig_approved(&signers, threshold, authority_info, accounts) {
+                    return Ok(());
+                }
             }
-        }
+            update_authority => {
+                if update_authority.key() == *authority_info.key {
+                    return Ok(());
+                }
+            }
+        },
         Authority::Address { address } => {
             if authority_info.key == address {
                 return Ok(());
             }
         }
+        Authority::Multisig { signers, threshold } => {
+            if is_multisig_approved(signers, *threshold, authority_info, accounts) {
+                return Ok(());
+            }
+        }
     }
 
     Err(MplCoreError::InvalidAuthority.into())
@@ -66,6 +84,7 @@ pub fn assert_collection_authority(
     collection: &CollectionV1,
     authority_info: &AccountInfo,
     authority: &Authority,
+    accounts: &[AccountInfo],
 ) -> ProgramResult {
     match authority {
         Authority::None | Authority::Owner => (),
@@ -79,11 +98,112 @@ pub fn assert_collection_authority(
                 return Ok(());
             }
         }
+        Authority::Multisig { signers, threshold } => {
+            if is_multisig_approved(signers, *threshold, authority_info, accounts) {
+                return Ok(());
+            }
+        }
     }
 
     Err(MplCoreError::InvalidAuthority.into())
 }
 
+/// Returns the authorities resolved for the authority, extended with the plugin authority when
+/// it is a multisig approved by the signing accounts.
+pub(crate) fn resolve_plugin_authorities<'b>(
+    resolved_authorities: &'b [Authority],
+    plugin_authority: &Authority,
+    authority_info: &AccountInfo,
+    accounts: &[AccountInfo],
+) -> Cow<'b, [Authority]> {
+    match plugin_authority {
+        Authority::Multisig { signers, threshold }
+            if is_multisig_approved(signers, *threshold, authority_info, accounts) =>
+        {
+            let mut authorities = resolved_authorities.to_vec();
+            authorities.push(plugin_authority.clone());
+            Cow::Owned(authorities)
+        }
+        _ => Cow::Borrowed(resolved_authorities),
+    }
+}
+
+/// Returns true if the authority is one of the multisig signers and at least `threshold`
+/// distinct signers have signed the transaction.
+pub fn is_multisig_approved(
+    signers: &[Pubkey],
+    threshold: u8,
+    authority_info: &AccountInfo,
+    accounts: &[AccountInfo],
+) -> bool {
+    if !authority_info.is_signer || !signers.contains(authority_info.key) {
+        return false;
+    }
+
+    let distinct_signers: HashSet<&Pubkey> = accounts
+        .iter()
+        .filter(|account| account.is_signer && signers.contains(account.key))
+        .map(|account| account.key)
+        .collect();
+
+    distinct_signers.len() >= threshold as usize
+}
+
+/// Returns a copy of the asset for validation in which a multisig update authority is replaced
+/// by the authority when the multisig is approved. The copy must never be saved.
+pub(crate) fn resolve_asset_update_authority(
+    asset: &AssetV1,
+    authority_info: &AccountInfo,
+    accounts: &[AccountInfo],
+) -> AssetV1 {
+    let mut resolved_asset = asset.clone();
+    if let UpdateAuthority::Multisig { signers, threshold } = &asset.update_authority {
+        if is_multisig_approved(signers, *threshold, authority_info, accounts) {
+            resolved_asset.update_authority = UpdateAuthority::Address(*authority_info.key);
+        }
+    }
+
+    resolved_asset
+}
+
+/// Loads a copy of the collection for validation in which the update authority is resolved for
+/// the authority. When the collection has a `Multisig` plugin, the stored update authority can
+/// no longer act alone and is replaced by the authority only when the multisig is approved.
+/// The copy must never be saved.
+pub(crate) fn resolve_collection_update_authority(
+    collection_info: &AccountInfo,
+    authority_info: &AccountInfo,
+    accounts: &[AccountInfo],
+) -> Result<CollectionV1, ProgramError> {
+    let mut collection = CollectionV1::load(collection_info, 0)?;
+
+    match fetch_wrapped_plugin::<CollectionV1>(
+        collection_info,
+        Some(&collection),
+        PluginType::Multisig,
+    ) {
+        Ok((_, Plugin::Multisig(multisig))) => {
+            collection.update_authority = if is_multisig_approved(
+                &multisig.signers,
+                multisig.threshold,
+                authority_info,
+                accounts,
+            ) {
+                *authority_info.key
+            } else {
+                Pubkey::default()
+            };
+        }
+        Ok(_) => return Err(MplCoreError::InvalidPlugin.into()),
+        Err(ProgramError::Custom(code))
+            if code == MplCoreError::PluginNotFound as u32
+                || code == MplCoreError::PluginsNotInitialized as u32 => {}
+        Err(err) => return Err(err),
+    }
+
+    Ok(collection)
+}
+
 /// Fetch the core data from the account; asset, plugin header (if present), and plugin registry (if present).
 pub fn fetch_core_data<T: DataBlob + SolanaAccount>(
     account: &AccountInfo,
@@ -101,22 +221,203 @@ pub fn fetch_core_data<T: DataBlob + SolanaAccount>(
     }
 }
 
-/// Persist a mutated `GroupV1` as flat Borsh data only.
-pub(crate) fn save_flat_group<'a>(
+/// Save the core data of an asset, collection or group whose serialized size may have
+/// changed from `core_size`, moving its plugins and their registry offsets to match.
+pub(crate) fn resize_and_save_core<'a, T: DataBlob + SolanaAccount>(
+    core: T,
+    plugin_header: &Option<PluginHeaderV1>,
+    plugin_registry: &Option<PluginRegistryV1>,
+    core_size: isize,
+    account: &AccountInfo<'a>,
+    payer: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+) -> ProgramResult {
+    if let (Some(mut plugin_header), Some(mut plugin_registry)) =
+        (plugin_header.clone(), plugin_registry.clone())
+    {
+        // The new size of the asset and new offset of the plugin header.
+        let new_core_size = core.len() as isize;
+
+        // The difference in size between the new and old asset which is used to calculate the new size of the account.
+        let size_diff = new_core_size
+            .checked_sub(core_size)
+            .ok_or(MplCoreError::NumericalOverflow)?;
+
+        // The new size of the account.
+        let new_size = (account.data_len() as isize)
+            .checked_add(size_diff)
+            .ok_or(MplCoreError::NumericalOverflow)?;
+
+        // The new offset of the plugin registry is the old offset plus the size difference.
+        let registry_offset = plugin_header.plugin_registry_offset;
+        let new_registry_offset = (registry_offset as isize)
+            .checked_add(size_diff)
+            .ok_or(MplCoreError::NumericalOverflow)?;
+        plugin_header.plugin_registry_offset = new_registry_offset as usize;
+
+        // The offset of the first plugin is the core size plus the size of the plugin header.
+        let plugin_offset = core_size
+            .checked_add(plugin_header.len() as isize)
+            .ok_or(MplCoreError::NumericalOverflow)?;
+
+        let new_plugin_offset = plugin_offset
+            .checked_add(size_diff)
+            .ok_or(MplCoreError::NumericalOverflow)?;
+
+        if size_diff > 0 {
+            // Growing: realloc first to make room for the rightward shift.
+            resize_or_reallocate_account(account, payer, system_program, new_size as usize)?;
+        }
+
+        let copy_len = registry_offset.saturating_sub(plugin_offset as usize);
+
+        if copy_len > 0 {
+            // SAFETY: When growing, the account was resized above so the destination
+            // region [new_plugin_offset, new_plugin_offset + copy_len) is in bounds.
+            // When shrinking, the account is still the original size, so the source
+            // region [plugin_offset, plugin_offset + copy_len) = [plugin_offset,
+            // registry_offset) is in bounds. `sol_memmove` correctly handles
+            // overlapping regions.
+            unsafe {
+                let base = account.data.borrow_mut().as_mut_ptr();
+                sol_memmove(
+                    base.add(new_plugin_offset as usize),
+                    base.add(plugin_offset as usize),
+                    copy_len,
+                );
+            }
+        }
+
+        if size_diff < 0 {
+            // Shrinking: realloc after memmove so the trailing plugin bytes are
+            // preserved while the buffer still has its full pre-shrink length.
+            resize_or_reallocate_account(account, payer, system_program, new_size as usize)?;
+        }
+
+        plugin_header.save(account, new_core_size as usize)?;
+
+        // Move offsets for existing registry records.
+        plugin_registry.bump_offsets(core_size as usize, size_diff)?;
+
+        plugin_registry.save(account, new_registry_offset as usize)?;
+    } else {
+        resize_or_reallocate_account(account, payer, system_program, core.len())?;
+    }
+
+    core.save(account, 0)?;
+
+    Ok(())
+}
+
+/// Persist a mutated `GroupV1`, moving any plugins that follow it if its size changed.
+pub(crate) fn save_group<'a>(
     group_info: &AccountInfo<'a>,
     group: &GroupV1,
     payer_info: &AccountInfo<'a>,
     system_program_info: &AccountInfo<'a>,
 ) -> ProgramResult {
-    let serialized_len = group.len();
+    let (stored_group, plugin_header, plugin_registry) = fetch_core_data::<GroupV1>(group_info)?;
+
+    resize_and_save_core(
+        group.clone(),
+        &plugin_header,
+        &plugin_registry,
+        stored_group.len() as isize,
+        group_info,
+        payer_info,
+        system_program_info,
+    )
+}
 
-    if serialized_len != group_info.data_len() {
-        resize_or_reallocate_account(group_info, payer_info, system_program_info, serialized_len)?;
+/// Create the membership account of an asset in a group, reopening it if it was closed.
+pub(crate) fn create_group_membership<'a>(
+    membership_info: &AccountInfo<'a>,
+    group: &Pubkey,
+    asset: &Pubkey,
+    payer_info: &AccountInfo<'a>,
+    system_program_info: &AccountInfo<'a>,
+) -> ProgramResult {
+    create_group_record(
+        membership_info,
+        &GroupMembershipV1::new(*group, *asset),
+        &[
+            GROUP_MEMBERSHIP_PREFIX.as_bytes(),
+            group.as_ref(),
+            asset.as_ref(),
+        ],
+        MplCoreError::InvalidGroupMembership,
+        payer_info,
+        system_program_info,
+    )
+}
+
+/// Create the delegate account of a delegate of a group, reopening it if it was closed.
+pub(crate) fn create_group_delegate<'a>(
+    delegate_record_info: &AccountInfo<'a>,
+    group: &Pubkey,
+    delegate: &Pubkey,
+    update_authority: &Pubkey,
+    payer_info: &AccountInfo<'a>,
+    system_program_info: &AccountInfo<'a>,
+) -> ProgramResult {
+    create_group_record(
+        delegate_record_info,
+        &GroupDelegateV1::new(*group, *delegate, *update_authority),
+        &[
+            GROUP_DELEGATE_PREFIX.as_bytes(),
+            group.as_ref(),
+            delegate.as_ref(),
+        ],
+        MplCoreError::InvalidGroupDelegate,
+        payer_info,
+        system_program_info,
+    )
+}
+
+/// Create a PDA account holding a record of a group, reopening it if it was closed.
+pub(crate) fn create_group_record<'a, T: DataBlob + SolanaAccount>(
+    record_info: &AccountInfo<'a>,
+    record: &T,
+    seeds: &[&[u8]],
+    derivation_error: MplCoreError,
+    payer_info: &AccountInfo<'a>,
+    system_program_info: &AccountInfo<'a>,
+) -> ProgramResult {
+    let bump = assert_derivation(&crate::ID, record_info, seeds, derivation_error)?;
+    let bump_seed = [bump];
+    let mut signer_seeds = seeds.to_vec();
+    signer_seeds.push(&bump_seed);
+
+    // A closed record whose lamports were collected is a system account holding the
+    // uninitialized key, which only the record itself can assign back to the program.
+    if record_info.owner == &solana_system_interface::program::ID && !record_info.data_is_empty() {
+        invoke_signed(
+            &system_instruction::assign(record_info.key, &crate::ID),
+            &[record_info.clone(), system_program_info.clone()],
+            &[&signer_seeds],
+        )?;
     }
 
-    group.save(group_info, 0)?;
+    if record_info.owner == &crate::ID {
+        // A closed record keeps its account until its lamports are collected.
+        if load_key(record_info, 0)? != Key::Uninitialized {
+            msg!("Error: Group record already exists");
+            return Err(MplCoreError::DuplicateEntry.into());
+        }
 
-    Ok(())
+        resize_or_reallocate_account(record_info, payer_info, system_program_info, record.len())?;
+    } else {
+        create_or_allocate_account_raw(
+            crate::ID,
+            record_info,
+            system_program_info,
+            payer_info,
+            record.len(),
+            &signer_seeds,
+        )?;
+    }
+
+    record.save(record_info, 0)
 }
 
 #[allow(clippy::too_many_arguments, clippy::type_complexity)]
@@ -127,6 +428,7 @@ pub(crate) fn validate_asset_permissions<'a>(
     asset: &'a AccountInfo<'a>,
     collection: Option<&'a AccountInfo<'a>>,
     new_owner: Option<&'a AccountInfo<'a>>,
+    sale_price: Option<u64>,
     new_authority: Option<&UpdateAuthority>,
     new_plugin: Option<&Plugin>,
     new_plugin_authority: Option<&Authority>,
@@ -167,7 +469,7 @@ pub(crate) fn validate_asset_permissions<'a>(
 
     let (deserialized_asset, plugin_header, plugin_registry) = fetch_core_data::<AssetV1>(asset)?;
     let resolved_authorities =
-        resolve_pubkey_to_authorities(authority_info, collection, &deserialized_asset)?;
+        resolve_pubkey_to_authorities(accounts, authority_info, collection, &deserialized_asset)?;
 
     // If the asset is part of a collection, the collection must be passed in and it must be correct.
     if let UpdateAuthority::Collection(collection_address) = deserialized_asset.update_authority {
@@ -231,7 +533,7 @@ pub(crate) fn validate_asset_permissions<'a>(
     let mut rejected = false;
     if asset_check != CheckResult::None {
         match asset_validate_fp(
-            &deserialized_asset,
+            &resolve_asset_update_authority(&deserialized_asset, authority_info, accounts),
             authority_info,
             new_plugin,
             new_external_plugin_adapter,
@@ -247,7 +549,11 @@ pub(crate) fn validate_asset_permissions<'a>(
 
     if collection_check != CheckResult::None {
         match collection_validate_fp(
-            &CollectionV1::load(collection.ok_or(MplCoreError::MissingCollection)?, 0)?,
+            &resolve_collection_update_authority(
+                collection.ok_or(MplCoreError::MissingCollection)?,
+                authority_info,
+                accounts,
+            )?,
             authority_info,
             new_plugin,
             new_external_plugin_adapter,
@@ -266,6 +572,7 @@ pub(crate) fn validate_asset_permissions<'a>(
         &checks,
         authority_info,
         new_owner,
+        sale_price,
         new_authority,
         None,
         new_plugin,
@@ -366,7 +673,7 @@ pub(crate) fn validate_collection_permissions<'a>(
     let (deserialized_collection, plugin_header, plugin_registry) =
         fetch_core_data::<CollectionV1>(collection)?;
     let resolved_authorities =
-        resolve_pubkey_to_authorities_collection(authority_info, collection)?;
+        resolve_pubkey_to_authorities_collection(accounts, authority_info, collection)?;
     let mut checks: BTreeMap<PluginType, (Key, CheckResult, RegistryRecord)> = BTreeMap::new();
     let mut external_checks: BTreeMap<
         ExternalPluginAdapterKey,
@@ -400,7 +707,7 @@ pub(crate) fn validate_collection_permissions<'a>(
     ) {
         let result = match core_check.0 {
             Key::CollectionV1 => collection_validate_fp(
-                &deserialized_collection,
+                &resolve_collection_update_authority(collection, authority_info, accounts)?,
                 authority_info,
                 new_plugin,
                 new_external_plugin_adapter,
@@ -423,6 +730,7 @@ pub(crate) fn validate_collection_permissions<'a>(
         authority_info,
         None,
         None,
+        None,
         new_authority,
         new_plugin,
         new_plugin_authority,
@@ -474,6 +782,7 @@ pub(crate) fn validate_collection_permissions<'a>(
 }
 
 pub(crate) fn resolve_pubkey_to_authorities(
+    accounts: &[AccountInfo],
     authority_info: &AccountInfo,
     maybe_collection_info: Option<&AccountInfo>,
     asset: &AssetV1,
@@ -483,21 +792,31 @@ pub(crate) fn resolve_pubkey_to_authorities(
         authorities.push(Authority::Owner);
     }
 
-    if asset.update_authority == UpdateAuthority::Address(*authority_info.key) {
-        authorities.push(Authority::UpdateAuthority);
-    } else if let UpdateAuthority::Collection(collection_address) = asset.update_authority {
-        match maybe_collection_info {
+    match &asset.update_authority {
+        UpdateAuthority::Address(address) => {
+            if address == authority_info.key {
+                authorities.push(Authority::UpdateAuthority);
+            }
+        }
+        UpdateAuthority::Collection(collection_address) => match maybe_collection_info {
             Some(collection_info) => {
-                if collection_info.key != &collection_address {
+                if collection_info.key != collection_address {
                     return Err(MplCoreError::InvalidCollection.into());
                 }
-                let collection: CollectionV1 = CollectionV1::load(collection_info, 0)?;
+                let collection =
+                    resolve_collection_update_authority(collection_info, authority_info, accounts)?;
                 if authority_info.key == &collection.update_authority {
                     authorities.push(Authority::UpdateAuthority);
                 }
             }
             None => return Err(MplCoreError::MissingCollection.into()),
+        },
+        UpdateAuthority::Multisig { signers, threshold } => {
+            if is_multisig_approved(signers, *threshold, authority_info, accounts) {
+                authorities.push(Authority::UpdateAuthority);
+            }
         }
+        UpdateAuthority::None => (),
     }
 
     authorities.push(Authority::Address {
@@ -508,10 +827,12 @@ pub(crate) fn resolve_pubkey_to_authorities(
 }
 
 pub(crate) fn resolve_pubkey_to_authorities_collection(
+    accounts: &[AccountInfo],
     authority_info: &AccountInfo,
     collection_info: &AccountInfo,
 ) -> Result<Vec<Authority>, ProgramError> {
-    let collection: CollectionV1 = CollectionV1::load(collection_info, 0)?;
+    let collection =
+        resolve_collection_update_authority(collection_info, authority_info, accounts)?;
     let mut authorities = Vec::with_capacity(3);
     if authority_info.key == collection.owner() {
         authorities.push(Authority::Owner);
@@ -550,6 +871,9 @@ pub(crate) fn resolve_authority<'a>(
 /// locating the collection `AccountInfo` in `all_accounts`.  Callers must ensure the
 /// collection account is present in the transaction when operating on collection-bound
 /// assets.
+///
+/// When the asset's update authority is `UpdateAuthority::Multisig`, the signer must be one
+/// of the multisig signers and enough distinct signers must be present in `all_accounts`.
 pub fn is_valid_asset_authority<'a>(
     asset_info: &AccountInfo<'a>,
     authority_info: &AccountInfo<'a>,
@@ -566,7 +890,8 @@ pub fn is_valid_asset_authority<'a>(
         UpdateAuthority::Collection(collection_addr) => {
             match all_accounts.iter().find(|a| a.key == collection_addr) {
                 Some(collection_info) => {
-                    if is_valid_collection_authority(collection_info, authority_info)? {
+                    if is_valid_collection_authority(collection_info, authority_info, all_accounts)?
+                    {
                         return Ok(true);
                     }
                 }
@@ -579,6 +904,11 @@ pub fn is_valid_asset_authority<'a>(
                 }
             }
         }
+        UpdateAuthority::Multisig { signers, threshold } => {
+            if is_multisig_approved(signers, *threshold, authority_info, all_accounts) {
+                return Ok(true);
+            }
+        }
         UpdateAuthority::None => {}
     }
 
@@ -612,13 +942,70 @@ pub fn is_valid_group_authority(
     Ok(authority_info.key == &group_core.update_authority)
 }
 
+/// Returns the `GroupDelegateV1` account if it leads the remaining accounts of an instruction
+/// managing the members of a group, along with the rest of the remaining accounts.
+pub(crate) fn split_group_delegate<'b, 'a>(
+    remaining_accounts: &'b [AccountInfo<'a>],
+) -> Result<(Option<&'b AccountInfo<'a>>, &'b [AccountInfo<'a>]), ProgramError> {
+    if let Some((first, rest)) = remaining_accounts.split_first() {
+        if first.owner == &crate::ID
+            && !first.data_is_empty()
+            && load_key(first, 0)? == Key::GroupDelegateV1
+        {
+            return Ok((Some(first), rest));
+        }
+    }
+
+    Ok((None, remaining_accounts))
+}
+
+/// Returns true if the `authority_info` represents either the update authority of the group or
+/// a delegate of the group approved to manage its members by the `GroupDelegateV1` account.
+/// A delegate approved by a previous update authority of the group is not valid.
+pub fn is_valid_group_member_authority(
+    group_info: &AccountInfo,
+    authority_info: &AccountInfo,
+    delegate_record_info: Option<&AccountInfo>,
+) -> Result<bool, ProgramError> {
+    let group_core = GroupV1::load(group_info, 0)?;
+    if authority_info.key == &group_core.update_authority {
+        return Ok(true);
+    }
+
+    match delegate_record_info {
+        Some(delegate_record_info) => {
+            assert_derivation(
+                &crate::ID,
+                delegate_record_info,
+                &[
+                    GROUP_DELEGATE_PREFIX.as_bytes(),
+                    group_info.key.as_ref(),
+                    authority_info.key.as_ref(),
+                ],
+                MplCoreError::InvalidGroupDelegate,
+            )?;
+            let delegate_record = GroupDelegateV1::load(delegate_record_info, 0)?;
+
+            Ok(delegate_record.group == *group_info.key
+                && delegate_record.delegate == *authority_info.key
+                && delegate_record.update_authority == group_core.update_authority)
+        }
+        None => Ok(false),
+    }
+}
+
 /// Returns true if the `authority_info` represents either the update authority of the collection
 /// or a valid update delegate (defined by an `UpdateDelegate` plugin on the collection).
+///
+/// When the collection has a `Multisig` plugin, the update authority is resolved against the
+/// multisig signers present in `all_accounts`.
 pub fn is_valid_collection_authority(
     collection_info: &AccountInfo,
     authority_info: &AccountInfo,
+    all_accounts: &[AccountInfo],
 ) -> Result<bool, ProgramError> {
-    let collection_core = CollectionV1::load(collection_info, 0)?;
+    let collection_core =
+        resolve_collection_update_authority(collection_info, authority_info, all_accounts)?;
     if authority_info.key == &collection_core.update_authority {
         return Ok(true);
     }
@@ -170,7 +170,7 @@ fn build_asset_with_plugins(
 
         registry_records.push(RegistryRecord {
             plugin_type,
-            authority: *authority,
+            authority: authority.clone(),
             offset,
         });
     }
@@ -243,7 +243,7 @@ fn build_collection_with_plugins(
 
         registry_records.push(RegistryRecord {
             plugin_type,
-            authority: *authority,
+            authority: authority.clone(),
             offset,
         });
     }